@@ -0,0 +1,206 @@
+#include <inttypes.h>
+#include <pthread.h>
+#include <setjmp.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+/* The runtime of the C backend, which does what stub.rs does for the native
+ * code. The declarations shared with the emitted code are repeated at the
+ * top of every compiled_code.c and have to agree with the ones here.
+ */
+
+#define INT_TAG 0x1ULL
+#define TAG_MASK 0x7ULL
+#define ARRAY_TAG 0x1ULL
+#define CLOSURE_TAG 0x3ULL
+
+#define SNAKE_TRU 0xFFFFFFFFFFFFFFFFULL
+#define SNAKE_FLS 0x7FFFFFFFFFFFFFFFULL
+
+/* Class index of the Error objects that carry a caught RuntimeErr code */
+#define ERROR_CLASS_ID 1
+
+/* The handler of the innermost try, which a raise jumps to */
+struct snake_handler {
+    jmp_buf buf;
+    struct snake_handler *prev;
+};
+
+struct snake_handler *snake_handler = NULL;
+uint64_t snake_raised;
+
+/* The address every function checks its frame against */
+uintptr_t snake_stack_limit;
+
+/* The stack a program checks its frames against. The C frames are several
+ * times the size of the native ones, a try keeps a whole jmp_buf, so this is
+ * larger than the native default of 8MB.
+ */
+#define STACK_BYTES ((size_t)64 << 20)
+
+/* The room the thread has below the stack limit, for the runtime functions
+ * and the C library
+ */
+#define STACK_RESERVE_BYTES ((size_t)256 << 10)
+
+extern uint64_t start_here(void);
+
+static const char *RUNTIME_ERRS[] = {
+    "if expected a boolean",
+    "comparison expected a number",
+    "arithmetic expected a number",
+    "logic expected a boolean",
+    "overflow",
+    "indexed into non-array",
+    "index not a number",
+    "index out of bounds",
+    "length called with non-array",
+    "called a non-function",
+    "wrong number of arguments",
+    "calling method from another class",
+    "wrong number of fields when constructing object",
+    "while expected a boolean",
+    "no match arm matched the value",
+    "let pattern does not match the value",
+    "out of memory",
+    "stack overflow",
+};
+
+/* The heap is handed out from chunks that are never freed */
+#define HEAP_CHUNK (1 << 16)
+
+static uint64_t *heap_next = NULL;
+static uint64_t heap_left = 0;
+
+uint64_t *snake_alloc(uint64_t words) {
+    if (words > heap_left) {
+        uint64_t size = words > HEAP_CHUNK ? words : HEAP_CHUNK;
+        heap_next = malloc(size * sizeof(uint64_t));
+        if (heap_next == NULL) {
+            fprintf(stderr, "out of memory\n");
+            exit(1);
+        }
+        heap_left = size;
+    }
+    uint64_t *p = heap_next;
+    heap_next += words;
+    heap_left -= words;
+    return p;
+}
+
+/* The arrays being printed, to print <loop> for the cyclic ones */
+struct seen {
+    uint64_t val;
+    struct seen *prev;
+};
+
+static void fprint_snake_val(FILE *out, uint64_t x, struct seen *seen) {
+    if ((x & INT_TAG) == 0) {
+        fprintf(out, "%" PRId64, (int64_t)x >> 1);
+    } else if (x == SNAKE_TRU) {
+        fprintf(out, "true");
+    } else if (x == SNAKE_FLS) {
+        fprintf(out, "false");
+    } else if ((x & TAG_MASK) == ARRAY_TAG) {
+        for (struct seen *s = seen; s != NULL; s = s->prev) {
+            if (s->val == x) {
+                fprintf(out, "<loop>");
+                return;
+            }
+        }
+        struct seen here = {x, seen};
+        uint64_t *array = (uint64_t *)(uintptr_t)(x - ARRAY_TAG);
+        uint64_t len = array[1] >> 1;
+        fprintf(out, "[");
+        for (uint64_t i = 0; i < len; i++) {
+            if (i > 0) {
+                fprintf(out, ", ");
+            }
+            fprint_snake_val(out, array[i + 2], &here);
+        }
+        fprintf(out, "]");
+    } else if ((x & TAG_MASK) == CLOSURE_TAG) {
+        fprintf(out, "<closure>");
+    } else {
+        fprintf(out, "Invalid snake value 0x%" PRIx64, x);
+    }
+}
+
+uint64_t print_snake_val(uint64_t v) {
+    fprint_snake_val(stdout, v, NULL);
+    printf("\n");
+    return v;
+}
+
+void snake_error(uint64_t err_code) {
+    if (err_code < sizeof(RUNTIME_ERRS) / sizeof(RUNTIME_ERRS[0])) {
+        fprintf(stderr, "%s\n", RUNTIME_ERRS[err_code]);
+    } else {
+        fprintf(stderr, "Unknown Error!\n");
+    }
+    fflush(stdout);
+    exit(1);
+}
+
+void snake_uncaught(uint64_t v) {
+    if ((v & TAG_MASK) == ARRAY_TAG) {
+        /* a re-raised built-in error reports like the original failure */
+        uint64_t *object = (uint64_t *)(uintptr_t)(v - ARRAY_TAG);
+        if (object[0] == ERROR_CLASS_ID) {
+            snake_error(object[2] >> 1);
+        }
+    }
+    fprintf(stderr, "uncaught exception: ");
+    fprint_snake_val(stderr, v, NULL);
+    fprintf(stderr, "\n");
+    fflush(stdout);
+    exit(1);
+}
+
+/* Jumps to the innermost handler, these return a value only so the emitted
+ * code can use them in place of one
+ */
+uint64_t snake_raise(uint64_t v) {
+    if (snake_handler == NULL) {
+        snake_uncaught(v);
+    }
+    snake_raised = v;
+    longjmp(snake_handler->buf, 1);
+}
+
+uint64_t snake_fail(uint64_t err_code) {
+    if (snake_handler == NULL) {
+        snake_error(err_code);
+    }
+    uint64_t *error = snake_alloc(3);
+    error[0] = ERROR_CLASS_ID;
+    error[1] = 2;
+    error[2] = err_code << 1;
+    return snake_raise((uint64_t)(uintptr_t)error + ARRAY_TAG);
+}
+
+/* The program runs on a thread of its own, so the size of its stack and
+ * with it the limit are known
+ */
+static void *run(void *arg) {
+    char top;
+    (void)arg;
+    snake_stack_limit = (uintptr_t)&top - STACK_BYTES;
+    uint64_t output = start_here();
+    print_snake_val(output);
+    return NULL;
+}
+
+int main(void) {
+    pthread_attr_t attr;
+    pthread_t thread;
+    if (pthread_attr_init(&attr) != 0 ||
+        pthread_attr_setstacksize(&attr, STACK_BYTES + STACK_RESERVE_BYTES) != 0 ||
+        pthread_create(&thread, &attr, run, NULL) != 0) {
+        fprintf(stderr, "failed to start the program's thread\n");
+        return 1;
+    }
+    pthread_join(thread, NULL);
+    return 0;
+}
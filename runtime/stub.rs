@@ -47,15 +47,22 @@ enum RuntimeErr {
     ArityError,
     MethodTypeError,
     FieldNumError,
+    WhileError,
+    MatchError,
+    DestructureError,
+    OutOfMemoryError,
+    StackOverflowError,
 }
 
-#[link(name = "compiled_code", kind = "static")]
+// Built as the cached runtime library (with --cfg snake_staticlib) the
+// compiled code is not linked here but next to the library by the linker
+#[cfg_attr(not(snake_staticlib), link(name = "compiled_code", kind = "static"))]
 extern "sysv64" {
 
     // The \x01 here is an undocumented feature of LLVM that ensures
     // it does not add an underscore in front of the name.
     #[link_name = "\x01start_here"]
-    fn start_here() -> SnakeVal;
+    fn start_here(heap: *mut u64, heap_end: *mut u64, stack_limit: *const u8) -> SnakeVal;
 }
 
 // reinterprets the bytes of an unsigned number to a signed number
@@ -112,7 +119,7 @@ fn sprint_snake_val(x: SnakeVal) -> String {
 }
 
 #[export_name = "\x01print_snake_val"]
-extern "sysv64" fn print_snake_val(v: SnakeVal) -> SnakeVal {
+pub extern "sysv64" fn print_snake_val(v: SnakeVal) -> SnakeVal {
     println!("{}", sprint_snake_val(v));
     return v;
 }
@@ -122,7 +129,7 @@ extern "sysv64" fn print_snake_val(v: SnakeVal) -> SnakeVal {
  *
 **/
 #[export_name = "\x01snake_error"]
-extern "sysv64" fn snake_error(err_code: u64) {
+pub extern "sysv64" fn snake_error(err_code: u64) {
     if err_code == (RuntimeErr::IfError as u64) {
         eprintln!("if expected a boolean");
     } else if err_code == (RuntimeErr::CmpError as u64) {
@@ -149,13 +156,111 @@ extern "sysv64" fn snake_error(err_code: u64) {
         eprintln!("calling method from another class");
     } else if err_code == (RuntimeErr::FieldNumError as u64) {
         eprintln!("wrong number of fields when constructing object");
+    } else if err_code == (RuntimeErr::WhileError as u64) {
+        eprintln!("while expected a boolean");
+    } else if err_code == (RuntimeErr::MatchError as u64) {
+        eprintln!("no match arm matched the value");
+    } else if err_code == (RuntimeErr::DestructureError as u64) {
+        eprintln!("let pattern does not match the value");
+    } else if err_code == (RuntimeErr::OutOfMemoryError as u64) {
+        eprintln!("out of memory");
+    } else if err_code == (RuntimeErr::StackOverflowError as u64) {
+        eprintln!("stack overflow");
     } else {
         eprintln!("Unknown Error!");
     }
     std::process::exit(1);
 }
 
+// Class index of the Error objects that carry a caught RuntimeErr code
+static ERROR_CLASS_ID: u64 = 1;
+
+#[export_name = "\x01snake_uncaught"]
+pub extern "sysv64" fn snake_uncaught(v: SnakeVal) {
+    if v.0 & TAG_MASK == ARRAY_TAG {
+        // a re-raised built-in error reports like the original failure
+        let address = (v.0 - ARRAY_TAG) as *const u64;
+        let classidx = unsafe { *address };
+        if classidx == ERROR_CLASS_ID {
+            let err_code = unsafe { *address.add(2) } >> 1;
+            snake_error(err_code);
+        }
+    }
+    eprintln!("uncaught exception: {}", sprint_snake_val(v));
+    std::process::exit(1);
+}
+
+// The heap a program gets unless --heap-size says otherwise, the same as
+// HEAP_SIZE in src/compile.rs
+static DEFAULT_HEAP_BYTES: usize = 64 << 20;
+
+// The stack a program gets unless --stack-size says otherwise, the usual
+// size of the main thread's stack
+static DEFAULT_STACK_BYTES: usize = 8 << 20;
+
+// The room the thread has below the stack limit the program checks its
+// frames against, for the arguments of calls and the runtime functions
+static STACK_RESERVE_BYTES: usize = 256 << 10;
+
+static USAGE: &str = "usage: PROGRAM [--heap-size BYTES] [--stack-size BYTES]";
+
+struct Options {
+    heap_bytes: usize,
+    stack_bytes: usize,
+}
+
+// The command line flags all go to the runtime, the programs take no input
+fn parse_options() -> Options {
+    let mut options = Options {
+        heap_bytes: DEFAULT_HEAP_BYTES,
+        stack_bytes: DEFAULT_STACK_BYTES,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        let bytes = args.next().and_then(|v| v.parse::<usize>().ok());
+        match (flag.as_str(), bytes) {
+            ("--heap-size", Some(bytes)) => options.heap_bytes = bytes,
+            ("--stack-size", Some(bytes)) => options.stack_bytes = bytes,
+            _ => {
+                eprintln!("{}", USAGE);
+                std::process::exit(1);
+            }
+        }
+    }
+    options
+}
+
+// The program runs on a thread of its own, so the size of its stack and
+// with it the limit start_here gets are known
+fn run() {
+    let options = parse_options();
+    let heap_words = (options.heap_bytes + 7) / 8;
+    let stack_bytes = options.stack_bytes;
+    let program = move || {
+        let mut heap = vec![0u64; heap_words];
+        let start = heap.as_mut_ptr();
+        let top = &heap as *const Vec<u64> as usize;
+        let limit = top.saturating_sub(stack_bytes) as *const u8;
+        let output = unsafe { start_here(start, start.add(heap_words), limit) };
+        println!("{}", sprint_snake_val(output));
+    };
+    std::thread::Builder::new()
+        .stack_size(stack_bytes.saturating_add(STACK_RESERVE_BYTES))
+        .spawn(program)
+        .expect("failed to start the program's thread")
+        .join()
+        .expect("the program's thread panicked");
+}
+
+#[cfg(not(snake_staticlib))]
 fn main() {
-    let output = unsafe { start_here() };
-    println!("{}", sprint_snake_val(output));
+    run()
+}
+
+// The runtime library provides the C entry point itself
+#[cfg(snake_staticlib)]
+#[no_mangle]
+pub extern "C" fn main() -> i32 {
+    run();
+    0
 }
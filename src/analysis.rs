@@ -2,7 +2,6 @@ use crate::asm::Reg;
 use crate::graph::Graph;
 use crate::syntax::{ImmExp, Prim1, SeqExp};
 use std::collections::{HashMap, HashSet};
-use std::process::ExitStatus;
 
 /* A location where a local variable is stored */
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -85,6 +84,17 @@ pub fn liveness<Ann>(
                     &acc | &liveness_imm(imm, params)
                 }),
         },
+        SeqExp::CallDirect {
+            label,
+            args,
+            ann: _,
+        } => SeqExp::CallDirect {
+            label: label.clone(),
+            args: args.clone(),
+            ann: args
+                .iter()
+                .fold(live_out, |acc, imm| &acc | &liveness_imm(imm, params)),
+        },
         SeqExp::Let {
             var,
             bound_exp,
@@ -121,6 +131,82 @@ pub fn liveness<Ann>(
                 ann: &ann | &liveness_imm(cond, params),
             }
         }
+        SeqExp::Object {
+            class,
+            fields,
+            ann: _,
+        } => SeqExp::Object {
+            class: class.clone(),
+            fields: fields.clone(),
+            ann: fields
+                .iter()
+                .fold(live_out, |acc, imm| &acc | &liveness_imm(imm, params)),
+        },
+        SeqExp::CallMethod {
+            object,
+            method,
+            args,
+            ann: _,
+        } => SeqExp::CallMethod {
+            object: (*object).clone(),
+            method: method.clone(),
+            args: args.clone(),
+            ann: args
+                .iter()
+                .fold(&live_out | &liveness_imm(object, params), |acc, imm| {
+                    &acc | &liveness_imm(imm, params)
+                }),
+        },
+        SeqExp::While { cond, body, ann: _ } => {
+            // Iterate until the variables live at the top of the loop are stable
+            let mut ann: HashSet<String> = live_out.clone();
+            loop {
+                let body_analysis: SeqExp<HashSet<String>> = liveness(body, params, ann.clone());
+                let cond_analysis: SeqExp<HashSet<String>> =
+                    liveness(cond, params, &live_out | &body_analysis.ann());
+                let next: HashSet<String> = &ann | &cond_analysis.ann();
+                if next == ann {
+                    return SeqExp::While {
+                        cond: Box::new(cond_analysis),
+                        body: Box::new(body_analysis),
+                        ann,
+                    };
+                }
+                ann = next;
+            }
+        }
+        // The assigned variable counts as used, its slot must stay allocated
+        SeqExp::Assign { var, value, ann: _ } => SeqExp::Assign {
+            var: var.clone(),
+            value: (*value).clone(),
+            ann: &(&live_out | &liveness_imm(value, params))
+                | &liveness_imm(&ImmExp::Var(var.clone()), params),
+        },
+        SeqExp::Raise(imm, _) => {
+            SeqExp::Raise((*imm).clone(), &live_out | &liveness_imm(imm, params))
+        }
+        SeqExp::Try {
+            body,
+            var,
+            handler,
+            ann: _,
+        } => {
+            // The body may jump to the handler at any point
+            let handler_analysis: SeqExp<HashSet<String>> =
+                liveness(handler, params, live_out.clone());
+            let mut handler_live: HashSet<String> = handler_analysis.ann();
+            handler_live.remove(var);
+            let body_analysis: SeqExp<HashSet<String>> =
+                liveness(body, params, &live_out | &handler_live);
+            let ann: HashSet<String> = &body_analysis.ann() | &handler_live;
+            SeqExp::Try {
+                body: Box::new(body_analysis),
+                var: var.clone(),
+                handler: Box::new(handler_analysis),
+                ann,
+            }
+        }
+        SeqExp::Fail(err, _) => SeqExp::Fail(*err, live_out),
     }
 }
 
@@ -187,7 +273,7 @@ fn conflicts_helper(
             deal_conflict(ann, conflict_graph, non_conflict_graph);
             None
         }
-        SeqExp::CallClosure { ann, .. } => {
+        SeqExp::CallClosure { ann, .. } | SeqExp::CallDirect { ann, .. } => {
             deal_conflict(ann, conflict_graph, non_conflict_graph);
             None
         }
@@ -227,6 +313,33 @@ fn conflicts_helper(
                 None
             }
         }
+        SeqExp::While { cond, body, ann } => {
+            deal_conflict(ann, conflict_graph, non_conflict_graph);
+            conflicts_helper(cond, conflict_graph, non_conflict_graph);
+            conflicts_helper(body, conflict_graph, non_conflict_graph);
+            None
+        }
+        SeqExp::Try {
+            body,
+            var,
+            handler,
+            ann,
+        } => {
+            deal_conflict(ann, conflict_graph, non_conflict_graph);
+            conflicts_helper(body, conflict_graph, non_conflict_graph);
+            conflict_graph.insert_vertex(var.clone());
+            non_conflict_graph.insert_vertex(var.clone());
+            conflicts_helper(handler, conflict_graph, non_conflict_graph);
+            None
+        }
+        SeqExp::Object { ann, .. }
+        | SeqExp::CallMethod { ann, .. }
+        | SeqExp::Assign { ann, .. }
+        | SeqExp::Raise(_, ann)
+        | SeqExp::Fail(_, ann) => {
+            deal_conflict(ann, conflict_graph, non_conflict_graph);
+            None
+        }
     }
 }
 
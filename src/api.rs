@@ -0,0 +1,196 @@
+use std::io::Write;
+
+use crate::asm::{program_to_string, Instr};
+use crate::compile::{
+    check_warnings, compile_to_bytecode, compile_to_program, compile_to_seq_prog, compile_to_wasm,
+    CompileErr,
+};
+use crate::modules::{self, LoadErr};
+use crate::runner::span::{file_info, span1_to_span2, FileInfo};
+use crate::span::{Span1, Span2};
+use crate::syntax::{SeqProg, SurfProg};
+use crate::types::TypeTable;
+use crate::vm;
+use crate::wasm;
+
+// The compiler pipeline for other tools. Unlike the runner, which reads
+// files, prints and exits, these take the source as a string and return
+// what each stage produced along with the diagnostics about the program.
+// Running a program writes its output into the sinks the caller gives.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    // where in the source the problem is, when it is known
+    pub span: Option<Span2>,
+}
+
+// The output of a stage, with the warnings about the program. When a stage
+// fails it returns the warnings found before the error, and the error last.
+#[derive(Clone, Debug)]
+pub struct Compiled<T> {
+    pub output: T,
+    pub warnings: Vec<Diagnostic>,
+}
+
+pub type CompileResult<T> = Result<Compiled<T>, Vec<Diagnostic>>;
+
+// The backends that run a program inside the compiler. They give it the
+// stack and heap of the native runtime, so a program that recurses or
+// allocates without end fails with a stack overflow or out of memory
+// instead of exhausting the caller. The native code is left out, a program
+// that goes wrong there takes the caller down with it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    Vm,
+    Wasm,
+}
+
+pub fn parse(source: &str) -> CompileResult<SurfProg<Span1>> {
+    parse_with_info(source).map(|(_, prog)| Compiled {
+        output: prog,
+        warnings: Vec::new(),
+    })
+}
+
+// Every error and warning about the program, without generating its code
+pub fn check(source: &str) -> Vec<Diagnostic> {
+    match compile_with(source, |p| compile_to_seq_prog(p).map(|_| ())) {
+        Ok(compiled) => compiled.warnings,
+        Err(diagnostics) => diagnostics,
+    }
+}
+
+// The program after all the passes, as the backends get it
+pub fn compile_to_seq(source: &str) -> CompileResult<(SeqProg<u32>, TypeTable)> {
+    compile_with(source, compile_to_seq_prog)
+}
+
+pub fn compile_to_instrs(source: &str) -> CompileResult<Vec<Instr>> {
+    compile_with(source, |p| compile_to_program(p).map(|(prog, _)| prog.text))
+}
+
+pub fn compile_to_asm(source: &str) -> CompileResult<String> {
+    compile_with(source, |p| {
+        compile_to_program(p).map(|(prog, _)| program_to_string(&prog))
+    })
+}
+
+// Runs the program on the backend. What it prints goes to stdout and the
+// message of a runtime failure to stderr, including running out of stack
+// or heap, and the output says whether the program finished without one.
+pub fn run<O, E>(
+    source: &str,
+    backend: Backend,
+    stdout: &mut O,
+    stderr: &mut E,
+) -> CompileResult<bool>
+where
+    O: Write,
+    E: Write,
+{
+    let compiled = match backend {
+        Backend::Vm => compile_with(source, compile_to_bytecode)
+            .and_then(|c| in_process(c, |b| Ok(vm::run(b)))),
+        Backend::Wasm => compile_with(source, compile_to_wasm)
+            .and_then(|c| in_process(c, |module| wasm::run(module))),
+    }?;
+    let (printed, failure) = &compiled.output;
+    let written = write!(stdout, "{}", printed).and_then(|()| match failure {
+        Some(msg) => writeln!(stderr, "{}", msg),
+        None => Ok(()),
+    });
+    match written {
+        Ok(()) => Ok(Compiled {
+            output: failure.is_none(),
+            warnings: compiled.warnings,
+        }),
+        Err(e) => {
+            let mut diagnostics = compiled.warnings;
+            diagnostics.push(error(format!("I/O error: {}", e), None));
+            Err(diagnostics)
+        }
+    }
+}
+
+type Run = (String, Option<String>);
+
+// Runs the compiled program and keeps the warnings of its compile
+fn in_process<T, F>(compiled: Compiled<T>, run: F) -> CompileResult<Run>
+where
+    F: FnOnce(&T) -> Result<Run, String>,
+{
+    match run(&compiled.output) {
+        Ok(output) => Ok(Compiled {
+            output,
+            warnings: compiled.warnings,
+        }),
+        Err(msg) => {
+            let mut diagnostics = compiled.warnings;
+            diagnostics.push(error(msg, None));
+            Err(diagnostics)
+        }
+    }
+}
+
+// Parses the source, collects its warnings and hands it to the stage
+fn compile_with<T, F>(source: &str, stage: F) -> CompileResult<T>
+where
+    F: FnOnce(&SurfProg<Span1>) -> Result<T, CompileErr<Span1>>,
+{
+    let (info, prog) = parse_with_info(source)?;
+    let warnings: Vec<Diagnostic> = check_warnings(&prog)
+        .into_iter()
+        .map(|w| {
+            let w = w.map_span(|s| span1_to_span2(&info, *s));
+            Diagnostic {
+                severity: Severity::Warning,
+                message: w.to_string(),
+                span: Some(*w.location()),
+            }
+        })
+        .collect();
+    match stage(&prog) {
+        Ok(output) => Ok(Compiled { output, warnings }),
+        Err(e) => {
+            let e = e.map_span(|s| span1_to_span2(&info, *s));
+            let mut diagnostics = warnings;
+            diagnostics.push(error(e.to_string(), Some(*e.location())));
+            Err(diagnostics)
+        }
+    }
+}
+
+// Imports are relative to the current directory
+fn parse_with_info(source: &str) -> Result<(FileInfo, SurfProg<Span1>), Vec<Diagnostic>> {
+    match modules::load(source, None) {
+        Ok((files, prog)) => Ok((file_info(&files), prog)),
+        Err((files, e)) => {
+            let info = file_info(&files);
+            Err(vec![match e {
+                LoadErr::Parse { message, location } => {
+                    error(message, location.map(|s| span1_to_span2(&info, s)))
+                }
+                LoadErr::Import(e) => {
+                    let e = e.map_span(|s| span1_to_span2(&info, *s));
+                    error(e.to_string(), Some(*e.location()))
+                }
+            }])
+        }
+    }
+}
+
+fn error(message: String, span: Option<Span2>) -> Diagnostic {
+    Diagnostic {
+        severity: Severity::Error,
+        message,
+        span,
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Reg {
     Rax,
     Rbx,
@@ -314,3 +314,33 @@ pub fn instrs_to_string(is: &[Instr]) -> String {
     }
     buf
 }
+
+// A whole assembly file. The data section only holds zeroed quadwords, given
+// as a label and how many quadwords it names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Program {
+    pub data: Vec<(String, usize)>,
+    pub globals: Vec<String>,
+    pub externs: Vec<String>,
+    pub text: Vec<Instr>,
+}
+
+pub fn program_to_string(p: &Program) -> String {
+    let mut buf = String::from("section .data\n");
+    for (label, size) in &p.data {
+        if *size == 1 {
+            buf.push_str(&format!("{}: dq 0\n", label));
+        } else {
+            buf.push_str(&format!("{}: times {} dq 0\n", label, size));
+        }
+    }
+    buf.push_str("section .text\n");
+    for g in &p.globals {
+        buf.push_str(&format!("        global {}\n", g));
+    }
+    for e in &p.externs {
+        buf.push_str(&format!("        extern {}\n", e));
+    }
+    buf.push_str(&instrs_to_string(&p.text));
+    buf
+}
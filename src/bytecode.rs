@@ -0,0 +1,423 @@
+use crate::runtime_error::RuntimeErr;
+use crate::syntax::{ClassInfo, ImmExp, Prim1, Prim2, SeqExp, SeqProg};
+use crate::types::TypeTable;
+use std::collections::HashMap;
+
+// A portable form of a sequentialized program, run by vm.rs. Every function
+// has a frame of slots for its parameters and let-bound variables, and the
+// instructions leave their result in an accumulator, like the native code
+// does with Rax. Values are tagged as in the native code, and the numbers
+// compile.rs keeps untagged stay untagged in their slots, so overflows are
+// reported at the same places.
+
+static SNAKE_TRUE: u64 = 0xFF_FF_FF_FF_FF_FF_FF_FF;
+static SNAKE_FALSE: u64 = 0x7F_FF_FF_FF_FF_FF_FF_FF;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Arg {
+    // A tagged constant
+    Imm(u64),
+    // A slot holding a tagged value
+    Slot(usize),
+    // A slot holding an untagged number, tagged when it is read as a value
+    Raw(usize),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Op {
+    Load(Arg),
+    Store(usize),
+    Prim1(Prim1, Arg),
+    Prim2(Prim2, Arg, Arg),
+    // Untagged numbers, see compile::compile_unboxed. Tag turns the
+    // accumulator back into a value
+    Untag(Arg),
+    Raw1(Prim1, Arg),
+    Raw2(Prim2, Arg, Arg),
+    Tag,
+    Array(Vec<Arg>),
+    Object {
+        class: usize,
+        fields: Vec<Arg>,
+    },
+    ArraySet {
+        array: Arg,
+        index: Arg,
+        value: Arg,
+    },
+    MakeClosure {
+        arity: usize,
+        fun: usize,
+        env: Arg,
+    },
+    CallClosure {
+        fun: Arg,
+        args: Vec<Arg>,
+        tail: bool,
+    },
+    CallDirect {
+        fun: usize,
+        args: Vec<Arg>,
+        tail: bool,
+    },
+    CallMethod {
+        object: Arg,
+        class: usize,
+        method: Arg,
+        args: Vec<Arg>,
+        tail: bool,
+    },
+    Return,
+    Jump(usize),
+    // Jump when the accumulator is false, it has to be a boolean
+    JumpUnless {
+        err: RuntimeErr,
+        target: usize,
+    },
+    // Push a handler that stores the raised value in var and jumps to catch
+    Try {
+        catch: usize,
+        var: usize,
+    },
+    EndTry,
+    Raise(Arg),
+    Fail(RuntimeErr),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function {
+    pub name: String,
+    pub entry: usize,
+    pub frame: usize,
+    // Direct functions take no env, closure calls to them drop it
+    pub takes_env: bool,
+}
+
+// main is the first function
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bytecode {
+    pub code: Vec<Op>,
+    pub funs: Vec<Function>,
+}
+
+struct Compiler<'a> {
+    code: Vec<Op>,
+    funs: HashMap<&'a str, usize>,
+    class: &'a HashMap<String, ClassInfo>,
+    types: &'a TypeTable,
+    // The number of slots the current function uses so far
+    frame: usize,
+}
+
+impl<'a> Compiler<'a> {
+    fn emit(&mut self, op: Op) -> usize {
+        self.code.push(op);
+        self.code.len() - 1
+    }
+
+    // Point the jump at the given position to the next instruction
+    fn patch(&mut self, at: usize) {
+        let here = self.code.len();
+        match &mut self.code[at] {
+            Op::Jump(target) | Op::JumpUnless { target, .. } | Op::Try { catch: target, .. } => {
+                *target = here
+            }
+            op => panic!("{:?} is not a jump", op),
+        }
+    }
+
+    fn slot(&mut self, slot: usize) -> usize {
+        self.frame = self.frame.max(slot + 1);
+        slot
+    }
+
+    fn is_unboxed(&self, imm: &ImmExp) -> bool {
+        matches!(imm, ImmExp::Var(x) if self.types.is_unboxed(x))
+    }
+
+    fn arg(&self, imm: &ImmExp, env: &HashMap<&str, usize>) -> Arg {
+        match imm {
+            ImmExp::Num(n) => Arg::Imm((*n << 1) as u64),
+            ImmExp::Bool(true) => Arg::Imm(SNAKE_TRUE),
+            ImmExp::Bool(false) => Arg::Imm(SNAKE_FALSE),
+            ImmExp::Var(x) => match env.get(x.as_str()) {
+                Some(slot) if self.types.is_unboxed(x) => Arg::Raw(*slot),
+                Some(slot) => Arg::Slot(*slot),
+                None => panic!("Variable {} is guaranteed to be in scope", x),
+            },
+        }
+    }
+
+    fn args(&self, imms: &[ImmExp], env: &HashMap<&str, usize>) -> Vec<Arg> {
+        imms.iter().map(|imm| self.arg(imm, env)).collect()
+    }
+
+    // The untagged result of the arithmetic an unboxed variable is bound to
+    fn compile_unboxed(&mut self, e: &SeqExp<u32>, env: &HashMap<&str, usize>) {
+        let op = match e {
+            SeqExp::Imm(imm, _) => Op::Untag(self.arg(imm, env)),
+            SeqExp::Prim1(p, imm, _) => Op::Raw1(*p, self.arg(imm, env)),
+            SeqExp::Prim2(p, e1, e2, _) => Op::Raw2(*p, self.arg(e1, env), self.arg(e2, env)),
+            _ => panic!("Only arithmetic is unboxed"),
+        };
+        self.emit(op);
+    }
+
+    fn compile_exp<'exp>(
+        &mut self,
+        e: &'exp SeqExp<u32>,
+        mut env: HashMap<&'exp str, usize>,
+        size: usize,
+        tail: bool,
+    ) {
+        match e {
+            SeqExp::Imm(imm, _) => {
+                self.emit(Op::Load(self.arg(imm, &env)));
+            }
+            SeqExp::Prim1(Prim1::Add1 | Prim1::Sub1, imm, _) if self.is_unboxed(imm) => {
+                self.compile_unboxed(e, &env);
+                self.emit(Op::Tag);
+            }
+            SeqExp::Prim1(p, imm, _) => {
+                self.emit(Op::Prim1(*p, self.arg(imm, &env)));
+            }
+            SeqExp::Prim2(Prim2::Add | Prim2::Sub | Prim2::Mul, e1, e2, _)
+                if self.is_unboxed(e1) || self.is_unboxed(e2) =>
+            {
+                self.compile_unboxed(e, &env);
+                self.emit(Op::Tag);
+            }
+            SeqExp::Prim2(Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge, e1, e2, _)
+                if self.is_unboxed(e1) || self.is_unboxed(e2) =>
+            {
+                self.compile_unboxed(e, &env)
+            }
+            SeqExp::Prim2(Prim2::Eq | Prim2::Neq, e1, e2, _)
+                if (self.is_unboxed(e1) || matches!(e1, ImmExp::Num(_)))
+                    && (self.is_unboxed(e2) || matches!(e2, ImmExp::Num(_))) =>
+            {
+                self.compile_unboxed(e, &env)
+            }
+            SeqExp::Prim2(p, e1, e2, _) => {
+                self.emit(Op::Prim2(*p, self.arg(e1, &env), self.arg(e2, &env)));
+            }
+            SeqExp::Array(elts, _) => {
+                self.emit(Op::Array(self.args(elts, &env)));
+            }
+            SeqExp::ArraySet {
+                array,
+                index,
+                new_value,
+                ann: _,
+            } => {
+                self.emit(Op::ArraySet {
+                    array: self.arg(array, &env),
+                    index: self.arg(index, &env),
+                    value: self.arg(new_value, &env),
+                });
+            }
+            SeqExp::MakeClosure {
+                arity,
+                label,
+                env: capture,
+                ann: _,
+            } => {
+                self.emit(Op::MakeClosure {
+                    arity: *arity,
+                    fun: self.funs[label.as_str()],
+                    env: self.arg(capture, &env),
+                });
+            }
+            SeqExp::CallClosure { fun, args, ann: _ } => {
+                self.emit(Op::CallClosure {
+                    fun: self.arg(fun, &env),
+                    args: self.args(args, &env),
+                    tail,
+                });
+            }
+            SeqExp::CallDirect {
+                label,
+                args,
+                ann: _,
+            } => {
+                self.emit(Op::CallDirect {
+                    fun: self.funs[label.as_str()],
+                    args: self.args(args, &env),
+                    tail,
+                });
+            }
+            SeqExp::Object {
+                class,
+                fields,
+                ann: _,
+            } => {
+                let info = match self.class.get(class) {
+                    Some(info) => info,
+                    None => panic!("class is guaranteed to be in scope. Compile."),
+                };
+                if fields.len() != info.fieldsize {
+                    self.emit(Op::Fail(RuntimeErr::FieldNumError));
+                }
+                self.emit(Op::Object {
+                    class: info.id,
+                    fields: self.args(fields, &env),
+                });
+            }
+            SeqExp::CallMethod {
+                object,
+                method,
+                args,
+                ann: _,
+            } => {
+                // Like the native code, only the first class is dispatched on
+                let (class, method_name) = match method.iter().next() {
+                    Some((class, name)) => match self.class.get(class) {
+                        Some(info) => (info.id, name),
+                        None => panic!("Class is guaranteed to be in scope. Error."),
+                    },
+                    None => panic!("A method call has at least one class"),
+                };
+                self.emit(Op::CallMethod {
+                    object: self.arg(object, &env),
+                    class,
+                    method: self.arg(&ImmExp::Var(method_name.clone()), &env),
+                    args: self.args(args, &env),
+                    tail,
+                });
+            }
+            SeqExp::Let {
+                var,
+                bound_exp,
+                body,
+                ann: _,
+            } => {
+                if self.types.is_unboxed(var) {
+                    self.compile_unboxed(bound_exp, &env);
+                } else {
+                    self.compile_exp(bound_exp, env.clone(), size, false);
+                }
+                let slot = self.slot(size);
+                self.emit(Op::Store(slot));
+                env.insert(var, slot);
+                self.compile_exp(body, env, size + 1, tail);
+            }
+            SeqExp::If {
+                cond,
+                thn,
+                els,
+                ann: _,
+            } => {
+                self.emit(Op::Load(self.arg(cond, &env)));
+                let to_els = self.emit(Op::JumpUnless {
+                    err: RuntimeErr::IfError,
+                    target: 0,
+                });
+                self.compile_exp(thn, env.clone(), size, tail);
+                let to_done = self.emit(Op::Jump(0));
+                self.patch(to_els);
+                self.compile_exp(els, env, size, tail);
+                self.patch(to_done);
+            }
+            SeqExp::While { cond, body, ann: _ } => {
+                let start = self.code.len();
+                self.compile_exp(cond, env.clone(), size, false);
+                let to_done = self.emit(Op::JumpUnless {
+                    err: RuntimeErr::WhileError,
+                    target: 0,
+                });
+                self.compile_exp(body, env, size, false);
+                self.emit(Op::Jump(start));
+                self.patch(to_done);
+                self.emit(Op::Load(Arg::Imm(SNAKE_FALSE)));
+            }
+            SeqExp::Assign { var, value, ann: _ } => {
+                let slot = env[var.as_str()];
+                if self.types.is_unboxed(var) {
+                    self.emit(Op::Untag(self.arg(value, &env)));
+                } else {
+                    self.emit(Op::Load(self.arg(value, &env)));
+                }
+                self.emit(Op::Store(slot));
+            }
+            SeqExp::Raise(imm, _) => {
+                self.emit(Op::Raise(self.arg(imm, &env)));
+            }
+            SeqExp::Try {
+                body,
+                var,
+                handler,
+                ann: _,
+            } => {
+                let slot = self.slot(size);
+                let to_catch = self.emit(Op::Try {
+                    catch: 0,
+                    var: slot,
+                });
+                // The handler has to be popped afterwards, so no tail calls
+                self.compile_exp(body, env.clone(), size, false);
+                self.emit(Op::EndTry);
+                let to_done = self.emit(Op::Jump(0));
+                self.patch(to_catch);
+                env.insert(var, slot);
+                self.compile_exp(handler, env, size + 1, tail);
+                self.patch(to_done);
+            }
+            SeqExp::Fail(err, _) => {
+                self.emit(Op::Fail(*err));
+            }
+        }
+    }
+
+    fn compile_fun(&mut self, params: &'a [String], body: &'a SeqExp<u32>) -> (usize, usize) {
+        let entry = self.code.len();
+        let env: HashMap<&str, usize> = params
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (x.as_str(), i))
+            .collect();
+        self.frame = params.len();
+        self.compile_exp(body, env, params.len(), true);
+        self.emit(Op::Return);
+        (entry, self.frame)
+    }
+}
+
+pub fn compile(p: &SeqProg<u32>, types: &TypeTable) -> Bytecode {
+    let mut names = vec![(String::from("main"), false)];
+    names.extend(
+        p.funs
+            .iter()
+            .map(|f| (f.name.clone(), !p.direct.contains(&f.name))),
+    );
+    names.extend(p.methods.iter().map(|m| (m.fundecl.name.clone(), true)));
+
+    let mut c = Compiler {
+        code: Vec::new(),
+        funs: names
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (name.as_str(), i))
+            .collect(),
+        class: &p.class,
+        types,
+        frame: 0,
+    };
+    let mut bodies = vec![(&[][..], &p.main)];
+    bodies.extend(p.funs.iter().map(|f| (&f.parameters[..], &f.body)));
+    bodies.extend(
+        p.methods
+            .iter()
+            .map(|m| (&m.fundecl.parameters[..], &m.fundecl.body)),
+    );
+    let mut funs = Vec::new();
+    for ((name, takes_env), (params, body)) in names.iter().zip(bodies) {
+        let (entry, frame) = c.compile_fun(params, body);
+        funs.push(Function {
+            name: name.clone(),
+            entry,
+            frame,
+            takes_env: *takes_env,
+        });
+    }
+    Bytecode { code: c.code, funs }
+}
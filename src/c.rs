@@ -0,0 +1,793 @@
+use crate::runtime_error::{prim1_operand, prim2_operand, RuntimeErr, Type};
+use crate::syntax::{ClassInfo, ImmExp, Prim1, Prim2, SeqExp, SeqProg};
+use crate::types::TypeTable;
+use std::collections::{BTreeSet, HashMap};
+
+// Emits a sequentialized program as C, to be built together with
+// runtime/stub.c. Values are the tagged 64-bit words of the native code and
+// the heap objects have the native layouts, so the output of a program is
+// the same with either backend. Every function becomes a C function taking
+// its parameters as uint64_t, and closures hold a pointer to theirs.
+//
+// A try pushes a handler with setjmp and a raise longjmps to it. The locals
+// of a function with a try are volatile, as they may be read after the
+// longjmp. A call in tail position returns the callee and its arguments to
+// a trampoline, which makes the call, so tail calls take no stack at any
+// optimisation level. C compilers only turn return f(...) into a jump when
+// optimising, and musttail is not in gcc.
+
+static TAG_MASK: u64 = 0b111;
+static BOOL_TAG: u64 = 0b111;
+static ARRAY_TAG: u64 = 0b001;
+static CLOSURE_TAG: u64 = 0b011;
+
+static XOR_NOT: u64 = 0x80_00_00_00_00_00_00_00;
+
+// The declarations shared with runtime/stub.c and the helpers for the
+// checks, with the error codes filled in
+fn prelude() -> String {
+    format!(
+        r#"#include <setjmp.h>
+#include <stdint.h>
+
+struct snake_handler {{
+    jmp_buf buf;
+    struct snake_handler *prev;
+}};
+
+extern struct snake_handler *snake_handler;
+extern uint64_t snake_raised;
+extern uintptr_t snake_stack_limit;
+extern uint64_t *snake_alloc(uint64_t words);
+extern uint64_t print_snake_val(uint64_t v);
+extern uint64_t snake_raise(uint64_t v);
+extern uint64_t snake_fail(uint64_t err_code);
+extern void snake_error(uint64_t err_code);
+
+#define SNAKE_TRU 0xFFFFFFFFFFFFFFFFULL
+#define SNAKE_FLS 0x7FFFFFFFFFFFFFFFULL
+#define SNAKE_BOOL(c) ((c) ? SNAKE_TRU : SNAKE_FLS)
+#define SNAKE_PTR(v, tag) ((uint64_t *)(uintptr_t)((v) - (tag)))
+#define SNAKE_VAL(p, tag) ((uint64_t)(uintptr_t)(p) + (tag))
+
+static inline uint64_t snake_check(uint64_t v, uint64_t mask, uint64_t tag, uint64_t err) {{
+    if ((v & mask) != tag) {{
+        snake_fail(err);
+    }}
+    return v;
+}}
+
+/* Every function checks its frame against the stack limit like the native
+ * code does, running out of stack is not caught
+ */
+static inline void snake_check_stack(void) {{
+    char here;
+    if ((uintptr_t)&here < snake_stack_limit) {{
+        snake_error({stack_err});
+    }}
+}}
+
+static inline int64_t snake_add(int64_t a, int64_t b) {{
+    if ((b > 0 && a > INT64_MAX - b) || (b < 0 && a < INT64_MIN - b)) {{
+        snake_fail({overflow});
+    }}
+    return a + b;
+}}
+
+static inline int64_t snake_sub(int64_t a, int64_t b) {{
+    if ((b < 0 && a > INT64_MAX + b) || (b > 0 && a < INT64_MIN + b)) {{
+        snake_fail({overflow});
+    }}
+    return a - b;
+}}
+
+static inline int64_t snake_mul(int64_t a, int64_t b) {{
+    int64_t r = (int64_t)((uint64_t)a * (uint64_t)b);
+    if (a != 0 && ((a == -1 && b == INT64_MIN) || (a != -1 && r / a != b))) {{
+        snake_fail({overflow});
+    }}
+    return r;
+}}
+
+static inline uint64_t snake_tag(int64_t n) {{
+    return (uint64_t)snake_add(n, n);
+}}
+
+/* An untagged number has to stay in the range of the tagged ones */
+static inline int64_t snake_untagged(int64_t n) {{
+    snake_tag(n);
+    return n;
+}}
+
+static inline int64_t snake_num(uint64_t v, uint64_t err) {{
+    return (int64_t)snake_check(v, 1, 0, err) >> 1;
+}}
+
+static inline uint64_t *snake_element(uint64_t array, uint64_t index) {{
+    uint64_t *a = SNAKE_PTR(snake_check(array, 7, 1, {array_err}), 1);
+    int64_t i = (int64_t)snake_check(index, 1, 0, {index_err});
+    if (i >= (int64_t)a[1] || i < 0) {{
+        snake_fail({bounding_err});
+    }}
+    return &a[2 + (i >> 1)];
+}}
+
+static inline uint64_t *snake_closure(uint64_t v, uint64_t arg_num) {{
+    uint64_t *c = SNAKE_PTR(snake_check(v, 7, 3, {closure_err}), 3);
+    if (c[0] != arg_num) {{
+        snake_fail({arity_err});
+    }}
+    return c;
+}}
+"#,
+        overflow = RuntimeErr::OverflowError as u64,
+        stack_err = RuntimeErr::StackOverflowError as u64,
+        array_err = RuntimeErr::ArrayError as u64,
+        index_err = RuntimeErr::IndexError as u64,
+        bounding_err = RuntimeErr::BoundingError as u64,
+        closure_err = RuntimeErr::ClosureError as u64,
+        arity_err = RuntimeErr::ArityError as u64,
+    )
+}
+
+fn lit(v: u64) -> String {
+    format!("0x{:x}ULL", v)
+}
+
+fn type_tag(ty: &Type) -> (u64, u64) {
+    match ty {
+        Type::Num => (1, 0),
+        Type::Bool => (TAG_MASK, BOOL_TAG),
+        Type::Array => (TAG_MASK, ARRAY_TAG),
+        Type::Closure => (TAG_MASK, CLOSURE_TAG),
+    }
+}
+
+// The C type of a pointer to a Snake function with n parameters
+fn fn_ptr(n: usize) -> String {
+    let params = if n == 0 {
+        String::from("void")
+    } else {
+        vec!["uint64_t"; n].join(", ")
+    };
+    format!("uint64_t (*)({})", params)
+}
+
+fn has_try(e: &SeqExp<u32>) -> bool {
+    match e {
+        SeqExp::Try { .. } => true,
+        SeqExp::Let {
+            bound_exp, body, ..
+        } => has_try(bound_exp) || has_try(body),
+        SeqExp::If { thn, els, .. } => has_try(thn) || has_try(els),
+        SeqExp::While { cond, body, .. } => has_try(cond) || has_try(body),
+        _ => false,
+    }
+}
+
+// Where the value of an expression goes
+enum Target {
+    Return,
+    Var(String),
+}
+
+struct CGen<'a> {
+    out: String,
+    indent: usize,
+    // The locals of the function so far, and whether each is unboxed
+    locals: Vec<(String, bool)>,
+    funs: &'a HashMap<&'a str, String>,
+    entries: &'a HashMap<&'a str, String>,
+    class: &'a HashMap<String, ClassInfo>,
+    types: &'a TypeTable,
+    // The numbers of arguments of the tail calls, each needs an adapter
+    tail_arities: BTreeSet<usize>,
+}
+
+impl<'a> CGen<'a> {
+    fn line(&mut self, s: &str) {
+        for _ in 0..self.indent {
+            self.out.push_str("    ");
+        }
+        self.out.push_str(s);
+        self.out.push('\n');
+    }
+
+    fn open(&mut self, s: &str) {
+        self.line(s);
+        self.indent += 1;
+    }
+
+    fn close(&mut self, s: &str) {
+        self.indent -= 1;
+        self.line(s);
+    }
+
+    fn local(&mut self, unboxed: bool) -> String {
+        let name = format!("v{}", self.locals.len());
+        self.locals.push((name.clone(), unboxed));
+        name
+    }
+
+    fn set(&mut self, target: &Target, expr: &str) {
+        match target {
+            Target::Return => self.line(&format!("return {};", expr)),
+            Target::Var(v) => self.line(&format!("{} = {};", v, expr)),
+        }
+    }
+
+    fn is_unboxed(&self, imm: &ImmExp) -> bool {
+        matches!(imm, ImmExp::Var(x) if self.types.is_unboxed(x))
+    }
+
+    fn var(&self, x: &str, env: &HashMap<&str, String>) -> String {
+        match env.get(x) {
+            Some(name) => name.clone(),
+            None => panic!("Variable {} is guaranteed to be in scope", x),
+        }
+    }
+
+    // The tagged value of imm, tagging an unboxed variable into a new local
+    // first so the overflow check happens here
+    fn operand(&mut self, imm: &ImmExp, env: &HashMap<&str, String>) -> String {
+        match imm {
+            ImmExp::Num(n) => lit((*n << 1) as u64),
+            ImmExp::Bool(true) => String::from("SNAKE_TRU"),
+            ImmExp::Bool(false) => String::from("SNAKE_FLS"),
+            ImmExp::Var(x) if self.types.is_unboxed(x) => {
+                let tagged = self.local(false);
+                let name = self.var(x, env);
+                self.line(&format!("{} = snake_tag({});", tagged, name));
+                tagged
+            }
+            ImmExp::Var(x) => self.var(x, env),
+        }
+    }
+
+    fn check(&mut self, v: &str, ty: &Type, err: RuntimeErr) {
+        let (mask, tag) = type_tag(ty);
+        self.line(&format!(
+            "snake_check({}, {}, {}, {});",
+            v, mask, tag, err as u64
+        ));
+    }
+
+    // The untagged number imm, a tagged operand is checked to be a number
+    // first
+    fn raw(
+        &mut self,
+        imm: &ImmExp,
+        env: &HashMap<&str, String>,
+        err: Option<RuntimeErr>,
+    ) -> String {
+        match imm {
+            ImmExp::Var(x) if self.types.is_unboxed(x) => self.var(x, env),
+            ImmExp::Num(n) => format!("INT64_C({})", n),
+            _ => {
+                let v = self.operand(imm, env);
+                match err {
+                    Some(err) => format!("snake_num({}, {})", v, err as u64),
+                    None => format!("((int64_t){} >> 1)", v),
+                }
+            }
+        }
+    }
+
+    // The untagged result of the arithmetic an unboxed variable is bound
+    // to, or the boolean of an unboxed comparison
+    fn unboxed(&mut self, e: &SeqExp<u32>, env: &HashMap<&str, String>) -> String {
+        match e {
+            SeqExp::Imm(imm, _) => self.raw(imm, env, None),
+            SeqExp::Prim1(p, imm, _) => {
+                let a = self.raw(imm, env, prim1_operand(p).map(|(_, err)| err));
+                match p {
+                    Prim1::Add1 => format!("snake_untagged(snake_add({}, 1))", a),
+                    Prim1::Sub1 => format!("snake_untagged(snake_sub({}, 1))", a),
+                    _ => panic!("Only Add1 and Sub1 are unboxed"),
+                }
+            }
+            SeqExp::Prim2(p, e1, e2, _) => {
+                let err = prim2_operand(p).map(|(_, err)| err);
+                let (a, b) = (self.local(true), self.local(true));
+                let raw = self.raw(e1, env, err);
+                self.line(&format!("{} = {};", a, raw));
+                let raw = self.raw(e2, env, err);
+                self.line(&format!("{} = {};", b, raw));
+                match p {
+                    Prim2::Add => format!("snake_untagged(snake_add({}, {}))", a, b),
+                    Prim2::Sub => format!("snake_untagged(snake_sub({}, {}))", a, b),
+                    Prim2::Mul => format!("snake_untagged(snake_mul({}, {}))", a, b),
+                    _ => format!("SNAKE_BOOL({} {} {})", a, compare(*p), b),
+                }
+            }
+            _ => panic!("Only arithmetic is unboxed"),
+        }
+    }
+
+    // Allocates an array or object with the given header and elements
+    fn heap_object(
+        &mut self,
+        header: [u64; 2],
+        elts: &[ImmExp],
+        env: &HashMap<&str, String>,
+        target: &Target,
+    ) {
+        self.open("{");
+        self.line(&format!(
+            "uint64_t *h = snake_alloc({});",
+            elts.len() + header.len()
+        ));
+        for (i, word) in header.iter().enumerate() {
+            self.line(&format!("h[{}] = {};", i, lit(*word)));
+        }
+        for (i, elt) in elts.iter().enumerate() {
+            let v = self.operand(elt, env);
+            self.line(&format!("h[{}] = {};", i + header.len(), v));
+        }
+        self.set(target, &format!("SNAKE_VAL(h, {})", ARRAY_TAG));
+        self.close("}");
+    }
+
+    // Calls the function pointer fun. A tail call leaves the callee for the
+    // trampoline and returns to it, any other call runs the trampoline until
+    // the callee and the tail calls it makes are done.
+    fn call(&mut self, fun: &str, args: &[String], target: &Target) {
+        match target {
+            Target::Return => {
+                for (i, a) in args.iter().enumerate() {
+                    self.line(&format!("snake_tail_args[{}] = {};", i, a));
+                }
+                self.line(&format!("snake_tail_fn = (void (*)(void)){};", fun));
+                self.line(&format!("snake_next = &snake_apply{};", args.len()));
+                self.line("return 0;");
+                self.tail_arities.insert(args.len());
+            }
+            Target::Var(v) => {
+                self.line(&format!("{} = snake_run({}({}));", v, fun, args.join(", ")))
+            }
+        }
+    }
+
+    // Calls the checked closure c with its env and the given arguments
+    fn call_closure(&mut self, args: Vec<String>, target: &Target) {
+        let fun = format!("(({})(uintptr_t)c[1])", fn_ptr(args.len() + 1));
+        let mut call_args = vec![String::from("c[2]")];
+        call_args.extend(args);
+        self.call(&fun, &call_args, target);
+        self.close("}");
+    }
+
+    fn compile_exp<'exp>(
+        &mut self,
+        e: &'exp SeqExp<u32>,
+        mut env: HashMap<&'exp str, String>,
+        target: &Target,
+    ) {
+        match e {
+            SeqExp::Imm(imm, _) => {
+                let v = self.operand(imm, &env);
+                self.set(target, &v)
+            }
+            SeqExp::Prim1(Prim1::Add1 | Prim1::Sub1, imm, _) if self.is_unboxed(imm) => {
+                let n = self.unboxed(e, &env);
+                self.set(target, &format!("snake_tag({})", n))
+            }
+            SeqExp::Prim1(p, imm, _) => {
+                let a = self.operand(imm, &env);
+                if let Some((ty, err)) = prim1_operand(p) {
+                    self.check(&a, &ty, err);
+                }
+                let result = match p {
+                    Prim1::Add1 => format!("(uint64_t)snake_add((int64_t){}, 2)", a),
+                    Prim1::Sub1 => format!("(uint64_t)snake_sub((int64_t){}, 2)", a),
+                    Prim1::Not => format!("({} ^ {})", a, lit(XOR_NOT)),
+                    Prim1::Print => format!("print_snake_val({})", a),
+                    Prim1::IsNum => format!("SNAKE_BOOL(({} & 1) == 0)", a),
+                    Prim1::IsBool => format!("SNAKE_BOOL(({} & 7) == {})", a, BOOL_TAG),
+                    Prim1::IsArray => format!("SNAKE_BOOL(({} & 7) == {})", a, ARRAY_TAG),
+                    Prim1::IsFun => format!("SNAKE_BOOL(({} & 7) == {})", a, CLOSURE_TAG),
+                    Prim1::Length => format!("SNAKE_PTR({}, {})[1]", a, ARRAY_TAG),
+                    // The header holds a raw index, tag it as a number
+                    Prim1::ClassIdx => format!("(SNAKE_PTR({}, {})[0] << 1)", a, ARRAY_TAG),
+                };
+                self.set(target, &result)
+            }
+            SeqExp::Prim2(Prim2::Add | Prim2::Sub | Prim2::Mul, e1, e2, _)
+                if self.is_unboxed(e1) || self.is_unboxed(e2) =>
+            {
+                let n = self.unboxed(e, &env);
+                self.set(target, &format!("snake_tag({})", n))
+            }
+            SeqExp::Prim2(Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge, e1, e2, _)
+                if self.is_unboxed(e1) || self.is_unboxed(e2) =>
+            {
+                let b = self.unboxed(e, &env);
+                self.set(target, &b)
+            }
+            SeqExp::Prim2(Prim2::Eq | Prim2::Neq, e1, e2, _)
+                if (self.is_unboxed(e1) || matches!(e1, ImmExp::Num(_)))
+                    && (self.is_unboxed(e2) || matches!(e2, ImmExp::Num(_))) =>
+            {
+                let b = self.unboxed(e, &env);
+                self.set(target, &b)
+            }
+            SeqExp::Prim2(p, e1, e2, _) => {
+                let operand = prim2_operand(p);
+                let mut vs = Vec::new();
+                for imm in [e1, e2] {
+                    let v = self.operand(imm, &env);
+                    if let Some((ty, err)) = &operand {
+                        self.check(&v, ty, *err);
+                    }
+                    vs.push(v);
+                }
+                let (a, b) = (&vs[0], &vs[1]);
+                let result = match p {
+                    Prim2::Add => format!("(uint64_t)snake_add((int64_t){}, (int64_t){})", a, b),
+                    Prim2::Sub => format!("(uint64_t)snake_sub((int64_t){}, (int64_t){})", a, b),
+                    Prim2::Mul => {
+                        format!("(uint64_t)snake_mul((int64_t){}, (int64_t){} >> 1)", a, b)
+                    }
+                    Prim2::And => format!("({} & {})", a, b),
+                    Prim2::Or => format!("({} | {})", a, b),
+                    Prim2::ArrayGet => format!("*snake_element({}, {})", a, b),
+                    _ => format!("SNAKE_BOOL((int64_t){} {} (int64_t){})", a, compare(*p), b),
+                };
+                self.set(target, &result)
+            }
+            SeqExp::Array(elts, _) => {
+                self.heap_object([0, 2 * elts.len() as u64], elts, &env, target)
+            }
+            SeqExp::ArraySet {
+                array,
+                index,
+                new_value,
+                ann: _,
+            } => {
+                let a = self.operand(array, &env);
+                let i = self.operand(index, &env);
+                self.open("{");
+                self.line(&format!("uint64_t *e = snake_element({}, {});", a, i));
+                let v = self.operand(new_value, &env);
+                self.line(&format!("*e = {};", v));
+                self.set(target, &a);
+                self.close("}");
+            }
+            SeqExp::MakeClosure {
+                arity,
+                label,
+                env: capture,
+                ann: _,
+            } => {
+                self.open("{");
+                self.line("uint64_t *c = snake_alloc(3);");
+                self.line(&format!("c[0] = {};", arity));
+                self.line(&format!(
+                    "c[1] = (uint64_t)(uintptr_t)&{};",
+                    self.entries[label.as_str()]
+                ));
+                let v = self.operand(capture, &env);
+                self.line(&format!("c[2] = {};", v));
+                self.set(target, &format!("SNAKE_VAL(c, {})", CLOSURE_TAG));
+                self.close("}");
+            }
+            SeqExp::CallClosure { fun, args, ann: _ } => {
+                let f = self.operand(fun, &env);
+                self.open("{");
+                self.line(&format!(
+                    "uint64_t *c = snake_closure({}, {});",
+                    f,
+                    args.len()
+                ));
+                let args = args.iter().map(|a| self.operand(a, &env)).collect();
+                self.call_closure(args, target)
+            }
+            SeqExp::CallDirect {
+                label,
+                args,
+                ann: _,
+            } => {
+                let args: Vec<String> = args.iter().map(|a| self.operand(a, &env)).collect();
+                let funs = self.funs;
+                self.call(&funs[label.as_str()], &args, target)
+            }
+            SeqExp::Object {
+                class,
+                fields,
+                ann: _,
+            } => {
+                let info = match self.class.get(class) {
+                    Some(info) => info.clone(),
+                    None => panic!("class is guaranteed to be in scope. Compile."),
+                };
+                if fields.len() != info.fieldsize {
+                    let err = RuntimeErr::FieldNumError as u64;
+                    return self.set(target, &format!("snake_fail({})", err));
+                }
+                self.heap_object(
+                    [info.id as u64, 2 * fields.len() as u64],
+                    fields,
+                    &env,
+                    target,
+                )
+            }
+            SeqExp::CallMethod {
+                object,
+                method,
+                args,
+                ann: _,
+            } => {
+                // Like the native code, only the first class is dispatched on
+                let (class, method_name) = match method.iter().next() {
+                    Some((class, name)) => match self.class.get(class) {
+                        Some(info) => (info.id, name),
+                        None => panic!("Class is guaranteed to be in scope. Error."),
+                    },
+                    None => panic!("A method call has at least one class"),
+                };
+                let o = self.operand(object, &env);
+                self.check(&o, &Type::Array, RuntimeErr::ArrayError);
+                self.open(&format!(
+                    "if (SNAKE_PTR({}, {})[0] != {}) {{",
+                    o, ARRAY_TAG, class
+                ));
+                self.line(&format!(
+                    "snake_fail({});",
+                    RuntimeErr::MethodTypeError as u64
+                ));
+                self.close("}");
+                let m = self.operand(&ImmExp::Var(method_name.clone()), &env);
+                self.open("{");
+                self.line(&format!(
+                    "uint64_t *c = snake_closure({}, {});",
+                    m,
+                    args.len() + 1
+                ));
+                let mut call_args = vec![o];
+                call_args.extend(args.iter().map(|a| self.operand(a, &env)));
+                self.call_closure(call_args, target)
+            }
+            SeqExp::Let {
+                var,
+                bound_exp,
+                body,
+                ann: _,
+            } => {
+                let unboxed = self.types.is_unboxed(var);
+                let v = self.local(unboxed);
+                if unboxed {
+                    let n = self.unboxed(bound_exp, &env);
+                    self.line(&format!("{} = {};", v, n));
+                } else {
+                    self.compile_exp(bound_exp, env.clone(), &Target::Var(v.clone()));
+                }
+                env.insert(var, v);
+                self.compile_exp(body, env, target);
+            }
+            SeqExp::If {
+                cond,
+                thn,
+                els,
+                ann: _,
+            } => {
+                let c = self.operand(cond, &env);
+                self.check(&c, &Type::Bool, RuntimeErr::IfError);
+                self.open(&format!("if ({} != SNAKE_FLS) {{", c));
+                self.compile_exp(thn, env.clone(), target);
+                self.indent -= 1;
+                self.open("} else {");
+                self.compile_exp(els, env, target);
+                self.close("}");
+            }
+            SeqExp::While { cond, body, ann: _ } => {
+                let (c, v) = (self.local(false), self.local(false));
+                self.open("for (;;) {");
+                self.compile_exp(cond, env.clone(), &Target::Var(c.clone()));
+                self.check(&c, &Type::Bool, RuntimeErr::WhileError);
+                self.line(&format!("if ({} == SNAKE_FLS) break;", c));
+                self.compile_exp(body, env, &Target::Var(v));
+                self.close("}");
+                self.set(target, "SNAKE_FLS")
+            }
+            SeqExp::Assign { var, value, ann: _ } => {
+                let name = self.var(var, &env);
+                if self.types.is_unboxed(var) {
+                    let n = self.raw(value, &env, None);
+                    self.line(&format!("{} = {};", name, n));
+                    self.set(target, &format!("(uint64_t){}", name))
+                } else {
+                    let v = self.operand(value, &env);
+                    self.line(&format!("{} = {};", name, v));
+                    self.set(target, &name)
+                }
+            }
+            SeqExp::Raise(imm, _) => {
+                let v = self.operand(imm, &env);
+                self.set(target, &format!("snake_raise({})", v))
+            }
+            SeqExp::Try {
+                body,
+                var,
+                handler,
+                ann: _,
+            } => {
+                let (result, caught) = (self.local(false), self.local(false));
+                self.open("{");
+                self.line("struct snake_handler h;");
+                self.line("h.prev = snake_handler;");
+                self.line("snake_handler = &h;");
+                self.open("if (setjmp(h.buf) == 0) {");
+                // The handler has to be popped afterwards, so no tail calls
+                self.compile_exp(body, env.clone(), &Target::Var(result.clone()));
+                self.line("snake_handler = h.prev;");
+                self.set(target, &result);
+                self.indent -= 1;
+                self.open("} else {");
+                self.line("snake_handler = h.prev;");
+                self.line(&format!("{} = snake_raised;", caught));
+                env.insert(var, caught);
+                self.compile_exp(handler, env, target);
+                self.close("}");
+                self.close("}");
+            }
+            SeqExp::Fail(err, _) => self.set(target, &format!("snake_fail({})", *err as u64)),
+        }
+    }
+
+    // The definition of a function with the given name and parameters
+    fn function(&mut self, name: &str, params: &[String], body: &SeqExp<u32>) -> String {
+        self.out.clear();
+        self.locals.clear();
+        self.indent = 1;
+        let env: HashMap<&str, String> = params
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (x.as_str(), format!("p{}", i)))
+            .collect();
+        self.compile_exp(body, env, &Target::Return);
+
+        let qualifier = if has_try(body) { "volatile " } else { "" };
+        let mut def = format!("{} {{\n", signature(name, params.len()));
+        for (local, unboxed) in &self.locals {
+            let ty = if *unboxed { "int64_t" } else { "uint64_t" };
+            def.push_str(&format!("    {}{} {};\n", qualifier, ty, local));
+        }
+        def.push_str("    snake_check_stack();\n");
+        def.push_str(&self.out);
+        def.push_str("}\n");
+        def
+    }
+}
+
+fn compare(p: Prim2) -> &'static str {
+    match p {
+        Prim2::Lt => "<",
+        Prim2::Gt => ">",
+        Prim2::Le => "<=",
+        Prim2::Ge => ">=",
+        Prim2::Eq => "==",
+        Prim2::Neq => "!=",
+        _ => panic!("Only comparisons are left"),
+    }
+}
+
+// The pending tail call, the trampoline that makes it and an adapter for
+// each number of arguments that calls the pending function with them
+fn trampoline(arities: &BTreeSet<usize>) -> String {
+    let mut out = format!(
+        r#"
+static uint64_t snake_tail_args[{}];
+static void (*snake_tail_fn)(void);
+static uint64_t (*snake_next)(void);
+
+static uint64_t snake_run(uint64_t v) {{
+    while (snake_next) {{
+        uint64_t (*next)(void) = snake_next;
+        snake_next = 0;
+        v = next();
+    }}
+    return v;
+}}
+"#,
+        arities.iter().max().copied().unwrap_or(0).max(1)
+    );
+    for n in arities {
+        let args: Vec<String> = (0..*n).map(|i| format!("snake_tail_args[{}]", i)).collect();
+        out.push_str(&format!(
+            "\nstatic uint64_t snake_apply{}(void) {{\n    return (({})snake_tail_fn)({});\n}}\n",
+            n,
+            fn_ptr(*n),
+            args.join(", ")
+        ));
+    }
+    out
+}
+
+fn signature(name: &str, params: usize) -> String {
+    let params = if params == 0 {
+        String::from("void")
+    } else {
+        (0..params)
+            .map(|i| format!("uint64_t p{}", i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!("uint64_t {}({})", name, params)
+}
+
+pub fn compile(p: &SeqProg<u32>, types: &TypeTable) -> String {
+    let mut bodies: Vec<(&str, &[String], &SeqExp<u32>)> = p
+        .funs
+        .iter()
+        .map(|f| (f.name.as_str(), &f.parameters[..], &f.body))
+        .collect();
+    bodies.extend(p.methods.iter().map(|m| {
+        (
+            m.fundecl.name.as_str(),
+            &m.fundecl.parameters[..],
+            &m.fundecl.body,
+        )
+    }));
+
+    // Closures of the direct functions go through an entry that drops the
+    // env
+    let mut funs = HashMap::new();
+    let mut entries = HashMap::new();
+    for (i, (name, _, _)) in bodies.iter().enumerate() {
+        let c_name = format!("snake_f{}", i);
+        let entry = if p.direct.contains(*name) {
+            format!("{}_env", c_name)
+        } else {
+            c_name.clone()
+        };
+        funs.insert(*name, c_name);
+        entries.insert(*name, entry);
+    }
+
+    let mut gen = CGen {
+        out: String::new(),
+        indent: 0,
+        locals: Vec::new(),
+        funs: &funs,
+        entries: &entries,
+        class: &p.class,
+        types,
+        tail_arities: BTreeSet::new(),
+    };
+    let defs: Vec<String> = bodies
+        .iter()
+        .map(|(name, params, body)| gen.function(&funs[name], params, body))
+        .collect();
+    // The program's own body may end in a tail call too, which the runtime
+    // cannot make
+    let main = gen.function("snake_main", &[], &p.main);
+
+    let mut out = prelude();
+    out.push('\n');
+    for (name, params, _) in &bodies {
+        out.push_str(&format!(
+            "/* {} */\nstatic {};\n",
+            name,
+            signature(&funs[name], params.len())
+        ));
+    }
+    out.push_str(&trampoline(&gen.tail_arities));
+    for (name, params, _) in &bodies {
+        if p.direct.contains(*name) {
+            let args: Vec<String> = (1..=params.len()).map(|i| format!("p{}", i)).collect();
+            out.push_str(&format!(
+                "\nstatic {} {{\n    return {}({});\n}}\n",
+                signature(&entries[name], params.len() + 1),
+                funs[name],
+                args.join(", ")
+            ));
+        }
+    }
+
+    for def in defs {
+        out.push_str(&format!("\nstatic {}", def));
+    }
+    out.push_str(&format!("\nstatic {}", main));
+    out.push_str("\nuint64_t start_here(void) {\n    return snake_run(snake_main());\n}\n");
+    out
+}
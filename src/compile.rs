@@ -1,17 +1,31 @@
-use crate::asm::instrs_to_string;
+use crate::asm::{program_to_string, Program};
 use crate::asm::{Arg32, Arg64, BinArgs, Instr, JmpArg, MemRef, MovArgs, Offset, Reg, Reg32};
+use crate::bytecode;
+use crate::bytecode::Bytecode;
+use crate::c;
+use crate::desugar;
 use crate::lift;
+use crate::matching;
+use crate::optimize;
+use crate::peephole;
 use crate::runtime_error::{
     call_error, check_arity_number, check_array_type, check_bounding, check_closure_type,
-    check_field_num, check_if_type, check_index_type, check_method_class, check_overflow,
-    check_prim1_type, check_prim2_type,
+    check_field_num, check_heap_space, check_if_type, check_index_type, check_method_class,
+    check_overflow, check_prim1_type, check_prim2_type, check_stack_space, check_untagged_overflow,
+    check_while_type, count_checks, fail, prim1_operand_type, prim2_operand_type, Type,
+    ERROR_CLASS, ERROR_CLASS_ID,
 };
 use crate::scope;
 use crate::sequence;
 use crate::syntax::{
-    ClassInfo, Exp, FunDecl, ImmExp, MethodDecl, Prim1, Prim2, SeqExp, SeqProg, SurfProg,
+    ClassInfo, Exp, FunDecl, ImmExp, MethodDecl, Prim1, Prim2, SeqExp, SeqProg, SnakeType, SurfProg,
 };
-use std::collections::HashMap;
+use crate::timing::time;
+use crate::typecheck;
+use crate::types;
+use crate::types::TypeTable;
+use crate::wasm;
+use std::collections::{HashMap, HashSet};
 
 static XOR_NOT: u64 = 0x80_00_00_00_00_00_00_00;
 static INT_TAG: u64 = 0x00_00_00_00_00_00_00_01;
@@ -84,22 +98,91 @@ pub enum CompileErr<Span> {
         class: String,
         location: Span,
     },
+
+    ImmutableAssignment {
+        name: String,
+        location: Span,
+    },
+
+    TypeMismatch {
+        expected: SnakeType,
+        found: SnakeType,
+        location: Span,
+    },
+
+    ArityMismatch {
+        expected: usize,
+        found: usize,
+        location: Span,
+    },
+    UnreadableImport {
+        path: String,
+        reason: String,
+        location: Span,
+    },
+    ImportCycle {
+        path: String,
+        location: Span,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CompileWarning<Span> {
+    NonExhaustiveMatch { location: Span },
+
+    RedundantArm { location: Span },
+}
+
+/* Counts reported by the passes, for --stats */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompileStats {
+    pub removed_checks: usize,
+    pub lifted_functions: usize,
+    pub lifted_methods: usize,
+    // not counting the predeclared Error class
+    pub classes: usize,
+    // not counting the labels and comments
+    pub instructions: usize,
+    pub runtime_checks: usize,
+    pub stack_slots: usize,
 }
 
 pub fn check_prog<Span>(p: &SurfProg<Span>) -> Result<(), CompileErr<Span>>
 where
     Span: Clone,
 {
-    scope::check_prog(p, Vec::new(), Vec::new(), Vec::new())
+    scope::check_prog(p, Vec::new(), vec![(ERROR_CLASS, 1)], Vec::new())
+}
+
+pub fn check_warnings<Span>(p: &SurfProg<Span>) -> Vec<CompileWarning<Span>>
+where
+    Span: Clone,
+{
+    let mut warnings = Vec::new();
+    scope::match_warnings(p, &mut warnings);
+    warnings
 }
 
 fn uniquify(e: &Exp<u32>) -> Exp<()> {
-    scope::uniquify(e, Vec::new(), Vec::new(), HashMap::new())
+    scope::uniquify(
+        e,
+        Vec::new(),
+        vec![(ERROR_CLASS, ERROR_CLASS.to_string())],
+        HashMap::new(),
+    )
 }
 
 // Precondition: all names are uniquified
 fn class_lift<Ann>(p: &Exp<Ann>) -> (HashMap<String, ClassInfo>, Exp<()>) {
-    lift::class_lift(p, Vec::new(), HashMap::new())
+    let mut classes = HashMap::new();
+    classes.insert(
+        ERROR_CLASS.to_string(),
+        ClassInfo {
+            id: ERROR_CLASS_ID,
+            fieldsize: 1,
+        },
+    );
+    lift::class_lift(p, Vec::new(), classes)
 }
 
 // Precondition: all names are uniquified
@@ -110,7 +193,9 @@ fn lambda_lift<Ann>(
     Vec<MethodDecl<Exp<()>, ()>>,
     Exp<()>,
 ) {
-    lift::lambda_lift(p, Vec::new())
+    // Captured variables that get assigned must be boxed before the
+    // closure environments copy them
+    lift::lambda_lift(&lift::box_captured_assignments(p), Vec::new())
 }
 
 fn tag_exp<Ann>(p: &SurfProg<Ann>) -> SurfProg<u32> {
@@ -193,6 +278,7 @@ fn sequentialize_program(
             parameters: fun.parameters.clone(),
             body: sequence::sequentialize(&fun.body),
             ann: (),
+            signature: fun.signature.clone(),
         })
         .collect();
     let seq_methods: Vec<MethodDecl<SeqExp<()>, ()>> = methods
@@ -204,12 +290,14 @@ fn sequentialize_program(
                 parameters: method.fundecl.parameters.clone(),
                 body: sequence::sequentialize(&method.fundecl.body),
                 ann: (),
+                signature: method.fundecl.signature.clone(),
             },
         })
         .collect();
     SeqProg {
         class: class_info,
         funs: seq_funs,
+        direct: HashSet::new(),
         methods: seq_methods,
         main: sequence::sequentialize(&p),
         ann: (),
@@ -257,6 +345,123 @@ fn compile_imm(e: &ImmExp, env: &HashMap<&str, i32>) -> Arg64 {
     }
 }
 
+fn is_unboxed(imm: &ImmExp, types: &TypeTable) -> bool {
+    matches!(imm, ImmExp::Var(x) if types.is_unboxed(x))
+}
+
+fn tag_num(reg: Reg) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    instr.push(Instr::Comment(String::from("Tag unboxed number")));
+    instr.push(Instr::Add(BinArgs::ToReg(reg, Arg32::Reg(reg))));
+    instr.extend(check_overflow());
+    instr
+}
+
+// Load the tagged value of imm into reg
+fn load_imm(reg: Reg, imm: &ImmExp, env: &HashMap<&str, i32>, types: &TypeTable) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    instr.push(Instr::Mov(MovArgs::ToReg(reg, compile_imm(imm, env))));
+    if let ImmExp::Var(x) = imm {
+        if types.is_unboxed(x) {
+            instr.extend(tag_num(reg));
+        }
+    }
+    instr
+}
+
+// Load the untagged number imm into reg, a tagged operand is checked to
+// be a number first
+fn load_raw(
+    reg: Reg,
+    imm: &ImmExp,
+    env: &HashMap<&str, i32>,
+    types: &TypeTable,
+    check: Vec<Instr>,
+) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    match imm {
+        ImmExp::Num(n) => instr.push(Instr::Mov(MovArgs::ToReg(reg, Arg64::Signed(*n)))),
+        ImmExp::Var(x) if types.is_unboxed(x) => {
+            instr.push(Instr::Mov(MovArgs::ToReg(reg, compile_imm(imm, env))))
+        }
+        _ => {
+            instr.push(Instr::Mov(MovArgs::ToReg(reg, compile_imm(imm, env))));
+            instr.extend(check);
+            instr.push(Instr::Sar(BinArgs::ToReg(reg, Arg32::Unsigned(1))));
+        }
+    }
+    instr
+}
+
+// Compute the untagged result of the arithmetic e into Rax. These are the
+// expressions the unboxed variables are bound to, see types::unboxed_vars
+fn compile_unboxed(e: &SeqExp<u32>, env: &HashMap<&str, i32>, types: &TypeTable) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    match e {
+        SeqExp::Imm(imm, _) => instr.extend(load_raw(Reg::Rax, imm, env, types, Vec::new())),
+        SeqExp::Prim1(p, imm, ann) => {
+            instr.push(Instr::Comment(String::from("Unboxed Prim1")));
+            instr.extend(load_raw(
+                Reg::Rax,
+                imm,
+                env,
+                types,
+                check_unless_known(
+                    types,
+                    *ann,
+                    0,
+                    Some(Type::Num),
+                    check_prim1_type(Reg::Rax, p),
+                ),
+            ));
+            instr.push(match p {
+                Prim1::Add1 => Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
+                Prim1::Sub1 => Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
+                _ => panic!("Only Add1 and Sub1 are unboxed"),
+            });
+            instr.extend(check_untagged_overflow(Reg::Rax));
+        }
+        SeqExp::Prim2(p, e1, e2, ann) => {
+            instr.push(Instr::Comment(String::from("Unboxed Prim2")));
+            instr.extend(load_raw(
+                Reg::Rax,
+                e1,
+                env,
+                types,
+                check_unless_known(
+                    types,
+                    *ann,
+                    0,
+                    Some(Type::Num),
+                    check_prim2_type(Reg::Rax, p),
+                ),
+            ));
+            instr.extend(load_raw(
+                Reg::R10,
+                e2,
+                env,
+                types,
+                check_unless_known(
+                    types,
+                    *ann,
+                    1,
+                    Some(Type::Num),
+                    check_prim2_type(Reg::R10, p),
+                ),
+            ));
+            instr.push(match p {
+                Prim2::Add => Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
+                Prim2::Sub => Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
+                Prim2::Mul => Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
+                _ => panic!("Only Add, Sub and Mul are unboxed"),
+            });
+            instr.extend(check_untagged_overflow(Reg::Rax));
+        }
+        _ => panic!("Only arithmetic is unboxed"),
+    }
+    instr
+}
+
 fn compile_prim1(p: Prim1, space: i32, ann: u32) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     match p {
@@ -329,6 +534,21 @@ fn compile_prim1(p: Prim1, space: i32, ann: u32) -> Vec<Instr> {
                 Reg::Rax,
                 Arg32::Unsigned(ARRAY_TAG),
             )));
+            // The length is stored after the classidx header
+            instr.push(Instr::Mov(MovArgs::ToReg(
+                Reg::Rax,
+                Arg64::Mem(MemRef {
+                    reg: Reg::Rax,
+                    offset: Offset::Constant(8),
+                }),
+            )));
+        }
+        Prim1::ClassIdx => {
+            instr.push(Instr::Comment(String::from("Class Index")));
+            instr.push(Instr::Sub(BinArgs::ToReg(
+                Reg::Rax,
+                Arg32::Unsigned(ARRAY_TAG),
+            )));
             instr.push(Instr::Mov(MovArgs::ToReg(
                 Reg::Rax,
                 Arg64::Mem(MemRef {
@@ -336,12 +556,14 @@ fn compile_prim1(p: Prim1, space: i32, ann: u32) -> Vec<Instr> {
                     offset: Offset::Constant(0),
                 }),
             )));
+            // The header holds a raw index, tag it as a number
+            instr.push(Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))));
         }
     };
     instr
 }
 
-fn compile_prim2(p: Prim2, ann: u32) -> Vec<Instr> {
+fn compile_prim2(p: Prim2, types: &TypeTable, ann: u32) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     match p {
         Prim2::Add => {
@@ -356,9 +578,11 @@ fn compile_prim2(p: Prim2, ann: u32) -> Vec<Instr> {
         }
         Prim2::Mul => {
             instr.push(Instr::Comment(String::from("Mul")));
+            // Untag one factor first, so the product overflows exactly when
+            // it leaves the range of the numbers, as the unboxed one does
+            instr.push(Instr::Sar(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(1))));
             instr.push(Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
             instr.extend(check_overflow());
-            instr.push(Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))));
         }
         Prim2::And => instr.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10)))),
         Prim2::Or => instr.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10)))),
@@ -398,12 +622,24 @@ fn compile_prim2(p: Prim2, ann: u32) -> Vec<Instr> {
         }
         Prim2::ArrayGet => {
             instr.push(Instr::Comment(String::from("ArrayGet")));
-            instr.extend(check_array_type(Reg::Rax));
+            instr.extend(check_unless_known(
+                types,
+                ann,
+                0,
+                Some(Type::Array),
+                check_array_type(Reg::Rax),
+            ));
             instr.push(Instr::Sub(BinArgs::ToReg(
                 Reg::Rax,
                 Arg32::Unsigned(ARRAY_TAG),
             )));
-            instr.extend(check_index_type(Reg::R10));
+            instr.extend(check_unless_known(
+                types,
+                ann,
+                1,
+                Some(Type::Num),
+                check_index_type(Reg::R10),
+            ));
             instr.extend(check_bounding(Reg::R10, Reg::Rax));
             instr.push(Instr::Mov(MovArgs::ToReg(
                 Reg::Rax,
@@ -421,10 +657,25 @@ fn compile_prim2(p: Prim2, ann: u32) -> Vec<Instr> {
     instr
 }
 
+// Skip a type check when the operand is statically known to have the type
+fn check_unless_known(
+    types: &TypeTable,
+    ann: u32,
+    operand: usize,
+    ty: Option<Type>,
+    check: Vec<Instr>,
+) -> Vec<Instr> {
+    match ty {
+        Some(ty) if types.has_type(ann, operand, &ty) => Vec::new(),
+        _ => check,
+    }
+}
+
 fn compile_to_instrs_help<'exp>(
     e: &'exp SeqExp<u32>,
     mut env: HashMap<&'exp str, i32>,
     class_info: HashMap<String, ClassInfo>,
+    types: &TypeTable,
     space: i32,
     is_tail: bool,
     env_size: usize,
@@ -432,26 +683,94 @@ fn compile_to_instrs_help<'exp>(
 ) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     match e {
-        SeqExp::Imm(imm, _) => instr.push(Instr::Mov(MovArgs::ToReg(
-            Reg::Rax,
-            compile_imm(&imm, &env),
-        ))),
+        SeqExp::Imm(imm, _) => instr.extend(load_imm(Reg::Rax, &imm, &env, types)),
+        SeqExp::Prim1(Prim1::Add1 | Prim1::Sub1, imm, _) if is_unboxed(imm, types) => {
+            instr.extend(compile_unboxed(e, &env, types));
+            instr.extend(tag_num(Reg::Rax));
+        }
         SeqExp::Prim1(p, e, ann) => {
             instr.push(Instr::Comment(String::from("Prim1")));
-            instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm(&e, &env))));
-            instr.extend(check_prim1_type(Reg::Rax, &p));
+            instr.extend(load_imm(Reg::Rax, &e, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                0,
+                prim1_operand_type(p),
+                check_prim1_type(Reg::Rax, &p),
+            ));
             instr.extend(compile_prim1(*p, space, *ann));
         }
+        SeqExp::Prim2(Prim2::Add | Prim2::Sub | Prim2::Mul, e1, e2, _)
+            if is_unboxed(e1, types) || is_unboxed(e2, types) =>
+        {
+            instr.extend(compile_unboxed(e, &env, types));
+            instr.extend(tag_num(Reg::Rax));
+        }
+        // Compare unboxed numbers without tagging them
+        SeqExp::Prim2(p @ (Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge), e1, e2, ann)
+            if is_unboxed(e1, types) || is_unboxed(e2, types) =>
+        {
+            instr.push(Instr::Comment(String::from("Prim2")));
+            instr.extend(load_raw(
+                Reg::Rax,
+                e1,
+                &env,
+                types,
+                check_unless_known(
+                    types,
+                    *ann,
+                    0,
+                    Some(Type::Num),
+                    check_prim2_type(Reg::Rax, p),
+                ),
+            ));
+            instr.extend(load_raw(
+                Reg::R10,
+                e2,
+                &env,
+                types,
+                check_unless_known(
+                    types,
+                    *ann,
+                    1,
+                    Some(Type::Num),
+                    check_prim2_type(Reg::R10, p),
+                ),
+            ));
+            instr.extend(compile_prim2(*p, types, *ann));
+        }
+        SeqExp::Prim2(p @ (Prim2::Eq | Prim2::Neq), e1, e2, ann)
+            if (is_unboxed(e1, types) || matches!(e1, ImmExp::Num(_)))
+                && (is_unboxed(e2, types) || matches!(e2, ImmExp::Num(_))) =>
+        {
+            instr.push(Instr::Comment(String::from("Prim2")));
+            instr.extend(load_raw(Reg::Rax, e1, &env, types, Vec::new()));
+            instr.extend(load_raw(Reg::R10, e2, &env, types, Vec::new()));
+            instr.extend(compile_prim2(*p, types, *ann));
+        }
         SeqExp::Prim2(p, e1, e2, ann) => {
             instr.push(Instr::Comment(String::from("Prim2")));
-            instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm(&e1, &env))));
-            instr.extend(check_prim2_type(Reg::Rax, &p));
-            instr.push(Instr::Mov(MovArgs::ToReg(Reg::R10, compile_imm(&e2, &env))));
-            instr.extend(check_prim2_type(Reg::R10, &p));
-            instr.extend(compile_prim2(*p, *ann));
+            instr.extend(load_imm(Reg::Rax, &e1, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                0,
+                prim2_operand_type(p),
+                check_prim2_type(Reg::Rax, &p),
+            ));
+            instr.extend(load_imm(Reg::R10, &e2, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                1,
+                prim2_operand_type(p),
+                check_prim2_type(Reg::R10, &p),
+            ));
+            instr.extend(compile_prim2(*p, types, *ann));
         }
         SeqExp::Array(array, _) => {
             instr.push(Instr::Comment(String::from("Array")));
+            instr.extend(check_heap_space(8 * (array.len() as u32 + 2)));
             // Push classidx into heap
             instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(0))));
             instr.push(Instr::Mov(MovArgs::ToMem(
@@ -475,10 +794,7 @@ fn compile_to_instrs_help<'exp>(
             )));
             // Push array elements into heap
             for (i, element) in array.iter().enumerate() {
-                instr.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    compile_imm(&element, &env),
-                )));
+                instr.extend(load_imm(Reg::Rax, &element, &env, types));
                 instr.push(Instr::Mov(MovArgs::ToMem(
                     MemRef {
                         reg: Reg::R15,
@@ -503,31 +819,34 @@ fn compile_to_instrs_help<'exp>(
             array,
             index,
             new_value,
-            ann: _,
+            ann,
         } => {
             instr.push(Instr::Comment(String::from("ArraySet")));
             // Check classidx of array
             // NYI
             // Set Array Element
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::Rax,
-                compile_imm(&array, &env),
-            )));
-            instr.extend(check_array_type(Reg::Rax));
+            instr.extend(load_imm(Reg::Rax, &array, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                0,
+                Some(Type::Array),
+                check_array_type(Reg::Rax),
+            ));
             instr.push(Instr::Sub(BinArgs::ToReg(
                 Reg::Rax,
                 Arg32::Unsigned(ARRAY_TAG),
             )));
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::R10,
-                compile_imm(&index, &env),
-            )));
-            instr.extend(check_index_type(Reg::R10));
+            instr.extend(load_imm(Reg::R10, &index, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                1,
+                Some(Type::Num),
+                check_index_type(Reg::R10),
+            ));
             instr.extend(check_bounding(Reg::R10, Reg::Rax));
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::Rbx,
-                compile_imm(&new_value, &env),
-            )));
+            instr.extend(load_imm(Reg::Rbx, &new_value, &env, types));
             instr.push(Instr::Mov(MovArgs::ToMem(
                 MemRef {
                     reg: Reg::Rax,
@@ -551,15 +870,20 @@ fn compile_to_instrs_help<'exp>(
             ann: _,
         } => {
             instr.push(Instr::Comment(String::from("Let")));
-            instr.extend(compile_to_instrs_help(
-                &bound_exp,
-                env.clone(),
-                class_info.clone(),
-                space,
-                false,
-                env_size,
-                classidx,
-            ));
+            if types.is_unboxed(var) {
+                instr.extend(compile_unboxed(bound_exp, &env, types));
+            } else {
+                instr.extend(compile_to_instrs_help(
+                    &bound_exp,
+                    env.clone(),
+                    class_info.clone(),
+                    types,
+                    space,
+                    false,
+                    env_size,
+                    classidx,
+                ));
+            }
             env.insert(&var, -8 * (env_size as i32 + 1));
             instr.push(Instr::Mov(MovArgs::ToMem(
                 MemRef {
@@ -572,6 +896,7 @@ fn compile_to_instrs_help<'exp>(
                 &body,
                 env.clone(),
                 class_info.clone(),
+                types,
                 space,
                 is_tail,
                 env_size + 1,
@@ -585,11 +910,14 @@ fn compile_to_instrs_help<'exp>(
             ann,
         } => {
             instr.push(Instr::Comment(String::from("If")));
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::Rax,
-                compile_imm(&cond, &env),
-            )));
-            instr.extend(check_if_type(Reg::Rax));
+            instr.extend(load_imm(Reg::Rax, &cond, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                0,
+                Some(Type::Bool),
+                check_if_type(Reg::Rax),
+            ));
             instr.push(Instr::Mov(MovArgs::ToReg(
                 Reg::R10,
                 Arg64::Unsigned(SNAKE_FALSE),
@@ -600,6 +928,7 @@ fn compile_to_instrs_help<'exp>(
                 &thn,
                 env.clone(),
                 class_info.clone(),
+                types,
                 space,
                 is_tail,
                 env_size,
@@ -611,6 +940,7 @@ fn compile_to_instrs_help<'exp>(
                 &els,
                 env.clone(),
                 class_info.clone(),
+                types,
                 space,
                 is_tail,
                 env_size,
@@ -625,6 +955,7 @@ fn compile_to_instrs_help<'exp>(
             ann: _,
         } => {
             instr.push(Instr::Comment(String::from("MakeClosure")));
+            instr.extend(check_heap_space(3 * 8));
             // Move arity into heap
             instr.push(Instr::Mov(MovArgs::ToReg(
                 Reg::Rax,
@@ -650,10 +981,7 @@ fn compile_to_instrs_help<'exp>(
                 Reg32::Reg(Reg::Rax),
             )));
             // Move captured environment into heap
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::Rax,
-                compile_imm(&capture, &env),
-            )));
+            instr.extend(load_imm(Reg::Rax, &capture, &env, types));
             instr.push(Instr::Mov(MovArgs::ToMem(
                 MemRef {
                     reg: Reg::R15,
@@ -670,21 +998,26 @@ fn compile_to_instrs_help<'exp>(
             // Update the heap pointer
             instr.push(Instr::Add(BinArgs::ToReg(Reg::R15, Arg32::Unsigned(3 * 8))));
         }
-        SeqExp::CallClosure { fun, args, ann: _ } => {
+        SeqExp::CallClosure { fun, args, ann } => {
             instr.push(Instr::Comment(String::from("CallClosure")));
             // Check closure type
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::R10,
-                compile_imm(&fun, &env),
-            )));
-            instr.extend(check_closure_type(Reg::R10));
+            instr.extend(load_imm(Reg::R10, &fun, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                0,
+                Some(Type::Closure),
+                check_closure_type(Reg::R10),
+            ));
             instr.push(Instr::Sub(BinArgs::ToReg(
                 Reg::R10,
                 Arg32::Unsigned(CLOSURE_TAG),
             )));
             // Check arity number
             let arg_num: usize = args.len();
-            instr.extend(check_arity_number(Reg::R10, arg_num as u64));
+            if !types.has_arity(*ann, 0, arg_num) {
+                instr.extend(check_arity_number(Reg::R10, arg_num as u64));
+            }
             // Push captured environment as argument to stack
             let mut count: i32 = 16;
             instr.push(Instr::Mov(MovArgs::ToReg(
@@ -704,10 +1037,7 @@ fn compile_to_instrs_help<'exp>(
             count += 8;
             // Push the rest arguments
             for arg in args.iter() {
-                instr.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    compile_imm(&arg, &env),
-                )));
+                instr.extend(load_imm(Reg::Rax, &arg, &env, types));
                 instr.push(Instr::Mov(MovArgs::ToMem(
                     MemRef {
                         reg: Reg::Rsp,
@@ -719,41 +1049,7 @@ fn compile_to_instrs_help<'exp>(
             }
             if is_tail {
                 instr.push(Instr::Comment(String::from("CallClosure-Tail Recursion")));
-                // Move the captured environment
-                let mut arg_idx: i32 = 8;
-                instr.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    Arg64::Mem(MemRef {
-                        reg: Reg::Rsp,
-                        offset: Offset::Constant(-space - arg_idx - 8),
-                    }),
-                )));
-                instr.push(Instr::Mov(MovArgs::ToMem(
-                    MemRef {
-                        reg: Reg::Rsp,
-                        offset: Offset::Constant(-arg_idx),
-                    },
-                    Reg32::Reg(Reg::Rax),
-                )));
-                arg_idx += 8;
-                // Move the arguments
-                for _ in args.iter() {
-                    instr.push(Instr::Mov(MovArgs::ToReg(
-                        Reg::Rax,
-                        Arg64::Mem(MemRef {
-                            reg: Reg::Rsp,
-                            offset: Offset::Constant(-space - arg_idx - 8),
-                        }),
-                    )));
-                    instr.push(Instr::Mov(MovArgs::ToMem(
-                        MemRef {
-                            reg: Reg::Rsp,
-                            offset: Offset::Constant(-arg_idx),
-                        },
-                        Reg32::Reg(Reg::Rax),
-                    )));
-                    arg_idx += 8;
-                }
+                instr.extend(move_tail_args(space, args.len() + 1));
                 instr.push(Instr::Mov(MovArgs::ToReg(
                     Reg::Rax,
                     Arg64::Mem(MemRef {
@@ -778,6 +1074,38 @@ fn compile_to_instrs_help<'exp>(
                 instr.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(space))));
             }
         }
+        SeqExp::CallDirect {
+            label,
+            args,
+            ann: _,
+        } => {
+            instr.push(Instr::Comment(String::from("CallDirect")));
+            // Push the arguments, there is no captured environment
+            let mut count: i32 = 16;
+            for arg in args.iter() {
+                instr.extend(load_imm(Reg::Rax, &arg, &env, types));
+                instr.push(Instr::Mov(MovArgs::ToMem(
+                    MemRef {
+                        reg: Reg::Rsp,
+                        offset: Offset::Constant(-space - count),
+                    },
+                    Reg32::Reg(Reg::Rax),
+                )));
+                count += 8;
+            }
+            if is_tail {
+                instr.push(Instr::Comment(String::from("CallDirect-Tail Recursion")));
+                instr.extend(move_tail_args(space, args.len()));
+                instr.push(Instr::Jmp(JmpArg::Label(direct_label(label))));
+            } else {
+                instr.push(Instr::Comment(String::from(
+                    "CallDirect-Non Tail Recursion",
+                )));
+                instr.push(Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(space))));
+                instr.push(Instr::Call(JmpArg::Label(direct_label(label))));
+                instr.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(space))));
+            }
+        }
         SeqExp::Object {
             class,
             fields,
@@ -790,6 +1118,7 @@ fn compile_to_instrs_help<'exp>(
                 None => panic!("class is guaranteed to be in scope. Compile."),
             };
             instr.extend(check_field_num(fields.len(), fieldsize));
+            instr.extend(check_heap_space(8 * (fields.len() as u32 + 2)));
             // Push classidx into heap
             instr.push(Instr::Mov(MovArgs::ToReg(
                 Reg::Rax,
@@ -816,10 +1145,7 @@ fn compile_to_instrs_help<'exp>(
             )));
             // Push array elements into heap
             for (i, element) in fields.iter().enumerate() {
-                instr.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    compile_imm(&element, &env),
-                )));
+                instr.extend(load_imm(Reg::Rax, &element, &env, types));
                 instr.push(Instr::Mov(MovArgs::ToMem(
                     MemRef {
                         reg: Reg::R15,
@@ -848,21 +1174,32 @@ fn compile_to_instrs_help<'exp>(
         } => {
             instr.push(Instr::Comment(String::from("CallMethod")));
             // Check object type and method type
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::Rax,
-                compile_imm(&object, &env),
-            )));
-            instr.extend(check_array_type(Reg::Rax));
+            instr.extend(load_imm(Reg::Rax, &object, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                0,
+                Some(Type::Array),
+                check_array_type(Reg::Rax),
+            ));
             instr.push(Instr::Sub(BinArgs::ToReg(
                 Reg::Rax,
                 Arg32::Unsigned(ARRAY_TAG),
             )));
-            instr.extend(check_method_class(
-                Reg::Rax,
-                method.clone(),
-                class_info,
-                *ann,
-            ));
+            let first_class = method
+                .keys()
+                .next()
+                .and_then(|class| class_info.get(class))
+                .map(|info| info.id);
+            match first_class {
+                Some(id) if types.has_class(*ann, 0, id) => {}
+                _ => instr.extend(check_method_class(
+                    Reg::Rax,
+                    method.clone(),
+                    class_info,
+                    *ann,
+                )),
+            }
             let mut method_name: String = String::from("");
             for (_, method_name_) in method.iter() {
                 method_name = method_name_.clone();
@@ -871,18 +1208,23 @@ fn compile_to_instrs_help<'exp>(
             let fun = ImmExp::Var(method_name);
             // Class index in Rbx
             // Check closure type
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::R10,
-                compile_imm(&fun, &env),
-            )));
-            instr.extend(check_closure_type(Reg::R10));
+            instr.extend(load_imm(Reg::R10, &fun, &env, types));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                1,
+                Some(Type::Closure),
+                check_closure_type(Reg::R10),
+            ));
             instr.push(Instr::Sub(BinArgs::ToReg(
                 Reg::R10,
                 Arg32::Unsigned(CLOSURE_TAG),
             )));
             // Check arity number
             let arg_num: usize = args.len();
-            instr.extend(check_arity_number(Reg::R10, (arg_num + 1) as u64));
+            if !types.has_arity(*ann, 1, arg_num + 1) {
+                instr.extend(check_arity_number(Reg::R10, (arg_num + 1) as u64));
+            }
             // Push captured environment as argument to stack
             let mut count: i32 = 16;
             instr.push(Instr::Mov(MovArgs::ToReg(
@@ -901,10 +1243,7 @@ fn compile_to_instrs_help<'exp>(
             )));
             count += 8;
             // Push the object onto the stack
-            instr.push(Instr::Mov(MovArgs::ToReg(
-                Reg::Rax,
-                compile_imm(&object, &env),
-            )));
+            instr.extend(load_imm(Reg::Rax, &object, &env, types));
             instr.push(Instr::Mov(MovArgs::ToMem(
                 MemRef {
                     reg: Reg::Rsp,
@@ -915,10 +1254,7 @@ fn compile_to_instrs_help<'exp>(
             count += 8;
             // Push the rest arguments
             for arg in args.iter() {
-                instr.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    compile_imm(&arg, &env),
-                )));
+                instr.extend(load_imm(Reg::Rax, &arg, &env, types));
                 instr.push(Instr::Mov(MovArgs::ToMem(
                     MemRef {
                         reg: Reg::Rsp,
@@ -929,58 +1265,8 @@ fn compile_to_instrs_help<'exp>(
                 count += 8;
             }
             if is_tail {
-                instr.push(Instr::Comment(String::from("CallClosure-Tail Recursion")));
-                // Move the captured environment
-                let mut arg_idx: i32 = 8;
-                instr.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    Arg64::Mem(MemRef {
-                        reg: Reg::Rsp,
-                        offset: Offset::Constant(-space - arg_idx - 8),
-                    }),
-                )));
-                instr.push(Instr::Mov(MovArgs::ToMem(
-                    MemRef {
-                        reg: Reg::Rsp,
-                        offset: Offset::Constant(-arg_idx),
-                    },
-                    Reg32::Reg(Reg::Rax),
-                )));
-                arg_idx += 8;
-                // Move the object
-                instr.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    Arg64::Mem(MemRef {
-                        reg: Reg::Rsp,
-                        offset: Offset::Constant(-space - arg_idx - 8),
-                    }),
-                )));
-                instr.push(Instr::Mov(MovArgs::ToMem(
-                    MemRef {
-                        reg: Reg::Rsp,
-                        offset: Offset::Constant(-arg_idx),
-                    },
-                    Reg32::Reg(Reg::Rax),
-                )));
-                arg_idx += 8;
-                // Move the arguments
-                for _ in args.iter() {
-                    instr.push(Instr::Mov(MovArgs::ToReg(
-                        Reg::Rax,
-                        Arg64::Mem(MemRef {
-                            reg: Reg::Rsp,
-                            offset: Offset::Constant(-space - arg_idx - 8),
-                        }),
-                    )));
-                    instr.push(Instr::Mov(MovArgs::ToMem(
-                        MemRef {
-                            reg: Reg::Rsp,
-                            offset: Offset::Constant(-arg_idx),
-                        },
-                        Reg32::Reg(Reg::Rax),
-                    )));
-                    arg_idx += 8;
-                }
+                instr.push(Instr::Comment(String::from("CallMethod-Tail Recursion")));
+                instr.extend(move_tail_args(space, args.len() + 2));
                 instr.push(Instr::Mov(MovArgs::ToReg(
                     Reg::Rax,
                     Arg64::Mem(MemRef {
@@ -991,7 +1277,7 @@ fn compile_to_instrs_help<'exp>(
                 instr.push(Instr::Jmp(JmpArg::Reg(Reg::Rax)));
             } else {
                 instr.push(Instr::Comment(String::from(
-                    "CallClosure-Non Tail Recursion",
+                    "CallMethod-Non Tail Recursion",
                 )));
                 instr.push(Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(space))));
                 instr.push(Instr::Mov(MovArgs::ToReg(
@@ -1005,6 +1291,164 @@ fn compile_to_instrs_help<'exp>(
                 instr.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(space))));
             }
         }
+        SeqExp::While { cond, body, ann } => {
+            instr.push(Instr::Comment(String::from("While")));
+            instr.push(Instr::Label(format!("{}_{}", "while_start", ann)));
+            // Neither the condition nor the body is in tail position,
+            // both run again on the next iteration
+            instr.extend(compile_to_instrs_help(
+                &cond,
+                env.clone(),
+                class_info.clone(),
+                types,
+                space,
+                false,
+                env_size,
+                classidx,
+            ));
+            instr.extend(check_unless_known(
+                types,
+                *ann,
+                0,
+                Some(Type::Bool),
+                check_while_type(Reg::Rax),
+            ));
+            instr.push(Instr::Mov(MovArgs::ToReg(
+                Reg::R10,
+                Arg64::Unsigned(SNAKE_FALSE),
+            )));
+            instr.push(Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
+            instr.push(Instr::Je(JmpArg::Label(format!(
+                "{}_{}",
+                "while_done", ann
+            ))));
+            instr.extend(compile_to_instrs_help(
+                &body,
+                env.clone(),
+                class_info.clone(),
+                types,
+                space,
+                false,
+                env_size,
+                classidx,
+            ));
+            instr.push(Instr::Jmp(JmpArg::Label(format!(
+                "{}_{}",
+                "while_start", ann
+            ))));
+            instr.push(Instr::Label(format!("{}_{}", "while_done", ann)));
+            // A while loop evaluates to false
+            instr.push(Instr::Mov(MovArgs::ToReg(
+                Reg::Rax,
+                Arg64::Unsigned(SNAKE_FALSE),
+            )));
+        }
+        SeqExp::Assign { var, value, ann: _ } => {
+            instr.push(Instr::Comment(String::from("Assign")));
+            if types.is_unboxed(var) {
+                instr.extend(load_raw(Reg::Rax, &value, &env, types, Vec::new()));
+            } else {
+                instr.extend(load_imm(Reg::Rax, &value, &env, types));
+            }
+            instr.push(Instr::Mov(MovArgs::ToMem(
+                MemRef {
+                    reg: Reg::Rsp,
+                    offset: get_offset(&env, &var),
+                },
+                Reg32::Reg(Reg::Rax),
+            )));
+        }
+        SeqExp::Raise(imm, _) => {
+            instr.push(Instr::Comment(String::from("Raise")));
+            instr.extend(load_imm(Reg::Rax, &imm, &env, types));
+            instr.push(Instr::Jmp(JmpArg::Label(String::from("snake_raise"))));
+        }
+        SeqExp::Try {
+            body,
+            var,
+            handler,
+            ann,
+        } => {
+            instr.push(Instr::Comment(String::from("Try")));
+            // Push a handler record [catch label, rsp, previous handler]
+            // into the next three stack slots
+            let slot = |i: usize| MemRef {
+                reg: Reg::Rsp,
+                offset: Offset::Constant(-8 * (env_size + i) as i32),
+            };
+            instr.push(Instr::Mov(MovArgs::ToReg(
+                Reg::Rbx,
+                Arg64::Label(String::from("HANDLER")),
+            )));
+            instr.push(Instr::Mov(MovArgs::ToReg(
+                Reg::Rax,
+                Arg64::Mem(MemRef {
+                    reg: Reg::Rbx,
+                    offset: Offset::Constant(0),
+                }),
+            )));
+            instr.push(Instr::Mov(MovArgs::ToMem(slot(1), Reg32::Reg(Reg::Rax))));
+            instr.push(Instr::Mov(MovArgs::ToMem(slot(2), Reg32::Reg(Reg::Rsp))));
+            instr.push(Instr::Mov(MovArgs::ToReg(
+                Reg::Rax,
+                Arg64::Label(format!("catch_{}", ann)),
+            )));
+            instr.push(Instr::Mov(MovArgs::ToMem(slot(3), Reg32::Reg(Reg::Rax))));
+            instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rsp))));
+            instr.push(Instr::Sub(BinArgs::ToReg(
+                Reg::Rax,
+                Arg32::Signed(8 * (env_size as i32 + 3)),
+            )));
+            instr.push(Instr::Mov(MovArgs::ToMem(
+                MemRef {
+                    reg: Reg::Rbx,
+                    offset: Offset::Constant(0),
+                },
+                Reg32::Reg(Reg::Rax),
+            )));
+            // The handler has to be popped afterwards, so no tail calls
+            instr.extend(compile_to_instrs_help(
+                &body,
+                env.clone(),
+                class_info.clone(),
+                types,
+                space,
+                false,
+                env_size + 3,
+                classidx,
+            ));
+            instr.push(Instr::Comment(String::from("Pop handler")));
+            instr.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(slot(1)))));
+            instr.push(Instr::Mov(MovArgs::ToReg(
+                Reg::Rbx,
+                Arg64::Label(String::from("HANDLER")),
+            )));
+            instr.push(Instr::Mov(MovArgs::ToMem(
+                MemRef {
+                    reg: Reg::Rbx,
+                    offset: Offset::Constant(0),
+                },
+                Reg32::Reg(Reg::R10),
+            )));
+            instr.push(Instr::Jmp(JmpArg::Label(format!("try_done_{}", ann))));
+            // snake_raise already popped the handler and restored rsp, the
+            // raised value is in Rax
+            instr.push(Instr::Label(format!("catch_{}", ann)));
+            env.insert(&var, -8 * (env_size as i32 + 1));
+            instr.push(Instr::Mov(MovArgs::ToMem(slot(1), Reg32::Reg(Reg::Rax))));
+            instr.extend(compile_to_instrs_help(
+                &handler,
+                env.clone(),
+                class_info.clone(),
+                types,
+                space,
+                is_tail,
+                env_size + 1,
+                classidx,
+            ));
+            instr.push(Instr::Label(format!("try_done_{}", ann)));
+        }
+        SeqExp::Fail(err, _) => instr.extend(fail(*err)),
     };
     instr
 }
@@ -1019,7 +1463,11 @@ fn space_needed_helper<Ann>(e: &SeqExp<Ann>) -> i32 {
         | SeqExp::ArraySet { .. }
         | SeqExp::CallMethod { .. }
         | SeqExp::MakeClosure { .. }
-        | SeqExp::CallClosure { .. } => 0,
+        | SeqExp::CallClosure { .. }
+        | SeqExp::CallDirect { .. }
+        | SeqExp::Assign { .. }
+        | SeqExp::Raise(..)
+        | SeqExp::Fail(..) => 0,
         SeqExp::Let {
             var: _,
             bound_exp,
@@ -1035,6 +1483,19 @@ fn space_needed_helper<Ann>(e: &SeqExp<Ann>) -> i32 {
             els,
             ann: _,
         } => std::cmp::max(space_needed_helper(&thn), space_needed_helper(&els)),
+        SeqExp::While { cond, body, ann: _ } => {
+            std::cmp::max(space_needed_helper(&cond), space_needed_helper(&body))
+        }
+        // The handler record takes three slots while the body runs
+        SeqExp::Try {
+            body,
+            var: _,
+            handler,
+            ann: _,
+        } => std::cmp::max(
+            3 + space_needed_helper(&body),
+            1 + space_needed_helper(&handler),
+        ),
     }
 }
 
@@ -1049,21 +1510,91 @@ fn space_needed(e: &SeqExp<u32>, arg_num: i32) -> i32 {
 
 fn init_pointers() -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
-    // Initialize the stack pointer
-    // Initialize the heap pointer
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::R15,
-        Arg64::Label(String::from("HEAP")),
+    // Initialize the heap pointer to the heap the runtime passes in, and
+    // keep the end of it for the allocations to check against
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::R15, Arg64::Reg(Reg::Rdi))));
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::R14, Arg64::Reg(Reg::Rsi))));
+    // Keep the lowest address the frames may reach for the calls to check
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::R13, Arg64::Reg(Reg::Rdx))));
+    instr
+}
+
+fn direct_label(name: &str) -> String {
+    format!("{}_direct", name)
+}
+
+// Moves the arguments staged below the frame into the parameter slots of
+// the current frame, so the callee reuses it. The parameters live below the
+// return address, so the frame just grows or shrinks to the callee's arity,
+// and since the staging area is deeper than the parameter slots each write
+// only lands on a staged argument that was already moved.
+fn move_tail_args(space: i32, count: usize) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    for i in 0..count as i32 {
+        instr.push(Instr::Mov(MovArgs::ToReg(
+            Reg::Rax,
+            Arg64::Mem(MemRef {
+                reg: Reg::Rsp,
+                offset: Offset::Constant(-space - 16 - 8 * i),
+            }),
+        )));
+        instr.push(Instr::Mov(MovArgs::ToMem(
+            MemRef {
+                reg: Reg::Rsp,
+                offset: Offset::Constant(-8 - 8 * i),
+            },
+            Reg32::Reg(Reg::Rax),
+        )));
+    }
+    instr
+}
+
+// The entry of a direct function used by closure calls: the arguments are
+// moved over the env slot into the places a direct call puts them
+fn drop_env_slot(arity: usize) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    instr.push(Instr::Comment(String::from(
+        "Drop the captured environment",
     )));
+    for i in 0..arity as i32 {
+        instr.push(Instr::Mov(MovArgs::ToReg(
+            Reg::Rax,
+            Arg64::Mem(MemRef {
+                reg: Reg::Rsp,
+                offset: Offset::Constant(-16 - 8 * i),
+            }),
+        )));
+        instr.push(Instr::Mov(MovArgs::ToMem(
+            MemRef {
+                reg: Reg::Rsp,
+                offset: Offset::Constant(-8 - 8 * i),
+            },
+            Reg32::Reg(Reg::Rax),
+        )));
+    }
     instr
 }
 
-fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
+// The stack slots the frames of main, the functions and the methods take
+fn stack_slots(p: &SeqProg<u32>) -> usize {
+    let mut space = space_needed(&p.main, 0);
+    for fun in p.funs.iter() {
+        space += space_needed(&fun.body, fun.parameters.len() as i32);
+    }
+    for method in p.methods.iter() {
+        let fun = &method.fundecl;
+        space += space_needed(&fun.body, fun.parameters.len() as i32);
+    }
+    space as usize / 8
+}
+
+fn compile_to_instrs(p: &SeqProg<u32>, types: &TypeTable) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     instr.extend(compile_to_instrs_help(
         &p.main,
         HashMap::new(),
         p.class.clone(),
+        types,
         space_needed(&p.main, 0),
         true,
         0,
@@ -1071,7 +1602,13 @@ fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
     ));
     instr.push(Instr::Ret);
     for fun in p.funs.iter() {
+        let space = space_needed(&fun.body, fun.parameters.len() as i32);
         instr.push(Instr::Label(fun.name.clone()));
+        if p.direct.contains(&fun.name) {
+            instr.extend(drop_env_slot(fun.parameters.len()));
+            instr.push(Instr::Label(direct_label(&fun.name)));
+        }
+        instr.extend(check_stack_space(space));
         let mut env: HashMap<&str, i32> = HashMap::new();
         for (i, arg) in fun.parameters.iter().enumerate() {
             env.insert(&arg, -8 * (i as i32 + 1));
@@ -1080,7 +1617,8 @@ fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
             &fun.body,
             env,
             p.class.clone(),
-            space_needed(&fun.body, fun.parameters.len() as i32),
+            types,
+            space,
             true,
             fun.parameters.len(),
             0,
@@ -1088,7 +1626,9 @@ fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
         instr.push(Instr::Ret);
     }
     for method in p.methods.iter() {
+        let space = space_needed(&method.fundecl.body, method.fundecl.parameters.len() as i32);
         instr.push(Instr::Label(method.fundecl.name.clone()));
+        instr.extend(check_stack_space(space));
         let mut env: HashMap<&str, i32> = HashMap::new();
         for (i, arg) in method.fundecl.parameters.iter().enumerate() {
             env.insert(&arg, -8 * (i as i32 + 1));
@@ -1097,7 +1637,8 @@ fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
             &method.fundecl.body,
             env,
             p.class.clone(),
-            space_needed(&method.fundecl.body, method.fundecl.parameters.len() as i32),
+            types,
+            space,
             true,
             method.fundecl.parameters.len(),
             method.class,
@@ -1108,44 +1649,167 @@ fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
     instr
 }
 
+pub fn compile_to_bytecode<Span>(p: &SurfProg<Span>) -> Result<Bytecode, CompileErr<Span>>
+where
+    Span: Clone,
+{
+    let (seq_p, types) = compile_to_seq_prog(p)?;
+    Ok(time("codegen", || bytecode::compile(&seq_p, &types)))
+}
+
+// The C source of the program, to be built with runtime/stub.c
+pub fn compile_to_c<Span>(p: &SurfProg<Span>) -> Result<String, CompileErr<Span>>
+where
+    Span: Clone,
+{
+    let (seq_p, types) = compile_to_seq_prog(p)?;
+    Ok(time("codegen", || c::compile(&seq_p, &types)))
+}
+
+// The binary WebAssembly module of the program
+pub fn compile_to_wasm<Span>(p: &SurfProg<Span>) -> Result<Vec<u8>, CompileErr<Span>>
+where
+    Span: Clone,
+{
+    let (seq_p, types) = compile_to_seq_prog(p)?;
+    Ok(time("codegen", || wasm::compile(&seq_p, &types)))
+}
+
 pub fn compile_to_string<Span>(p: &SurfProg<Span>) -> Result<String, CompileErr<Span>>
+where
+    Span: Clone,
+{
+    compile_with_stats(p).map(|(asm, _)| asm)
+}
+
+pub fn compile_with_stats<Span>(
+    p: &SurfProg<Span>,
+) -> Result<(String, CompileStats), CompileErr<Span>>
+where
+    Span: Clone,
+{
+    compile_to_program(p).map(|(prog, stats)| (program_to_string(&prog), stats))
+}
+
+// The registers the System V ABI has a function preserve for its caller. An
+// even number of them keeps the stack aligned as it was.
+static CALLEE_SAVED: [Reg; 6] = [Reg::Rbx, Reg::Rbp, Reg::R12, Reg::R13, Reg::R14, Reg::R15];
+
+// The size in quadwords of the heap the runtimes give a program by default,
+// 64 MB
+pub const HEAP_SIZE: usize = 1 << 23;
+
+// The program as the backends get it, sequentialized and optimized, along
+// with the types inferred for its operands
+pub fn compile_to_seq_prog<Span>(
+    p: &SurfProg<Span>,
+) -> Result<(SeqProg<u32>, TypeTable), CompileErr<Span>>
+where
+    Span: Clone,
+{
+    seq_prog_with_stats(p, &mut CompileStats::default())
+}
+
+fn seq_prog_with_stats<Span>(
+    p: &SurfProg<Span>,
+    stats: &mut CompileStats,
+) -> Result<(SeqProg<u32>, TypeTable), CompileErr<Span>>
 where
     Span: Clone,
 {
     // first check for errors
-    check_prog(p)?;
+    time("check_prog", || check_prog(p))?;
+
+    // then check the type annotations, if there are any
+    time("typecheck", || typecheck::check_types(p))?;
+
+    // expand for loops into the core language
+    let core_p = time("desugar", || desugar::desugar(&tag_exp(p)));
 
     // then give all the variables unique names
-    let uniq_p = uniquify(&tag_exp(p));
+    let uniq_p = time("uniquify", || uniquify(&core_p));
 
     // lift class information to the top level
-    let (class_info, uniq_main) = class_lift(&uniq_p);
+    let (class_info, lifted_main) = time("class_lift", || class_lift(&uniq_p));
+    stats.classes = class_info.len() - 1;
+
+    // compile pattern matching into decision trees
+    let uniq_main = time("lower_matches", || {
+        matching::lower_matches(&lifted_main, &class_info)
+    });
 
     // lift definitions to the top level
-    let (defs, methods, main) = lambda_lift(&uniq_main);
+    let (defs, methods, main) = time("lambda_lift", || lambda_lift(&uniq_main));
+    stats.lifted_functions = defs.len();
+    stats.lifted_methods = methods.len();
     let (t_defs, t_methods, t_main) = tag_prog(&defs, &methods, &main);
 
     // then sequentialize
-    let seq_p = tag_sprog(&sequentialize_program(
-        class_info, &t_defs, &t_methods, &t_main,
-    ));
+    let seq_p = time("sequentialize", || {
+        sequentialize_program(class_info, &t_defs, &t_methods, &t_main)
+    });
+
+    let seq_p = time("optimize", || {
+        // fold constants and call the known functions directly
+        let seq_p = optimize::direct_calls(&optimize::fold_constants(&seq_p));
+
+        // inline the small functions and fold the constants they receive
+        let seq_p = optimize::fold_constants(&optimize::inline_calls(&seq_p));
+
+        // then drop the bindings that are no longer used
+        let seq_p = optimize::eliminate_dead_bindings(&seq_p);
+
+        // unnest the lets so the arithmetic in them can be unboxed
+        tag_sprog(&optimize::flatten_lets(&seq_p))
+    });
+
+    // find the operands whose types are known statically and the numbers
+    // that are kept untagged
+    let types = time("infer_types", || types::infer_types(&seq_p));
+    stats.removed_checks = types.removed_checks();
+    Ok((seq_p, types))
+}
+
+pub fn compile_to_program<Span>(
+    p: &SurfProg<Span>,
+) -> Result<(Program, CompileStats), CompileErr<Span>>
+where
+    Span: Clone,
+{
+    let mut stats = CompileStats::default();
+    let (seq_p, types) = seq_prog_with_stats(p, &mut stats)?;
 
     // then codegen
-    Ok(format!(
-        "\
-section .data
-HEAP:   times 1024 dq 0
-section .text
-        global start_here
-        extern snake_error
-        extern print_snake_val
-start_here:
-{}        call main
-        ret
-main:
-{}
-",
-        instrs_to_string(&init_pointers()),
-        instrs_to_string(&compile_to_instrs(&seq_p))
-    ))
+    // start_here keeps the callee-saved registers for the runtime, the rest
+    // of the code uses them freely
+    let mut text = vec![Instr::Label(String::from("start_here"))];
+    for r in CALLEE_SAVED {
+        text.push(Instr::Push(Arg32::Reg(r)));
+    }
+    text.extend(init_pointers());
+    text.push(Instr::Call(JmpArg::Label(String::from("main"))));
+    for r in CALLEE_SAVED.iter().rev() {
+        text.push(Instr::Pop(Arg32::Reg(*r)));
+    }
+    text.push(Instr::Ret);
+    text.push(Instr::Label(String::from("main")));
+    let instrs = time("codegen", || compile_to_instrs(&seq_p, &types));
+    text.extend(time("peephole", || peephole::optimize(&instrs)));
+    stats.instructions = text
+        .iter()
+        .filter(|i| !matches!(i, Instr::Label(_) | Instr::Comment(_)))
+        .count();
+    stats.runtime_checks = count_checks(&text);
+    stats.stack_slots = stack_slots(&seq_p);
+    let prog = Program {
+        data: vec![(String::from("HANDLER"), 1)],
+        globals: vec![String::from("start_here")],
+        externs: vec![
+            String::from("snake_error"),
+            String::from("snake_uncaught"),
+            String::from("print_snake_val"),
+        ],
+        text,
+    };
+    Ok((prog, stats))
 }
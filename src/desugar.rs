@@ -0,0 +1,372 @@
+use crate::runtime_error::RuntimeErr;
+use crate::syntax::{Exp, FunDecl, Pattern, Prim1, Prim2};
+
+// Hidden variables start with '#', which the parser never accepts in an Id,
+// so they cannot capture or shadow anything the user wrote.
+fn hidden_var(name: &str, ann: u32) -> String {
+    format!("#{}_{}", name, ann)
+}
+
+fn desugar_decls(decls: &[FunDecl<Exp<u32>, u32>]) -> Vec<FunDecl<Exp<u32>, u32>> {
+    decls
+        .iter()
+        .map(|decl| FunDecl {
+            name: decl.name.clone(),
+            parameters: decl.parameters.clone(),
+            body: desugar(&decl.body),
+            ann: decl.ann,
+            signature: decl.signature.clone(),
+        })
+        .collect()
+}
+
+// The same pattern with every variable replaced by a wildcard
+fn shape(pat: &Pattern<u32>) -> Pattern<u32> {
+    match pat {
+        Pattern::Var(_, ann) => Pattern::Wildcard(*ann),
+        Pattern::Wildcard(..) | Pattern::Num(..) | Pattern::Bool(..) => pat.clone(),
+        Pattern::Array(pats, ann) => Pattern::Array(pats.iter().map(shape).collect(), *ann),
+        Pattern::Object { class, fields, ann } => Pattern::Object {
+            class: class.clone(),
+            fields: fields.iter().map(shape).collect(),
+            ann: *ann,
+        },
+    }
+}
+
+// Bind the variables of pat to the parts of the value held by var
+fn destructure(pat: &Pattern<u32>, var: &str, bindings: &mut Vec<(String, Exp<u32>)>) {
+    match pat {
+        Pattern::Wildcard(..) | Pattern::Num(..) | Pattern::Bool(..) => {}
+        Pattern::Var(x, ann) => bindings.push((x.clone(), Exp::Var(var.to_string(), *ann))),
+        Pattern::Array(pats, ann)
+        | Pattern::Object {
+            fields: pats, ann, ..
+        } => {
+            for (i, p) in pats.iter().enumerate() {
+                let element = Exp::Prim2(
+                    Prim2::ArrayGet,
+                    Box::new(Exp::Var(var.to_string(), *ann)),
+                    Box::new(Exp::Num(i as i64, *ann)),
+                    *ann,
+                );
+                match p {
+                    Pattern::Var(x, _) => bindings.push((x.clone(), element)),
+                    Pattern::Array(..) | Pattern::Object { .. } => {
+                        let part = hidden_var("let", p.ann());
+                        bindings.push((part.clone(), element));
+                        destructure(p, &part, bindings);
+                    }
+                    Pattern::Wildcard(..) | Pattern::Num(..) | Pattern::Bool(..) => {}
+                }
+            }
+        }
+    }
+}
+
+// Loop over the hidden index from its current value up to (excluding) stop,
+// binding var to the element produced by current for every iteration:
+//
+// while index < stop:
+//   (let var = current in body);
+//   index := index + 1
+// end
+fn counting_loop(
+    var: &str,
+    index: &str,
+    stop: Exp<u32>,
+    current: Exp<u32>,
+    body: Exp<u32>,
+    ann: u32,
+) -> Exp<u32> {
+    Exp::While {
+        cond: Box::new(Exp::Prim2(
+            Prim2::Lt,
+            Box::new(Exp::Var(index.to_string(), ann)),
+            Box::new(stop),
+            ann,
+        )),
+        body: Box::new(Exp::Semicolon {
+            e1: Box::new(Exp::Let {
+                bindings: vec![(var.to_string(), current)],
+                body: Box::new(body),
+                ann,
+            }),
+            e2: Box::new(Exp::SetField {
+                field: index.to_string(),
+                value: Box::new(Exp::Prim1(
+                    Prim1::Add1,
+                    Box::new(Exp::Var(index.to_string(), ann)),
+                    ann,
+                )),
+                ann,
+            }),
+            ann,
+        }),
+        ann,
+    }
+}
+
+// Expand the surface-only loops and destructuring lets into the core language.
+//
+// let [a, Point(b, _)] = e in body
+// ==> let #let = e,
+//         #check = match #let: | [_, Point(_, _)] => true | _ => <fail> end,
+//         a = #let[0], #part = #let[1], b = #part[0] in
+//     body
+//
+// The match checks the whole shape before any part is read, it is lowered
+// into length and class tests together with the user's own matches.
+//
+// for x in e: body end
+// ==> let #array = e, #length = length(#array), #index = 0 in
+//     while #index < #length: (let x = #array[#index] in body); ... end
+//
+// for i in range(a, b): body end
+// ==> let #index = a, #stop = b in
+//     while #index < #stop: (let i = #index in body); ... end
+//
+// The ArrayGet keeps the usual array type and bounds checks, and the
+// comparison keeps the usual number checks on the range bounds.
+//
+// Precondition: the program passed check_prog and is tagged
+pub fn desugar(p: &Exp<u32>) -> Exp<u32> {
+    match p {
+        Exp::Num(..) | Exp::Bool(..) | Exp::Var(..) => p.clone(),
+        Exp::Prim1(prim, e, ann) => Exp::Prim1(*prim, Box::new(desugar(e)), *ann),
+        Exp::Prim2(prim, e1, e2, ann) => {
+            Exp::Prim2(*prim, Box::new(desugar(e1)), Box::new(desugar(e2)), *ann)
+        }
+        Exp::Let {
+            bindings,
+            body,
+            ann,
+        } => Exp::Let {
+            bindings: bindings
+                .iter()
+                .map(|(x, e)| (x.clone(), desugar(e)))
+                .collect(),
+            body: Box::new(desugar(body)),
+            ann: *ann,
+        },
+        Exp::If {
+            cond,
+            thn,
+            els,
+            ann,
+        } => Exp::If {
+            cond: Box::new(desugar(cond)),
+            thn: Box::new(desugar(thn)),
+            els: Box::new(desugar(els)),
+            ann: *ann,
+        },
+        Exp::Array(es, ann) => Exp::Array(es.iter().map(desugar).collect(), *ann),
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ann,
+        } => Exp::ArraySet {
+            array: Box::new(desugar(array)),
+            index: Box::new(desugar(index)),
+            new_value: Box::new(desugar(new_value)),
+            ann: *ann,
+        },
+        Exp::Semicolon { e1, e2, ann } => Exp::Semicolon {
+            e1: Box::new(desugar(e1)),
+            e2: Box::new(desugar(e2)),
+            ann: *ann,
+        },
+        Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+            decls: desugar_decls(decls),
+            body: Box::new(desugar(body)),
+            ann: *ann,
+        },
+        Exp::Call(fun, args, ann) => Exp::Call(
+            Box::new(desugar(fun)),
+            args.iter().map(desugar).collect(),
+            *ann,
+        ),
+        Exp::Lambda {
+            parameters,
+            body,
+            ann,
+        } => Exp::Lambda {
+            parameters: parameters.clone(),
+            body: Box::new(desugar(body)),
+            ann: *ann,
+        },
+        Exp::ClassDef {
+            name,
+            fields,
+            field_types,
+            methods,
+            body,
+            ann,
+        } => Exp::ClassDef {
+            name: name.clone(),
+            fields: fields.clone(),
+            field_types: field_types.clone(),
+            methods: desugar_decls(methods),
+            body: Box::new(desugar(body)),
+            ann: *ann,
+        },
+        Exp::Object { class, fields, ann } => Exp::Object {
+            class: class.clone(),
+            fields: fields.iter().map(desugar).collect(),
+            ann: *ann,
+        },
+        Exp::CallMethod {
+            object,
+            method,
+            args,
+            ann,
+        } => Exp::CallMethod {
+            object: Box::new(desugar(object)),
+            method: method.clone(),
+            args: args.iter().map(desugar).collect(),
+            ann: *ann,
+        },
+        Exp::SetField { field, value, ann } => Exp::SetField {
+            field: field.clone(),
+            value: Box::new(desugar(value)),
+            ann: *ann,
+        },
+        Exp::While { cond, body, ann } => Exp::While {
+            cond: Box::new(desugar(cond)),
+            body: Box::new(desugar(body)),
+            ann: *ann,
+        },
+        Exp::For {
+            var,
+            array,
+            body,
+            ann,
+        } => {
+            let array_var = hidden_var("for_array", *ann);
+            let length_var = hidden_var("for_length", *ann);
+            let index_var = hidden_var("for_index", *ann);
+            let element = Exp::Prim2(
+                Prim2::ArrayGet,
+                Box::new(Exp::Var(array_var.clone(), *ann)),
+                Box::new(Exp::Var(index_var.clone(), *ann)),
+                *ann,
+            );
+            Exp::Let {
+                bindings: vec![
+                    (array_var.clone(), desugar(array)),
+                    (
+                        length_var.clone(),
+                        Exp::Prim1(
+                            Prim1::Length,
+                            Box::new(Exp::Var(array_var.clone(), *ann)),
+                            *ann,
+                        ),
+                    ),
+                    (index_var.clone(), Exp::Num(0, *ann)),
+                ],
+                body: Box::new(counting_loop(
+                    var,
+                    &index_var,
+                    Exp::Var(length_var, *ann),
+                    element,
+                    desugar(body),
+                    *ann,
+                )),
+                ann: *ann,
+            }
+        }
+        Exp::ForRange {
+            var,
+            start,
+            stop,
+            body,
+            ann,
+        } => {
+            let index_var = hidden_var("for_index", *ann);
+            let stop_var = hidden_var("for_stop", *ann);
+            Exp::Let {
+                bindings: vec![
+                    (index_var.clone(), desugar(start)),
+                    (stop_var.clone(), desugar(stop)),
+                ],
+                body: Box::new(counting_loop(
+                    var,
+                    &index_var,
+                    Exp::Var(stop_var, *ann),
+                    Exp::Var(index_var.clone(), *ann),
+                    desugar(body),
+                    *ann,
+                )),
+                ann: *ann,
+            }
+        }
+        Exp::Raise(e, ann) => Exp::Raise(Box::new(desugar(e)), *ann),
+        // annotations were checked by typecheck and are not needed anymore
+        Exp::Ascribe(e, _, _) => desugar(e),
+        Exp::Try {
+            body,
+            var,
+            handler,
+            ann,
+        } => Exp::Try {
+            body: Box::new(desugar(body)),
+            var: var.clone(),
+            handler: Box::new(desugar(handler)),
+            ann: *ann,
+        },
+        Exp::LetPattern {
+            bindings,
+            body,
+            ann,
+        } => {
+            let mut core_bindings: Vec<(String, Exp<u32>)> = Vec::new();
+            for (pat, e) in bindings.iter() {
+                if let Pattern::Var(x, _) = pat {
+                    core_bindings.push((x.clone(), desugar(e)));
+                    continue;
+                }
+                let pat_ann = pat.ann();
+                let value = hidden_var("let", pat_ann);
+                let check = Exp::Match {
+                    scrutinee: Box::new(Exp::Var(value.clone(), pat_ann)),
+                    arms: vec![
+                        (shape(pat), Exp::Bool(true, pat_ann)),
+                        (
+                            Pattern::Wildcard(pat_ann),
+                            Exp::Fail(RuntimeErr::DestructureError, pat_ann),
+                        ),
+                    ],
+                    ann: pat_ann,
+                };
+                core_bindings.push((value.clone(), desugar(e)));
+                core_bindings.push((hidden_var("let_check", pat_ann), check));
+                destructure(pat, &value, &mut core_bindings);
+            }
+            Exp::Let {
+                bindings: core_bindings,
+                body: Box::new(desugar(body)),
+                ann: *ann,
+            }
+        }
+        Exp::Match {
+            scrutinee,
+            arms,
+            ann,
+        } => Exp::Match {
+            scrutinee: Box::new(desugar(scrutinee)),
+            arms: arms
+                .iter()
+                .map(|(pat, e)| (pat.clone(), desugar(e)))
+                .collect(),
+            ann: *ann,
+        },
+        Exp::MakeClosure { .. }
+        | Exp::Fail(..)
+        | Exp::CallUniqMethod { .. }
+        | Exp::MethodDefs { .. }
+        | Exp::Assign { .. } => {
+            panic!("Should never exist during desugar!")
+        }
+    }
+}
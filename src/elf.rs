@@ -0,0 +1,327 @@
+use crate::asm::Program;
+use crate::encode::{encode, Reloc, RelocKind};
+use std::collections::HashMap;
+
+// Writes a program as an ELF64 relocatable object for x86-64, the same kind
+// of file nasm -f elf64 produces. The object has a data section, a text
+// section with its relocations, and the symbol and string tables. Labels
+// other than the globals are referred to through their section's symbol,
+// like nasm does.
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+
+const SHF_WRITE: u64 = 1;
+const SHF_ALLOC: u64 = 2;
+const SHF_EXECINSTR: u64 = 4;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_SECTION: u8 = 3;
+
+const R_X86_64_64: u64 = 1;
+const R_X86_64_PC32: u64 = 2;
+
+// The section indices, in the order the headers are written, .rela.text
+// comes in between at 3
+const DATA: u16 = 1;
+const TEXT: u16 = 2;
+const SHSTRTAB: u16 = 4;
+const SYMTAB: u16 = 5;
+const STRTAB: u16 = 6;
+
+// A string table, the first string is always the empty one
+struct StrTab {
+    bytes: Vec<u8>,
+}
+
+impl StrTab {
+    fn new() -> StrTab {
+        StrTab { bytes: vec![0] }
+    }
+
+    fn add(&mut self, s: &str) -> u32 {
+        let idx = self.bytes.len() as u32;
+        self.bytes.extend(s.as_bytes());
+        self.bytes.push(0);
+        idx
+    }
+}
+
+struct Symbol {
+    name: u32,
+    bind: u8,
+    kind: u8,
+    section: u16,
+    value: u64,
+}
+
+struct Section {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    data: Vec<u8>,
+    link: u32,
+    info: u32,
+    align: u64,
+    entsize: u64,
+}
+
+fn write_symbol(out: &mut Vec<u8>, s: &Symbol) {
+    out.extend(s.name.to_le_bytes());
+    out.push(s.bind << 4 | s.kind);
+    out.push(0);
+    out.extend(s.section.to_le_bytes());
+    out.extend(s.value.to_le_bytes());
+    out.extend(0u64.to_le_bytes());
+}
+
+fn write_rela(out: &mut Vec<u8>, offset: usize, symbol: usize, kind: RelocKind, addend: i64) {
+    let kind = match kind {
+        RelocKind::Abs64 => R_X86_64_64,
+        RelocKind::Pc32 => R_X86_64_PC32,
+    };
+    out.extend((offset as u64).to_le_bytes());
+    out.extend(((symbol as u64) << 32 | kind).to_le_bytes());
+    out.extend(addend.to_le_bytes());
+}
+
+fn pad_to(out: &mut Vec<u8>, align: u64) {
+    let len = out.len() as u64;
+    out.resize(len.next_multiple_of(align) as usize, 0);
+}
+
+pub fn write_object(p: &Program) -> Vec<u8> {
+    let code = encode(&p.text);
+
+    // the data section is all zeroes, so only the labels need placing
+    let mut data_labels: HashMap<&str, usize> = HashMap::new();
+    let mut data_size = 0;
+    for (label, size) in &p.data {
+        data_labels.insert(label, data_size);
+        data_size += 8 * size;
+    }
+
+    // the local symbols come first: the sections, then every label
+    let mut strtab = StrTab::new();
+    let mut symbols = vec![
+        Symbol {
+            name: 0,
+            bind: STB_LOCAL,
+            kind: STT_NOTYPE,
+            section: 0,
+            value: 0,
+        },
+        Symbol {
+            name: 0,
+            bind: STB_LOCAL,
+            kind: STT_SECTION,
+            section: DATA,
+            value: 0,
+        },
+        Symbol {
+            name: 0,
+            bind: STB_LOCAL,
+            kind: STT_SECTION,
+            section: TEXT,
+            value: 0,
+        },
+    ];
+    let mut local_labels: Vec<(&str, u16, usize)> = p
+        .data
+        .iter()
+        .map(|(l, _)| (l.as_str(), DATA, data_labels[l.as_str()]))
+        .collect();
+    let mut text_labels: Vec<(&str, u16, usize)> = code
+        .labels
+        .iter()
+        .map(|(l, at)| (l.as_str(), TEXT, *at))
+        .collect();
+    text_labels.sort_by_key(|(l, _, at)| (*at, *l));
+    local_labels.extend(text_labels);
+    for (label, section, value) in local_labels {
+        if !p.globals.iter().any(|g| g == label) {
+            symbols.push(Symbol {
+                name: strtab.add(label),
+                bind: STB_LOCAL,
+                kind: STT_NOTYPE,
+                section,
+                value: value as u64,
+            });
+        }
+    }
+    let first_global = symbols.len();
+
+    // then the globals defined here and the externs
+    let mut global_idx: HashMap<&str, usize> = HashMap::new();
+    for g in &p.globals {
+        let (section, value) = match code.labels.get(g) {
+            Some(at) => (TEXT, *at),
+            None => (DATA, data_labels[g.as_str()]),
+        };
+        global_idx.insert(g, symbols.len());
+        symbols.push(Symbol {
+            name: strtab.add(g),
+            bind: STB_GLOBAL,
+            kind: STT_NOTYPE,
+            section,
+            value: value as u64,
+        });
+    }
+    let mut undefined: Vec<&str> = p.externs.iter().map(|e| e.as_str()).collect();
+    for Reloc { target, .. } in &code.relocs {
+        let known = code.labels.contains_key(target) || data_labels.contains_key(target.as_str());
+        if !known && !undefined.contains(&target.as_str()) {
+            undefined.push(target);
+        }
+    }
+    for e in undefined {
+        global_idx.insert(e, symbols.len());
+        symbols.push(Symbol {
+            name: strtab.add(e),
+            bind: STB_GLOBAL,
+            kind: STT_NOTYPE,
+            section: 0,
+            value: 0,
+        });
+    }
+
+    let mut rela = Vec::new();
+    for r in &code.relocs {
+        let (symbol, base) = if let Some(idx) = global_idx.get(r.target.as_str()) {
+            (*idx, 0)
+        } else if let Some(at) = code.labels.get(&r.target) {
+            (TEXT as usize, *at)
+        } else {
+            (DATA as usize, data_labels[r.target.as_str()])
+        };
+        write_rela(&mut rela, r.offset, symbol, r.kind, r.addend + base as i64);
+    }
+
+    let mut symtab = Vec::new();
+    for s in &symbols {
+        write_symbol(&mut symtab, s);
+    }
+
+    let mut shstrtab = StrTab::new();
+    let names: Vec<u32> = [
+        ".data",
+        ".text",
+        ".rela.text",
+        ".shstrtab",
+        ".symtab",
+        ".strtab",
+    ]
+    .iter()
+    .map(|n| shstrtab.add(n))
+    .collect();
+    let sections = vec![
+        Section {
+            name: names[0],
+            kind: SHT_PROGBITS,
+            flags: SHF_WRITE | SHF_ALLOC,
+            data: vec![0; data_size],
+            link: 0,
+            info: 0,
+            align: 8,
+            entsize: 0,
+        },
+        Section {
+            name: names[1],
+            kind: SHT_PROGBITS,
+            flags: SHF_ALLOC | SHF_EXECINSTR,
+            data: code.bytes,
+            link: 0,
+            info: 0,
+            align: 16,
+            entsize: 0,
+        },
+        Section {
+            name: names[2],
+            kind: SHT_RELA,
+            flags: 0,
+            data: rela,
+            link: SYMTAB as u32,
+            info: TEXT as u32,
+            align: 8,
+            entsize: 24,
+        },
+        Section {
+            name: names[3],
+            kind: SHT_STRTAB,
+            flags: 0,
+            data: shstrtab.bytes.clone(),
+            link: 0,
+            info: 0,
+            align: 1,
+            entsize: 0,
+        },
+        Section {
+            name: names[4],
+            kind: SHT_SYMTAB,
+            flags: 0,
+            data: symtab,
+            link: STRTAB as u32,
+            info: first_global as u32,
+            align: 8,
+            entsize: 24,
+        },
+        Section {
+            name: names[5],
+            kind: SHT_STRTAB,
+            flags: 0,
+            data: strtab.bytes,
+            link: 0,
+            info: 0,
+            align: 1,
+            entsize: 0,
+        },
+    ];
+
+    // the contents follow the file header, then come the section headers
+    let mut out = vec![0; 64];
+    let mut offsets = Vec::new();
+    for s in &sections {
+        pad_to(&mut out, s.align);
+        offsets.push(out.len());
+        out.extend(&s.data);
+    }
+    pad_to(&mut out, 8);
+    let shoff = out.len();
+
+    out.extend([0; 64]);
+    for (s, offset) in sections.iter().zip(offsets) {
+        out.extend(s.name.to_le_bytes());
+        out.extend(s.kind.to_le_bytes());
+        out.extend(s.flags.to_le_bytes());
+        out.extend(0u64.to_le_bytes());
+        out.extend((offset as u64).to_le_bytes());
+        out.extend((s.data.len() as u64).to_le_bytes());
+        out.extend(s.link.to_le_bytes());
+        out.extend(s.info.to_le_bytes());
+        out.extend(s.align.to_le_bytes());
+        out.extend(s.entsize.to_le_bytes());
+    }
+
+    let mut header = Vec::new();
+    header.extend([0x7f, b'E', b'L', b'F', 2, 1, 1, 0]);
+    header.extend([0; 8]);
+    header.extend(1u16.to_le_bytes()); // relocatable
+    header.extend(62u16.to_le_bytes()); // x86-64
+    header.extend(1u32.to_le_bytes());
+    header.extend(0u64.to_le_bytes()); // no entry point
+    header.extend(0u64.to_le_bytes()); // no program headers
+    header.extend((shoff as u64).to_le_bytes());
+    header.extend(0u32.to_le_bytes());
+    header.extend(64u16.to_le_bytes());
+    header.extend(0u16.to_le_bytes());
+    header.extend(0u16.to_le_bytes());
+    header.extend(64u16.to_le_bytes());
+    header.extend((sections.len() as u16 + 1).to_le_bytes());
+    header.extend(SHSTRTAB.to_le_bytes());
+    out[..64].copy_from_slice(&header);
+    out
+}
@@ -0,0 +1,776 @@
+use crate::asm::{Arg32, Arg64, BinArgs, Instr, JmpArg, MemRef, MovArgs, Offset, Reg, Reg32};
+use std::collections::HashMap;
+
+// Machine code for the instructions we emit. The encodings are the ones
+// nasm picks with its default optimisations, so the bytes match what nasm
+// assembles from the same listing.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelocKind {
+    // the 64-bit address of the target
+    Abs64,
+    // the 32-bit offset of the target from the end of the field
+    Pc32,
+}
+
+// A field the linker has to fill in with the address of a label that is not
+// in the text section, or whose absolute address is needed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reloc {
+    pub offset: usize,
+    pub target: String,
+    pub kind: RelocKind,
+    pub addend: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Code {
+    pub bytes: Vec<u8>,
+    pub labels: HashMap<String, usize>,
+    pub relocs: Vec<Reloc>,
+}
+
+fn reg_num(r: Reg) -> u8 {
+    match r {
+        Reg::Rax => 0,
+        Reg::Rcx => 1,
+        Reg::Rdx => 2,
+        Reg::Rbx => 3,
+        Reg::Rsp => 4,
+        Reg::Rbp => 5,
+        Reg::Rsi => 6,
+        Reg::Rdi => 7,
+        Reg::R8 => 8,
+        Reg::R9 => 9,
+        Reg::R10 => 10,
+        Reg::R11 => 11,
+        Reg::R12 => 12,
+        Reg::R13 => 13,
+        Reg::R14 => 14,
+        Reg::R15 => 15,
+    }
+}
+
+fn fits_i8(n: i64) -> bool {
+    i8::try_from(n).is_ok()
+}
+
+fn fits_i32(n: i64) -> bool {
+    i32::try_from(n).is_ok()
+}
+
+enum Rm {
+    Reg(Reg),
+    Mem(MemRef),
+}
+
+// Emits an optional REX prefix, the opcode and the ModRM byte with whatever
+// SIB byte and displacement the r/m operand needs. field is either a
+// register number or the opcode extension.
+fn emit_modrm(out: &mut Vec<u8>, wide: bool, opcode: &[u8], field: u8, rm: &Rm) {
+    let (index, base) = match rm {
+        Rm::Reg(r) => (0, reg_num(*r)),
+        Rm::Mem(MemRef {
+            reg,
+            offset: Offset::Computed { reg: index, .. },
+        }) => (reg_num(*index), reg_num(*reg)),
+        Rm::Mem(m) => (0, reg_num(m.reg)),
+    };
+    let rex = 0x40 | (wide as u8) << 3 | (field >> 3) << 2 | (index >> 3) << 1 | base >> 3;
+    if rex != 0x40 {
+        out.push(rex);
+    }
+    out.extend(opcode);
+    let field = (field & 7) << 3;
+    let base = base & 7;
+    match rm {
+        Rm::Reg(_) => out.push(0xc0 | field | base),
+        Rm::Mem(m) => {
+            let (sib, disp) = match m.offset {
+                // rsp and r12 can only be a base through a SIB byte
+                Offset::Constant(disp) => (if base == 4 { Some(0x24) } else { None }, disp),
+                Offset::Computed {
+                    factor, constant, ..
+                } => {
+                    let scale = match factor {
+                        1 => 0,
+                        2 => 1,
+                        4 => 2,
+                        8 => 3,
+                        _ => panic!("Invalid scale factor {} in memory operand", factor),
+                    };
+                    (Some(scale << 6 | (index & 7) << 3 | base), constant)
+                }
+            };
+            // rbp and r13 need a displacement even when it is zero
+            let mode = if disp == 0 && base != 5 {
+                0x00
+            } else if fits_i8(disp as i64) {
+                0x40
+            } else {
+                0x80
+            };
+            out.push(mode | field | if sib.is_some() { 4 } else { base });
+            out.extend(sib);
+            match mode {
+                0x40 => out.push(disp as u8),
+                0x80 => out.extend(disp.to_le_bytes()),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn arg32_imm(arg: Arg32) -> Option<i64> {
+    match arg {
+        Arg32::Signed(n) => Some(n as i64),
+        Arg32::Unsigned(n) => Some(n as i64),
+        Arg32::Reg(_) | Arg32::Mem(_) => None,
+    }
+}
+
+fn reg32_imm(arg: Reg32) -> Option<i64> {
+    match arg {
+        Reg32::Signed(n) => Some(n as i64),
+        Reg32::Unsigned(n) => Some(n as i64),
+        Reg32::Reg(_) => None,
+    }
+}
+
+// The opcode extension of an arithmetic instruction with an immediate; the
+// instruction's other opcodes are derived from it
+fn arith_ext(i: &Instr) -> Option<u8> {
+    match i {
+        Instr::Add(_) => Some(0),
+        Instr::Or(_) => Some(1),
+        Instr::And(_) => Some(4),
+        Instr::Sub(_) => Some(5),
+        Instr::Xor(_) => Some(6),
+        Instr::Cmp(_) => Some(7),
+        _ => None,
+    }
+}
+
+fn shift_ext(i: &Instr) -> Option<u8> {
+    match i {
+        Instr::Shl(_) => Some(4),
+        Instr::Shr(_) => Some(5),
+        Instr::Sar(_) => Some(7),
+        _ => None,
+    }
+}
+
+fn encode_arith(out: &mut Vec<u8>, ext: u8, args: &BinArgs) {
+    // add r/m, reg is 0x01, add reg, r/m is 0x03 and add rax, imm32 is 0x05,
+    // the other instructions follow in steps of eight
+    let base = ext << 3;
+    let (rm, src) = match args {
+        BinArgs::ToReg(r, Arg32::Reg(s)) => {
+            return emit_modrm(out, true, &[base | 1], reg_num(*s), &Rm::Reg(*r));
+        }
+        BinArgs::ToReg(r, Arg32::Mem(m)) => {
+            return emit_modrm(out, true, &[base | 3], reg_num(*r), &Rm::Mem(*m));
+        }
+        BinArgs::ToMem(m, Reg32::Reg(s)) => {
+            return emit_modrm(out, true, &[base | 1], reg_num(*s), &Rm::Mem(*m));
+        }
+        BinArgs::ToReg(r, arg) => (Rm::Reg(*r), arg32_imm(*arg).unwrap()),
+        BinArgs::ToMem(m, arg) => (Rm::Mem(*m), reg32_imm(*arg).unwrap()),
+    };
+    if fits_i8(src) {
+        emit_modrm(out, true, &[0x83], ext, &rm);
+        out.push(src as u8);
+    } else {
+        if let Rm::Reg(Reg::Rax) = rm {
+            out.extend([0x48, base | 5]);
+        } else {
+            emit_modrm(out, true, &[0x81], ext, &rm);
+        }
+        out.extend((src as i32).to_le_bytes());
+    }
+}
+
+fn encode_shift(out: &mut Vec<u8>, ext: u8, args: &BinArgs) {
+    let (rm, count) = match args {
+        BinArgs::ToReg(r, arg) => (Rm::Reg(*r), arg32_imm(*arg)),
+        BinArgs::ToMem(m, arg) => (Rm::Mem(*m), reg32_imm(*arg)),
+    };
+    match count {
+        Some(1) => emit_modrm(out, true, &[0xd1], ext, &rm),
+        Some(n) => {
+            emit_modrm(out, true, &[0xc1], ext, &rm);
+            out.push(n as u8);
+        }
+        // the count is in cl
+        None => emit_modrm(out, true, &[0xd3], ext, &rm),
+    }
+}
+
+fn encode_imul(out: &mut Vec<u8>, args: &BinArgs) {
+    match args {
+        BinArgs::ToReg(r, Arg32::Reg(s)) => {
+            emit_modrm(out, true, &[0x0f, 0xaf], reg_num(*r), &Rm::Reg(*s))
+        }
+        BinArgs::ToReg(r, Arg32::Mem(m)) => {
+            emit_modrm(out, true, &[0x0f, 0xaf], reg_num(*r), &Rm::Mem(*m))
+        }
+        BinArgs::ToReg(r, arg) => {
+            let n = arg32_imm(*arg).unwrap();
+            if fits_i8(n) {
+                emit_modrm(out, true, &[0x6b], reg_num(*r), &Rm::Reg(*r));
+                out.push(n as u8);
+            } else {
+                emit_modrm(out, true, &[0x69], reg_num(*r), &Rm::Reg(*r));
+                out.extend((n as i32).to_le_bytes());
+            }
+        }
+        BinArgs::ToMem(..) => panic!("imul cannot write to memory"),
+    }
+}
+
+fn encode_test(out: &mut Vec<u8>, args: &BinArgs) {
+    let (rm, src) = match args {
+        BinArgs::ToReg(r, Arg32::Reg(s)) => {
+            return emit_modrm(out, true, &[0x85], reg_num(*s), &Rm::Reg(*r));
+        }
+        BinArgs::ToReg(r, Arg32::Mem(m)) => {
+            return emit_modrm(out, true, &[0x85], reg_num(*r), &Rm::Mem(*m));
+        }
+        BinArgs::ToMem(m, Reg32::Reg(s)) => {
+            return emit_modrm(out, true, &[0x85], reg_num(*s), &Rm::Mem(*m));
+        }
+        BinArgs::ToReg(r, arg) => (Rm::Reg(*r), arg32_imm(*arg).unwrap()),
+        BinArgs::ToMem(m, arg) => (Rm::Mem(*m), reg32_imm(*arg).unwrap()),
+    };
+    // test has no short immediate, only the one for rax
+    if let Rm::Reg(Reg::Rax) = rm {
+        out.extend([0x48, 0xa9]);
+    } else {
+        emit_modrm(out, true, &[0xf7], 0, &rm);
+    }
+    out.extend((src as i32).to_le_bytes());
+}
+
+// Returns the relocation of the instruction relative to its start, if any
+fn encode_mov(out: &mut Vec<u8>, args: &MovArgs) -> Option<Reloc> {
+    match args {
+        MovArgs::ToReg(r, Arg64::Reg(s)) => {
+            emit_modrm(out, true, &[0x89], reg_num(*s), &Rm::Reg(*r))
+        }
+        MovArgs::ToReg(r, Arg64::Mem(m)) => {
+            emit_modrm(out, true, &[0x8b], reg_num(*r), &Rm::Mem(*m))
+        }
+        MovArgs::ToReg(r, Arg64::Label(l)) => {
+            emit_short_reg(out, true, 0xb8, *r);
+            out.extend([0; 8]);
+            return Some(Reloc {
+                offset: out.len() - 8,
+                target: l.clone(),
+                kind: RelocKind::Abs64,
+                addend: 0,
+            });
+        }
+        MovArgs::ToReg(r, arg) => {
+            let n = match arg {
+                Arg64::Signed(n) => *n,
+                Arg64::Unsigned(n) => *n as i64,
+                _ => unreachable!(),
+            };
+            // a non-negative 32-bit number is moved into the lower half,
+            // which clears the upper half
+            if u32::try_from(n).is_ok() {
+                emit_short_reg(out, false, 0xb8, *r);
+                out.extend((n as u32).to_le_bytes());
+            } else if fits_i32(n) {
+                emit_modrm(out, true, &[0xc7], 0, &Rm::Reg(*r));
+                out.extend((n as i32).to_le_bytes());
+            } else {
+                emit_short_reg(out, true, 0xb8, *r);
+                out.extend(n.to_le_bytes());
+            }
+        }
+        MovArgs::ToMem(m, Reg32::Reg(s)) => {
+            emit_modrm(out, true, &[0x89], reg_num(*s), &Rm::Mem(*m))
+        }
+        MovArgs::ToMem(m, arg) => {
+            emit_modrm(out, true, &[0xc7], 0, &Rm::Mem(*m));
+            out.extend((reg32_imm(*arg).unwrap() as i32).to_le_bytes());
+        }
+    }
+    None
+}
+
+// An opcode with the register in its low bits, like push or mov reg, imm
+fn emit_short_reg(out: &mut Vec<u8>, wide: bool, opcode: u8, r: Reg) {
+    let rex = 0x40 | (wide as u8) << 3 | reg_num(r) >> 3;
+    if rex != 0x40 {
+        out.push(rex);
+    }
+    out.push(opcode | reg_num(r) & 7);
+}
+
+fn encode_push_pop(out: &mut Vec<u8>, push: bool, arg: Arg32) {
+    match (push, arg) {
+        (true, Arg32::Reg(r)) => emit_short_reg(out, false, 0x50, r),
+        (false, Arg32::Reg(r)) => emit_short_reg(out, false, 0x58, r),
+        (true, Arg32::Mem(m)) => emit_modrm(out, false, &[0xff], 6, &Rm::Mem(m)),
+        (false, Arg32::Mem(m)) => emit_modrm(out, false, &[0x8f], 0, &Rm::Mem(m)),
+        (true, arg) => {
+            let n = arg32_imm(arg).unwrap();
+            if fits_i8(n) {
+                out.extend([0x6a, n as u8]);
+            } else {
+                out.push(0x68);
+                out.extend((n as i32).to_le_bytes());
+            }
+        }
+        (false, _) => panic!("Cannot pop into an immediate"),
+    }
+}
+
+// The condition code of a conditional jump, None for jmp and call
+fn condition(i: &Instr) -> Option<u8> {
+    match i {
+        Instr::Jo(_) => Some(0x0),
+        Instr::Jno(_) => Some(0x1),
+        Instr::Je(_) | Instr::Jz(_) => Some(0x4),
+        Instr::Jne(_) | Instr::Jnz(_) => Some(0x5),
+        Instr::Jl(_) => Some(0xc),
+        Instr::Jge(_) => Some(0xd),
+        Instr::Jle(_) => Some(0xe),
+        Instr::Jg(_) => Some(0xf),
+        _ => None,
+    }
+}
+
+fn jump_target(i: &Instr) -> Option<&JmpArg> {
+    match i {
+        Instr::Jmp(a)
+        | Instr::Call(a)
+        | Instr::Je(a)
+        | Instr::Jne(a)
+        | Instr::Jl(a)
+        | Instr::Jle(a)
+        | Instr::Jg(a)
+        | Instr::Jge(a)
+        | Instr::Jz(a)
+        | Instr::Jnz(a)
+        | Instr::Jo(a)
+        | Instr::Jno(a) => Some(a),
+        _ => None,
+    }
+}
+
+// Encodes a jump or call at offset at. Jumps to labels outside the text
+// section are always near and get a relocation, jumps to labels inside it
+// are short unless the caller found the target out of reach.
+fn encode_jump(
+    out: &mut Vec<u8>,
+    i: &Instr,
+    at: usize,
+    near: bool,
+    labels: &HashMap<String, usize>,
+) -> Option<Reloc> {
+    let l = match jump_target(i).unwrap() {
+        JmpArg::Reg(r) => {
+            let ext = if let Instr::Call(_) = i { 2 } else { 4 };
+            emit_modrm(out, false, &[0xff], ext, &Rm::Reg(*r));
+            return None;
+        }
+        JmpArg::Label(l) => l,
+    };
+    let is_call = matches!(i, Instr::Call(_));
+    let short = !is_call && !near && labels.contains_key(l);
+    match (is_call, condition(i), short) {
+        (_, None, true) => out.push(0xeb),
+        (_, Some(cc), true) => out.push(0x70 | cc),
+        (true, _, _) => out.push(0xe8),
+        (false, None, false) => out.push(0xe9),
+        (false, Some(cc), false) => out.extend([0x0f, 0x80 | cc]),
+    }
+    let width = if short { 1 } else { 4 };
+    let end = (at + out.len() + width) as i64;
+    match labels.get(l) {
+        Some(target) => {
+            let disp = *target as i64 - end;
+            if short {
+                out.push(disp as u8);
+            } else {
+                out.extend((disp as i32).to_le_bytes());
+            }
+            None
+        }
+        None => {
+            out.extend([0; 4]);
+            Some(Reloc {
+                offset: out.len() - 4,
+                target: l.clone(),
+                kind: RelocKind::Pc32,
+                addend: -4,
+            })
+        }
+    }
+}
+
+// Encodes one instruction at offset at, with the relocation it needs
+fn encode_instr(
+    i: &Instr,
+    at: usize,
+    near: bool,
+    labels: &HashMap<String, usize>,
+) -> (Vec<u8>, Option<Reloc>) {
+    let mut out = Vec::new();
+    let reloc = match i {
+        Instr::Mov(args) => encode_mov(&mut out, args),
+        Instr::Add(args)
+        | Instr::Sub(args)
+        | Instr::And(args)
+        | Instr::Or(args)
+        | Instr::Xor(args)
+        | Instr::Cmp(args) => {
+            encode_arith(&mut out, arith_ext(i).unwrap(), args);
+            None
+        }
+        Instr::Shl(args) | Instr::Shr(args) | Instr::Sar(args) => {
+            encode_shift(&mut out, shift_ext(i).unwrap(), args);
+            None
+        }
+        Instr::IMul(args) => {
+            encode_imul(&mut out, args);
+            None
+        }
+        Instr::Test(args) => {
+            encode_test(&mut out, args);
+            None
+        }
+        Instr::Push(arg) => {
+            encode_push_pop(&mut out, true, *arg);
+            None
+        }
+        Instr::Pop(arg) => {
+            encode_push_pop(&mut out, false, *arg);
+            None
+        }
+        Instr::Ret => {
+            out.push(0xc3);
+            None
+        }
+        Instr::Comment(_) | Instr::Label(_) => None,
+        _ => encode_jump(&mut out, i, at, near, labels),
+    };
+    let reloc = reloc.map(|r| Reloc {
+        offset: at + r.offset,
+        ..r
+    });
+    (out, reloc)
+}
+
+// Lays the instructions out from offset 0. Every jump starts out short and
+// the ones whose target turns out to be too far are made near, until no
+// jump needs to change.
+pub fn encode(is: &[Instr]) -> Code {
+    let local: HashMap<String, usize> = is
+        .iter()
+        .filter_map(|i| match i {
+            Instr::Label(l) => Some((l.clone(), 0)),
+            _ => None,
+        })
+        .collect();
+    let mut near = vec![false; is.len()];
+    loop {
+        // place the labels with the current jump sizes
+        let mut labels = local.clone();
+        let mut offsets = Vec::with_capacity(is.len());
+        let mut at = 0;
+        for (idx, i) in is.iter().enumerate() {
+            if let Instr::Label(l) = i {
+                labels.insert(l.clone(), at);
+            }
+            offsets.push(at);
+            at += encode_instr(i, at, near[idx], &local).0.len();
+        }
+
+        let mut changed = false;
+        for (idx, i) in is.iter().enumerate() {
+            if let Some(JmpArg::Label(l)) = jump_target(i) {
+                if let Some(target) = labels.get(l) {
+                    let end = offsets[idx] as i64 + 2;
+                    if !near[idx] && !fits_i8(*target as i64 - end) {
+                        near[idx] = true;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        if changed {
+            continue;
+        }
+
+        let mut code = Code {
+            bytes: Vec::new(),
+            labels: HashMap::new(),
+            relocs: Vec::new(),
+        };
+        for (idx, i) in is.iter().enumerate() {
+            let (bytes, reloc) = encode_instr(i, offsets[idx], near[idx], &labels);
+            code.bytes.extend(bytes);
+            code.relocs.extend(reloc);
+        }
+        code.labels = labels;
+        return code;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mem(reg: Reg, disp: i32) -> MemRef {
+        MemRef {
+            reg,
+            offset: Offset::Constant(disp),
+        }
+    }
+
+    fn bytes(i: Instr) -> Vec<u8> {
+        encode(&[i]).bytes
+    }
+
+    fn label(l: &str) -> JmpArg {
+        JmpArg::Label(String::from(l))
+    }
+
+    #[test]
+    fn mov_reg() {
+        let mov = |r, a| bytes(Instr::Mov(MovArgs::ToReg(r, a)));
+        assert_eq!(mov(Reg::Rax, Arg64::Reg(Reg::Rbx)), [0x48, 0x89, 0xd8]);
+        assert_eq!(mov(Reg::R15, Arg64::Reg(Reg::Rsi)), [0x49, 0x89, 0xf7]);
+        assert_eq!(mov(Reg::Rax, Arg64::Unsigned(5)), [0xb8, 5, 0, 0, 0]);
+        assert_eq!(mov(Reg::R9, Arg64::Signed(5)), [0x41, 0xb9, 5, 0, 0, 0]);
+        assert_eq!(
+            mov(Reg::Rax, Arg64::Signed(-1)),
+            [0x48, 0xc7, 0xc0, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            mov(Reg::Rax, Arg64::Unsigned(0x7fff_ffff_ffff_ffff)),
+            [0x48, 0xb8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]
+        );
+    }
+
+    #[test]
+    fn mov_mem() {
+        let load = |m| bytes(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(m))));
+        assert_eq!(load(mem(Reg::Rsp, 8)), [0x48, 0x8b, 0x44, 0x24, 0x08]);
+        assert_eq!(load(mem(Reg::Rbp, -16)), [0x48, 0x8b, 0x45, 0xf0]);
+        assert_eq!(load(mem(Reg::R13, 0)), [0x49, 0x8b, 0x45, 0x00]);
+        assert_eq!(load(mem(Reg::R12, 0)), [0x49, 0x8b, 0x04, 0x24]);
+        assert_eq!(
+            load(mem(Reg::Rsp, 512)),
+            [0x48, 0x8b, 0x84, 0x24, 0x00, 0x02, 0x00, 0x00]
+        );
+        let indexed = |reg, index, factor, constant| MemRef {
+            reg,
+            offset: Offset::Computed {
+                reg: index,
+                factor,
+                constant,
+            },
+        };
+        assert_eq!(
+            load(indexed(Reg::Rbx, Reg::Rcx, 8, 16)),
+            [0x48, 0x8b, 0x44, 0xcb, 0x10]
+        );
+        assert_eq!(
+            load(indexed(Reg::R14, Reg::R9, 4, 0)),
+            [0x4b, 0x8b, 0x04, 0x8e]
+        );
+
+        let store = |m, a| bytes(Instr::Mov(MovArgs::ToMem(m, a)));
+        assert_eq!(
+            store(mem(Reg::Rsp, -8), Reg32::Reg(Reg::Rdi)),
+            [0x48, 0x89, 0x7c, 0x24, 0xf8]
+        );
+        assert_eq!(
+            store(mem(Reg::Rax, 0), Reg32::Unsigned(0)),
+            [0x48, 0xc7, 0x00, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn mov_label() {
+        let code = encode(&[Instr::Mov(MovArgs::ToReg(
+            Reg::Rax,
+            Arg64::Label(String::from("snake_error")),
+        ))]);
+        assert_eq!(code.bytes, [0x48, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            code.relocs,
+            [Reloc {
+                offset: 2,
+                target: String::from("snake_error"),
+                kind: RelocKind::Abs64,
+                addend: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn arith() {
+        let to_reg = |i: fn(BinArgs) -> Instr, r, a| bytes(i(BinArgs::ToReg(r, a)));
+        assert_eq!(
+            to_reg(Instr::Add, Reg::Rax, Arg32::Reg(Reg::Rbx)),
+            [0x48, 0x01, 0xd8]
+        );
+        assert_eq!(
+            to_reg(Instr::Xor, Reg::R10, Arg32::Reg(Reg::Rax)),
+            [0x49, 0x31, 0xc2]
+        );
+        assert_eq!(
+            to_reg(Instr::Sub, Reg::Rax, Arg32::Mem(mem(Reg::Rsp, 8))),
+            [0x48, 0x2b, 0x44, 0x24, 0x08]
+        );
+        assert_eq!(
+            to_reg(Instr::Add, Reg::Rsp, Arg32::Signed(16)),
+            [0x48, 0x83, 0xc4, 0x10]
+        );
+        assert_eq!(
+            to_reg(Instr::Or, Reg::Rax, Arg32::Signed(-128)),
+            [0x48, 0x83, 0xc8, 0x80]
+        );
+        assert_eq!(
+            to_reg(Instr::And, Reg::Rbx, Arg32::Unsigned(7)),
+            [0x48, 0x83, 0xe3, 0x07]
+        );
+        assert_eq!(
+            to_reg(Instr::Sub, Reg::Rsp, Arg32::Signed(256)),
+            [0x48, 0x81, 0xec, 0x00, 0x01, 0x00, 0x00]
+        );
+        assert_eq!(
+            to_reg(Instr::Cmp, Reg::Rax, Arg32::Signed(1000)),
+            [0x48, 0x3d, 0xe8, 0x03, 0x00, 0x00]
+        );
+
+        let to_mem = |i: fn(BinArgs) -> Instr, m, a| bytes(i(BinArgs::ToMem(m, a)));
+        assert_eq!(
+            to_mem(Instr::Add, mem(Reg::Rax, 8), Reg32::Reg(Reg::Rbx)),
+            [0x48, 0x01, 0x58, 0x08]
+        );
+        assert_eq!(
+            to_mem(Instr::Cmp, mem(Reg::Rax, 0), Reg32::Signed(2)),
+            [0x48, 0x83, 0x38, 0x02]
+        );
+    }
+
+    #[test]
+    fn shift() {
+        let shift = |i: fn(BinArgs) -> Instr, a| bytes(i(BinArgs::ToReg(Reg::Rax, a)));
+        assert_eq!(shift(Instr::Shl, Arg32::Signed(1)), [0x48, 0xd1, 0xe0]);
+        assert_eq!(shift(Instr::Sar, Arg32::Signed(1)), [0x48, 0xd1, 0xf8]);
+        assert_eq!(
+            shift(Instr::Shr, Arg32::Unsigned(63)),
+            [0x48, 0xc1, 0xe8, 0x3f]
+        );
+        assert_eq!(shift(Instr::Shl, Arg32::Reg(Reg::Rcx)), [0x48, 0xd3, 0xe0]);
+    }
+
+    #[test]
+    fn imul() {
+        let imul = |a| bytes(Instr::IMul(BinArgs::ToReg(Reg::Rax, a)));
+        assert_eq!(imul(Arg32::Reg(Reg::Rbx)), [0x48, 0x0f, 0xaf, 0xc3]);
+        assert_eq!(
+            imul(Arg32::Mem(mem(Reg::Rsp, 8))),
+            [0x48, 0x0f, 0xaf, 0x44, 0x24, 0x08]
+        );
+        assert_eq!(imul(Arg32::Signed(3)), [0x48, 0x6b, 0xc0, 0x03]);
+        assert_eq!(
+            imul(Arg32::Signed(1000)),
+            [0x48, 0x69, 0xc0, 0xe8, 0x03, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn test() {
+        let test = |r, a| bytes(Instr::Test(BinArgs::ToReg(r, a)));
+        assert_eq!(test(Reg::Rax, Arg32::Reg(Reg::Rbx)), [0x48, 0x85, 0xd8]);
+        assert_eq!(
+            test(Reg::Rax, Arg32::Unsigned(1)),
+            [0x48, 0xa9, 0x01, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            test(Reg::Rbx, Arg32::Unsigned(1)),
+            [0x48, 0xf7, 0xc3, 0x01, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn push_pop() {
+        assert_eq!(bytes(Instr::Push(Arg32::Reg(Reg::Rbp))), [0x55]);
+        assert_eq!(bytes(Instr::Push(Arg32::Reg(Reg::R12))), [0x41, 0x54]);
+        assert_eq!(bytes(Instr::Pop(Arg32::Reg(Reg::Rbp))), [0x5d]);
+        assert_eq!(bytes(Instr::Pop(Arg32::Reg(Reg::R15))), [0x41, 0x5f]);
+        assert_eq!(
+            bytes(Instr::Push(Arg32::Mem(mem(Reg::Rsp, 8)))),
+            [0xff, 0x74, 0x24, 0x08]
+        );
+        assert_eq!(
+            bytes(Instr::Pop(Arg32::Mem(mem(Reg::Rax, 0)))),
+            [0x8f, 0x00]
+        );
+        assert_eq!(bytes(Instr::Push(Arg32::Signed(5))), [0x6a, 0x05]);
+        assert_eq!(
+            bytes(Instr::Push(Arg32::Signed(1000))),
+            [0x68, 0xe8, 0x03, 0x00, 0x00]
+        );
+        assert_eq!(bytes(Instr::Ret), [0xc3]);
+    }
+
+    #[test]
+    fn jump_reg() {
+        assert_eq!(bytes(Instr::Call(JmpArg::Reg(Reg::Rax))), [0xff, 0xd0]);
+        assert_eq!(bytes(Instr::Jmp(JmpArg::Reg(Reg::Rax))), [0xff, 0xe0]);
+        assert_eq!(bytes(Instr::Jmp(JmpArg::Reg(Reg::R11))), [0x41, 0xff, 0xe3]);
+    }
+
+    #[test]
+    fn jump_short() {
+        let code = encode(&[
+            Instr::Label(String::from("top")),
+            Instr::Jne(label("end")),
+            Instr::Jmp(label("top")),
+            Instr::Label(String::from("end")),
+        ]);
+        assert_eq!(code.bytes, [0x75, 0x02, 0xeb, 0xfc]);
+        assert_eq!(code.labels["end"], 4);
+        assert!(code.relocs.is_empty());
+    }
+
+    #[test]
+    fn jump_near() {
+        // the jumps over 128 bytes of code are out of reach of a short one
+        let mut is = vec![Instr::Jmp(label("end")), Instr::Jl(label("end"))];
+        is.extend(vec![Instr::Ret; 128]);
+        is.push(Instr::Label(String::from("end")));
+        let code = encode(&is);
+        assert_eq!(
+            code.bytes[..11],
+            [0xe9, 0x86, 0, 0, 0, 0x0f, 0x8c, 0x80, 0, 0, 0]
+        );
+        assert_eq!(code.labels["end"], 139);
+    }
+
+    #[test]
+    fn jump_extern() {
+        let code = encode(&[
+            Instr::Call(label("snake_error")),
+            Instr::Je(label("snake_error")),
+        ]);
+        assert_eq!(code.bytes, [0xe8, 0, 0, 0, 0, 0x0f, 0x84, 0, 0, 0, 0]);
+        let reloc = |offset| Reloc {
+            offset,
+            target: String::from("snake_error"),
+            kind: RelocKind::Pc32,
+            addend: -4,
+        };
+        assert_eq!(code.relocs, [reloc(1), reloc(7)]);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::asm::{
+    Arg32, Arg64, BinArgs, Instr, JmpArg, MemRef, MovArgs, Offset, Program, Reg, Reg32,
+};
+use crate::compile::HEAP_SIZE;
+use crate::encode::{encode, RelocKind};
+use crate::runtime_error::{error_message, ERROR_CLASS_ID};
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+// Runs a program inside the compiler instead of linking it with
+// runtime/stub.rs. The program is encoded into an executable mapping
+// together with an entry trampoline, and its calls to the runtime land on
+// the Rust functions below. Those print into a buffer instead of stdout,
+// and a failure returns through the trampoline instead of exiting, so the
+// caller gets the output and the error of the run back.
+
+pub static SUPPORTED: bool = cfg!(all(target_os = "linux", target_arch = "x86_64"));
+
+// The stack the program gets, the usual main thread size. Its thread has
+// some room below the limit the program checks its frames against, for the
+// arguments of calls and the Rust functions the program calls.
+static STACK_SIZE: usize = 8 << 20;
+static STACK_RESERVE: usize = 256 << 10;
+
+static INT_TAG: u64 = 0x00_00_00_00_00_00_00_01;
+static TAG_MASK: u64 = 0b111;
+static ARRAY_TAG: u64 = 0b001;
+static CLOSURE_TAG: u64 = 0b011;
+
+static SNAKE_TRU: u64 = 0xFF_FF_FF_FF_FF_FF_FF_FF;
+static SNAKE_FLS: u64 = 0x7F_FF_FF_FF_FF_FF_FF_FF;
+
+thread_local! {
+    static OUTPUT: RefCell<String> = const { RefCell::new(String::new()) };
+    static FAILURE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn sprint_snake_val_helper(x: u64, seen: &mut HashSet<u64>) -> String {
+    if x & INT_TAG == 0 {
+        format!("{}", (x as i64) >> 1)
+    } else if x == SNAKE_TRU {
+        String::from("true")
+    } else if x == SNAKE_FLS {
+        String::from("false")
+    } else if x & TAG_MASK == ARRAY_TAG {
+        if !seen.insert(x) {
+            return String::from("<loop>");
+        }
+        let address = (x - ARRAY_TAG + 8) as *const u64;
+        let len = unsafe { *address } >> 1;
+        let elts: Vec<String> = (1..=len as usize)
+            .map(|i| sprint_snake_val_helper(unsafe { *address.add(i) }, seen))
+            .collect();
+        seen.remove(&x);
+        format!("[{}]", elts.join(", "))
+    } else if x & TAG_MASK == CLOSURE_TAG {
+        String::from("<closure>")
+    } else {
+        format!("Invalid snake value 0x{:x}", x)
+    }
+}
+
+fn sprint_snake_val(x: u64) -> String {
+    sprint_snake_val_helper(x, &mut HashSet::new())
+}
+
+extern "sysv64" fn print_snake_val(v: u64) -> u64 {
+    OUTPUT.with(|out| {
+        let mut out = out.borrow_mut();
+        out.push_str(&sprint_snake_val(v));
+        out.push('\n');
+    });
+    v
+}
+
+extern "sysv64" fn snake_error(err_code: u64) {
+    FAILURE.with(|f| *f.borrow_mut() = Some(String::from(error_message(err_code))));
+}
+
+extern "sysv64" fn snake_uncaught(v: u64) {
+    if v & TAG_MASK == ARRAY_TAG {
+        // a re-raised built-in error reports like the original failure
+        let address = (v - ARRAY_TAG) as *const u64;
+        if unsafe { *address } == ERROR_CLASS_ID as u64 {
+            return snake_error(unsafe { *address.add(2) } >> 1);
+        }
+    }
+    let msg = format!("uncaught exception: {}", sprint_snake_val(v));
+    FAILURE.with(|f| *f.borrow_mut() = Some(msg));
+}
+
+fn mov_label(reg: Reg, label: &str) -> Instr {
+    Instr::Mov(MovArgs::ToReg(reg, Arg64::Label(String::from(label))))
+}
+
+fn mem(reg: Reg) -> MemRef {
+    MemRef {
+        reg,
+        offset: Offset::Constant(0),
+    }
+}
+
+// The callee-saved registers, which start_here does not get to restore when
+// a failure returns through jit_exit
+static SAVED_REGS: [Reg; 6] = [Reg::Rbx, Reg::Rbp, Reg::R12, Reg::R13, Reg::R14, Reg::R15];
+
+// jit_entry saves the registers and the stack pointer and calls start_here,
+// which gets the heap, its end and the stack limit it is passed.
+// The runtime functions the program calls are replaced by stubs that jump to
+// the Rust functions, and the failing ones return to the caller of
+// jit_entry once they are done.
+fn runtime_instrs() -> Vec<Instr> {
+    let mut instr = vec![Instr::Label(String::from("jit_entry"))];
+    for r in SAVED_REGS {
+        instr.push(Instr::Push(Arg32::Reg(r)));
+    }
+    // keep the stack aligned like the stub's call to start_here
+    instr.push(Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));
+    instr.push(mov_label(Reg::Rax, "jit_saved_rsp"));
+    instr.push(Instr::Mov(MovArgs::ToMem(
+        mem(Reg::Rax),
+        Reg32::Reg(Reg::Rsp),
+    )));
+    instr.push(Instr::Call(JmpArg::Label(String::from("start_here"))));
+    instr.push(Instr::Label(String::from("jit_exit")));
+    instr.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));
+    for r in SAVED_REGS.iter().rev() {
+        instr.push(Instr::Pop(Arg32::Reg(*r)));
+    }
+    instr.push(Instr::Ret);
+
+    instr.push(Instr::Label(String::from("print_snake_val")));
+    instr.push(Instr::Mov(MovArgs::ToReg(
+        Reg::Rax,
+        Arg64::Unsigned(print_snake_val as *const () as u64),
+    )));
+    instr.push(Instr::Jmp(JmpArg::Reg(Reg::Rax)));
+
+    let failing: [(&str, u64); 2] = [
+        ("snake_error", snake_error as *const () as u64),
+        ("snake_uncaught", snake_uncaught as *const () as u64),
+    ];
+    for (label, f) in failing {
+        instr.push(Instr::Label(String::from(label)));
+        instr.push(mov_label(Reg::Rax, "jit_saved_rsp"));
+        instr.push(Instr::Mov(MovArgs::ToReg(
+            Reg::Rsp,
+            Arg64::Mem(mem(Reg::Rax)),
+        )));
+        instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(f))));
+        instr.push(Instr::Call(JmpArg::Reg(Reg::Rax)));
+        instr.push(Instr::Jmp(JmpArg::Label(String::from("jit_exit"))));
+    }
+    instr
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod mapping {
+    const PROT_READ: i32 = 1;
+    const PROT_WRITE: i32 = 2;
+    const PROT_EXEC: i32 = 4;
+    const MAP_PRIVATE: i32 = 2;
+    const MAP_ANONYMOUS: i32 = 0x20;
+
+    extern "C" {
+        fn mmap(addr: *mut u8, len: usize, prot: i32, flags: i32, fd: i32, off: i64) -> *mut u8;
+        fn mprotect(addr: *mut u8, len: usize, prot: i32) -> i32;
+        fn munmap(addr: *mut u8, len: usize) -> i32;
+    }
+
+    // Zeroed anonymous memory, unmapped on drop
+    pub struct Mapping {
+        pub ptr: *mut u8,
+        len: usize,
+    }
+
+    impl Mapping {
+        pub fn new(len: usize) -> Result<Mapping, String> {
+            let len = len.max(1);
+            let ptr = unsafe {
+                mmap(
+                    std::ptr::null_mut(),
+                    len,
+                    PROT_READ | PROT_WRITE,
+                    MAP_PRIVATE | MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )
+            };
+            if ptr as isize == -1 {
+                return Err(String::from("mmap failed"));
+            }
+            Ok(Mapping { ptr, len })
+        }
+
+        // Copies the code in and makes the memory executable, it is no
+        // longer writable afterwards
+        pub fn load_code(&self, bytes: &[u8]) -> Result<(), String> {
+            assert!(bytes.len() <= self.len);
+            unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), self.ptr, bytes.len()) };
+            if unsafe { mprotect(self.ptr, self.len, PROT_READ | PROT_EXEC) } != 0 {
+                return Err(String::from("mprotect failed"));
+            }
+            Ok(())
+        }
+    }
+
+    impl Drop for Mapping {
+        fn drop(&mut self) {
+            unsafe { munmap(self.ptr, self.len) };
+        }
+    }
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn run_here(p: &Program) -> Result<Option<String>, String> {
+    use mapping::Mapping;
+
+    let mut text = p.text.clone();
+    text.extend(runtime_instrs());
+    let mut code = encode(&text);
+
+    let mut data_offsets = std::collections::HashMap::new();
+    let mut data_size = 0;
+    for (label, size) in p
+        .data
+        .iter()
+        .chain([(String::from("jit_saved_rsp"), 1)].iter())
+    {
+        data_offsets.insert(label.clone(), data_size);
+        data_size += 8 * size;
+    }
+    let data = Mapping::new(data_size)?;
+    let text = Mapping::new(code.bytes.len())?;
+
+    for r in &code.relocs {
+        let target = match (code.labels.get(&r.target), data_offsets.get(&r.target)) {
+            (Some(at), _) => text.ptr as u64 + *at as u64,
+            (None, Some(at)) => data.ptr as u64 + *at as u64,
+            (None, None) => return Err(format!("undefined label {}", r.target)),
+        };
+        match r.kind {
+            RelocKind::Abs64 => {
+                let value = target.wrapping_add(r.addend as u64);
+                code.bytes[r.offset..r.offset + 8].copy_from_slice(&value.to_le_bytes());
+            }
+            RelocKind::Pc32 => return Err(format!("unexpected relative call to {}", r.target)),
+        }
+    }
+    text.load_code(&code.bytes)?;
+
+    let mut heap = vec![0u64; HEAP_SIZE];
+    let entry: extern "sysv64" fn(*mut u64, *mut u64, usize) -> u64 =
+        unsafe { std::mem::transmute(text.ptr.add(code.labels["jit_entry"])) };
+    let heap_end = unsafe { heap.as_mut_ptr().add(HEAP_SIZE) };
+    let stack_limit = (&heap as *const Vec<u64> as usize).saturating_sub(STACK_SIZE);
+    let result = entry(heap.as_mut_ptr(), heap_end, stack_limit);
+    Ok(match FAILURE.with(|f| f.borrow_mut().take()) {
+        Some(msg) => Some(msg),
+        None => {
+            print_snake_val(result);
+            None
+        }
+    })
+}
+
+#[cfg(not(all(target_os = "linux", target_arch = "x86_64")))]
+fn run_here(_p: &Program) -> Result<Option<String>, String> {
+    Err(String::from("the JIT only runs on x86-64 Linux"))
+}
+
+// Runs the program on a fresh thread and returns what it printed, along with
+// the message of the failure that stopped it, if one did
+pub fn run(p: &Program) -> Result<(String, Option<String>), String> {
+    let p = p.clone();
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE + STACK_RESERVE)
+        .spawn(move || {
+            let failure = run_here(&p)?;
+            Ok((OUTPUT.with(|out| out.take()), failure))
+        })
+        .map_err(|e| e.to_string())?
+        .join()
+        .map_err(|_| String::from("the JIT thread panicked"))?
+}
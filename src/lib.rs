@@ -1,11 +1,30 @@
+pub mod analysis;
+pub mod api;
 pub mod asm;
+pub mod bytecode;
+pub mod c;
 pub mod compile;
+pub mod desugar;
+pub mod elf;
+pub mod encode;
+pub mod graph;
+pub mod jit;
 // pub mod interp;
 pub mod lift;
+pub mod matching;
+pub mod modules;
+pub mod optimize;
 pub mod parser;
+pub mod peephole;
 pub mod runner;
 pub mod runtime_error;
 pub mod scope;
 pub mod sequence;
 pub mod span;
 pub mod syntax;
+pub mod timing;
+pub mod typecheck;
+pub mod types;
+pub mod vm;
+pub mod wasm;
+pub mod wasm_interp;
@@ -1,9 +1,9 @@
 use crate::scope::get;
 use crate::syntax::{
-    ClassInfo, Exp, FunDecl, MethodDecl, Prim2, SurfFunDecl, SurfMethodDecl, SurfProg,
+    ClassInfo, Exp, FunDecl, MethodDecl, Pattern, Prim2, SurfFunDecl, SurfMethodDecl, SurfProg,
 };
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub fn class_lift<'exp, Ann>(
     p: &'exp Exp<Ann>,
@@ -13,6 +13,7 @@ pub fn class_lift<'exp, Ann>(
     match p {
         Exp::Num(i, _) => (acc_class.clone(), Exp::Num(*i, ())),
         Exp::Bool(b, _) => (acc_class.clone(), Exp::Bool(*b, ())),
+        Exp::Fail(err, _) => (acc_class.clone(), Exp::Fail(*err, ())),
         Exp::Var(x, _) => match get(&env, x) {
             Some((class_array, idx)) => (
                 acc_class.clone(),
@@ -139,6 +140,7 @@ pub fn class_lift<'exp, Ann>(
                     parameters: decl.parameters.clone(),
                     body: main_body,
                     ann: (),
+                    signature: decl.signature.clone(),
                 });
                 class_map = class_map_body;
             }
@@ -215,17 +217,37 @@ pub fn class_lift<'exp, Ann>(
             ann: _,
         } => {
             let class_map: HashMap<String, ClassInfo> = acc_class.clone();
-            let (array, idx) = match get(&env, field) {
-                Some((class_array, idx)) => (class_array, idx),
-                None => panic!("Trying to set variable as class field"),
-            };
             let (class_map, main_value) = class_lift(value, env.clone(), class_map.clone());
+            match get(&env, field) {
+                Some((class_array, idx)) => (
+                    class_map,
+                    Exp::ArraySet {
+                        array: Box::new(Exp::Var(class_array.clone(), ())),
+                        index: Box::new(Exp::Num(idx as i64, ())),
+                        new_value: Box::new(main_value),
+                        ann: (),
+                    },
+                ),
+                // Not a field, so it is an assignment to a let-bound variable
+                None => (
+                    class_map,
+                    Exp::Assign {
+                        var: field.clone(),
+                        value: Box::new(main_value),
+                        ann: (),
+                    },
+                ),
+            }
+        }
+        Exp::While { cond, body, ann: _ } => {
+            let class_map: HashMap<String, ClassInfo> = acc_class.clone();
+            let (class_map, main_cond) = class_lift(cond, env.clone(), class_map.clone());
+            let (class_map, main_body) = class_lift(body, env.clone(), class_map.clone());
             (
                 class_map,
-                Exp::ArraySet {
-                    array: Box::new(Exp::Var(array.clone(), ())),
-                    index: Box::new(Exp::Num(idx as i64, ())),
-                    new_value: Box::new(main_value),
+                Exp::While {
+                    cond: Box::new(main_cond),
+                    body: Box::new(main_body),
                     ann: (),
                 },
             )
@@ -233,6 +255,7 @@ pub fn class_lift<'exp, Ann>(
         Exp::ClassDef {
             name,
             fields,
+            field_types: _,
             methods,
             body,
             ann: _,
@@ -264,6 +287,7 @@ pub fn class_lift<'exp, Ann>(
                     parameters: extended_params.clone(),
                     body: main_method_body.clone(),
                     ann: (),
+                    signature: None,
                 });
             }
             (
@@ -276,15 +300,369 @@ pub fn class_lift<'exp, Ann>(
                 },
             )
         }
+        Exp::Raise(e, _) => {
+            let (class_map, main) = class_lift(e, env.clone(), acc_class.clone());
+            (class_map, Exp::Raise(Box::new(main), ()))
+        }
+        Exp::Try {
+            body,
+            var,
+            handler,
+            ann: _,
+        } => {
+            let class_map: HashMap<String, ClassInfo> = acc_class.clone();
+            let (class_map, main_body) = class_lift(body, env.clone(), class_map.clone());
+            let (class_map, main_handler) = class_lift(handler, env.clone(), class_map.clone());
+            (
+                class_map,
+                Exp::Try {
+                    body: Box::new(main_body),
+                    var: var.clone(),
+                    handler: Box::new(main_handler),
+                    ann: (),
+                },
+            )
+        }
+        Exp::Match {
+            scrutinee,
+            arms,
+            ann: _,
+        } => {
+            let class_map: HashMap<String, ClassInfo> = acc_class.clone();
+            let (mut class_map, main_scrutinee) =
+                class_lift(scrutinee, env.clone(), class_map.clone());
+            let mut main_arms: Vec<(Pattern<()>, Exp<()>)> = Vec::new();
+            for (pat, body) in arms.iter() {
+                let (class_map_arm, main_body) = class_lift(body, env.clone(), class_map);
+                class_map = class_map_arm;
+                main_arms.push((pat.map_ann(&mut |_| ()), main_body));
+            }
+            (
+                class_map,
+                Exp::Match {
+                    scrutinee: Box::new(main_scrutinee),
+                    arms: main_arms,
+                    ann: (),
+                },
+            )
+        }
         Exp::CallMethod { .. }
         | Exp::MethodDefs { .. }
         | Exp::Lambda { .. }
-        | Exp::MakeClosure { .. } => {
-            panic!("Should never exist during lambda lift!")
+        | Exp::MakeClosure { .. }
+        | Exp::For { .. }
+        | Exp::ForRange { .. }
+        | Exp::LetPattern { .. }
+        | Exp::Ascribe(..)
+        | Exp::Assign { .. } => {
+            panic!("Should never exist during class lift!")
         }
     }
 }
 
+// Boxing rule for mutable variables.
+//
+// lambda_lift copies every variable in scope into the environment array of a
+// closure, so a let-bound variable that is assigned with := and also used
+// inside a function that does not bind it would be copied and go stale.
+// Such variables are boxed: the let binds a one-element array, every read
+// becomes x[0] and every assignment becomes x[0] := v. Variables that are
+// never assigned, or never captured, keep living in their stack slot.
+//
+// Precondition: all names are uniquified, so a set of names is enough.
+#[derive(Default)]
+struct VarUsage {
+    referenced: HashSet<String>,
+    bound: HashSet<String>,
+    assigned: HashSet<String>,
+    captured: HashSet<String>,
+}
+
+fn scan_decls<Ann>(decls: &[FunDecl<Exp<Ann>, Ann>], usage: &mut VarUsage) {
+    for decl in decls.iter() {
+        usage.bound.insert(decl.name.clone());
+        let mut inner = VarUsage::default();
+        inner.bound.extend(decl.parameters.iter().cloned());
+        scan_vars(&decl.body, &mut inner);
+        // Whatever the function uses but does not bind comes from outside
+        for x in inner.referenced.difference(&inner.bound) {
+            usage.captured.insert(x.clone());
+        }
+        usage.referenced.extend(inner.referenced);
+        usage.bound.extend(inner.bound);
+        usage.assigned.extend(inner.assigned);
+        usage.captured.extend(inner.captured);
+    }
+}
+
+fn scan_vars<Ann>(p: &Exp<Ann>, usage: &mut VarUsage) {
+    match p {
+        Exp::Num(..) | Exp::Bool(..) | Exp::Fail(..) => {}
+        Exp::Var(x, _) => {
+            usage.referenced.insert(x.clone());
+        }
+        Exp::Prim1(_, e, _) => scan_vars(e, usage),
+        Exp::Prim2(_, e1, e2, _) => {
+            scan_vars(e1, usage);
+            scan_vars(e2, usage);
+        }
+        Exp::Let { bindings, body, .. } => {
+            for (x, e) in bindings.iter() {
+                usage.bound.insert(x.clone());
+                scan_vars(e, usage);
+            }
+            scan_vars(body, usage);
+        }
+        Exp::If { cond, thn, els, .. } => {
+            scan_vars(cond, usage);
+            scan_vars(thn, usage);
+            scan_vars(els, usage);
+        }
+        Exp::Array(es, _) | Exp::Object { fields: es, .. } => {
+            for e in es.iter() {
+                scan_vars(e, usage);
+            }
+        }
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ..
+        } => {
+            scan_vars(array, usage);
+            scan_vars(index, usage);
+            scan_vars(new_value, usage);
+        }
+        Exp::Semicolon { e1, e2, .. } => {
+            scan_vars(e1, usage);
+            scan_vars(e2, usage);
+        }
+        Exp::Call(fun, args, _) => {
+            scan_vars(fun, usage);
+            for arg in args.iter() {
+                scan_vars(arg, usage);
+            }
+        }
+        Exp::CallUniqMethod { object, args, .. } => {
+            scan_vars(object, usage);
+            for arg in args.iter() {
+                scan_vars(arg, usage);
+            }
+        }
+        Exp::FunDefs { decls, body, .. } | Exp::MethodDefs { decls, body, .. } => {
+            scan_decls(decls, usage);
+            scan_vars(body, usage);
+        }
+        Exp::While { cond, body, .. } => {
+            scan_vars(cond, usage);
+            scan_vars(body, usage);
+        }
+        Exp::Raise(e, _) => scan_vars(e, usage),
+        Exp::Try {
+            body, var, handler, ..
+        } => {
+            scan_vars(body, usage);
+            usage.bound.insert(var.clone());
+            scan_vars(handler, usage);
+        }
+        Exp::Assign { var, value, .. } => {
+            usage.referenced.insert(var.clone());
+            usage.assigned.insert(var.clone());
+            scan_vars(value, usage);
+        }
+        Exp::Lambda { .. }
+        | Exp::ClassDef { .. }
+        | Exp::SetField { .. }
+        | Exp::CallMethod { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::For { .. }
+        | Exp::ForRange { .. }
+        | Exp::LetPattern { .. }
+        | Exp::Ascribe(..)
+        | Exp::Match { .. } => {
+            panic!("Should never exist during boxing!")
+        }
+    }
+}
+
+fn unbox(x: &str) -> Exp<()> {
+    Exp::Prim2(
+        Prim2::ArrayGet,
+        Box::new(Exp::Var(x.to_string(), ())),
+        Box::new(Exp::Num(0, ())),
+        (),
+    )
+}
+
+fn box_decls<Ann>(
+    decls: &[FunDecl<Exp<Ann>, Ann>],
+    boxed: &HashSet<String>,
+) -> Vec<FunDecl<Exp<()>, ()>> {
+    decls
+        .iter()
+        .map(|decl| FunDecl {
+            name: decl.name.clone(),
+            parameters: decl.parameters.clone(),
+            body: box_vars(&decl.body, boxed),
+            ann: (),
+            signature: decl.signature.clone(),
+        })
+        .collect()
+}
+
+fn box_vars<Ann>(p: &Exp<Ann>, boxed: &HashSet<String>) -> Exp<()> {
+    match p {
+        Exp::Num(i, _) => Exp::Num(*i, ()),
+        Exp::Bool(b, _) => Exp::Bool(*b, ()),
+        Exp::Fail(err, _) => Exp::Fail(*err, ()),
+        Exp::Var(x, _) => {
+            if boxed.contains(x) {
+                unbox(x)
+            } else {
+                Exp::Var(x.clone(), ())
+            }
+        }
+        Exp::Prim1(prim, e, _) => Exp::Prim1(*prim, Box::new(box_vars(e, boxed)), ()),
+        Exp::Prim2(prim, e1, e2, _) => Exp::Prim2(
+            *prim,
+            Box::new(box_vars(e1, boxed)),
+            Box::new(box_vars(e2, boxed)),
+            (),
+        ),
+        Exp::Let { bindings, body, .. } => Exp::Let {
+            bindings: bindings
+                .iter()
+                .map(|(x, e)| {
+                    let e = box_vars(e, boxed);
+                    if boxed.contains(x) {
+                        (x.clone(), Exp::Array(vec![e], ()))
+                    } else {
+                        (x.clone(), e)
+                    }
+                })
+                .collect(),
+            body: Box::new(box_vars(body, boxed)),
+            ann: (),
+        },
+        Exp::If { cond, thn, els, .. } => Exp::If {
+            cond: Box::new(box_vars(cond, boxed)),
+            thn: Box::new(box_vars(thn, boxed)),
+            els: Box::new(box_vars(els, boxed)),
+            ann: (),
+        },
+        Exp::Array(es, _) => Exp::Array(es.iter().map(|e| box_vars(e, boxed)).collect(), ()),
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ..
+        } => Exp::ArraySet {
+            array: Box::new(box_vars(array, boxed)),
+            index: Box::new(box_vars(index, boxed)),
+            new_value: Box::new(box_vars(new_value, boxed)),
+            ann: (),
+        },
+        Exp::Semicolon { e1, e2, .. } => Exp::Semicolon {
+            e1: Box::new(box_vars(e1, boxed)),
+            e2: Box::new(box_vars(e2, boxed)),
+            ann: (),
+        },
+        Exp::FunDefs { decls, body, .. } => Exp::FunDefs {
+            decls: box_decls(decls, boxed),
+            body: Box::new(box_vars(body, boxed)),
+            ann: (),
+        },
+        Exp::Call(fun, args, _) => Exp::Call(
+            Box::new(box_vars(fun, boxed)),
+            args.iter().map(|e| box_vars(e, boxed)).collect(),
+            (),
+        ),
+        Exp::Object { class, fields, .. } => Exp::Object {
+            class: class.clone(),
+            fields: fields.iter().map(|e| box_vars(e, boxed)).collect(),
+            ann: (),
+        },
+        Exp::CallUniqMethod {
+            object,
+            uniqmethod,
+            args,
+            ..
+        } => Exp::CallUniqMethod {
+            object: Box::new(box_vars(object, boxed)),
+            uniqmethod: uniqmethod.clone(),
+            args: args.iter().map(|e| box_vars(e, boxed)).collect(),
+            ann: (),
+        },
+        Exp::MethodDefs {
+            class, decls, body, ..
+        } => Exp::MethodDefs {
+            class: *class,
+            decls: box_decls(decls, boxed),
+            body: Box::new(box_vars(body, boxed)),
+            ann: (),
+        },
+        Exp::While { cond, body, .. } => Exp::While {
+            cond: Box::new(box_vars(cond, boxed)),
+            body: Box::new(box_vars(body, boxed)),
+            ann: (),
+        },
+        Exp::Raise(e, _) => Exp::Raise(Box::new(box_vars(e, boxed)), ()),
+        Exp::Try {
+            body, var, handler, ..
+        } => Exp::Try {
+            body: Box::new(box_vars(body, boxed)),
+            var: var.clone(),
+            handler: Box::new(box_vars(handler, boxed)),
+            ann: (),
+        },
+        Exp::Assign { var, value, .. } => {
+            let value = box_vars(value, boxed);
+            if boxed.contains(var) {
+                // x[0] := v evaluates to the array, read the box back for v
+                Exp::Semicolon {
+                    e1: Box::new(Exp::ArraySet {
+                        array: Box::new(Exp::Var(var.clone(), ())),
+                        index: Box::new(Exp::Num(0, ())),
+                        new_value: Box::new(value),
+                        ann: (),
+                    }),
+                    e2: Box::new(unbox(var)),
+                    ann: (),
+                }
+            } else {
+                Exp::Assign {
+                    var: var.clone(),
+                    value: Box::new(value),
+                    ann: (),
+                }
+            }
+        }
+        Exp::Lambda { .. }
+        | Exp::ClassDef { .. }
+        | Exp::SetField { .. }
+        | Exp::CallMethod { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::For { .. }
+        | Exp::ForRange { .. }
+        | Exp::LetPattern { .. }
+        | Exp::Ascribe(..)
+        | Exp::Match { .. } => {
+            panic!("Should never exist during boxing!")
+        }
+    }
+}
+
+pub fn box_captured_assignments<Ann>(p: &Exp<Ann>) -> SurfProg<()> {
+    let mut usage = VarUsage::default();
+    scan_vars(p, &mut usage);
+    let boxed: HashSet<String> = usage
+        .assigned
+        .intersection(&usage.captured)
+        .cloned()
+        .collect();
+    box_vars(p, &boxed)
+}
+
 pub fn lambda_lift<'exp, Ann>(
     p: &'exp Exp<Ann>,
     mut env: Vec<(&'exp str, ())>,
@@ -293,6 +671,7 @@ pub fn lambda_lift<'exp, Ann>(
         Exp::Num(i, _) => (Vec::new(), Vec::new(), Exp::Num(*i, ())),
         Exp::Bool(b, _) => (Vec::new(), Vec::new(), Exp::Bool(*b, ())),
         Exp::Var(x, _) => (Vec::new(), Vec::new(), Exp::Var(x.clone(), ())),
+        Exp::Fail(err, _) => (Vec::new(), Vec::new(), Exp::Fail(*err, ())),
         Exp::Prim1(prim, p, _) => {
             let (fun_vec, method_vec, main) = lambda_lift(&p, env.clone());
             (fun_vec, method_vec, Exp::Prim1(*prim, Box::new(main), ()))
@@ -452,6 +831,7 @@ pub fn lambda_lift<'exp, Ann>(
                 parameters,
                 body,
                 ann: _,
+                signature: _,
             } in decls.iter()
             {
                 // Construct function environment
@@ -494,6 +874,7 @@ pub fn lambda_lift<'exp, Ann>(
                     parameters: main_parameters.clone(),
                     body: main_body.clone(),
                     ann: (),
+                    signature: None,
                 });
             }
             // Get the lambda lifted body
@@ -648,6 +1029,7 @@ pub fn lambda_lift<'exp, Ann>(
                 parameters,
                 body,
                 ann: _,
+                signature: _,
             } in decls.iter()
             {
                 // Construct function environment
@@ -692,6 +1074,7 @@ pub fn lambda_lift<'exp, Ann>(
                         parameters: main_parameters.clone(),
                         body: main_body.clone(),
                         ann: (),
+                        signature: None,
                     },
                 });
             }
@@ -751,11 +1134,78 @@ pub fn lambda_lift<'exp, Ann>(
                 },
             )
         }
+        Exp::While { cond, body, ann: _ } => {
+            let mut fun_vec: Vec<FunDecl<Exp<()>, ()>> = Vec::new();
+            let mut method_vec: Vec<MethodDecl<Exp<()>, ()>> = Vec::new();
+            let (fun_vec_cond, method_vec_cond, main_cond) = lambda_lift(&cond, env.clone());
+            let (fun_vec_body, method_vec_body, main_body) = lambda_lift(&body, env.clone());
+            fun_vec.extend(fun_vec_cond);
+            fun_vec.extend(fun_vec_body);
+            method_vec.extend(method_vec_cond);
+            method_vec.extend(method_vec_body);
+            (
+                fun_vec,
+                method_vec,
+                Exp::While {
+                    cond: Box::new(main_cond),
+                    body: Box::new(main_body),
+                    ann: (),
+                },
+            )
+        }
+        Exp::Raise(e, _) => {
+            let (fun_vec, method_vec, main) = lambda_lift(&e, env.clone());
+            (fun_vec, method_vec, Exp::Raise(Box::new(main), ()))
+        }
+        Exp::Try {
+            body,
+            var,
+            handler,
+            ann: _,
+        } => {
+            let mut fun_vec: Vec<FunDecl<Exp<()>, ()>> = Vec::new();
+            let mut method_vec: Vec<MethodDecl<Exp<()>, ()>> = Vec::new();
+            let (fun_vec_body, method_vec_body, main_body) = lambda_lift(&body, env.clone());
+            env.push((&var, ()));
+            let (fun_vec_handler, method_vec_handler, main_handler) =
+                lambda_lift(&handler, env.clone());
+            fun_vec.extend(fun_vec_body);
+            fun_vec.extend(fun_vec_handler);
+            method_vec.extend(method_vec_body);
+            method_vec.extend(method_vec_handler);
+            (
+                fun_vec,
+                method_vec,
+                Exp::Try {
+                    body: Box::new(main_body),
+                    var: var.clone(),
+                    handler: Box::new(main_handler),
+                    ann: (),
+                },
+            )
+        }
+        Exp::Assign { var, value, ann: _ } => {
+            let (fun_vec, method_vec, main_value) = lambda_lift(&value, env.clone());
+            (
+                fun_vec,
+                method_vec,
+                Exp::Assign {
+                    var: var.clone(),
+                    value: Box::new(main_value),
+                    ann: (),
+                },
+            )
+        }
         Exp::Lambda { .. }
         | Exp::ClassDef { .. }
         | Exp::SetField { .. }
         | Exp::CallMethod { .. }
-        | Exp::MakeClosure { .. } => {
+        | Exp::MakeClosure { .. }
+        | Exp::For { .. }
+        | Exp::ForRange { .. }
+        | Exp::LetPattern { .. }
+        | Exp::Ascribe(..)
+        | Exp::Match { .. } => {
             panic!("Should never exist during lambda lift!")
         }
     }
@@ -1,4 +1,5 @@
 use snake::runner::*;
+use snake::timing;
 use std::path::Path;
 
 static USAGE_MSG: &str = "\
@@ -10,6 +11,44 @@ To compile a program, link it and run the produced binary use
 
     snake --run INPUT_FILE
 
+To compile a program into a standalone executable use
+
+    snake build INPUT_FILE -o OUTPUT_FILE
+
+which is named after the input file when -o is left out. The executable
+takes the runtime's flags, --heap-size BYTES and --stack-size BYTES.
+
+To compile a program and run it inside the compiler, without linking, use
+
+    snake --jit INPUT_FILE
+
+To compile a program to bytecode and run it on the portable VM use
+
+    snake --vm INPUT_FILE
+
+To compile a program into the object file compiled_code.o use
+
+    snake --object INPUT_FILE
+
+To compile a program into the C source compiled_code.c, to be built with
+runtime/stub.c, use
+
+    snake --c INPUT_FILE
+
+To compile a program into the WebAssembly module compiled_code.wasm use
+
+    snake --wasm INPUT_FILE
+
+To compile a program and report the counts of its functions, classes,
+instructions, runtime checks and stack slots use
+
+    snake --stats INPUT_FILE
+
+To report the time each pass and external tool took, put --time-passes
+before any of these commands, as in
+
+    snake --time-passes --run INPUT_FILE
+
 To run the reference interpreter use
 
     snake --interp INPUT_FILE
@@ -20,10 +59,29 @@ To see this usage message run
 ";
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() == 2 {
+    let mut args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("--time-passes") {
+        args.remove(1);
+        timing::start_recording();
+    }
+    if args.get(1).map(String::as_str) == Some("build") {
+        match &args[2..] {
+            [input] => {
+                let input = Path::new(input);
+                let output = input.file_stem().unwrap_or(input.as_os_str());
+                build(input, Path::new(output))
+            }
+            [input, flag, output] if flag == "-o" => build(Path::new(input), Path::new(output)),
+            [] => usage(Some("build command requires an input file")),
+            _ => usage(Some("Failed to parse input")),
+        }
+    } else if args.len() == 2 {
         match args[1].as_str() {
-            "--interp" | "--run" => usage(Some("interp and run comands require an input file")),
+            "--interp" | "--run" | "--jit" | "--vm" | "--stats" | "--object" | "--c" | "--wasm" => {
+                usage(Some(
+                    "interp, run, jit, vm, stats, object, c and wasm comands require an input file",
+                ))
+            }
             "--help" => usage(None),
             path => emit_assembly(Path::new(&path)),
         }
@@ -31,6 +89,12 @@ fn main() {
         match args[1].as_str() {
             "--interp" => interp(Path::new(&args[2]), &mut std::io::stdout()),
             "--run" => run(Path::new(&args[2])),
+            "--jit" => jit(Path::new(&args[2])),
+            "--vm" => vm(Path::new(&args[2])),
+            "--stats" => emit_stats(Path::new(&args[2])),
+            "--object" => emit_object(Path::new(&args[2])),
+            "--c" => emit_c(Path::new(&args[2])),
+            "--wasm" => emit_wasm(Path::new(&args[2])),
             _ => usage(Some("Failed to parse input")),
         }
     } else if args.len() <= 1 {
@@ -38,6 +102,7 @@ fn main() {
     } else {
         usage(Some("Too many arguments"))
     }
+    report_times();
 }
 
 fn usage(err_msg: Option<&str>) {
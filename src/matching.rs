@@ -0,0 +1,438 @@
+use crate::runtime_error::RuntimeErr;
+use crate::syntax::{ClassInfo, Exp, FunDecl, Pattern, Prim1, Prim2};
+
+use std::collections::HashMap;
+
+// The test a decision node performs on a value
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Constructor {
+    Num(i64),
+    Bool(bool),
+    Array(usize),
+    Object(String),
+}
+
+// A match arm that is still alive in the decision tree: the patterns left
+// to test against the variables holding the parts of the scrutinee, and the
+// pattern variables already known to be bound to such a variable
+struct Row<'exp> {
+    tests: Vec<(String, &'exp Pattern<()>)>,
+    binds: Vec<(String, String)>,
+    arm: usize,
+}
+
+struct MatchCtx<'a> {
+    class_info: &'a HashMap<String, ClassInfo>,
+    count: usize,
+}
+
+impl<'a> MatchCtx<'a> {
+    fn fresh(&mut self) -> String {
+        self.count += 1;
+        format!("#match_{}", self.count)
+    }
+}
+
+fn var(x: &str) -> Box<Exp<()>> {
+    Box::new(Exp::Var(x.to_string(), ()))
+}
+
+fn if_exp(cond: Exp<()>, thn: Exp<()>, els: Exp<()>) -> Exp<()> {
+    Exp::If {
+        cond: Box::new(cond),
+        thn: Box::new(thn),
+        els: Box::new(els),
+        ann: (),
+    }
+}
+
+fn constructor(pat: &Pattern<()>) -> (Constructor, Vec<&Pattern<()>>) {
+    match pat {
+        Pattern::Num(i, _) => (Constructor::Num(*i), Vec::new()),
+        Pattern::Bool(b, _) => (Constructor::Bool(*b), Vec::new()),
+        Pattern::Array(pats, _) => (Constructor::Array(pats.len()), pats.iter().collect()),
+        Pattern::Object { class, fields, .. } => {
+            (Constructor::Object(class.clone()), fields.iter().collect())
+        }
+        Pattern::Wildcard(_) | Pattern::Var(..) => {
+            panic!("Variables and wildcards are removed from the tests of a row")
+        }
+    }
+}
+
+// Move variables and wildcards out of the tests, they always match
+fn simplify(mut row: Row) -> Row {
+    let mut tests = Vec::new();
+    for (occ, pat) in row.tests.into_iter() {
+        match pat {
+            Pattern::Wildcard(_) => {}
+            Pattern::Var(x, _) => row.binds.push((x.clone(), occ)),
+            _ => tests.push((occ, pat)),
+        }
+    }
+    row.tests = tests;
+    row
+}
+
+// The tests never raise, is_array guards reading the class header
+fn test(occ: &str, c: &Constructor, ctx: &MatchCtx) -> Exp<()> {
+    let class_idx = Exp::Prim1(Prim1::ClassIdx, var(occ), ());
+    let is_array = Exp::Prim1(Prim1::IsArray, var(occ), ());
+    match c {
+        Constructor::Num(i) => Exp::Prim2(Prim2::Eq, var(occ), Box::new(Exp::Num(*i, ())), ()),
+        Constructor::Bool(b) => Exp::Prim2(Prim2::Eq, var(occ), Box::new(Exp::Bool(*b, ())), ()),
+        // Objects share the layout of arrays, an array pattern only matches
+        // plain arrays (class 0)
+        Constructor::Array(n) => if_exp(
+            is_array,
+            if_exp(
+                Exp::Prim2(
+                    Prim2::Eq,
+                    Box::new(class_idx),
+                    Box::new(Exp::Num(0, ())),
+                    (),
+                ),
+                Exp::Prim2(
+                    Prim2::Eq,
+                    Box::new(Exp::Prim1(Prim1::Length, var(occ), ())),
+                    Box::new(Exp::Num(*n as i64, ())),
+                    (),
+                ),
+                Exp::Bool(false, ()),
+            ),
+            Exp::Bool(false, ()),
+        ),
+        Constructor::Object(class) => {
+            let id = match ctx.class_info.get(class) {
+                Some(info) => info.id,
+                None => panic!("Class is guaranteed to be lifted"),
+            };
+            if_exp(
+                is_array,
+                Exp::Prim2(
+                    Prim2::Eq,
+                    Box::new(class_idx),
+                    Box::new(Exp::Num(id as i64, ())),
+                    (),
+                ),
+                Exp::Bool(false, ()),
+            )
+        }
+    }
+}
+
+// Build the decision tree for rows, evaluating to the index of the first
+// arm that matches after assigning its pattern variables
+fn decision_tree(rows: Vec<Row>, ctx: &mut MatchCtx) -> Exp<()> {
+    let first = match rows.first() {
+        Some(first) => first,
+        None => return Exp::Fail(RuntimeErr::MatchError, ()),
+    };
+    // The first arm matches once all its tests passed
+    let (occ, _) = match first.tests.first() {
+        Some(test) => test.clone(),
+        None => {
+            return first.binds.iter().rev().fold(
+                Exp::Num(first.arm as i64, ()),
+                |acc, (x, occ)| Exp::Semicolon {
+                    e1: Box::new(Exp::Assign {
+                        var: x.clone(),
+                        value: var(occ),
+                        ann: (),
+                    }),
+                    e2: Box::new(acc),
+                    ann: (),
+                },
+            )
+        }
+    };
+    // Every constructor some arm tests occ against, in order
+    let mut constructors: Vec<Constructor> = Vec::new();
+    for row in rows.iter() {
+        for (o, pat) in row.tests.iter() {
+            if *o == occ {
+                let (c, _) = constructor(pat);
+                if !constructors.contains(&c) {
+                    constructors.push(c);
+                }
+            }
+        }
+    }
+    let default: Vec<Row> = rows
+        .iter()
+        .filter(|row| row.tests.iter().all(|(o, _)| *o != occ))
+        .map(|row| Row {
+            tests: row.tests.clone(),
+            binds: row.binds.clone(),
+            arm: row.arm,
+        })
+        .collect();
+    let mut tree = decision_tree(default, ctx);
+    for c in constructors.iter().rev() {
+        let (_, args) = constructor(
+            rows.iter()
+                .flat_map(|row| row.tests.iter())
+                .find(|(o, pat)| *o == occ && constructor(pat).0 == *c)
+                .map(|(_, pat)| *pat)
+                .expect("constructor comes from some row"),
+        );
+        let children: Vec<String> = args.iter().map(|_| ctx.fresh()).collect();
+        let specialized: Vec<Row> = rows
+            .iter()
+            .filter_map(|row| {
+                let mut tests = Vec::new();
+                for (o, pat) in row.tests.iter() {
+                    if *o != occ {
+                        continue;
+                    }
+                    let (c2, args2) = constructor(pat);
+                    if c2 != *c {
+                        return None;
+                    }
+                    tests.extend(children.iter().cloned().zip(args2));
+                }
+                tests.extend(row.tests.iter().filter(|(o, _)| *o != occ).cloned());
+                Some(simplify(Row {
+                    tests,
+                    binds: row.binds.clone(),
+                    arm: row.arm,
+                }))
+            })
+            .collect();
+        let subtree = decision_tree(specialized, ctx);
+        let thn = if children.is_empty() {
+            subtree
+        } else {
+            Exp::Let {
+                bindings: children
+                    .iter()
+                    .enumerate()
+                    .map(|(i, child)| {
+                        (
+                            child.clone(),
+                            Exp::Prim2(
+                                Prim2::ArrayGet,
+                                var(&occ),
+                                Box::new(Exp::Num(i as i64, ())),
+                                (),
+                            ),
+                        )
+                    })
+                    .collect(),
+                body: Box::new(subtree),
+                ann: (),
+            }
+        };
+        tree = if_exp(test(&occ, c, ctx), thn, tree);
+    }
+    tree
+}
+
+fn pattern_vars(pat: &Pattern<()>, vars: &mut Vec<String>) {
+    match pat {
+        Pattern::Wildcard(_) | Pattern::Num(..) | Pattern::Bool(..) => {}
+        Pattern::Var(x, _) => vars.push(x.clone()),
+        Pattern::Array(pats, _) | Pattern::Object { fields: pats, .. } => {
+            for pat in pats.iter() {
+                pattern_vars(pat, vars);
+            }
+        }
+    }
+}
+
+// match e: | p0 => b0 | p1 => b1 ... end
+// ==> let #scrutinee = e, x = false, ... (every pattern variable),
+//         #arm = <decision tree> in
+//     if #arm == 0: b0 else: if #arm == 1: b1 else: ...
+//
+// The decision tree tests every part of the scrutinee at most once on each
+// path, and its leaves only assign the pattern variables and name the arm,
+// so no arm body is ever duplicated.
+fn lower_match(
+    scrutinee: &Exp<()>,
+    arms: &[(Pattern<()>, Exp<()>)],
+    ctx: &mut MatchCtx,
+) -> Exp<()> {
+    let scrutinee_var = ctx.fresh();
+    let arm_var = ctx.fresh();
+    let mut bindings = vec![(scrutinee_var.clone(), lower(scrutinee, ctx))];
+    let mut vars = Vec::new();
+    for (pat, _) in arms.iter() {
+        pattern_vars(pat, &mut vars);
+    }
+    bindings.extend(vars.into_iter().map(|x| (x, Exp::Bool(false, ()))));
+    let rows: Vec<Row> = arms
+        .iter()
+        .enumerate()
+        .map(|(arm, (pat, _))| {
+            simplify(Row {
+                tests: vec![(scrutinee_var.clone(), pat)],
+                binds: Vec::new(),
+                arm,
+            })
+        })
+        .collect();
+    bindings.push((arm_var.clone(), decision_tree(rows, ctx)));
+    let mut bodies = arms
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, (_, body))| (i, lower(body, ctx)));
+    let (_, last) = bodies.next().expect("the parser requires at least one arm");
+    let dispatch = bodies.fold(last, |els, (i, body)| {
+        if_exp(
+            Exp::Prim2(
+                Prim2::Eq,
+                var(&arm_var),
+                Box::new(Exp::Num(i as i64, ())),
+                (),
+            ),
+            body,
+            els,
+        )
+    });
+    Exp::Let {
+        bindings,
+        body: Box::new(dispatch),
+        ann: (),
+    }
+}
+
+fn lower_decls(decls: &[FunDecl<Exp<()>, ()>], ctx: &mut MatchCtx) -> Vec<FunDecl<Exp<()>, ()>> {
+    decls
+        .iter()
+        .map(|decl| FunDecl {
+            name: decl.name.clone(),
+            parameters: decl.parameters.clone(),
+            body: lower(&decl.body, ctx),
+            ann: (),
+            signature: decl.signature.clone(),
+        })
+        .collect()
+}
+
+fn lower(p: &Exp<()>, ctx: &mut MatchCtx) -> Exp<()> {
+    match p {
+        Exp::Num(..) | Exp::Bool(..) | Exp::Var(..) | Exp::Fail(..) => p.clone(),
+        Exp::Prim1(prim, e, _) => Exp::Prim1(*prim, Box::new(lower(e, ctx)), ()),
+        Exp::Prim2(prim, e1, e2, _) => Exp::Prim2(
+            *prim,
+            Box::new(lower(e1, ctx)),
+            Box::new(lower(e2, ctx)),
+            (),
+        ),
+        Exp::Let { bindings, body, .. } => Exp::Let {
+            bindings: bindings
+                .iter()
+                .map(|(x, e)| (x.clone(), lower(e, ctx)))
+                .collect(),
+            body: Box::new(lower(body, ctx)),
+            ann: (),
+        },
+        Exp::If { cond, thn, els, .. } => {
+            if_exp(lower(cond, ctx), lower(thn, ctx), lower(els, ctx))
+        }
+        Exp::Array(es, _) => Exp::Array(es.iter().map(|e| lower(e, ctx)).collect(), ()),
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ..
+        } => Exp::ArraySet {
+            array: Box::new(lower(array, ctx)),
+            index: Box::new(lower(index, ctx)),
+            new_value: Box::new(lower(new_value, ctx)),
+            ann: (),
+        },
+        Exp::Semicolon { e1, e2, .. } => Exp::Semicolon {
+            e1: Box::new(lower(e1, ctx)),
+            e2: Box::new(lower(e2, ctx)),
+            ann: (),
+        },
+        Exp::FunDefs { decls, body, .. } => Exp::FunDefs {
+            decls: lower_decls(decls, ctx),
+            body: Box::new(lower(body, ctx)),
+            ann: (),
+        },
+        Exp::Call(fun, args, _) => Exp::Call(
+            Box::new(lower(fun, ctx)),
+            args.iter().map(|e| lower(e, ctx)).collect(),
+            (),
+        ),
+        Exp::Lambda {
+            parameters, body, ..
+        } => Exp::Lambda {
+            parameters: parameters.clone(),
+            body: Box::new(lower(body, ctx)),
+            ann: (),
+        },
+        Exp::Object { class, fields, .. } => Exp::Object {
+            class: class.clone(),
+            fields: fields.iter().map(|e| lower(e, ctx)).collect(),
+            ann: (),
+        },
+        Exp::CallUniqMethod {
+            object,
+            uniqmethod,
+            args,
+            ..
+        } => Exp::CallUniqMethod {
+            object: Box::new(lower(object, ctx)),
+            uniqmethod: uniqmethod.clone(),
+            args: args.iter().map(|e| lower(e, ctx)).collect(),
+            ann: (),
+        },
+        Exp::MethodDefs {
+            class, decls, body, ..
+        } => Exp::MethodDefs {
+            class: *class,
+            decls: lower_decls(decls, ctx),
+            body: Box::new(lower(body, ctx)),
+            ann: (),
+        },
+        Exp::While { cond, body, .. } => Exp::While {
+            cond: Box::new(lower(cond, ctx)),
+            body: Box::new(lower(body, ctx)),
+            ann: (),
+        },
+        Exp::Assign { var, value, .. } => Exp::Assign {
+            var: var.clone(),
+            value: Box::new(lower(value, ctx)),
+            ann: (),
+        },
+        Exp::Raise(e, _) => Exp::Raise(Box::new(lower(e, ctx)), ()),
+        Exp::Try {
+            body, var, handler, ..
+        } => Exp::Try {
+            body: Box::new(lower(body, ctx)),
+            var: var.clone(),
+            handler: Box::new(lower(handler, ctx)),
+            ann: (),
+        },
+        Exp::Match {
+            scrutinee, arms, ..
+        } => lower_match(scrutinee, arms, ctx),
+        Exp::ClassDef { .. }
+        | Exp::SetField { .. }
+        | Exp::CallMethod { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::For { .. }
+        | Exp::ForRange { .. }
+        | Exp::LetPattern { .. }
+        | Exp::Ascribe(..) => {
+            panic!("Should never exist during match lowering!")
+        }
+    }
+}
+
+// Compile every match into a decision tree over the core language.
+//
+// Precondition: all names are uniquified and classes are lifted, so class
+// patterns can be tested against the class index in the object header
+pub fn lower_matches(p: &Exp<()>, class_info: &HashMap<String, ClassInfo>) -> Exp<()> {
+    let mut ctx = MatchCtx {
+        class_info,
+        count: 0,
+    };
+    lower(p, &mut ctx)
+}
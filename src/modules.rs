@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use lalrpop_util::ParseError;
+
+use crate::compile::CompileErr;
+use crate::parser::ProgParser;
+use crate::span::Span1;
+use crate::syntax::{Exp, SurfProg};
+
+// Resolves the imports at the top of a program. Each imported file is read
+// once, and only its top-level defs and classes are kept. They are put
+// around the program that imports them, with the files they import further
+// out, so the usual scoping applies: the importer's own definitions shadow
+// the imported ones. The spans of every file are moved past the files
+// before it, so they all share one range of offsets and the nodes of the
+// whole program get distinct tags, which keeps the names uniquify gives
+// apart even when two files define the same one.
+
+// A file of the program and the offset its spans start at
+pub struct SourceFile {
+    pub name: Option<String>, // None for the program itself
+    pub base: usize,
+    pub text: String,
+}
+
+pub enum LoadErr {
+    Parse {
+        message: String,
+        location: Option<Span1>,
+    },
+    Import(CompileErr<Span1>),
+}
+
+type Imports = Vec<(String, Span1)>;
+type Loaded = (Vec<SourceFile>, SurfProg<Span1>);
+
+struct Loader {
+    files: Vec<SourceFile>,
+    loaded: HashSet<PathBuf>,
+    // the chain of files being loaded, an import of one of them is a cycle
+    loading: Vec<PathBuf>,
+    // the definitions of the imported files, each one after its imports
+    modules: Vec<SurfProg<Span1>>,
+}
+
+// The imports are relative to the directory of path, the file the program
+// was read from, or to the current directory without one. The files read
+// come back with an error too, since it may be in any of them.
+pub fn load(source: &str, path: Option<&Path>) -> Result<Loaded, (Vec<SourceFile>, LoadErr)> {
+    let mut loader = Loader {
+        files: vec![SourceFile {
+            name: None,
+            base: 0,
+            text: source.to_string(),
+        }],
+        loaded: HashSet::new(),
+        loading: path.map(identity).into_iter().collect(),
+        modules: Vec::new(),
+    };
+    let dir = path.and_then(Path::parent).unwrap_or(Path::new(""));
+    let body = match parse(source, 0, None)
+        .and_then(|(imports, body)| loader.import_all(&imports, dir).map(|()| body))
+    {
+        Ok(body) => body,
+        Err(e) => return Err((loader.files, e)),
+    };
+    let prog = loader
+        .modules
+        .into_iter()
+        .rev()
+        .fold(body, |inner, module| splice(module, inner));
+    Ok((loader.files, prog))
+}
+
+impl Loader {
+    fn import_all(&mut self, imports: &Imports, dir: &Path) -> Result<(), LoadErr> {
+        for (import, location) in imports.iter() {
+            let path = dir.join(import);
+            let id = identity(&path);
+            if self.loading.contains(&id) {
+                return Err(LoadErr::Import(CompileErr::ImportCycle {
+                    path: import.clone(),
+                    location: *location,
+                }));
+            }
+            if !self.loaded.insert(id.clone()) {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path).map_err(|e| {
+                LoadErr::Import(CompileErr::UnreadableImport {
+                    path: import.clone(),
+                    reason: e.to_string(),
+                    location: *location,
+                })
+            })?;
+            // one past the end of the last file, so an error at its very
+            // end still points into it
+            let last = &self.files[self.files.len() - 1];
+            let base = last.base + last.text.len() + 1;
+            let name = path.display().to_string();
+            let parsed = parse(&text, base, Some(&name));
+            self.files.push(SourceFile {
+                name: Some(name),
+                base,
+                text,
+            });
+            let (imports, body) = parsed?;
+
+            self.loading.push(id);
+            self.import_all(&imports, path.parent().unwrap_or(Path::new("")))?;
+            self.loading.pop();
+            self.modules.push(body);
+        }
+        Ok(())
+    }
+}
+
+// The same file reached through different paths is loaded once
+fn identity(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn parse(
+    text: &str,
+    base: usize,
+    name: Option<&str>,
+) -> Result<(Imports, SurfProg<Span1>), LoadErr> {
+    let shift = |s: &Span1| Span1 {
+        start_ix: s.start_ix + base,
+        end_ix: s.end_ix + base,
+    };
+    match ProgParser::new().parse(text) {
+        Ok((imports, body)) if base == 0 => Ok((imports, body)),
+        Ok((imports, body)) => Ok((
+            imports.into_iter().map(|(p, s)| (p, shift(&s))).collect(),
+            body.map_ann(&mut |s| shift(s)),
+        )),
+        Err(e) => Err(LoadErr::Parse {
+            message: match name {
+                Some(name) => format!("{}: {}", name, e),
+                None => e.to_string(),
+            },
+            location: error_span(&e, text.len()).map(|s| shift(&s)),
+        }),
+    }
+}
+
+// The top-level defs and classes of a module around the program importing
+// it, the rest of the module is left out
+fn splice(module: SurfProg<Span1>, inner: SurfProg<Span1>) -> SurfProg<Span1> {
+    match module {
+        Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+            decls,
+            body: Box::new(splice(*body, inner)),
+            ann,
+        },
+        Exp::ClassDef {
+            name,
+            fields,
+            field_types,
+            methods,
+            body,
+            ann,
+        } => Exp::ClassDef {
+            name,
+            fields,
+            field_types,
+            methods,
+            body: Box::new(splice(*body, inner)),
+            ann,
+        },
+        _ => inner,
+    }
+}
+
+// The tokens a parse error points at, or the character after the location
+// it names. Nothing is pointed at in an empty file.
+pub fn error_span<T, E>(e: &ParseError<usize, T, E>, len: usize) -> Option<Span1> {
+    let (start_ix, end_ix) = match e {
+        ParseError::InvalidToken { location } | ParseError::UnrecognizedEOF { location, .. } => {
+            (*location, *location + 1)
+        }
+        ParseError::UnrecognizedToken {
+            token: (start, _, end),
+            ..
+        }
+        | ParseError::ExtraToken {
+            token: (start, _, end),
+        } => (*start, *end),
+        ParseError::User { .. } => return None,
+    };
+    if len == 0 {
+        return None;
+    }
+    let start_ix = start_ix.min(len - 1);
+    Some(Span1 {
+        start_ix,
+        end_ix: end_ix.clamp(start_ix + 1, len),
+    })
+}
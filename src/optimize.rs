@@ -0,0 +1,1229 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::liveness;
+use crate::runtime_error::RuntimeErr;
+use crate::scope::{MAX_SNAKE_INT, MIN_SNAKE_INT};
+use crate::syntax::{FunDecl, ImmExp, MethodDecl, Prim1, Prim2, SeqExp, SeqProg};
+
+// Variables that are the target of an Assign, they cannot be propagated
+fn assigned_vars(e: &SeqExp<()>, assigned: &mut HashSet<String>) {
+    match e {
+        SeqExp::Assign { var, .. } => {
+            assigned.insert(var.clone());
+        }
+        SeqExp::Let {
+            bound_exp, body, ..
+        } => {
+            assigned_vars(bound_exp, assigned);
+            assigned_vars(body, assigned);
+        }
+        SeqExp::If { thn, els, .. } => {
+            assigned_vars(thn, assigned);
+            assigned_vars(els, assigned);
+        }
+        SeqExp::While { cond, body, .. } => {
+            assigned_vars(cond, assigned);
+            assigned_vars(body, assigned);
+        }
+        SeqExp::Try { body, handler, .. } => {
+            assigned_vars(body, assigned);
+            assigned_vars(handler, assigned);
+        }
+        SeqExp::Imm(..)
+        | SeqExp::Prim1(..)
+        | SeqExp::Prim2(..)
+        | SeqExp::ArraySet { .. }
+        | SeqExp::Array(..)
+        | SeqExp::MakeClosure { .. }
+        | SeqExp::CallClosure { .. }
+        | SeqExp::CallDirect { .. }
+        | SeqExp::Object { .. }
+        | SeqExp::CallMethod { .. }
+        | SeqExp::Raise(..)
+        | SeqExp::Fail(..) => {}
+    }
+}
+
+fn in_range(n: i64) -> Option<ImmExp> {
+    if n <= MAX_SNAKE_INT && n >= MIN_SNAKE_INT {
+        Some(ImmExp::Num(n))
+    } else {
+        None
+    }
+}
+
+// The constant result of a primitive, or None when it is only known at
+// runtime. An operation that would raise a runtime error (a type error or
+// an overflow) is never folded, so the error is still reported.
+fn fold_prim1(prim: Prim1, imm: &ImmExp) -> Option<ImmExp> {
+    match (prim, imm) {
+        (Prim1::Add1, ImmExp::Num(n)) => n.checked_add(1).and_then(in_range),
+        (Prim1::Sub1, ImmExp::Num(n)) => n.checked_sub(1).and_then(in_range),
+        (Prim1::Not, ImmExp::Bool(b)) => Some(ImmExp::Bool(!b)),
+        (Prim1::IsNum, ImmExp::Num(_) | ImmExp::Bool(_)) => {
+            Some(ImmExp::Bool(matches!(imm, ImmExp::Num(_))))
+        }
+        (Prim1::IsBool, ImmExp::Num(_) | ImmExp::Bool(_)) => {
+            Some(ImmExp::Bool(matches!(imm, ImmExp::Bool(_))))
+        }
+        (Prim1::IsArray | Prim1::IsFun, ImmExp::Num(_) | ImmExp::Bool(_)) => {
+            Some(ImmExp::Bool(false))
+        }
+        _ => None,
+    }
+}
+
+fn fold_prim2(prim: Prim2, imm1: &ImmExp, imm2: &ImmExp) -> Option<ImmExp> {
+    match (prim, imm1, imm2) {
+        (Prim2::Add, ImmExp::Num(n1), ImmExp::Num(n2)) => n1.checked_add(*n2).and_then(in_range),
+        (Prim2::Sub, ImmExp::Num(n1), ImmExp::Num(n2)) => n1.checked_sub(*n2).and_then(in_range),
+        // the runtime multiplies the tagged n1 by the untagged n2, and only
+        // a product that does not overflow there is folded
+        (Prim2::Mul, ImmExp::Num(n1), ImmExp::Num(n2)) => n1
+            .checked_mul(2)
+            .and_then(|tagged| tagged.checked_mul(*n2))
+            .map(|tagged| ImmExp::Num(tagged >> 1)),
+        (Prim2::Lt, ImmExp::Num(n1), ImmExp::Num(n2)) => Some(ImmExp::Bool(n1 < n2)),
+        (Prim2::Gt, ImmExp::Num(n1), ImmExp::Num(n2)) => Some(ImmExp::Bool(n1 > n2)),
+        (Prim2::Le, ImmExp::Num(n1), ImmExp::Num(n2)) => Some(ImmExp::Bool(n1 <= n2)),
+        (Prim2::Ge, ImmExp::Num(n1), ImmExp::Num(n2)) => Some(ImmExp::Bool(n1 >= n2)),
+        (Prim2::And, ImmExp::Bool(b1), ImmExp::Bool(b2)) => Some(ImmExp::Bool(*b1 && *b2)),
+        (Prim2::Or, ImmExp::Bool(b1), ImmExp::Bool(b2)) => Some(ImmExp::Bool(*b1 || *b2)),
+        // equality compares the tagged words, so a number never equals a bool
+        (
+            Prim2::Eq | Prim2::Neq,
+            ImmExp::Num(_) | ImmExp::Bool(_),
+            ImmExp::Num(_) | ImmExp::Bool(_),
+        ) => Some(ImmExp::Bool((imm1 == imm2) == (prim == Prim2::Eq))),
+        _ => None,
+    }
+}
+
+fn subst(imm: &ImmExp, env: &HashMap<String, ImmExp>) -> ImmExp {
+    match imm {
+        ImmExp::Var(x) => match env.get(x) {
+            Some(v) => v.clone(),
+            None => imm.clone(),
+        },
+        ImmExp::Num(_) | ImmExp::Bool(_) => imm.clone(),
+    }
+}
+
+fn propagatable(imm: &ImmExp, assigned: &HashSet<String>) -> bool {
+    match imm {
+        ImmExp::Var(y) => !assigned.contains(y),
+        ImmExp::Num(_) | ImmExp::Bool(_) => true,
+    }
+}
+
+fn fold(e: &SeqExp<()>, env: &HashMap<String, ImmExp>, assigned: &HashSet<String>) -> SeqExp<()> {
+    let imms = |es: &[ImmExp]| es.iter().map(|imm| subst(imm, env)).collect();
+    match e {
+        SeqExp::Imm(imm, _) => SeqExp::Imm(subst(imm, env), ()),
+        SeqExp::Prim1(prim, imm, _) => {
+            let imm = subst(imm, env);
+            match fold_prim1(*prim, &imm) {
+                Some(v) => SeqExp::Imm(v, ()),
+                None => SeqExp::Prim1(*prim, imm, ()),
+            }
+        }
+        SeqExp::Prim2(prim, imm1, imm2, _) => {
+            let imm1 = subst(imm1, env);
+            let imm2 = subst(imm2, env);
+            match fold_prim2(*prim, &imm1, &imm2) {
+                Some(v) => SeqExp::Imm(v, ()),
+                None => SeqExp::Prim2(*prim, imm1, imm2, ()),
+            }
+        }
+        SeqExp::ArraySet {
+            array,
+            index,
+            new_value,
+            ..
+        } => SeqExp::ArraySet {
+            array: subst(array, env),
+            index: subst(index, env),
+            new_value: subst(new_value, env),
+            ann: (),
+        },
+        SeqExp::Array(es, _) => SeqExp::Array(imms(es), ()),
+        SeqExp::MakeClosure {
+            arity,
+            label,
+            env: closure_env,
+            ..
+        } => SeqExp::MakeClosure {
+            arity: *arity,
+            label: label.clone(),
+            env: subst(closure_env, env),
+            ann: (),
+        },
+        SeqExp::CallClosure { fun, args, .. } => SeqExp::CallClosure {
+            fun: subst(fun, env),
+            args: imms(args),
+            ann: (),
+        },
+        SeqExp::CallDirect { label, args, .. } => SeqExp::CallDirect {
+            label: label.clone(),
+            args: imms(args),
+            ann: (),
+        },
+        SeqExp::Let {
+            var,
+            bound_exp,
+            body,
+            ..
+        } => {
+            let bound_exp = fold(bound_exp, env, assigned);
+            match bound_exp {
+                SeqExp::Imm(imm, _) if !assigned.contains(var) && propagatable(&imm, assigned) => {
+                    let mut body_env = env.clone();
+                    body_env.insert(var.clone(), imm);
+                    fold(body, &body_env, assigned)
+                }
+                _ => {
+                    let mut body_env = env.clone();
+                    body_env.remove(var);
+                    SeqExp::Let {
+                        var: var.clone(),
+                        bound_exp: Box::new(bound_exp),
+                        body: Box::new(fold(body, &body_env, assigned)),
+                        ann: (),
+                    }
+                }
+            }
+        }
+        SeqExp::If { cond, thn, els, .. } => match subst(cond, env) {
+            ImmExp::Bool(true) => fold(thn, env, assigned),
+            ImmExp::Bool(false) => fold(els, env, assigned),
+            cond => SeqExp::If {
+                cond,
+                thn: Box::new(fold(thn, env, assigned)),
+                els: Box::new(fold(els, env, assigned)),
+                ann: (),
+            },
+        },
+        SeqExp::Object { class, fields, .. } => SeqExp::Object {
+            class: class.clone(),
+            fields: imms(fields),
+            ann: (),
+        },
+        SeqExp::CallMethod {
+            object,
+            method,
+            args,
+            ..
+        } => SeqExp::CallMethod {
+            object: subst(object, env),
+            method: method.clone(),
+            args: imms(args),
+            ann: (),
+        },
+        // a loop whose condition is false from the start only evaluates it once
+        SeqExp::While { cond, body, .. } => match fold(cond, env, assigned) {
+            SeqExp::Imm(ImmExp::Bool(false), _) => SeqExp::Imm(ImmExp::Bool(false), ()),
+            cond => SeqExp::While {
+                cond: Box::new(cond),
+                body: Box::new(fold(body, env, assigned)),
+                ann: (),
+            },
+        },
+        SeqExp::Assign { var, value, .. } => SeqExp::Assign {
+            var: var.clone(),
+            value: subst(value, env),
+            ann: (),
+        },
+        SeqExp::Raise(imm, _) => SeqExp::Raise(subst(imm, env), ()),
+        SeqExp::Try {
+            body, var, handler, ..
+        } => {
+            let mut handler_env = env.clone();
+            handler_env.remove(var);
+            SeqExp::Try {
+                body: Box::new(fold(body, env, assigned)),
+                var: var.clone(),
+                handler: Box::new(fold(handler, &handler_env, assigned)),
+                ann: (),
+            }
+        }
+        SeqExp::Fail(err, _) => SeqExp::Fail(*err, ()),
+    }
+}
+
+fn fold_decl(
+    decl: &FunDecl<SeqExp<()>, ()>,
+    assigned: &HashSet<String>,
+) -> FunDecl<SeqExp<()>, ()> {
+    FunDecl {
+        name: decl.name.clone(),
+        parameters: decl.parameters.clone(),
+        body: fold(&decl.body, &HashMap::new(), assigned),
+        ann: (),
+        signature: decl.signature.clone(),
+    }
+}
+
+// Constant folding and propagation.
+//
+// Arithmetic, comparisons and boolean logic on constants are computed at
+// compile time, let-bound immediates are substituted into their uses, and
+// an if on a known condition is replaced by the branch it takes. Variables
+// that are assigned (by a while loop or a captured mutation) keep their let.
+//
+// Precondition: all variable names in the program are unique
+pub fn fold_constants(p: &SeqProg<()>) -> SeqProg<()> {
+    let mut assigned = HashSet::new();
+    for decl in p.funs.iter() {
+        assigned_vars(&decl.body, &mut assigned);
+    }
+    for method in p.methods.iter() {
+        assigned_vars(&method.fundecl.body, &mut assigned);
+    }
+    assigned_vars(&p.main, &mut assigned);
+    SeqProg {
+        class: p.class.clone(),
+        direct: p.direct.clone(),
+        funs: p
+            .funs
+            .iter()
+            .map(|decl| fold_decl(decl, &assigned))
+            .collect(),
+        methods: p
+            .methods
+            .iter()
+            .map(|method| MethodDecl {
+                class: method.class,
+                fundecl: fold_decl(&method.fundecl, &assigned),
+            })
+            .collect(),
+        main: fold(&p.main, &HashMap::new(), &assigned),
+        ann: (),
+    }
+}
+
+// A binding that can be dropped when its variable is dead: evaluating it has
+// no effect, allocates nothing and can never fail at runtime
+fn is_pure<Ann>(e: &SeqExp<Ann>) -> bool {
+    match e {
+        SeqExp::Imm(..)
+        | SeqExp::Prim1(Prim1::IsNum | Prim1::IsBool | Prim1::IsArray | Prim1::IsFun, ..)
+        | SeqExp::Prim2(Prim2::Eq | Prim2::Neq, ..) => true,
+        SeqExp::Let {
+            bound_exp, body, ..
+        } => is_pure(bound_exp) && is_pure(body),
+        _ => false,
+    }
+}
+
+fn eliminate(e: &SeqExp<HashSet<String>>) -> SeqExp<()> {
+    match e {
+        SeqExp::Let {
+            var,
+            bound_exp,
+            body,
+            ..
+        } => {
+            if !body.ann().contains(var) && is_pure(bound_exp) {
+                eliminate(body)
+            } else {
+                SeqExp::Let {
+                    var: var.clone(),
+                    bound_exp: Box::new(eliminate(bound_exp)),
+                    body: Box::new(eliminate(body)),
+                    ann: (),
+                }
+            }
+        }
+        SeqExp::If { cond, thn, els, .. } => SeqExp::If {
+            cond: cond.clone(),
+            thn: Box::new(eliminate(thn)),
+            els: Box::new(eliminate(els)),
+            ann: (),
+        },
+        SeqExp::While { cond, body, .. } => SeqExp::While {
+            cond: Box::new(eliminate(cond)),
+            body: Box::new(eliminate(body)),
+            ann: (),
+        },
+        SeqExp::Try {
+            body, var, handler, ..
+        } => SeqExp::Try {
+            body: Box::new(eliminate(body)),
+            var: var.clone(),
+            handler: Box::new(eliminate(handler)),
+            ann: (),
+        },
+        _ => e.map_ann(&mut |_| ()),
+    }
+}
+
+// Removing a binding can make the bindings it used dead in turn
+fn eliminate_decl(decl: &FunDecl<SeqExp<()>, ()>) -> FunDecl<SeqExp<()>, ()> {
+    let params: HashSet<String> = decl.parameters.iter().cloned().collect();
+    let mut body = decl.body.clone();
+    loop {
+        let next = eliminate(&liveness(&body, &params, HashSet::new()));
+        if next == body {
+            break;
+        }
+        body = next;
+    }
+    FunDecl {
+        name: decl.name.clone(),
+        parameters: decl.parameters.clone(),
+        body,
+        ann: (),
+        signature: decl.signature.clone(),
+    }
+}
+
+// Dead binding elimination.
+//
+// A let whose variable is never used afterwards is removed when its bound
+// expression is pure, so it no longer takes a stack slot in the frame.
+// Bindings of prints, array and object allocation, mutation and calls are
+// kept, as are primitives that check the types of their arguments.
+pub fn eliminate_dead_bindings(p: &SeqProg<()>) -> SeqProg<()> {
+    let main = FunDecl {
+        name: String::new(),
+        parameters: Vec::new(),
+        body: p.main.clone(),
+        ann: (),
+        signature: None,
+    };
+    SeqProg {
+        class: p.class.clone(),
+        direct: p.direct.clone(),
+        funs: p.funs.iter().map(eliminate_decl).collect(),
+        methods: p
+            .methods
+            .iter()
+            .map(|method| MethodDecl {
+                class: method.class,
+                fundecl: eliminate_decl(&method.fundecl),
+            })
+            .collect(),
+        main: eliminate_decl(&main).body,
+        ann: (),
+    }
+}
+
+// Walks every let, try and parameter binding of a body
+fn collect_bindings<'a>(
+    e: &'a SeqExp<()>,
+    bindings: &mut HashMap<&'a str, Vec<&'a SeqExp<()>>>,
+    opaque: &mut HashSet<&'a str>,
+) {
+    match e {
+        SeqExp::Let {
+            var,
+            bound_exp,
+            body,
+            ..
+        } => {
+            bindings.entry(var).or_default().push(bound_exp);
+            collect_bindings(bound_exp, bindings, opaque);
+            collect_bindings(body, bindings, opaque);
+        }
+        SeqExp::If { thn, els, .. } => {
+            collect_bindings(thn, bindings, opaque);
+            collect_bindings(els, bindings, opaque);
+        }
+        SeqExp::While { cond, body, .. } => {
+            collect_bindings(cond, bindings, opaque);
+            collect_bindings(body, bindings, opaque);
+        }
+        SeqExp::Try {
+            body, var, handler, ..
+        } => {
+            opaque.insert(var);
+            collect_bindings(body, bindings, opaque);
+            collect_bindings(handler, bindings, opaque);
+        }
+        SeqExp::Assign { var, .. } => {
+            opaque.insert(var);
+        }
+        _ => {}
+    }
+}
+
+// A load of slot i of the env parameter, the way lambda_lift binds the
+// captured variables at the start of a lifted function
+fn env_load(e: &SeqExp<()>) -> Option<&str> {
+    match e {
+        SeqExp::Prim2(Prim2::ArrayGet, ImmExp::Var(env), ImmExp::Num(_), _) => Some(env),
+        _ => None,
+    }
+}
+
+// The variables that always hold the closure of one lifted function, with
+// its label and arity. lambda_lift binds such a variable once with
+// MakeClosure and loads it again by name from the env of every function
+// that captures it.
+fn known_closures(p: &SeqProg<()>) -> HashMap<String, (String, usize)> {
+    let decls: Vec<&FunDecl<SeqExp<()>, ()>> = p
+        .funs
+        .iter()
+        .chain(p.methods.iter().map(|method| &method.fundecl))
+        .collect();
+    let mut bindings = HashMap::new();
+    let mut opaque = HashSet::new();
+    let mut env_params = HashSet::new();
+    for decl in decls.iter() {
+        collect_bindings(&decl.body, &mut bindings, &mut opaque);
+        opaque.extend(decl.parameters.iter().map(|x| x.as_str()));
+        if let Some(env) = decl.parameters.first() {
+            env_params.insert(env.as_str());
+        }
+    }
+    collect_bindings(&p.main, &mut bindings, &mut opaque);
+    let mut known = HashMap::new();
+    for (x, bound) in bindings.into_iter() {
+        if opaque.contains(x) {
+            continue;
+        }
+        let mut closure = None;
+        let mut only_closure = true;
+        for e in bound.into_iter() {
+            match e {
+                SeqExp::MakeClosure { arity, label, .. } if closure.is_none() => {
+                    closure = Some((label.clone(), *arity));
+                }
+                _ if env_load(e).is_some_and(|env| env_params.contains(env)) => {}
+                _ => only_closure = false,
+            }
+        }
+        if let (true, Some(closure)) = (only_closure, closure) {
+            known.insert(x.to_string(), closure);
+        }
+    }
+    known
+}
+
+fn direct_callee<'a>(
+    fun: &ImmExp,
+    args: &[ImmExp],
+    known: &'a HashMap<String, (String, usize)>,
+    direct: &HashSet<String>,
+) -> Option<&'a String> {
+    match fun {
+        ImmExp::Var(x) => match known.get(x) {
+            Some((label, arity)) if *arity == args.len() && direct.contains(label) => Some(label),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// The variables of e used as values. A known closure that is only called
+// directly is not, and neither is the env parameter in its loads, whose
+// variables are collected in loaded.
+fn escaping_vars(
+    e: &SeqExp<()>,
+    env: &str,
+    known: &HashMap<String, (String, usize)>,
+    direct: &HashSet<String>,
+    loaded: &mut HashSet<String>,
+    escaping: &mut HashSet<String>,
+) {
+    let mut uses = |imms: &[&ImmExp]| {
+        for imm in imms.iter() {
+            if let ImmExp::Var(x) = imm {
+                escaping.insert(x.clone());
+            }
+        }
+    };
+    match e {
+        SeqExp::Imm(imm, _) | SeqExp::Prim1(_, imm, _) | SeqExp::Raise(imm, _) => uses(&[imm]),
+        SeqExp::Assign { value, .. } => uses(&[value]),
+        SeqExp::Prim2(_, imm1, imm2, _) => uses(&[imm1, imm2]),
+        SeqExp::ArraySet {
+            array,
+            index,
+            new_value,
+            ..
+        } => uses(&[array, index, new_value]),
+        SeqExp::Array(imms, _) | SeqExp::Object { fields: imms, .. } => {
+            uses(&imms.iter().collect::<Vec<_>>())
+        }
+        SeqExp::CallDirect { args, .. } => uses(&args.iter().collect::<Vec<_>>()),
+        SeqExp::MakeClosure {
+            env: closure_env, ..
+        } => uses(&[closure_env]),
+        SeqExp::CallClosure { fun, args, .. } => {
+            let mut imms: Vec<&ImmExp> = args.iter().collect();
+            if direct_callee(fun, args, known, direct).is_none() {
+                imms.push(fun);
+            }
+            uses(&imms)
+        }
+        SeqExp::CallMethod {
+            object,
+            method,
+            args,
+            ..
+        } => {
+            // the method table reads the closure of every class's method
+            let closures: Vec<ImmExp> = method.values().map(|x| ImmExp::Var(x.clone())).collect();
+            let mut imms: Vec<&ImmExp> = args.iter().chain(closures.iter()).collect();
+            imms.push(object);
+            uses(&imms)
+        }
+        SeqExp::If { cond, thn, els, .. } => {
+            uses(&[cond]);
+            escaping_vars(thn, env, known, direct, loaded, escaping);
+            escaping_vars(els, env, known, direct, loaded, escaping);
+        }
+        SeqExp::Let {
+            var,
+            bound_exp,
+            body,
+            ..
+        } => {
+            if env_load(bound_exp) == Some(env) {
+                loaded.insert(var.clone());
+            } else {
+                escaping_vars(bound_exp, env, known, direct, loaded, escaping);
+            }
+            escaping_vars(body, env, known, direct, loaded, escaping);
+        }
+        SeqExp::While { cond, body, .. } => {
+            escaping_vars(cond, env, known, direct, loaded, escaping);
+            escaping_vars(body, env, known, direct, loaded, escaping);
+        }
+        SeqExp::Try { body, handler, .. } => {
+            escaping_vars(body, env, known, direct, loaded, escaping);
+            escaping_vars(handler, env, known, direct, loaded, escaping);
+        }
+        SeqExp::Fail(..) => {}
+    }
+}
+
+// Replaces the calls of direct functions, and drops the loads from env when
+// it is the parameter of a direct function
+fn call_direct(
+    e: &SeqExp<()>,
+    env: Option<&str>,
+    known: &HashMap<String, (String, usize)>,
+    direct: &HashSet<String>,
+) -> SeqExp<()> {
+    match e {
+        SeqExp::CallClosure { fun, args, .. } => match direct_callee(fun, args, known, direct) {
+            Some(label) => SeqExp::CallDirect {
+                label: label.clone(),
+                args: args.clone(),
+                ann: (),
+            },
+            None => e.clone(),
+        },
+        SeqExp::Let {
+            var,
+            bound_exp,
+            body,
+            ..
+        } => {
+            if env.is_some() && env_load(bound_exp) == env {
+                call_direct(body, env, known, direct)
+            } else {
+                SeqExp::Let {
+                    var: var.clone(),
+                    bound_exp: Box::new(call_direct(bound_exp, env, known, direct)),
+                    body: Box::new(call_direct(body, env, known, direct)),
+                    ann: (),
+                }
+            }
+        }
+        SeqExp::If { cond, thn, els, .. } => SeqExp::If {
+            cond: cond.clone(),
+            thn: Box::new(call_direct(thn, env, known, direct)),
+            els: Box::new(call_direct(els, env, known, direct)),
+            ann: (),
+        },
+        SeqExp::While { cond, body, .. } => SeqExp::While {
+            cond: Box::new(call_direct(cond, env, known, direct)),
+            body: Box::new(call_direct(body, env, known, direct)),
+            ann: (),
+        },
+        SeqExp::Try {
+            body, var, handler, ..
+        } => SeqExp::Try {
+            body: Box::new(call_direct(body, env, known, direct)),
+            var: var.clone(),
+            handler: Box::new(call_direct(handler, env, known, direct)),
+            ann: (),
+        },
+        _ => e.clone(),
+    }
+}
+
+// Direct calls of known functions.
+//
+// A call whose callee always holds the closure of one lifted function is
+// compiled to a `call` of its label, without the closure tag and arity
+// checks. When the env of that function is only used to load closures that
+// it calls directly, the function stops taking the env at all: its loads
+// are dropped and direct calls pass only the arguments. Closure calls of it
+// still go through an entry that removes the env slot.
+//
+// Precondition: all variable names in the program are unique
+pub fn direct_calls(p: &SeqProg<()>) -> SeqProg<()> {
+    let known = known_closures(p);
+    let mut direct: HashSet<String> = p.funs.iter().map(|decl| decl.name.clone()).collect();
+    // assume every function is direct and drop the ones that need their env
+    // until nothing changes
+    loop {
+        let needs_env: Vec<String> = p
+            .funs
+            .iter()
+            .filter(|decl| direct.contains(&decl.name))
+            .filter(|decl| {
+                let env = match decl.parameters.first() {
+                    Some(env) => env,
+                    None => return true,
+                };
+                let mut loaded = HashSet::new();
+                let mut escaping = HashSet::new();
+                escaping_vars(&decl.body, env, &known, &direct, &mut loaded, &mut escaping);
+                escaping.contains(env) || loaded.iter().any(|x| escaping.contains(x))
+            })
+            .map(|decl| decl.name.clone())
+            .collect();
+        if needs_env.is_empty() {
+            break;
+        }
+        for name in needs_env.iter() {
+            direct.remove(name);
+        }
+    }
+    let rewrite = |decl: &FunDecl<SeqExp<()>, ()>| {
+        if direct.contains(&decl.name) {
+            let env = decl.parameters.first().map(|env| env.as_str());
+            FunDecl {
+                name: decl.name.clone(),
+                parameters: decl.parameters[1..].to_vec(),
+                body: call_direct(&decl.body, env, &known, &direct),
+                ann: (),
+                signature: decl.signature.clone(),
+            }
+        } else {
+            FunDecl {
+                name: decl.name.clone(),
+                parameters: decl.parameters.clone(),
+                body: call_direct(&decl.body, None, &known, &direct),
+                ann: (),
+                signature: decl.signature.clone(),
+            }
+        }
+    };
+    SeqProg {
+        class: p.class.clone(),
+        funs: p.funs.iter().map(rewrite).collect(),
+        methods: p
+            .methods
+            .iter()
+            .map(|method| MethodDecl {
+                class: method.class,
+                fundecl: rewrite(&method.fundecl),
+            })
+            .collect(),
+        main: call_direct(&p.main, None, &known, &direct),
+        direct,
+        ann: (),
+    }
+}
+
+// Bodies with at most this many expressions are inlined
+static INLINE_SIZE: usize = 16;
+
+fn size(e: &SeqExp<()>) -> usize {
+    match e {
+        SeqExp::Let {
+            bound_exp, body, ..
+        } => 1 + size(bound_exp) + size(body),
+        SeqExp::If { thn, els, .. } => 1 + size(thn) + size(els),
+        SeqExp::While { cond, body, .. } => 1 + size(cond) + size(body),
+        SeqExp::Try { body, handler, .. } => 1 + size(body) + size(handler),
+        _ => 1,
+    }
+}
+
+// A function that can be copied into its callers: its body only refers to
+// its parameters
+struct Inlinable {
+    parameters: Vec<String>,
+    body: SeqExp<()>,
+    // the class an inlined method checks the object against
+    class: Option<usize>,
+}
+
+// The labels of the functions e calls, when they are known
+fn callees(e: &SeqExp<()>, known: &HashMap<String, (String, usize)>, calls: &mut HashSet<String>) {
+    match e {
+        SeqExp::CallDirect { label, .. } => {
+            calls.insert(label.clone());
+        }
+        SeqExp::CallClosure {
+            fun: ImmExp::Var(x),
+            ..
+        } => {
+            if let Some((label, _)) = known.get(x) {
+                calls.insert(label.clone());
+            }
+        }
+        SeqExp::CallMethod { method, .. } => {
+            for x in method.values() {
+                if let Some((label, _)) = known.get(x) {
+                    calls.insert(label.clone());
+                }
+            }
+        }
+        SeqExp::Let {
+            bound_exp, body, ..
+        } => {
+            callees(bound_exp, known, calls);
+            callees(body, known, calls);
+        }
+        SeqExp::If { thn, els, .. } => {
+            callees(thn, known, calls);
+            callees(els, known, calls);
+        }
+        SeqExp::While { cond, body, .. } => {
+            callees(cond, known, calls);
+            callees(body, known, calls);
+        }
+        SeqExp::Try { body, handler, .. } => {
+            callees(body, known, calls);
+            callees(handler, known, calls);
+        }
+        _ => {}
+    }
+}
+
+// The functions that can call themselves, directly or through others
+fn recursive_functions(
+    p: &SeqProg<()>,
+    known: &HashMap<String, (String, usize)>,
+) -> HashSet<String> {
+    let mut graph: HashMap<&str, HashSet<String>> = HashMap::new();
+    for decl in p
+        .funs
+        .iter()
+        .chain(p.methods.iter().map(|method| &method.fundecl))
+    {
+        let mut calls = HashSet::new();
+        callees(&decl.body, known, &mut calls);
+        graph.insert(&decl.name, calls);
+    }
+    let mut recursive = HashSet::new();
+    for start in graph.keys() {
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut todo: Vec<&str> = vec![start];
+        while let Some(f) = todo.pop() {
+            for g in graph.get(f).into_iter().flatten() {
+                if g == start {
+                    recursive.insert(start.to_string());
+                }
+                if seen.insert(g) {
+                    todo.push(g);
+                }
+            }
+        }
+    }
+    recursive
+}
+
+// Appends f to order after the functions it calls
+fn callees_first(
+    f: &str,
+    graph: &HashMap<&str, Vec<String>>,
+    seen: &mut HashSet<String>,
+    order: &mut Vec<String>,
+) {
+    if !seen.insert(f.to_string()) {
+        return;
+    }
+    for g in graph.get(f).into_iter().flatten() {
+        callees_first(g, graph, seen, order);
+    }
+    order.push(f.to_string());
+}
+
+// Finds the small non-recursive functions and methods, with the loads from
+// their env dropped. The callees come first and a body is measured after
+// its own calls are inlined, so inlining a call never copies more than
+// INLINE_SIZE expressions. A function whose env is still needed is not
+// inlinable.
+fn find_inlinable(p: &SeqProg<()>, inliner: &mut Inliner) {
+    let known = inliner.known;
+    let recursive = recursive_functions(p, known);
+    let decls: HashMap<&str, (&FunDecl<SeqExp<()>, ()>, Option<usize>)> = p
+        .funs
+        .iter()
+        .map(|decl| (decl, None))
+        .chain(p.methods.iter().map(|m| (&m.fundecl, Some(m.class))))
+        .filter(|(decl, _)| !recursive.contains(&decl.name))
+        .map(|(decl, class)| (decl.name.as_str(), (decl, class)))
+        .collect();
+    let mut graph: HashMap<&str, Vec<String>> = HashMap::new();
+    for (name, (decl, _)) in decls.iter() {
+        let mut calls = HashSet::new();
+        callees(&decl.body, known, &mut calls);
+        let mut calls: Vec<String> = calls
+            .into_iter()
+            .filter(|g| decls.contains_key(g.as_str()))
+            .collect();
+        calls.sort();
+        graph.insert(name, calls);
+    }
+    let mut names: Vec<&str> = decls.keys().copied().collect();
+    names.sort();
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    for name in names {
+        callees_first(name, &graph, &mut seen, &mut order);
+    }
+
+    for name in order {
+        let (decl, class) = decls[name.as_str()];
+        let inlined = inliner.inline(&decl.body);
+        if size(&inlined) > INLINE_SIZE {
+            continue;
+        }
+        let (parameters, body) = if p.direct.contains(&decl.name) {
+            (decl.parameters.clone(), inlined)
+        } else {
+            let env = &decl.parameters[0];
+            let mut loaded = HashSet::new();
+            let mut escaping = HashSet::new();
+            let no_direct = HashSet::new();
+            escaping_vars(&inlined, env, known, &no_direct, &mut loaded, &mut escaping);
+            if escaping.contains(env) || loaded.iter().any(|x| escaping.contains(x)) {
+                continue;
+            }
+            let body = call_direct(&inlined, Some(env), known, &no_direct);
+            (decl.parameters[1..].to_vec(), body)
+        };
+        inliner.inlinable.insert(
+            decl.name.clone(),
+            Inlinable {
+                parameters,
+                body,
+                class,
+            },
+        );
+    }
+}
+
+// Gives the variables bound in a copy of an inlined body fresh names, so
+// all names in the program stay unique
+fn rename(e: &SeqExp<()>, names: &mut HashMap<String, ImmExp>, suffix: &str) -> SeqExp<()> {
+    let fresh = |x: &String, names: &mut HashMap<String, ImmExp>| {
+        let y = format!("{}#{}", x, suffix);
+        names.insert(x.clone(), ImmExp::Var(y.clone()));
+        y
+    };
+    match e {
+        SeqExp::Let {
+            var,
+            bound_exp,
+            body,
+            ..
+        } => {
+            let bound_exp = rename(bound_exp, names, suffix);
+            let var = fresh(var, names);
+            SeqExp::Let {
+                var,
+                bound_exp: Box::new(bound_exp),
+                body: Box::new(rename(body, names, suffix)),
+                ann: (),
+            }
+        }
+        SeqExp::If { cond, thn, els, .. } => SeqExp::If {
+            cond: subst(cond, names),
+            thn: Box::new(rename(thn, names, suffix)),
+            els: Box::new(rename(els, names, suffix)),
+            ann: (),
+        },
+        SeqExp::While { cond, body, .. } => SeqExp::While {
+            cond: Box::new(rename(cond, names, suffix)),
+            body: Box::new(rename(body, names, suffix)),
+            ann: (),
+        },
+        SeqExp::Try {
+            body, var, handler, ..
+        } => {
+            let body = rename(body, names, suffix);
+            let var = fresh(var, names);
+            SeqExp::Try {
+                body: Box::new(body),
+                var,
+                handler: Box::new(rename(handler, names, suffix)),
+                ann: (),
+            }
+        }
+        SeqExp::Assign { var, value, .. } => SeqExp::Assign {
+            var: match names.get(var) {
+                Some(ImmExp::Var(y)) => y.clone(),
+                _ => var.clone(),
+            },
+            value: subst(value, names),
+            ann: (),
+        },
+        // the other expressions only have immediates, which fold substitutes
+        _ => fold(e, names, &HashSet::new()),
+    }
+}
+
+struct Inliner<'a> {
+    known: &'a HashMap<String, (String, usize)>,
+    inlinable: HashMap<String, Inlinable>,
+    class_ids: HashMap<&'a str, usize>,
+    count: usize,
+}
+
+impl<'a> Inliner<'a> {
+    // A copy of the body of the function with its parameters replaced by
+    // args
+    fn instantiate(&mut self, label: &str, args: &[ImmExp]) -> SeqExp<()> {
+        self.count += 1;
+        let f = &self.inlinable[label];
+        let mut names: HashMap<String, ImmExp> = f
+            .parameters
+            .iter()
+            .cloned()
+            .zip(args.iter().cloned())
+            .collect();
+        rename(&f.body, &mut names, &format!("inline_{}", self.count))
+    }
+
+    fn inline(&mut self, e: &SeqExp<()>) -> SeqExp<()> {
+        match e {
+            SeqExp::CallDirect { label, args, .. } => match self.inlinable.get(label) {
+                Some(f) if f.parameters.len() == args.len() => self.instantiate(label, args),
+                _ => e.clone(),
+            },
+            // a method of a single class, after the class check it performs
+            SeqExp::CallMethod {
+                object,
+                method,
+                args,
+                ..
+            } if method.len() == 1 => {
+                let (class, closure) = method.iter().next().unwrap();
+                let label = self.known.get(closure).map(|(label, _)| label);
+                let f = label.and_then(|label| self.inlinable.get(label));
+                let classidx = self.class_ids.get(class.as_str()).copied();
+                match (label, f, classidx) {
+                    (Some(label), Some(f), Some(classidx))
+                        if f.class == Some(classidx) && f.parameters.len() == args.len() + 1 =>
+                    {
+                        let mut all_args = vec![object.clone()];
+                        all_args.extend(args.iter().cloned());
+                        let body = self.instantiate(label, &all_args);
+                        let class_var = format!("#class_inline_{}", self.count);
+                        let same_var = format!("#same_class_inline_{}", self.count);
+                        SeqExp::Let {
+                            var: class_var.clone(),
+                            bound_exp: Box::new(SeqExp::Prim1(Prim1::ClassIdx, object.clone(), ())),
+                            body: Box::new(SeqExp::Let {
+                                var: same_var.clone(),
+                                bound_exp: Box::new(SeqExp::Prim2(
+                                    Prim2::Eq,
+                                    ImmExp::Var(class_var),
+                                    ImmExp::Num(classidx as i64),
+                                    (),
+                                )),
+                                body: Box::new(SeqExp::If {
+                                    cond: ImmExp::Var(same_var),
+                                    thn: Box::new(body),
+                                    els: Box::new(SeqExp::Fail(RuntimeErr::MethodTypeError, ())),
+                                    ann: (),
+                                }),
+                                ann: (),
+                            }),
+                            ann: (),
+                        }
+                    }
+                    _ => e.clone(),
+                }
+            }
+            SeqExp::Let {
+                var,
+                bound_exp,
+                body,
+                ..
+            } => SeqExp::Let {
+                var: var.clone(),
+                bound_exp: Box::new(self.inline(bound_exp)),
+                body: Box::new(self.inline(body)),
+                ann: (),
+            },
+            SeqExp::If { cond, thn, els, .. } => SeqExp::If {
+                cond: cond.clone(),
+                thn: Box::new(self.inline(thn)),
+                els: Box::new(self.inline(els)),
+                ann: (),
+            },
+            SeqExp::While { cond, body, .. } => SeqExp::While {
+                cond: Box::new(self.inline(cond)),
+                body: Box::new(self.inline(body)),
+                ann: (),
+            },
+            SeqExp::Try {
+                body, var, handler, ..
+            } => SeqExp::Try {
+                body: Box::new(self.inline(body)),
+                var: var.clone(),
+                handler: Box::new(self.inline(handler)),
+                ann: (),
+            },
+            _ => e.clone(),
+        }
+    }
+
+    fn inline_decl(&mut self, decl: &FunDecl<SeqExp<()>, ()>) -> FunDecl<SeqExp<()>, ()> {
+        FunDecl {
+            name: decl.name.clone(),
+            parameters: decl.parameters.clone(),
+            body: self.inline(&decl.body),
+            ann: (),
+            signature: decl.signature.clone(),
+        }
+    }
+}
+
+// Function inlining.
+//
+// Calls of small functions that are not recursive, and of small methods
+// defined by a single class, are replaced by a copy of the callee's body.
+// The copy gets fresh variable names and its parameters are substituted by
+// the arguments. An inlined method call keeps the check of the object's
+// class. The copied bodies already have their own calls inlined, so one
+// pass over the program is enough.
+//
+// Precondition: all variable names in the program are unique, and direct
+// calls are already found
+pub fn inline_calls(p: &SeqProg<()>) -> SeqProg<()> {
+    let known = known_closures(p);
+    let mut inliner = Inliner {
+        known: &known,
+        inlinable: HashMap::new(),
+        class_ids: p
+            .class
+            .iter()
+            .map(|(name, info)| (name.as_str(), info.id))
+            .collect(),
+        count: 0,
+    };
+    find_inlinable(p, &mut inliner);
+    SeqProg {
+        class: p.class.clone(),
+        direct: p.direct.clone(),
+        funs: p
+            .funs
+            .iter()
+            .map(|decl| inliner.inline_decl(decl))
+            .collect(),
+        methods: p
+            .methods
+            .iter()
+            .map(|method| MethodDecl {
+                class: method.class,
+                fundecl: inliner.inline_decl(&method.fundecl),
+            })
+            .collect(),
+        main: inliner.inline(&p.main),
+        ann: (),
+    }
+}
+
+fn flatten(e: &SeqExp<()>) -> SeqExp<()> {
+    match e {
+        SeqExp::Let {
+            var,
+            bound_exp,
+            body,
+            ..
+        } => match flatten(bound_exp) {
+            SeqExp::Let {
+                var: inner_var,
+                bound_exp: inner_bound,
+                body: inner_body,
+                ..
+            } => SeqExp::Let {
+                var: inner_var,
+                bound_exp: inner_bound,
+                body: Box::new(flatten(&SeqExp::Let {
+                    var: var.clone(),
+                    bound_exp: inner_body,
+                    body: body.clone(),
+                    ann: (),
+                })),
+                ann: (),
+            },
+            bound_exp => SeqExp::Let {
+                var: var.clone(),
+                bound_exp: Box::new(bound_exp),
+                body: Box::new(flatten(body)),
+                ann: (),
+            },
+        },
+        SeqExp::If { cond, thn, els, .. } => SeqExp::If {
+            cond: cond.clone(),
+            thn: Box::new(flatten(thn)),
+            els: Box::new(flatten(els)),
+            ann: (),
+        },
+        SeqExp::While { cond, body, .. } => SeqExp::While {
+            cond: Box::new(flatten(cond)),
+            body: Box::new(flatten(body)),
+            ann: (),
+        },
+        SeqExp::Try {
+            body, var, handler, ..
+        } => SeqExp::Try {
+            body: Box::new(flatten(body)),
+            var: var.clone(),
+            handler: Box::new(flatten(handler)),
+            ann: (),
+        },
+        e => e.clone(),
+    }
+}
+
+fn flatten_decl(decl: &FunDecl<SeqExp<()>, ()>) -> FunDecl<SeqExp<()>, ()> {
+    FunDecl {
+        name: decl.name.clone(),
+        parameters: decl.parameters.clone(),
+        body: flatten(&decl.body),
+        ann: (),
+        signature: decl.signature.clone(),
+    }
+}
+
+// Let flattening.
+//
+// A let bound to another let is rewritten so the inner binding comes
+// first: `let x = (let y = e in b) in c` becomes `let y = e in let x = b
+// in c`. No let is then bound to a let, so the arithmetic that the
+// sequentialization nests into one binding can be unboxed, see
+// types::unboxed_vars.
+//
+// Precondition: all variable names in the program are unique
+pub fn flatten_lets(p: &SeqProg<()>) -> SeqProg<()> {
+    SeqProg {
+        class: p.class.clone(),
+        direct: p.direct.clone(),
+        funs: p.funs.iter().map(flatten_decl).collect(),
+        methods: p
+            .methods
+            .iter()
+            .map(|method| MethodDecl {
+                class: method.class,
+                fundecl: flatten_decl(&method.fundecl),
+            })
+            .collect(),
+        main: flatten(&p.main),
+        ann: (),
+    }
+}
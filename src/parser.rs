@@ -1,7 +1,7 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: db8876bafb1fbfcedeafc11606cda045de87bdd299d288b822cdacb249305af8
+// sha3: c6baeb9b73dc4e036f33703523aee04dd9318a9b0c24a9a4204be28ab07c005c
 use std::str::FromStr;
-use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim1, Prim2};
+use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Pattern, Prim1, Prim2, Signature, SnakeType};
 use crate::span::Span1;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
@@ -15,7 +15,7 @@ mod __parse__Exp {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
     use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim1, Prim2};
+    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Pattern, Prim1, Prim2, Signature, SnakeType};
     use crate::span::Span1;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -28,363 +28,559 @@ mod __parse__Exp {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(&'input str),
-        Variant1(Exp<Span1>),
-        Variant2(alloc::vec::Vec<Exp<Span1>>),
-        Variant3(String),
-        Variant4(alloc::vec::Vec<String>),
-        Variant5((SurfFunDecl<Span1>, &'input str)),
-        Variant6(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
-        Variant7(usize),
-        Variant8(Vec<(String, Exp<Span1>)>),
-        Variant9(bool),
-        Variant10(Box<Exp<Span1>>),
-        Variant11(Prim2),
-        Variant12(Vec<Exp<Span1>>),
-        Variant13(Vec<String>),
-        Variant14(core::option::Option<Exp<Span1>>),
-        Variant15(SurfFunDecl<Span1>),
-        Variant16(core::option::Option<String>),
-        Variant17(()),
-        Variant18(i64),
-        Variant19(Prim1),
-        Variant20(SurfProg<Span1>),
+        Variant1(SnakeType),
+        Variant2(core::option::Option<SnakeType>),
+        Variant3(Exp<Span1>),
+        Variant4(alloc::vec::Vec<Exp<Span1>>),
+        Variant5(String),
+        Variant6(alloc::vec::Vec<String>),
+        Variant7(Pattern<Span1>),
+        Variant8(alloc::vec::Vec<Pattern<Span1>>),
+        Variant9(alloc::vec::Vec<SnakeType>),
+        Variant10((String, Option<SnakeType>)),
+        Variant11(alloc::vec::Vec<(String, Option<SnakeType>)>),
+        Variant12((SurfFunDecl<Span1>, &'input str)),
+        Variant13(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
+        Variant14(usize),
+        Variant15((Pattern<Span1>, Exp<Span1>)),
+        Variant16(Vec<(Pattern<Span1>, Exp<Span1>)>),
+        Variant17(bool),
+        Variant18(Box<Exp<Span1>>),
+        Variant19(Prim2),
+        Variant20(Vec<Exp<Span1>>),
+        Variant21(Vec<String>),
+        Variant22(Vec<Pattern<Span1>>),
+        Variant23(Vec<SnakeType>),
+        Variant24(Vec<(String, Option<SnakeType>)>),
+        Variant25(core::option::Option<Exp<Span1>>),
+        Variant26(SurfFunDecl<Span1>),
+        Variant27(core::option::Option<String>),
+        Variant28((String, Span1)),
+        Variant29(alloc::vec::Vec<(String, Span1)>),
+        Variant30(()),
+        Variant31(alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>),
+        Variant32(i64),
+        Variant33(core::option::Option<Pattern<Span1>>),
+        Variant34(Prim1),
+        Variant35((Vec<(String, Span1)>, SurfProg<Span1>)),
+        Variant36(core::option::Option<(String, Option<SnakeType>)>),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0,
+        0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0,
         // State 3
-        0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 90, 91, 0, 92, 93, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 123, 0, 124, 0, 125, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 95, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 127, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 7
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 0, 0, 0, 0, 73, 0, 0, 74, 75, 76, 77, 78, 79, 0, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 8
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 9
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -59, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -89, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 12
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 152, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 116, 117,
         // State 15
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 16
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 17
-        8, 0, 0, 9, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 19
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 0, 0, 0, 0, 73, 0, 0, 74, 75, 76, 77, 78, 79, 0, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 20
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 0, 0, 0, 0, 73, 0, 0, 74, 75, 76, 77, 78, 79, 0, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 21
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 0, 0, 0, 0, 73, 0, 0, 74, 75, 76, 77, 78, 79, 0, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 22
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 0, 0, 0, 0, 73, 0, 0, 74, 75, 76, 77, 78, 79, 0, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 23
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 24
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 0, 0, 0, 0, 73, 0, 0, 74, 75, 76, 77, 78, 79, 0, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 25
-        0, 0, 0, 0, -65, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 26
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 27
-        8, 0, 0, 9, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -61, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 28
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 29
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 31
-        0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        8, 0, 0, 9, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -91, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 32
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, -97, 152, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 116, 117,
         // State 33
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 35
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 36
-        8, 0, 0, 9, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 117,
         // State 37
-        8, 0, 0, 9, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 38
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 0, 0, 0, 0, 73, 0, 0, 74, 75, 76, 77, 78, 79, 0, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 196, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 39
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 41
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 42
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 152, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 116, 117,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 44
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 152, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 116, 117,
         // State 45
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 72, 0, 11, 12, 0, 0, 73, 13, 0, 74, 75, 76, 77, 78, 79, 14, 15, 80, 81, 82, 0, 0, 0, 83, 84, 85,
+        0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, -99, 152, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 116, 117,
         // State 46
-        0, -93, -93, 0, -93, -93, -93, -93, -93, 0, -93, 0, -93, -93, -93, 0, -93, -93, -93, -38, -93, 0, -93, 0, 0, -93, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0,
         // State 47
-        0, -18, -18, -39, -18, -18, -18, -18, -18, -39, -18, 0, -18, -18, -18, 0, -18, -18, -18, -18, -18, 0, -18, 0, 0, -18, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0,
+        8, 0, 0, 9, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 48
-        0, 0, 0, 0, -111, 0, 0, -111, 0, 0, -111, 0, 16, 0, 0, 0, 0, 0, 0, 0, -111, 0, -111, 0, 0, -111, -111, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 49
-        0, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, -23, -23, -23, -23, 0, -23, 0, 0, -23, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 51
-        0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 117,
         // State 52
-        0, 0, 0, 0, -69, 0, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, -69, 0, 0, -69, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 53, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 53
-        0, 0, -40, 0, -109, 0, 0, -109, 0, 0, -109, 0, -109, 0, 0, 0, 0, 0, 0, 0, -109, 0, -109, 0, 0, -109, -109, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 152, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 116, 117,
         // State 55
-        0, 0, 0, 0, -68, 0, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, -68, 0, 0, -68, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 0, 0, 0, 0, 104, 13, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 16, 17, 111, 0, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 57
-        0, 0, 0, 0, -66, 0, 0, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, 0, -66, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 58
-        0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, 25, -22, -22, -22, 0, -22, -22, -22, -22, -22, 0, -22, 0, 0, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 59
-        0, -42, -57, 0, -57, 0, 0, -57, 0, 0, -57, 0, -57, -42, -42, 0, -42, -42, -42, 0, -57, 0, -57, 0, 0, -57, -57, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 60
-        0, -112, -112, 0, -112, 0, -43, -112, -43, 0, -112, 0, -112, -112, -112, 0, -112, -112, -112, 0, -112, 0, -112, 0, 0, -112, -112, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 61
-        0, -106, -106, 0, -106, -44, -106, -106, -106, 0, -106, 0, -106, -106, -106, 0, -106, -106, -106, 0, -106, 0, -106, 0, 0, -106, -106, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0,
+        0, 0, 0, 53, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 62
-        0, 0, 0, 0, -67, 0, 0, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, -67, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, 0, 0, 0, -32, 0, 0, -32, 0, 0, -32, 0, -32, 0, 0, 0, 0, 0, 0, 0, -32, 0, -32, 0, 0, -32, -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 64
-        0, -87, -87, 0, -87, -87, -87, -87, -87, 0, -87, 0, -87, -87, -87, 0, -87, -87, -87, 0, -87, 0, -87, 0, 0, -87, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 65
-        0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, 0, -24, -24, -24, -24, -24, 0, -24, 0, 0, -24, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 66
-        0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 67
-        0, -85, -85, 0, -85, 0, -85, -85, -85, 0, -85, 0, -85, -85, -85, 0, -85, -85, -85, 0, -85, 0, -85, 0, 0, -85, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 68
-        0, 0, 0, 0, -91, 0, 0, -91, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, -91, 0, -91, 0, 0, -91, -91, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        0, 0, 0, 0, -70, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, -70, 0, 0, -70, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 70
-        0, -83, -83, 0, -83, 0, 0, -83, 0, 0, -83, 0, -83, -83, -83, 0, -83, -83, -83, 0, -83, 0, -83, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0,
+        0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117,
         // State 71
-        0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 72
-        0, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, -37, -37, 0, -37, -37, -37, -37, -37, 0, -37, 0, 0, -37, -37, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 73
-        0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 74
-        0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 103, 0, 0, 11, 12, 0, 0, 104, 13, 14, 0, 0, 105, 106, 107, 108, 109, 110, 15, 16, 17, 111, 18, 0, 112, 113, 114, 19, 0, 0, 0, 0, 115, 0, 116, 117,
         // State 75
-        0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -169, 0, 0, -169, 0, 0, 0, -169, 0, -169, 0, 0, 0, 0, 0, 0, 0, 0, -169, 0, 0, -169, -169, 0, 0, -169, -169, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0,
         // State 76
-        0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -144, -144, 0, -144, -144, -144, -144, -144, 0, 0, -144, 0, -144, -144, -144, 0, -144, 0, -144, -144, -67, -144, 0, 0, -144, -144, 0, 0, -144, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, -144, 0, 0, 0, 0, 0,
         // State 77
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80,
+        0, -42, -42, -68, -42, -42, -42, -42, -42, 0, -68, -42, 0, -42, -42, -42, 0, -42, 0, -42, -42, -42, -42, 0, 0, -42, -42, 0, 0, -42, -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0, 0,
         // State 78
-        0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -41, 0, 0, -41, 0, 0, 0, -41, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, -41, -41, 0, 0, -41, -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0,
         // State 79
-        0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, 0, -45, -45, -45, 0, -45, 0, -45, -45, -45, -45, 0, 0, -45, -45, 0, 0, -45, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, -45, 0, 0, 0, 0, 0,
         // State 80
-        0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        0, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, 0, -36, -36, -36, 0, -36, -36, -36, -36, -36, 0, -36, 0, 0, -36, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0,
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 82
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81,
+        0, 0, 0, 0, -111, 0, 0, -111, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, -111, -111, 0, 0, -111, -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0,
         // State 83
-        0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, 0, -94, -94, -94, -94, -94, 0, -94, 0, 0, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0,
+        0, 0, -70, 0, -171, 0, 0, -171, 0, 0, 0, -171, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, -171, 0, 0, -171, -171, 0, 0, -171, -171, 0, 0, 0, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -171, -70, 0, 0, 0, 0, 0,
         // State 84
-        0, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, 0, -77, 0, 0, -77, -77, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, -77, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -110, 0, 0, -110, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, -110, -110, 0, 0, -110, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0,
         // State 86
-        -89, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, -89, 0, 0, 0, 0, 0, -89, 0, 0, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, -89, 0, 0, 0, -89, -89, -89,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        -90, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, -90, 0, 0, 0, 0, 0, -90, 0, 0, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, -90, 0, 0, 0, -90, -90, -90,
+        0, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, -108, -108, 0, 0, -108, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0,
         // State 88
-        -56, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, -56, 0, 0, 0, 0, 0, -56, 0, 0, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, 0, 0, 0, -56, -56, -56,
+        0, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, 28, -44, -44, -44, 0, -44, 0, -44, -44, -44, -44, 0, 0, -44, -44, 0, 0, -44, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0, 0,
         // State 89
-        -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, -51, 0, 0, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, 0, 0, 0, -51, -51, -51,
+        0, -72, -87, 0, -87, 0, 0, -87, 0, 0, 0, -87, 0, -87, -72, -72, 0, -72, 0, -72, -72, 0, -87, 0, 0, -87, -87, 0, 0, -87, -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0, 0,
         // State 90
-        -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, -52, 0, 0, -52, -52, -52, -52, -52, -52, 0, -52, -52, -52, -52, 0, 0, 0, -52, -52, -52,
+        0, -175, -175, 0, -175, 0, -73, -175, -73, 0, 0, -175, 0, -175, -175, -175, 0, -175, 0, -175, -175, 0, -175, 0, 0, -175, -175, 0, 0, -175, -175, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, -175, 0, 0, 0, 0, 0,
         // State 91
-        -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, -55, 0, 0, 0, 0, 0, -55, 0, 0, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, 0, 0, 0, -55, -55, -55,
+        0, -165, -165, 0, -165, -74, -165, -165, -165, 0, 0, -165, 0, -165, -165, -165, 0, -165, 0, -165, -165, 0, -165, 0, 0, -165, -165, 0, 0, -165, -165, 0, 0, 0, 0, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, -165, 0, 0, 0, 0, 0,
         // State 92
-        -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, -53, 0, 0, -53, -53, -53, -53, -53, -53, 0, -53, -53, -53, -53, 0, 0, 0, -53, -53, -53,
+        0, 0, 0, 0, -109, 0, 0, -109, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, -109, -109, 0, 0, -109, -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0,
         // State 93
-        -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, -54, 0, 0, -54, -54, -54, -54, -54, -54, 0, -54, -54, -54, -54, 0, 0, 0, -54, -54, -54,
+        0, 0, 0, 0, -59, 0, 0, -59, 0, 0, 0, -59, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, -59, -59, 0, 0, -59, -59, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0,
         // State 94
-        -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, -96, 0, 0, 0, 0, 0, -96, 0, 0, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, -96, 0, 0, 0, -96, -96, -96,
+        0, -135, -135, 0, -135, -135, -135, -135, -135, 0, 0, -135, 0, -135, -135, -135, 0, -135, 0, -135, -135, 0, -135, 0, 0, -135, -135, 0, 0, -135, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, 0, 0, 0, 0, 0,
         // State 95
-        -97, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, -97, 0, 0, 0, 0, 0, -97, 0, 0, -97, -97, -97, -97, -97, -97, 0, -97, -97, -97, -97, 0, 0, 0, -97, -97, -97,
+        0, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, 0, -46, -46, -46, 0, -46, 0, -46, -46, -46, -46, 0, 0, -46, -46, 0, 0, -46, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, 0, 0, 0, 0, 0,
         // State 96
-        -113, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, -113, 0, 0, 0, 0, 0, -113, 0, 0, -113, -113, -113, -113, -113, -113, 0, -113, -113, -113, -113, 0, 0, 0, -113, -113, -113,
+        0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 97
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -133, -133, 0, -133, 0, -133, -133, -133, 0, 0, -133, 0, -133, -133, -133, 0, -133, 0, -133, -133, 0, -133, 0, 0, -133, -133, 0, 0, -133, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, 0, 0, 0, 0, 0,
         // State 98
-        0, 0, 0, 0, -95, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -173, 0, 0, -173, 0, 0, 0, -173, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, -173, -173, 0, 0, -173, -173, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0,
         // State 99
-        0, 0, 0, 0, -62, 0, 0, 132, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0,
+        0, 0, 0, 0, -139, 0, 0, -139, 0, 0, 0, -139, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, -139, -139, 0, 0, -139, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0,
         // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -112, 0, 0, -112, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, -112, -112, 0, 0, -112, -112, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0,
         // State 101
-        0, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, -39, -39, -39, 0, -39, -39, -39, -39, -39, 0, -39, 0, 0, -39, -39, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0,
+        0, -131, -131, 0, -131, 0, 0, -131, 0, 0, 0, -131, 0, -131, -131, -131, 0, -131, 0, -131, -131, 0, -131, 0, 0, -131, -131, 0, 0, -131, -131, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, 0, 0, 0, 0, 0,
         // State 102
-        0, -92, -92, 18, -92, -92, -92, -92, -92, 19, -92, 0, -92, -92, -92, 0, -92, -92, -92, 0, -92, 0, -92, 0, 0, -92, -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0,
+        0, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, -22, -22, -22, -22, 0, -22, 0, 0, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0,
+        0, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, 0, -66, -66, 0, 0, -66, -66, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, -66, 0, 0, 0, 0, 0,
         // State 104
-        0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 105
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 106
-        0, 0, 0, 0, -58, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 107
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0,
+        0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128,
         // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 110
-        0, 0, 0, 0, -41, 0, 0, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, -41, 0, 0, -41, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 111
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 112
-        0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, -65, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, 0, -65, -65, 0, 0, -65, -65, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, -65, 0, 0, 0, 0, 0,
         // State 113
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 114
-        0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129,
         // State 115
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -145, -145, -145, -145, -145, -145, -145, -145, 0, -145, -145, 0, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, 0, 0, -145, -145, 0, 0, -145, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, -145, 0, 0, 0, 0, 0,
         // State 116
-        0, 0, 0, 0, -110, 0, 0, -110, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, -110, 0, 0, -110, -110, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -120, -120, -120, -120, -120, -120, -120, -120, 0, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, 0, 0, -120, -120, 0, 0, -120, -120, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, -120, -120, -120, 0, 0, 0, 0,
         // State 117
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -137, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, 0, -137, 0, 0, 0, 0, 0, 0, -137, -137, 0, 0, 0, -137, -137, -137, -137, -137, -137, 0, -137, -137, -137, 0, 0, -137, -137, -137, -137, 0, 0, 0, 0, -137, 0, -137, -137,
         // State 119
-        0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, -138, -138, 0, 0, 0, -138, -138, -138, -138, -138, -138, 0, -138, -138, -138, 0, 0, -138, -138, -138, -138, 0, 0, 0, 0, -138, 0, -138, -138,
         // State 120
-        0, 0, 0, 0, -108, 0, 0, -108, 0, 0, -108, 0, -108, 0, 0, 0, 0, 0, 0, 0, -108, 0, -108, 0, 0, -108, -108, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, 0, 0, -86, -86, -86, -86, 0, 0, 0, 0, -86, 0, -86, -86,
         // State 121
-        0, 0, 0, 0, -47, 0, 0, -47, 0, 0, -47, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, -47, 0, 0, -47, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, -81, -81, 0, 0, 0, -81, -81, -81, -81, -81, -81, 0, -81, -81, -81, 0, 0, -81, -81, -81, -81, 0, 0, 0, 0, -81, 0, -81, -81,
         // State 122
-        0, -82, -82, 0, -82, 0, 0, -82, 0, 0, -82, 0, -82, -82, -82, 0, -82, -82, -82, 0, -82, 0, -82, 0, 0, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0,
+        -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, -82, -82, -82, -82, -82, -82, 0, -82, -82, -82, 0, 0, -82, -82, -82, -82, 0, 0, 0, 0, -82, 0, -82, -82,
         // State 123
-        0, -48, -48, 0, -48, 0, 0, -48, 0, 0, -48, 0, -48, -48, -48, 0, -48, -48, -48, 0, -48, 0, -48, 0, 0, -48, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0,
+        -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, -85, -85, -85, -85, -85, -85, 0, -85, -85, -85, 0, 0, -85, -85, -85, -85, 0, 0, 0, 0, -85, 0, -85, -85,
         // State 124
-        0, -84, -84, 0, -84, 0, -84, -84, -84, 0, -84, 0, -84, -84, -84, 0, -84, -84, -84, 0, -84, 0, -84, 0, 0, -84, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0,
+        -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, -83, -83, 0, 0, 0, -83, -83, -83, -83, -83, -83, 0, -83, -83, -83, 0, 0, -83, -83, -83, -83, 0, 0, 0, 0, -83, 0, -83, -83,
         // State 125
-        0, -46, -46, 0, -46, 0, -46, -46, -46, 0, -46, 0, -46, -46, -46, 0, -46, -46, -46, 0, -46, 0, -46, 0, 0, -46, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0,
+        -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, -84, -84, 0, 0, 0, -84, -84, -84, -84, -84, -84, 0, -84, -84, -84, 0, 0, -84, -84, -84, -84, 0, 0, 0, 0, -84, 0, -84, -84,
         // State 126
-        0, -86, -86, 0, -86, -86, -86, -86, -86, 0, -86, 0, -86, -86, -86, 0, -86, -86, -86, 0, -86, 0, -86, 0, 0, -86, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0,
+        -155, 0, 0, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0, 0, -155, 0, 0, 0, 0, 0, 0, -155, -155, 0, 0, 0, -155, -155, -155, -155, -155, -155, 0, -155, -155, -155, 0, 0, -155, -155, -155, -155, 0, 0, 0, 0, -155, 0, -155, -155,
         // State 127
-        0, -45, -45, 0, -45, -45, -45, -45, -45, 0, -45, 0, -45, -45, -45, 0, -45, -45, -45, 0, -45, 0, -45, 0, 0, -45, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0,
+        -156, 0, 0, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -156, 0, 0, -156, 0, 0, 0, 0, 0, 0, -156, -156, 0, 0, 0, -156, -156, -156, -156, -156, -156, 0, -156, -156, -156, 0, 0, -156, -156, -156, -156, 0, 0, 0, 0, -156, 0, -156, -156,
         // State 128
-        0, 0, 0, 0, -73, 0, 0, -73, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, -73, 0, 0, -73, -73, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -176, 0, 0, -176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176, 0, 0, -176, 0, 0, 0, 0, 0, 0, -176, -176, 0, 0, 0, -176, -176, -176, -176, -176, -176, 0, -176, -176, -176, 0, 0, -176, -176, -176, -176, 0, 0, 0, 0, -176, 0, -176, -176,
         // State 129
-        0, -21, -21, 18, -21, -21, -21, -21, -21, 19, -21, 0, -21, -21, -21, 0, -21, -21, -21, -21, -21, 0, -21, 0, 0, -21, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 130
-        0, 0, 0, 0, -64, 0, 0, 141, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 131
-        0, 0, 0, 0, -9, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, -9,
+        0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 132
-        0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 133
-        0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, -27, -27, -27, -27, 0, -27, 0, 0, -27, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0,
+        0, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, -68, 0, -68, 0, 0, -68, -68, 0, 0, -68, -68, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, -68, 0, 0, 0, 0, 0,
         // State 134
-        0, 0, 0, 0, -60, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -143, -143, 21, -143, -143, -143, -143, -143, 0, 22, -143, 0, -143, -143, -143, 0, -143, 0, -143, -143, 0, -143, 0, 0, -143, -143, 0, 0, -143, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, -143, 0, 0, 0, 0, 0,
         // State 135
-        0, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 0, -31, -31, -31, 0, -31, -31, -31, -31, -31, 0, -31, 0, 0, -31, -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0,
+        0, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, 0, -44, -44, -44, 0, -44, 0, -44, -44, -44, -44, 0, 0, -44, -44, 0, 0, -44, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0, 0,
         // State 136
-        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, -4, -4, 0, -4, -4, 0, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, 0, -4, -4, -4,
+        0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 137
-        0, 0, 0, 0, -74, 0, 0, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, -74, 0, 0, -74, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        0, -19, -19, 0, -19, -19, -19, -19, -19, 0, -19, 39, -19, -19, -19, 0, -19, -19, -19, -19, -19, 0, -19, 0, 0, -19, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0,
+        0, 0, 0, 0, -88, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 139
-        0, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, -28, -28, -28, -28, 0, -28, 0, 0, -28, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        0, 0, 0, 0, -10, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, -10,
+        0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 141
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 142
-        0, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, 0, -26, -26, -26, -26, -26, 0, -26, 0, 0, -26, -26, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 143
-        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, -5, -5, 0, -5, -5, 0, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, 0, -5, -5, -5,
+        0, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, -71, -71, 0, 0, -71, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0,
         // State 144
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 145
-        0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 146
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 147
-        0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -150, 0, 0, -150, 0, 0, 0, -150, 0, 0, 0, 0, -150, 0, -150, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 148
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 45, -148, 0, 0, -148, 0, 0, 0, -148, 0, 0, 0, 0, -148, 0, -148, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 149
-        0, 0, 0, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, -88, 0, 0, -88, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -149, 0, 0, -149, 0, 0, 0, -149, 0, 0, 0, 0, -149, 0, -149, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 150
-        0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 151
-        0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -147, 0, 0, -147, 0, 0, 0, -147, 0, 0, 0, 0, -147, 0, -147, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 152
-        0, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, 0, -30, -30, -30, 0, -30, -30, -30, -30, -30, 0, -30, 0, 0, -30, -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 153
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 154
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -69, 0, 0, -69, 0, 0, 0, -69, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, -69, -69, 0, 0, -69, -69, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0,
         // State 155
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 156
-        0, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, 0, -25, -25, -25, -25, -25, 0, -25, 0, 0, -25, -25, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0,
+        0, 0, 0, 0, -168, 0, 0, -168, 0, 0, 0, -168, 0, -168, 0, 0, 0, 0, 0, 0, 0, 0, -168, 0, 0, -168, -168, 0, 0, -168, -168, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, 0,
         // State 157
-        0, -20, -20, 18, -20, -20, -20, -20, -20, 19, -20, 0, -20, -20, -20, 0, -20, -20, -20, -20, -20, 0, -20, 0, 0, -20, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 158
-        0, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, 0, -29, -29, -29, 0, -29, -29, -29, -29, -29, 0, -29, 0, 0, -29, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 159
-        0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 160
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 161
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 162
-        0, 0, 0, 0, -76, 0, 0, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, -76, 0, 0, -76, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -170, 0, 0, -170, 0, 0, 0, -170, 0, -170, 0, 0, 0, 0, 0, 0, 0, 0, -170, 0, 0, -170, -170, 0, 0, -170, -170, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, 0,
         // State 163
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -77, 0, 0, -77, 0, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, -77, -77, 0, 0, -77, -77, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0,
         // State 164
-        0, 0, 0, 0, -49, 0, 0, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, -49, 0, 0, -49, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -130, -130, 0, -130, 0, 0, -130, 0, 0, 0, -130, 0, -130, -130, -130, 0, -130, 0, -130, -130, 0, -130, 0, 0, -130, -130, 0, 0, -130, -130, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, 0, 0, 0, 0, 0,
         // State 165
-        0, 0, 0, 0, -50, 0, 0, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, -50, 0, 0, -50, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -78, -78, 0, -78, 0, 0, -78, 0, 0, 0, -78, 0, -78, -78, -78, 0, -78, 0, -78, -78, 0, -78, 0, 0, -78, -78, 0, 0, -78, -78, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0, 0,
+        // State 166
+        0, -132, -132, 0, -132, 0, -132, -132, -132, 0, 0, -132, 0, -132, -132, -132, 0, -132, 0, -132, -132, 0, -132, 0, 0, -132, -132, 0, 0, -132, -132, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, 0, 0, 0, 0, 0,
+        // State 167
+        0, -76, -76, 0, -76, 0, -76, -76, -76, 0, 0, -76, 0, -76, -76, -76, 0, -76, 0, -76, -76, 0, -76, 0, 0, -76, -76, 0, 0, -76, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0, 0,
+        // State 168
+        0, -134, -134, 0, -134, -134, -134, -134, -134, 0, 0, -134, 0, -134, -134, -134, 0, -134, 0, -134, -134, 0, -134, 0, 0, -134, -134, 0, 0, -134, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, 0, 0, 0, 0, 0,
+        // State 169
+        0, -75, -75, 0, -75, -75, -75, -75, -75, 0, 0, -75, 0, -75, -75, -75, 0, -75, 0, -75, -75, 0, -75, 0, 0, -75, -75, 0, 0, -75, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0,
+        // State 170
+        0, 0, 0, 0, -115, 0, 0, -115, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, -115, -115, 0, 0, -115, -115, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, -40, 0, 0, -40, 0, 0, 0, -40, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, -40, -40, 0, 0, -40, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15,
+        // State 174
+        0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, -172, 0, 0, -172, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -172, 0, 0, -172, -172, 0, 0, -172, -172, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, 0, -49, -49, -49, 0, -49, 0, -49, -49, -49, -49, 0, 0, -49, -49, 0, 0, -49, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, -49, 0, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 0, -90, 0, 0, 190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        0, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, 0, -58, -58, -58, 0, -58, 0, -58, -58, -58, -58, 0, 0, -58, -58, 0, 0, -58, -58, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, -58, 0, 0, 0, 0, 0,
+        // State 179
+        -10, 0, 0, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, -10, 0, -10, 0, 0, -10, -10, 0, 0, -10, -10, -10, 0, 0, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, 0, -10, -10, -10, -10, 0, 0, 0, 0, -10, 0, -10, -10,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, -96, 0, 0, 206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, -116, 0, 0, -116, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, -116, -116, 0, 0, -116, -116, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, -43, -43, 0, -43, -43, -43, -43, -43, 0, 0, -43, 57, -43, -43, -43, 0, -43, 0, -43, -43, -43, -43, 0, 0, -43, -43, 0, 0, -43, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0, 0,
+        // State 185
+        0, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, 0, -50, -50, -50, 0, -50, 0, -50, -50, -50, -50, 0, 0, -50, -50, 0, 0, -50, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 188
+        0, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, 0, -48, -48, -48, 0, -48, 0, -48, -48, -48, -48, 0, 0, -48, -48, 0, 0, -48, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, -48, 0, 0, 0, 0, 0,
+        // State 189
+        -11, 0, 0, -11, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, -11, 0, -11, 0, 0, -11, -11, 0, 0, -11, -11, -11, 0, 0, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, 0, -11, -11, -11, -11, 0, 0, 0, 0, -11, 0, -11, -11,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, -182, 0, 0, -182, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, -104, 0, 0, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0,
+        // State 193
+        0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 195
+        0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, 0, 0, 0, -177, 0, 0, -177, 0, 0, 0, -177, 0, 0, 0, 0, -177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -177, 0, 0, 0, 0,
+        // State 198
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, -136, 0, 0, -136, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, -136, -136, 0, 0, -136, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0,
+        // State 202
+        0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, 0, 0, 0, -98, 0, 0, 221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, -151, 0, 0, -151, 0, 0, 0, -151, 0, 0, 0, 0, -151, 0, -151, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20, -20, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0,
+        // State 207
+        0, 0, 0, 0, 225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 208
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 209
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 210
+        0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 211
+        0, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, 0, -52, -52, -52, 0, -52, 0, -52, -52, -52, -52, 0, 0, -52, -52, 0, 0, -52, -52, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, 0, 0, 0, 0, 0,
+        // State 212
+        0, 0, 0, 0, -106, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0,
+        // State 213
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 214
+        0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30,
+        // State 215
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 217
+        0, 0, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        0, 0, 0, 0, -100, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 219
+        0, 0, 0, 0, -152, 0, 0, -152, 0, 0, 0, -152, 0, 0, 0, 0, -152, 0, -152, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 220
+        0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21, -21, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21,
+        // State 221
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0,
+        // State 222
+        0, -57, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, 0, -57, -57, -57, 0, -57, 0, -57, -57, -57, -57, 0, 0, -57, -57, 0, 0, -57, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, 0, 0, 0, 0, 0,
+        // State 223
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, 0, -47, -47, -47, 0, -47, 0, -47, -47, -47, -47, 0, 0, -47, -47, 0, 0, -47, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0, 0, 0, 0, 0,
+        // State 225
+        0, -43, -43, 0, -43, -43, -43, -43, -43, 0, 0, -43, 0, -43, -43, -43, 0, -43, 0, -43, -43, -43, -43, 0, 0, -43, -43, 0, 0, -43, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0, 0,
+        // State 226
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 227
+        0, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, 0, -53, -53, -53, 0, -53, 0, -53, -53, -53, -53, 0, 0, -53, -53, 0, 0, -53, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, 0, 0, -39, 0, 0, -39, 0, 0, 0, -39, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, -39, -39, 0, 0, -39, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0,
+        // State 229
+        0, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, 0, -51, -51, -51, 0, -51, 0, -51, -51, -51, -51, 0, 0, -51, -51, 0, 0, -51, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, 0, 0, 0, 0, 0,
+        // State 230
+        0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31,
+        // State 231
+        0, 0, 0, 0, -181, 0, 0, -181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -181, 0, 0, 0, 0,
+        // State 232
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 233
+        0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 234
+        0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 235
+        0, 0, 0, 0, -102, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 236
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 237
+        0, 0, 0, -25, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25,
+        // State 238
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 239
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 240
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 241
+        0, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, 0, -54, -54, -54, 0, -54, 0, -54, -54, -54, -54, 0, 0, -54, -54, 0, 0, -54, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0,
+        // State 242
+        0, 0, 0, 0, -119, 0, 0, -119, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, -119, -119, 0, 0, -119, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0,
+        // State 243
+        0, 0, 0, -26, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26,
+        // State 244
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 250, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 246
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 247
+        0, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 248
+        0, 0, 0, 0, -178, 0, 0, -178, 0, 0, 0, -178, 0, 0, 0, 0, -178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -178, 0, 0, 0, 0,
+        // State 249
+        0, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, 0, -56, -56, -56, 0, -56, 0, -56, -56, -56, -56, 0, 0, -56, -56, 0, 0, -56, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, -56, 0, 0, 0, 0, 0,
+        // State 250
+        0, 0, 0, 0, -79, 0, 0, -79, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, -79, -79, 0, 0, -79, -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0,
+        // State 251
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 252
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 253
+        0, 0, 0, 0, -80, 0, 0, -80, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, -80, -80, 0, 0, -80, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0,
+        // State 254
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 256, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 255
+        0, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, 0, -55, -55, -55, 0, -55, 0, -55, -55, -55, -55, 0, 0, -55, -55, 0, 0, -55, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 47 + integer]
+        __ACTION[(state as usize) * 60 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
@@ -480,121 +676,121 @@ mod __parse__Exp {
         // State 45
         0,
         // State 46
-        -93,
+        0,
         // State 47
-        -18,
+        0,
         // State 48
-        -111,
+        0,
         // State 49
-        -23,
+        0,
         // State 50
         0,
         // State 51
         0,
         // State 52
-        -69,
+        0,
         // State 53
-        -109,
+        0,
         // State 54
-        -114,
+        0,
         // State 55
-        -68,
+        0,
         // State 56
         0,
         // State 57
-        -66,
+        0,
         // State 58
-        -22,
+        0,
         // State 59
-        -57,
+        0,
         // State 60
-        -112,
+        0,
         // State 61
-        -106,
+        0,
         // State 62
-        -67,
+        0,
         // State 63
-        -32,
+        0,
         // State 64
-        -87,
+        0,
         // State 65
-        -24,
+        0,
         // State 66
         0,
         // State 67
-        -85,
+        0,
         // State 68
-        -91,
+        0,
         // State 69
-        -70,
+        0,
         // State 70
-        -83,
+        0,
         // State 71
         0,
         // State 72
-        -37,
+        0,
         // State 73
         0,
         // State 74
         0,
         // State 75
-        0,
+        -169,
         // State 76
-        0,
+        -144,
         // State 77
-        0,
+        -42,
         // State 78
-        0,
+        -41,
         // State 79
-        0,
+        -45,
         // State 80
         0,
         // State 81
-        -36,
-        // State 82
         0,
+        // State 82
+        -111,
         // State 83
-        -94,
+        -171,
         // State 84
-        -77,
+        -185,
         // State 85
-        0,
+        -110,
         // State 86
         0,
         // State 87
-        0,
+        -108,
         // State 88
-        0,
+        -44,
         // State 89
-        0,
+        -87,
         // State 90
-        0,
+        -175,
         // State 91
-        0,
+        -165,
         // State 92
-        0,
+        -109,
         // State 93
-        0,
+        -59,
         // State 94
-        0,
+        -135,
         // State 95
-        0,
+        -46,
         // State 96
         0,
         // State 97
-        0,
+        -133,
         // State 98
-        0,
+        -173,
         // State 99
-        0,
+        -139,
         // State 100
-        0,
+        -112,
         // State 101
-        -39,
+        -131,
         // State 102
-        -92,
+        0,
         // State 103
-        -22,
+        -66,
         // State 104
         0,
         // State 105
@@ -608,19 +804,19 @@ mod __parse__Exp {
         // State 109
         0,
         // State 110
-        -41,
+        0,
         // State 111
         0,
         // State 112
-        0,
+        -65,
         // State 113
         0,
         // State 114
         0,
         // State 115
-        0,
+        -145,
         // State 116
-        -110,
+        -120,
         // State 117
         0,
         // State 118
@@ -628,25 +824,25 @@ mod __parse__Exp {
         // State 119
         0,
         // State 120
-        -108,
+        0,
         // State 121
-        -47,
+        0,
         // State 122
-        -82,
+        0,
         // State 123
-        -48,
+        0,
         // State 124
-        -84,
+        0,
         // State 125
-        -46,
+        0,
         // State 126
-        -86,
+        0,
         // State 127
-        -45,
+        0,
         // State 128
-        -73,
+        0,
         // State 129
-        -21,
+        0,
         // State 130
         0,
         // State 131
@@ -654,27 +850,27 @@ mod __parse__Exp {
         // State 132
         0,
         // State 133
-        -27,
+        -68,
         // State 134
-        0,
+        -143,
         // State 135
-        -31,
+        -44,
         // State 136
         0,
         // State 137
-        -74,
+        0,
         // State 138
-        -19,
+        0,
         // State 139
-        -28,
+        0,
         // State 140
         0,
         // State 141
         0,
         // State 142
-        -26,
-        // State 143
         0,
+        // State 143
+        -71,
         // State 144
         0,
         // State 145
@@ -686,25 +882,25 @@ mod __parse__Exp {
         // State 148
         0,
         // State 149
-        -88,
+        0,
         // State 150
         0,
         // State 151
         0,
         // State 152
-        -30,
+        0,
         // State 153
         0,
         // State 154
-        0,
+        -69,
         // State 155
         0,
         // State 156
-        -25,
+        -168,
         // State 157
-        -20,
+        0,
         // State 158
-        -29,
+        0,
         // State 159
         0,
         // State 160
@@ -712,137 +908,381 @@ mod __parse__Exp {
         // State 161
         0,
         // State 162
-        -76,
+        -170,
         // State 163
-        0,
+        -77,
         // State 164
-        -49,
+        -130,
         // State 165
+        -78,
+        // State 166
+        -132,
+        // State 167
+        -76,
+        // State 168
+        -134,
+        // State 169
+        -75,
+        // State 170
+        -115,
+        // State 171
+        -40,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        -172,
+        // State 176
+        -49,
+        // State 177
+        0,
+        // State 178
+        -58,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        -116,
+        // State 184
+        -43,
+        // State 185
         -50,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        -48,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        -136,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        0,
+        // State 207
+        0,
+        // State 208
+        0,
+        // State 209
+        0,
+        // State 210
+        0,
+        // State 211
+        -52,
+        // State 212
+        0,
+        // State 213
+        0,
+        // State 214
+        0,
+        // State 215
+        0,
+        // State 216
+        0,
+        // State 217
+        0,
+        // State 218
+        0,
+        // State 219
+        0,
+        // State 220
+        0,
+        // State 221
+        0,
+        // State 222
+        -57,
+        // State 223
+        0,
+        // State 224
+        -47,
+        // State 225
+        -43,
+        // State 226
+        0,
+        // State 227
+        -53,
+        // State 228
+        -39,
+        // State 229
+        -51,
+        // State 230
+        0,
+        // State 231
+        0,
+        // State 232
+        0,
+        // State 233
+        0,
+        // State 234
+        0,
+        // State 235
+        0,
+        // State 236
+        0,
+        // State 237
+        0,
+        // State 238
+        0,
+        // State 239
+        0,
+        // State 240
+        0,
+        // State 241
+        -54,
+        // State 242
+        -119,
+        // State 243
+        0,
+        // State 244
+        0,
+        // State 245
+        0,
+        // State 246
+        0,
+        // State 247
+        0,
+        // State 248
+        0,
+        // State 249
+        -56,
+        // State 250
+        -79,
+        // State 251
+        0,
+        // State 252
+        0,
+        // State 253
+        -80,
+        // State 254
+        0,
+        // State 255
+        -55,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 27,
-            5 => 25,
-            8 => match state {
-                40 => 43,
+            6 => 31,
+            9 => 28,
+            12 => 45,
+            15 => 61,
+            18 => 51,
+            21 => match state {
+                62 => 68,
                 _ => 1,
             },
-            11 => 46,
-            12 => match state {
-                7 | 24 | 38 => 101,
-                _ => 47,
-            },
-            13 => 48,
-            14 => match state {
-                34 => 148,
-                _ => 111,
+            24 => 75,
+            25 => 76,
+            26 => match state {
+                7 => 133,
+                _ => 77,
             },
-            15 => 112,
-            16 => 49,
-            17 => 50,
-            18 => match state {
-                7 => 102,
-                24 => 129,
-                38 => 157,
-                _ => 51,
+            27 => match state {
+                17 | 27 | 56 => 154,
+                _ => 78,
             },
-            19 => 2,
-            20 => match state {
-                16 => 117,
-                23 => 128,
-                26 => 132,
-                28 => 137,
-                29 => 141,
-                32 => 146,
-                35 => 149,
-                42 => 162,
-                44 => 164,
-                45 => 165,
-                _ => 109,
+            28 => 144,
+            29 => match state {
+                42 => 200,
+                _ => 145,
             },
-            21 => 3,
-            22 => 4,
-            23 => 5,
-            24 => 126,
-            25 => 124,
-            26 => 120,
-            27 => 122,
-            28 => 52,
-            29 => 20,
-            30 => 53,
+            30 => 146,
             31 => match state {
-                17 => 118,
-                36 => 150,
-                37 => 151,
-                _ => 105,
+                14 | 32 | 42 | 44..=45 | 54 => 147,
+                _ => 79,
             },
             32 => match state {
-                30 => 144,
-                _ => 98,
+                17 | 22..=25 | 27 | 56 => 155,
+                _ => 80,
             },
             33 => match state {
-                0 => 54,
-                8 => 104,
-                9 | 17 | 36..=37 => 106,
-                15 => 116,
-                27 => 134,
-                33 => 147,
-                39 => 159,
-                41 => 161,
-                _ => 110,
+                7 => 134,
+                _ => 81,
             },
-            35 => 55,
-            36 => match state {
-                1 => 85,
-                40 => 160,
-                43 => 163,
-                _ => 56,
+            34 => match state {
+                27 => 171,
+                56 => 228,
+                _ => 156,
             },
-            37 => 57,
-            38 => match state {
-                6 | 30..=31 => 99,
-                7 | 24 | 38 => 103,
-                10 => 107,
-                11 => 108,
-                13 | 34 => 113,
-                14 => 114,
-                18 => 119,
-                25 => 130,
-                _ => 58,
+            35 => 2,
+            36 => match state {
+                15 => 152,
+                18 => 157,
+                19 => 159,
+                26 => 170,
+                29 => 174,
+                33 => 182,
+                34 => 183,
+                35 => 187,
+                38 => 194,
+                39 => 196,
+                43 => 201,
+                48 => 208,
+                49 => 209,
+                58 => 232,
+                59 => 233,
+                65 => 242,
+                67 => 245,
+                69 => 247,
+                71 => 250,
+                73 => 253,
+                74 => 254,
+                _ => 142,
             },
-            40 => 6,
-            41 => 59,
-            42 => 60,
-            43 => 61,
-            44 => 62,
-            45 => 19,
-            46 => 63,
+            37 => 3,
+            38 => 4,
+            39 => 5,
+            40 => 168,
+            41 => 166,
+            42 => 162,
+            43 => 164,
+            44 => 82,
+            45 => 23,
+            46 => 83,
             47 => match state {
-                22 => 127,
-                _ => 64,
+                20 => 160,
+                47 => 207,
+                50 => 210,
+                _ => 137,
             },
-            48 => 65,
+            48 => 130,
             49 => match state {
-                31 => 145,
-                _ => 100,
+                44 => 202,
+                _ => 180,
+            },
+            50 => 217,
+            51 => match state {
+                37 => 193,
+                _ => 190,
             },
-            50 => 21,
-            51 => 66,
             52 => match state {
-                21 => 125,
-                _ => 67,
+                0 => 84,
+                8 => 136,
+                9 | 20 | 47 | 50 => 138,
+                30 => 175,
+                31 => 177,
+                41 => 199,
+                60 => 234,
+                64 => 240,
+                66 => 244,
+                72 => 251,
+                _ => 143,
+            },
+            54 => 85,
+            55 => match state {
+                1 => 117,
+                62 => 238,
+                68 => 246,
+                _ => 86,
+            },
+            56 => 87,
+            57 => match state {
+                6 => 131,
+                7 | 17 | 22..=25 | 27 | 56 => 135,
+                10 => 139,
+                11 => 140,
+                12 => 141,
+                14 | 32 | 42 | 44..=45 | 54 => 148,
+                16 => 153,
+                21 => 161,
+                28 => 172,
+                36..=37 | 51 => 191,
+                40 | 52 | 57 | 61 | 63 | 70 => 197,
+                55 => 226,
+                _ => 88,
+            },
+            62 => 6,
+            63 => 89,
+            64 => 90,
+            65 => 91,
+            66 => 92,
+            67 => 22,
+            68 => 93,
+            69 => match state {
+                53 => 221,
+                _ => 206,
+            },
+            70 => 53,
+            71 => match state {
+                25 => 169,
+                _ => 94,
             },
-            54 => match state {
-                19 => 121,
-                _ => 68,
+            72 => match state {
+                14 | 32 | 42 | 44..=45 | 54 => 149,
+                _ => 95,
             },
-            55 => 69,
-            56 => match state {
-                20 => 123,
-                _ => 70,
+            73 => 132,
+            74 => match state {
+                32 | 44 => 181,
+                45 => 203,
+                54 => 223,
+                _ => 150,
+            },
+            76 => 24,
+            77 => 96,
+            78 => match state {
+                24 => 167,
+                _ => 97,
+            },
+            80 => 98,
+            81 => match state {
+                22 => 163,
+                _ => 99,
+            },
+            82 => 100,
+            84 => match state {
+                23 => 165,
+                _ => 101,
+            },
+            85 => 25,
+            86 => match state {
+                52 => 218,
+                57 => 231,
+                61 => 235,
+                63 => 239,
+                70 => 248,
+                _ => 198,
+            },
+            88 => match state {
+                51 => 212,
+                _ => 192,
             },
-            57 => 22,
             _ => 0,
         }
     }
@@ -857,6 +1297,7 @@ mod __parse__Exp {
             r###""+""###,
             r###"",""###,
             r###""-""###,
+            r###""->""###,
             r###"".""###,
             r###"":""###,
             r###"":=""###,
@@ -865,18 +1306,23 @@ mod __parse__Exp {
             r###""<=""###,
             r###""=""###,
             r###""==""###,
+            r###""=>""###,
             r###"">""###,
             r###"">=""###,
             r###""[""###,
             r###""]""###,
+            r###""_""###,
             r###""add1""###,
             r###""and""###,
+            r###""catch""###,
             r###""class""###,
             r###""def""###,
             r###""else""###,
             r###""end""###,
             r###""false""###,
+            r###""for""###,
             r###""if""###,
+            r###""import""###,
             r###""in""###,
             r###""isarray""###,
             r###""isbool""###,
@@ -885,14 +1331,21 @@ mod __parse__Exp {
             r###""lambda""###,
             r###""length""###,
             r###""let""###,
+            r###""match""###,
             r###""new""###,
             r###""print""###,
+            r###""raise""###,
+            r###""range""###,
             r###""sub1""###,
             r###""true""###,
+            r###""try""###,
+            r###""while""###,
             r###""{""###,
+            r###""|""###,
             r###""||""###,
             r###""}""###,
             r###""λ""###,
+            r###"r#"\"[^\"\\n]*\""#"###,
             r###"r#"[+-]?[0-9]+"#"###,
             r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
         ];
@@ -947,7 +1400,7 @@ mod __parse__Exp {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 47 - 1)
+            __action(state, 60 - 1)
         }
 
         #[inline]
@@ -1010,53 +1463,66 @@ mod __parse__Exp {
     ) -> Option<usize>
     {
         match *__token {
-            Token(2, _) if true => Some(0),
-            Token(3, _) if true => Some(1),
-            Token(4, _) if true => Some(2),
-            Token(5, _) if true => Some(3),
-            Token(6, _) if true => Some(4),
-            Token(7, _) if true => Some(5),
-            Token(8, _) if true => Some(6),
-            Token(9, _) if true => Some(7),
-            Token(10, _) if true => Some(8),
-            Token(11, _) if true => Some(9),
-            Token(12, _) if true => Some(10),
-            Token(13, _) if true => Some(11),
-            Token(14, _) if true => Some(12),
-            Token(15, _) if true => Some(13),
-            Token(16, _) if true => Some(14),
-            Token(17, _) if true => Some(15),
-            Token(18, _) if true => Some(16),
-            Token(19, _) if true => Some(17),
-            Token(20, _) if true => Some(18),
-            Token(21, _) if true => Some(19),
-            Token(22, _) if true => Some(20),
-            Token(23, _) if true => Some(21),
-            Token(24, _) if true => Some(22),
-            Token(25, _) if true => Some(23),
-            Token(26, _) if true => Some(24),
-            Token(27, _) if true => Some(25),
-            Token(28, _) if true => Some(26),
-            Token(29, _) if true => Some(27),
-            Token(30, _) if true => Some(28),
-            Token(31, _) if true => Some(29),
-            Token(32, _) if true => Some(30),
-            Token(33, _) if true => Some(31),
-            Token(34, _) if true => Some(32),
-            Token(35, _) if true => Some(33),
-            Token(36, _) if true => Some(34),
-            Token(37, _) if true => Some(35),
-            Token(38, _) if true => Some(36),
-            Token(39, _) if true => Some(37),
-            Token(40, _) if true => Some(38),
-            Token(41, _) if true => Some(39),
-            Token(42, _) if true => Some(40),
-            Token(43, _) if true => Some(41),
-            Token(44, _) if true => Some(42),
-            Token(45, _) if true => Some(43),
-            Token(46, _) if true => Some(44),
-            Token(0, _) if true => Some(45),
-            Token(1, _) if true => Some(46),
+            Token(3, _) if true => Some(0),
+            Token(4, _) if true => Some(1),
+            Token(5, _) if true => Some(2),
+            Token(6, _) if true => Some(3),
+            Token(7, _) if true => Some(4),
+            Token(8, _) if true => Some(5),
+            Token(9, _) if true => Some(6),
+            Token(10, _) if true => Some(7),
+            Token(11, _) if true => Some(8),
+            Token(12, _) if true => Some(9),
+            Token(13, _) if true => Some(10),
+            Token(14, _) if true => Some(11),
+            Token(15, _) if true => Some(12),
+            Token(16, _) if true => Some(13),
+            Token(17, _) if true => Some(14),
+            Token(18, _) if true => Some(15),
+            Token(19, _) if true => Some(16),
+            Token(20, _) if true => Some(17),
+            Token(21, _) if true => Some(18),
+            Token(22, _) if true => Some(19),
+            Token(23, _) if true => Some(20),
+            Token(24, _) if true => Some(21),
+            Token(25, _) if true => Some(22),
+            Token(26, _) if true => Some(23),
+            Token(27, _) if true => Some(24),
+            Token(28, _) if true => Some(25),
+            Token(29, _) if true => Some(26),
+            Token(30, _) if true => Some(27),
+            Token(31, _) if true => Some(28),
+            Token(32, _) if true => Some(29),
+            Token(33, _) if true => Some(30),
+            Token(34, _) if true => Some(31),
+            Token(35, _) if true => Some(32),
+            Token(36, _) if true => Some(33),
+            Token(37, _) if true => Some(34),
+            Token(38, _) if true => Some(35),
+            Token(39, _) if true => Some(36),
+            Token(40, _) if true => Some(37),
+            Token(41, _) if true => Some(38),
+            Token(42, _) if true => Some(39),
+            Token(43, _) if true => Some(40),
+            Token(44, _) if true => Some(41),
+            Token(45, _) if true => Some(42),
+            Token(46, _) if true => Some(43),
+            Token(47, _) if true => Some(44),
+            Token(48, _) if true => Some(45),
+            Token(49, _) if true => Some(46),
+            Token(50, _) if true => Some(47),
+            Token(51, _) if true => Some(48),
+            Token(52, _) if true => Some(49),
+            Token(53, _) if true => Some(50),
+            Token(54, _) if true => Some(51),
+            Token(55, _) if true => Some(52),
+            Token(56, _) if true => Some(53),
+            Token(57, _) if true => Some(54),
+            Token(58, _) if true => Some(55),
+            Token(59, _) if true => Some(56),
+            Token(0, _) if true => Some(57),
+            Token(1, _) if true => Some(58),
+            Token(2, _) if true => Some(59),
             _ => None,
         }
     }
@@ -1069,8 +1535,8 @@ mod __parse__Exp {
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(41, __tok0) | Token(42, __tok0) | Token(43, __tok0) | Token(44, __tok0) | Token(45, __tok0) | Token(46, __tok0) | Token(0, __tok0) | Token(1, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 => match __token {
+                Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(41, __tok0) | Token(42, __tok0) | Token(43, __tok0) | Token(44, __tok0) | Token(45, __tok0) | Token(46, __tok0) | Token(47, __tok0) | Token(48, __tok0) | Token(49, __tok0) | Token(50, __tok0) | Token(51, __tok0) | Token(52, __tok0) | Token(53, __tok0) | Token(54, __tok0) | Token(55, __tok0) | Token(56, __tok0) | Token(57, __tok0) | Token(58, __tok0) | Token(59, __tok0) | Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -1460,213 +1926,393 @@ mod __parse__Exp {
                 __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             113 => {
-                // __Exp = Exp => ActionFn(1);
-                let __sym0 = __pop_Variant1(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
-                return Some(Ok(__nt));
+                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             114 => {
                 __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        let __state = *__states.last().unwrap();
-        let __next_state = __goto(__state, __nonterminal);
-        __states.push(__next_state);
-        None
-    }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
-    }
-    fn __pop_Variant17<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant5<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant10<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant1<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Exp<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant19<
-      'input,
+            115 => {
+                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            116 => {
+                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            117 => {
+                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            118 => {
+                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            119 => {
+                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            120 => {
+                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            121 => {
+                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            122 => {
+                __reduce122(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            123 => {
+                __reduce123(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            124 => {
+                __reduce124(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            125 => {
+                __reduce125(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            126 => {
+                __reduce126(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            127 => {
+                __reduce127(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            128 => {
+                __reduce128(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            129 => {
+                __reduce129(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            130 => {
+                __reduce130(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            131 => {
+                __reduce131(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            132 => {
+                __reduce132(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            133 => {
+                __reduce133(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            134 => {
+                __reduce134(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            135 => {
+                __reduce135(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            136 => {
+                __reduce136(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            137 => {
+                __reduce137(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            138 => {
+                __reduce138(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            139 => {
+                __reduce139(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            140 => {
+                __reduce140(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            141 => {
+                __reduce141(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            142 => {
+                __reduce142(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            143 => {
+                __reduce143(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            144 => {
+                __reduce144(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            145 => {
+                __reduce145(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            146 => {
+                __reduce146(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            147 => {
+                __reduce147(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            148 => {
+                __reduce148(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            149 => {
+                __reduce149(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            150 => {
+                __reduce150(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            151 => {
+                __reduce151(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            152 => {
+                __reduce152(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            153 => {
+                __reduce153(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            154 => {
+                __reduce154(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            155 => {
+                __reduce155(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            156 => {
+                __reduce156(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            157 => {
+                __reduce157(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            158 => {
+                __reduce158(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            159 => {
+                __reduce159(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            160 => {
+                __reduce160(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            161 => {
+                __reduce161(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            162 => {
+                __reduce162(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            163 => {
+                __reduce163(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            164 => {
+                __reduce164(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            165 => {
+                __reduce165(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            166 => {
+                __reduce166(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            167 => {
+                __reduce167(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            168 => {
+                __reduce168(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            169 => {
+                __reduce169(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            170 => {
+                __reduce170(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            171 => {
+                __reduce171(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            172 => {
+                __reduce172(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            173 => {
+                __reduce173(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            174 => {
+                __reduce174(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            175 => {
+                __reduce175(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            176 => {
+                __reduce176(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            177 => {
+                __reduce177(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            178 => {
+                __reduce178(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            179 => {
+                __reduce179(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            180 => {
+                __reduce180(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            181 => {
+                __reduce181(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            182 => {
+                __reduce182(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            183 => {
+                __reduce183(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            184 => {
+                // __Exp = Exp => ActionFn(1);
+                let __sym0 = __pop_Variant3(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            185 => {
+                __reduce185(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant30<
+      'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim1, usize)
+    ) -> (usize, (), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim2, usize)
+    ) -> (usize, (Pattern<Span1>, Exp<Span1>), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant10<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
+    ) -> (usize, (String, Option<SnakeType>), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant28<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfFunDecl<Span1>, usize)
+    ) -> (usize, (String, Span1), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant12<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfProg<Span1>, usize)
+    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant35<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
+    ) -> (usize, (Vec<(String, Span1)>, SurfProg<Span1>), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Exp<Span1>>, usize)
+    ) -> (usize, Box<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant3<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<String>, usize)
+    ) -> (usize, Exp<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
+    ) -> (usize, Pattern<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant34<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
+    ) -> (usize, Prim1, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<String>, usize)
+    ) -> (usize, Prim2, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant1<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
+    ) -> (usize, SnakeType, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant5<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
+    ) -> (usize, String, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant26<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfFunDecl<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -1674,119 +2320,257 @@ mod __parse__Exp {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<String>, usize)
+    ) -> (usize, Vec<(Pattern<Span1>, Exp<Span1>)>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
+    ) -> (usize, Vec<(String, Option<SnakeType>)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant20<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
+    ) -> (usize, Vec<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant0<
+    fn __pop_Variant22<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
+    ) -> (usize, Vec<Pattern<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    pub(crate) fn __reduce0<
-        'input,
+    fn __pop_Variant23<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",") = Exp, "," => ActionFn(102);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action102::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<SnakeType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce1<
-        'input,
+    fn __pop_Variant21<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")* =  => ActionFn(100);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action100::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce2<
-        'input,
+    fn __pop_Variant31<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(101);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action101::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce3<
-        'input,
+    fn __pop_Variant11<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")+ = Exp, "," => ActionFn(107);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action107::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce4<
-        'input,
+    fn __pop_Variant29<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(String, Span1)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Pattern<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<SnakeType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant36<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<(String, Option<SnakeType>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant25<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant33<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Pattern<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<SnakeType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant27<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant32<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    pub(crate) fn __reduce0<
+        'input,
     >(
         input: &'input str,
         __lookahead_start: Option<&usize>,
@@ -1794,18 +2578,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(108);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
+        // ("->" <Type>) = "->", Type => ActionFn(105);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action108::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action105::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 0)
     }
-    pub(crate) fn __reduce5<
+    pub(crate) fn __reduce1<
         'input,
     >(
         input: &'input str,
@@ -1814,17 +2597,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",") = Id, "," => ActionFn(89);
+        // ("->" <Type>)? = "->", Type => ActionFn(166);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action89::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        let __nt = super::__action166::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 1)
     }
-    pub(crate) fn __reduce6<
+    pub(crate) fn __reduce2<
         'input,
     >(
         input: &'input str,
@@ -1833,14 +2616,14 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* =  => ActionFn(87);
+        // ("->" <Type>)? =  => ActionFn(104);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action87::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        let __nt = super::__action104::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 1)
     }
-    pub(crate) fn __reduce7<
+    pub(crate) fn __reduce3<
         'input,
     >(
         input: &'input str,
@@ -1849,15 +2632,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* = (<Id> ",")+ => ActionFn(88);
-        let __sym0 = __pop_Variant4(__symbols);
+        // (":" <Type>) = ":", Type => ActionFn(108);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action88::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action108::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 2)
     }
-    pub(crate) fn __reduce8<
+    pub(crate) fn __reduce4<
         'input,
     >(
         input: &'input str,
@@ -1866,17 +2651,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = Id, "," => ActionFn(111);
+        // (":" <Type>)? = ":", Type => ActionFn(169);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action111::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 5)
+        let __nt = super::__action169::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 3)
     }
-    pub(crate) fn __reduce9<
+    pub(crate) fn __reduce5<
         'input,
     >(
         input: &'input str,
@@ -1885,18 +2670,14 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(112);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action112::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
+        // (":" <Type>)? =  => ActionFn(107);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action107::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 3)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce6<
         'input,
     >(
         input: &'input str,
@@ -1905,17 +2686,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and") = FunDecl, "and" => ActionFn(81);
+        // (<Exp> ",") = Exp, "," => ActionFn(150);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action81::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
+        let __nt = super::__action150::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 4)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce7<
         'input,
     >(
         input: &'input str,
@@ -1924,14 +2705,14 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* =  => ActionFn(79);
+        // (<Exp> ",")* =  => ActionFn(148);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action79::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        let __nt = super::__action148::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 5)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce8<
         'input,
     >(
         input: &'input str,
@@ -1940,15 +2721,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(80);
-        let __sym0 = __pop_Variant6(__symbols);
+        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(149);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action80::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        let __nt = super::__action149::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 5)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce9<
         'input,
     >(
         input: &'input str,
@@ -1957,17 +2738,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(115);
+        // (<Exp> ",")+ = Exp, "," => ActionFn(172);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action115::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        let __nt = super::__action172::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 6)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce10<
         'input,
     >(
         input: &'input str,
@@ -1976,18 +2757,18 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(116);
+        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(173);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        let __nt = super::__action173::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 6)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce11<
         'input,
     >(
         input: &'input str,
@@ -1996,14 +2777,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(82);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action82::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 9)
+        // (<Id> ",") = Id, "," => ActionFn(132);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action132::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 7)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce12<
         'input,
     >(
         input: &'input str,
@@ -2012,14 +2796,14 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(77);
+        // (<Id> ",")* =  => ActionFn(130);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action77::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 10)
+        let __nt = super::__action130::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 8)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce13<
         'input,
     >(
         input: &'input str,
@@ -2028,15 +2812,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = BaseExp => ActionFn(34);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (<Id> ",")* = (<Id> ",")+ => ActionFn(131);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action131::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 8)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce14<
         'input,
     >(
         input: &'input str,
@@ -2045,17 +2829,87 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]" => ActionFn(146);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
+        // (<Id> ",")+ = Id, "," => ActionFn(176);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action146::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action176::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 9)
+    }
+    pub(crate) fn __reduce15<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(177);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action177::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 9)
+    }
+    pub(crate) fn __reduce16<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",") = Pattern, "," => ActionFn(155);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action155::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 10)
+    }
+    pub(crate) fn __reduce17<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",")* =  => ActionFn(153);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action153::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 11)
+    }
+    pub(crate) fn __reduce18<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",")* = (<Pattern> ",")+ => ActionFn(154);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action154::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce19<
         'input,
@@ -2066,19 +2920,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]", ":=", Boxed<BaseExp> => ActionFn(147);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant10(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
+        // (<Pattern> ",")+ = Pattern, "," => ActionFn(180);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action147::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (6, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action180::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 12)
     }
     pub(crate) fn __reduce20<
         'input,
@@ -2089,16 +2939,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = Id, ":=", Boxed<BaseExp> => ActionFn(148);
+        // (<Pattern> ",")+ = (<Pattern> ",")+, Pattern, "," => ActionFn(181);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action148::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 11)
+        let __nt = super::__action181::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 12)
     }
     pub(crate) fn __reduce21<
         'input,
@@ -2109,13 +2959,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id => ActionFn(149);
-        let __sym0 = __pop_Variant3(__symbols);
+        // (<Type> ",") = Type, "," => ActionFn(137);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action149::<>(input, __sym0);
+        let __end = __sym1.2.clone();
+        let __nt = super::__action137::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        (2, 13)
     }
     pub(crate) fn __reduce22<
         'input,
@@ -2126,13 +2978,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Bool => ActionFn(150);
-        let __sym0 = __pop_Variant9(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action150::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        // (<Type> ",")* =  => ActionFn(135);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action135::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 14)
     }
     pub(crate) fn __reduce23<
         'input,
@@ -2143,13 +2994,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Num => ActionFn(151);
-        let __sym0 = __pop_Variant18(__symbols);
+        // (<Type> ",")* = (<Type> ",")+ => ActionFn(136);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action151::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __nt = super::__action136::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 14)
     }
     pub(crate) fn __reduce24<
         'input,
@@ -2160,18 +3011,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "new", Id, "(", Comma<Exp>, ")" => ActionFn(152);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<Type> ",")+ = Type, "," => ActionFn(184);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action152::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action184::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 15)
     }
     pub(crate) fn __reduce25<
         'input,
@@ -2182,17 +3030,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Prim1, "(", Boxed<Exp>, ")" => ActionFn(153);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        // (<Type> ",")+ = (<Type> ",")+, Type, "," => ActionFn(185);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action153::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 12)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action185::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 15)
     }
     pub(crate) fn __reduce26<
         'input,
@@ -2203,16 +3050,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "(", Exp, ")" => ActionFn(43);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<TypedParam> ",") = TypedParam, "," => ActionFn(127);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action43::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action127::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 16)
     }
     pub(crate) fn __reduce27<
         'input,
@@ -2223,17 +3069,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Boxed<BaseExp>, "(", Comma<Exp>, ")" => ActionFn(154);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action154::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 12)
+        // (<TypedParam> ",")* =  => ActionFn(125);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action125::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 17)
     }
     pub(crate) fn __reduce28<
         'input,
@@ -2244,19 +3085,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Boxed<BaseExp>, ".", Id, "(", Comma<Exp>, ")" => ActionFn(155);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // (<TypedParam> ",")* = (<TypedParam> ",")+ => ActionFn(126);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action155::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (6, 12)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action126::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 17)
     }
     pub(crate) fn __reduce29<
         'input,
@@ -2267,18 +3102,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = LAMBDA, Params, ":", Boxed<Exp>, "end" => ActionFn(156);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
+        // (<TypedParam> ",")+ = TypedParam, "," => ActionFn(188);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action156::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action188::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 18)
     }
     pub(crate) fn __reduce30<
         'input,
@@ -2289,16 +3121,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "[", Comma<Exp>, "]" => ActionFn(157);
+        // (<TypedParam> ",")+ = (<TypedParam> ",")+, TypedParam, "," => ActionFn(189);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action157::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __nt = super::__action189::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 18)
     }
     pub(crate) fn __reduce31<
         'input,
@@ -2309,13 +3141,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExp => ActionFn(16);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (FunDecl "and") = FunDecl, "and" => ActionFn(114);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 13)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action114::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 19)
     }
     pub(crate) fn __reduce32<
         'input,
@@ -2326,13 +3160,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BindExp = Id => ActionFn(60);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action60::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        // (FunDecl "and")* =  => ActionFn(112);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action112::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 20)
     }
     pub(crate) fn __reduce33<
         'input,
@@ -2343,16 +3176,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = BindExp, "=", Exp => ActionFn(58);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(113);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action58::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 15)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce34<
         'input,
@@ -2363,18 +3193,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Bindings, ",", BindExp, "=", Exp => ActionFn(59);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
+        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(192);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action59::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (5, 15)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action192::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 21)
     }
     pub(crate) fn __reduce35<
         'input,
@@ -2385,13 +3212,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(63);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(193);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action63::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action193::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (3, 21)
     }
     pub(crate) fn __reduce36<
         'input,
@@ -2402,13 +3232,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(64);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        // @L =  => ActionFn(116);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action116::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 22)
     }
     pub(crate) fn __reduce37<
         'input,
@@ -2419,13 +3248,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<ArrExp> = ArrExp => ActionFn(66);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action66::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
+        // @R =  => ActionFn(115);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action115::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 23)
     }
     pub(crate) fn __reduce38<
         'input,
@@ -2436,13 +3264,19 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<BaseExp> = BaseExp => ActionFn(67);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ASSIGN = Boxed<ArrExp>, "[", Boxed<Exp>, "]", ":=", Boxed<BinOps> => ActionFn(238);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant18(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 18)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action238::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (6, 24)
     }
     pub(crate) fn __reduce39<
         'input,
@@ -2453,13 +3287,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<CmpExp> = CmpExp => ActionFn(91);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ASSIGN = Id, ":=", Boxed<BinOps> => ActionFn(239);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action91::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 19)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action239::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 24)
     }
     pub(crate) fn __reduce40<
         'input,
@@ -2470,13 +3307,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Exp> = Exp => ActionFn(78);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ASSIGN = BinOps => ActionFn(24);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action78::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 20)
+        let __nt = super::__action24::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 24)
     }
     pub(crate) fn __reduce41<
         'input,
@@ -2487,13 +3324,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<Cmp, Sum>> = LAssoc<Cmp, Sum> => ActionFn(93);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ArrExp = BaseExp => ActionFn(43);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action93::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 21)
+        let __nt = super::__action43::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 25)
     }
     pub(crate) fn __reduce42<
         'input,
@@ -2504,13 +3341,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<PlusMinus, Product>> = LAssoc<PlusMinus, Product> => ActionFn(95);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]" => ActionFn(240);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action95::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 22)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action240::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 25)
     }
     pub(crate) fn __reduce43<
         'input,
@@ -2521,13 +3362,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<Times, NotExp>> = LAssoc<Times, NotExp> => ActionFn(97);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Id => ActionFn(241);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action97::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 23)
+        let __nt = super::__action241::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce44<
         'input,
@@ -2538,13 +3379,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<NotExp> = NotExp => ActionFn(96);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Bool => ActionFn(242);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action96::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 24)
+        let __nt = super::__action242::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce45<
         'input,
@@ -2555,13 +3396,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Product> = Product => ActionFn(94);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Num => ActionFn(243);
+        let __sym0 = __pop_Variant32(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action94::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 25)
+        let __nt = super::__action243::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce46<
         'input,
@@ -2572,13 +3413,18 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<RAssoc<Log, CmpExp>> = RAssoc<Log, CmpExp> => ActionFn(90);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "new", Id, "(", Comma<Exp>, ")" => ActionFn(244);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant20(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action90::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 26)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action244::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
     }
     pub(crate) fn __reduce47<
         'input,
@@ -2589,13 +3435,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Sum> = Sum => ActionFn(92);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Prim1, "(", Boxed<Exp>, ")" => ActionFn(245);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant34(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action92::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 27)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action245::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 26)
     }
     pub(crate) fn __reduce48<
         'input,
@@ -2606,22 +3456,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CLASSDEF = "class", Id, "{", Comma<Id>, "}", ":", FunDecl, "in", Boxed<Exp> => ActionFn(158);
-        assert!(__symbols.len() >= 9);
-        let __sym8 = __pop_Variant10(__symbols);
-        let __sym7 = __pop_Variant0(__symbols);
-        let __sym6 = __pop_Variant15(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
+        // BaseExp = "(", Exp, ")" => ActionFn(50);
+        assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym8.2.clone();
-        let __nt = super::__action158::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (9, 28)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action50::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 26)
     }
     pub(crate) fn __reduce49<
         'input,
@@ -2632,23 +3476,17 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CLASSDEF = "class", Id, "{", Comma<Id>, "}", ":", (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(159);
-        assert!(__symbols.len() >= 10);
-        let __sym9 = __pop_Variant10(__symbols);
-        let __sym8 = __pop_Variant0(__symbols);
-        let __sym7 = __pop_Variant15(__symbols);
-        let __sym6 = __pop_Variant6(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = Boxed<BaseExp>, "(", Comma<Exp>, ")" => ActionFn(246);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym9.2.clone();
-        let __nt = super::__action159::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (10, 28)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action246::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 26)
     }
     pub(crate) fn __reduce50<
         'input,
@@ -2659,13 +3497,19 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = Boxed<BaseExp>, ".", Id, "(", Comma<Exp>, ")" => ActionFn(247);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action247::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (6, 26)
     }
     pub(crate) fn __reduce51<
         'input,
@@ -2676,13 +3520,18 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = LAMBDA, Params, ":", Boxed<Exp>, "end" => ActionFn(248);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action248::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
     }
     pub(crate) fn __reduce52<
         'input,
@@ -2693,13 +3542,18 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(28);
+        // BaseExp = "while", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(249);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action249::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
     }
     pub(crate) fn __reduce53<
         'input,
@@ -2710,13 +3564,20 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(29);
+        // BaseExp = "for", Id, "in", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(250);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant18(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action250::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (7, 26)
     }
     pub(crate) fn __reduce54<
         'input,
@@ -2727,13 +3588,25 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(30);
+        // BaseExp = "for", Id, "in", "range", "(", Boxed<Exp>, ",", Boxed<Exp>, ")", ":", Boxed<Exp>, "end" => ActionFn(251);
+        assert!(__symbols.len() >= 12);
+        let __sym11 = __pop_Variant0(__symbols);
+        let __sym10 = __pop_Variant18(__symbols);
+        let __sym9 = __pop_Variant0(__symbols);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant18(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant18(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym11.2.clone();
+        let __nt = super::__action251::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9, __sym10, __sym11);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (12, 26)
     }
     pub(crate) fn __reduce55<
         'input,
@@ -2744,13 +3617,21 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(31);
+        // BaseExp = "try", ":", Boxed<Exp>, "catch", Id, ":", Boxed<Exp>, "end" => ActionFn(252);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant18(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action31::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action252::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (8, 26)
     }
     pub(crate) fn __reduce56<
         'input,
@@ -2761,13 +3642,18 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(18);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "match", Boxed<Exp>, ":", MatchArm+, "end" => ActionFn(253);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant31(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 30)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action253::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
     }
     pub(crate) fn __reduce57<
         'input,
@@ -2778,13 +3664,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = Exp => ActionFn(171);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "[", Comma<Exp>, "]" => ActionFn(254);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action171::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 31)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action254::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 26)
     }
     pub(crate) fn __reduce58<
         'input,
@@ -2795,12 +3684,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> =  => ActionFn(172);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action172::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 31)
+        // BinOps = LogExp => ActionFn(25);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 27)
     }
     pub(crate) fn __reduce59<
         'input,
@@ -2811,15 +3701,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(173);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BindExp = Pattern => ActionFn(81);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action173::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 31)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 28)
     }
     pub(crate) fn __reduce60<
         'input,
@@ -2830,13 +3718,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+ => ActionFn(174);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Binding = BindExp, "=", Exp => ActionFn(79);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action174::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 31)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action79::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 29)
     }
     pub(crate) fn __reduce61<
         'input,
@@ -2847,13 +3738,18 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = Id => ActionFn(175);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Binding = BindExp, ":", Type, "=", Exp => ActionFn(255);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant3(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action175::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 32)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action255::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (5, 29)
     }
     pub(crate) fn __reduce62<
         'input,
@@ -2864,12 +3760,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> =  => ActionFn(176);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action176::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 32)
+        // Bindings = Binding => ActionFn(77);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 30)
     }
     pub(crate) fn __reduce63<
         'input,
@@ -2880,15 +3777,16 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+, Id => ActionFn(177);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Bindings = Bindings, ",", Binding => ActionFn(78);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action177::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 32)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action78::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 30)
     }
     pub(crate) fn __reduce64<
         'input,
@@ -2899,13 +3797,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+ => ActionFn(178);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Bool = "true" => ActionFn(85);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action178::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 32)
+        let __nt = super::__action85::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 31)
     }
     pub(crate) fn __reduce65<
         'input,
@@ -2916,13 +3814,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = IF => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Bool = "false" => ActionFn(86);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action86::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 31)
     }
     pub(crate) fn __reduce66<
         'input,
@@ -2933,13 +3831,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = LET => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<ArrExp> = ArrExp => ActionFn(100);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action100::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 32)
     }
     pub(crate) fn __reduce67<
         'input,
@@ -2950,12 +3848,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = FUNDEFS => ActionFn(9);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<BaseExp> = BaseExp => ActionFn(91);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action91::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
         (1, 33)
     }
     pub(crate) fn __reduce68<
@@ -2967,13 +3865,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = CLASSDEF => ActionFn(10);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<BinOps> = BinOps => ActionFn(101);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action101::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 34)
     }
     pub(crate) fn __reduce69<
         'input,
@@ -2984,13 +3882,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = SEQ => ActionFn(11);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<CmpExp> = CmpExp => ActionFn(139);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action139::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     pub(crate) fn __reduce70<
         'input,
@@ -3001,13 +3899,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp? = Exp => ActionFn(98);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<Exp> = Exp => ActionFn(111);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action98::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 34)
+        let __nt = super::__action111::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce71<
         'input,
@@ -3018,13 +3916,14 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp? =  => ActionFn(99);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action99::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 34)
-    }
+        // Boxed<LAssoc<Cmp, Sum>> = LAssoc<Cmp, Sum> => ActionFn(141);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action141::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 37)
+    }
     pub(crate) fn __reduce72<
         'input,
     >(
@@ -3034,16 +3933,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(160);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // Boxed<LAssoc<PlusMinus, Product>> = LAssoc<PlusMinus, Product> => ActionFn(143);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action160::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 35)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action143::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 38)
     }
     pub(crate) fn __reduce73<
         'input,
@@ -3054,17 +3950,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(161);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Boxed<LAssoc<Times, NotExp>> = LAssoc<Times, NotExp> => ActionFn(145);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action161::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 35)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action145::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 39)
     }
     pub(crate) fn __reduce74<
         'input,
@@ -3075,20 +3967,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Params, ")", ":", Exp => ActionFn(162);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<NotExp> = NotExp => ActionFn(144);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action162::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (7, 36)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action144::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 40)
     }
     pub(crate) fn __reduce75<
         'input,
@@ -3099,20 +3984,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // IF = "if", Boxed<Exp>, ":", Boxed<Exp>, "else", ":", Boxed<Exp> => ActionFn(163);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant10(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<Product> = Product => ActionFn(142);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action163::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 37)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action142::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 41)
     }
     pub(crate) fn __reduce76<
         'input,
@@ -3123,13 +4001,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(62);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<RAssoc<Log, CmpExp>> = RAssoc<Log, CmpExp> => ActionFn(138);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action62::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 38)
+        let __nt = super::__action138::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce77<
         'input,
@@ -3140,13 +4018,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id? = Id => ActionFn(85);
+        // Boxed<Sum> = Sum => ActionFn(140);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action85::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 39)
+        let __nt = super::__action140::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 43)
     }
     pub(crate) fn __reduce78<
         'input,
@@ -3157,12 +4035,22 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id? =  => ActionFn(86);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action86::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 39)
+        // CLASSDEF = "class", Id, "{", Comma<TypedParam>, "}", ":", FunDecl, "in", Boxed<Exp> => ActionFn(256);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant18(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant26(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym8.2.clone();
+        let __nt = super::__action256::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (9, 44)
     }
     pub(crate) fn __reduce79<
         'input,
@@ -3173,13 +4061,23 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAMBDA = "lambda" => ActionFn(48);
+        // CLASSDEF = "class", Id, "{", Comma<TypedParam>, "}", ":", (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(257);
+        assert!(__symbols.len() >= 10);
+        let __sym9 = __pop_Variant18(__symbols);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant26(__symbols);
+        let __sym6 = __pop_Variant13(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 40)
+        let __end = __sym9.2.clone();
+        let __nt = super::__action257::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (10, 44)
     }
     pub(crate) fn __reduce80<
         'input,
@@ -3190,13 +4088,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAMBDA = "λ" => ActionFn(49);
+        // Cmp = "<" => ActionFn(35);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action49::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 40)
+        let __nt = super::__action35::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce81<
         'input,
@@ -3207,16 +4105,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Boxed<LAssoc<Cmp, Sum>>, Cmp, Boxed<Sum> => ActionFn(164);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Cmp = "<=" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action164::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 41)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce82<
         'input,
@@ -3227,13 +4122,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Sum => ActionFn(73);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = ">" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action73::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 41)
+        let __nt = super::__action37::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce83<
         'input,
@@ -3244,16 +4139,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Boxed<LAssoc<PlusMinus, Product>>, PlusMinus, Boxed<Product> => ActionFn(165);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Cmp = ">=" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action165::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 42)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce84<
         'input,
@@ -3264,13 +4156,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Product => ActionFn(71);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = "==" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action71::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 42)
+        let __nt = super::__action39::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce85<
         'input,
@@ -3281,16 +4173,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = Boxed<LAssoc<Times, NotExp>>, Times, Boxed<NotExp> => ActionFn(166);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Cmp = "!=" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action166::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 43)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce86<
         'input,
@@ -3301,13 +4190,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = NotExp => ActionFn(69);
-        let __sym0 = __pop_Variant1(__symbols);
+        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(27);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 43)
+        let __nt = super::__action27::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 46)
     }
     pub(crate) fn __reduce87<
         'input,
@@ -3318,17 +4207,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LET = "let", Bindings, "in", Boxed<Exp> => ActionFn(167);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Exp> = Exp => ActionFn(278);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action167::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 44)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action278::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 47)
     }
     pub(crate) fn __reduce88<
         'input,
@@ -3339,13 +4224,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 45)
+        // Comma<Exp> =  => ActionFn(279);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action279::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 47)
     }
     pub(crate) fn __reduce89<
         'input,
@@ -3356,13 +4240,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(280);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 45)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action280::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 47)
     }
     pub(crate) fn __reduce90<
         'input,
@@ -3373,13 +4259,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LogExp = RAssoc<Log, CmpExp> => ActionFn(17);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<Exp> = (<Exp> ",")+ => ActionFn(281);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 46)
+        let __nt = super::__action281::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 47)
     }
     pub(crate) fn __reduce91<
         'input,
@@ -3390,15 +4276,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExp = "!", Boxed<BaseExp> => ActionFn(168);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Id> = Id => ActionFn(282);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action168::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 47)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action282::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 48)
     }
     pub(crate) fn __reduce92<
         'input,
@@ -3409,13 +4293,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExp = ArrExp => ActionFn(33);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 47)
+        // Comma<Id> =  => ActionFn(283);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action283::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (0, 48)
     }
     pub(crate) fn __reduce93<
         'input,
@@ -3426,13 +4309,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(61);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Id> = (<Id> ",")+, Id => ActionFn(284);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action61::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 48)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action284::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 48)
     }
     pub(crate) fn __reduce94<
         'input,
@@ -3443,13 +4328,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Params = Comma<Id> => ActionFn(5);
-        let __sym0 = __pop_Variant13(__symbols);
+        // Comma<Id> = (<Id> ",")+ => ActionFn(285);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 49)
+        let __nt = super::__action285::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 48)
     }
     pub(crate) fn __reduce95<
         'input,
@@ -3460,13 +4345,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(21);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Pattern> = Pattern => ActionFn(288);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 50)
+        let __nt = super::__action288::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 49)
     }
     pub(crate) fn __reduce96<
         'input,
@@ -3477,13 +4362,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 50)
+        // Comma<Pattern> =  => ActionFn(289);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action289::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 49)
     }
     pub(crate) fn __reduce97<
         'input,
@@ -3494,13 +4378,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(50);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Pattern> = (<Pattern> ",")+, Pattern => ActionFn(290);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action290::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 49)
     }
     pub(crate) fn __reduce98<
         'input,
@@ -3511,13 +4397,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(51);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Pattern> = (<Pattern> ",")+ => ActionFn(291);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action51::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __nt = super::__action291::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 49)
     }
     pub(crate) fn __reduce99<
         'input,
@@ -3528,13 +4414,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "print" => ActionFn(52);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Type> = Type => ActionFn(292);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __nt = super::__action292::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 50)
     }
     pub(crate) fn __reduce100<
         'input,
@@ -3545,13 +4431,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isnum" => ActionFn(53);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action53::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        // Comma<Type> =  => ActionFn(293);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action293::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (0, 50)
     }
     pub(crate) fn __reduce101<
         'input,
@@ -3562,13 +4447,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isbool" => ActionFn(54);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Type> = (<Type> ",")+, Type => ActionFn(294);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action294::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 50)
     }
     pub(crate) fn __reduce102<
         'input,
@@ -3579,13 +4466,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isarray" => ActionFn(55);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Type> = (<Type> ",")+ => ActionFn(295);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action55::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __nt = super::__action295::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 50)
     }
     pub(crate) fn __reduce103<
         'input,
@@ -3596,12 +4483,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "length" => ActionFn(56);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<TypedParam> = TypedParam => ActionFn(296);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action56::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        let __nt = super::__action296::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
         (1, 51)
     }
     pub(crate) fn __reduce104<
@@ -3613,13 +4500,12 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isfun" => ActionFn(57);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        // Comma<TypedParam> =  => ActionFn(297);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action297::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (0, 51)
     }
     pub(crate) fn __reduce105<
         'input,
@@ -3630,13 +4516,15 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Product = LAssoc<Times, NotExp> => ActionFn(20);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 52)
+        // Comma<TypedParam> = (<TypedParam> ",")+, TypedParam => ActionFn(298);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action298::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (2, 51)
     }
     pub(crate) fn __reduce106<
         'input,
@@ -3647,13 +4535,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prog = Exp => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<TypedParam> = (<TypedParam> ",")+ => ActionFn(299);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 53)
+        let __nt = super::__action299::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 51)
     }
     pub(crate) fn __reduce107<
         'input,
@@ -3664,16 +4552,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = Boxed<CmpExp>, Log, Boxed<RAssoc<Log, CmpExp>> => ActionFn(169);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Exp = IF => ActionFn(11);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action169::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 54)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce108<
         'input,
@@ -3684,13 +4569,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(75);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = LET => ActionFn(12);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action75::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 54)
+        let __nt = super::__action12::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce109<
         'input,
@@ -3701,16 +4586,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // SEQ = BinOps, ";", Exp => ActionFn(170);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = FUNDEFS => ActionFn(13);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 55)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce110<
         'input,
@@ -3721,13 +4603,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // SEQ = BinOps => ActionFn(15);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = CLASSDEF => ActionFn(14);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 55)
+        let __nt = super::__action14::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce111<
         'input,
@@ -3738,13 +4620,13 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Sum = LAssoc<PlusMinus, Product> => ActionFn(19);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = SEQ => ActionFn(15);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 56)
+        let __nt = super::__action15::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce112<
         'input,
@@ -3755,15 +4637,163 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(23);
+        // Exp? = Exp => ActionFn(146);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action146::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 53)
+    }
+    pub(crate) fn __reduce113<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Exp? =  => ActionFn(147);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action147::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 53)
+    }
+    pub(crate) fn __reduce114<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(258);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action258::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 54)
+    }
+    pub(crate) fn __reduce115<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(259);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action259::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 54)
+    }
+    pub(crate) fn __reduce116<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // FunDecl = "def", Id, "(", Comma<TypedParam>, ")", "->", Type, ":", Exp => ActionFn(260);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant3(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant1(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym8.2.clone();
+        let __nt = super::__action260::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (9, 55)
+    }
+    pub(crate) fn __reduce117<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // FunDecl = "def", Id, "(", Comma<TypedParam>, ")", ":", Exp => ActionFn(261);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant3(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action261::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (7, 55)
+    }
+    pub(crate) fn __reduce118<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // IF = "if", Boxed<Exp>, ":", Boxed<Exp>, "else", ":", Boxed<Exp> => ActionFn(262);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant18(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action262::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (7, 56)
+    }
+    pub(crate) fn __reduce119<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(84);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        let __nt = super::__action84::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (1, 57)
     }
-    pub(crate) fn __reduce114<
+    pub(crate) fn __reduce120<
         'input,
     >(
         input: &'input str,
@@ -3772,1158 +4802,2792 @@ mod __parse__Exp {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // __Prog = Prog => ActionFn(0);
-        let __sym0 = __pop_Variant20(__symbols);
+        // Id? = Id => ActionFn(128);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action0::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 59)
+        let __nt = super::__action128::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 58)
     }
-}
-pub use self::__parse__Exp::ExpParser;
-
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __parse__Prog {
-    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
-
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim1, Prim2};
-    use crate::span::Span1;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
-    #[allow(dead_code)]
-    pub(crate) enum __Symbol<'input>
-     {
-        Variant0(&'input str),
-        Variant1(Exp<Span1>),
-        Variant2(alloc::vec::Vec<Exp<Span1>>),
-        Variant3(String),
-        Variant4(alloc::vec::Vec<String>),
-        Variant5((SurfFunDecl<Span1>, &'input str)),
-        Variant6(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
-        Variant7(usize),
-        Variant8(Vec<(String, Exp<Span1>)>),
-        Variant9(bool),
-        Variant10(Box<Exp<Span1>>),
-        Variant11(Prim2),
-        Variant12(Vec<Exp<Span1>>),
-        Variant13(Vec<String>),
-        Variant14(core::option::Option<Exp<Span1>>),
-        Variant15(SurfFunDecl<Span1>),
-        Variant16(core::option::Option<String>),
-        Variant17(()),
-        Variant18(i64),
-        Variant19(Prim1),
-        Variant20(SurfProg<Span1>),
+    pub(crate) fn __reduce121<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Id? =  => ActionFn(129);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action129::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (0, 58)
     }
-    const __ACTION: &[i16] = &[
-        // State 0
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0,
-        // State 3
-        0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 0, 93, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 4
-        0, 0, 0, 0, 0, 0, 96, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 5
-        0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 7
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 0, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 8
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 9
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -59, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 12
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 15
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 16
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 17
-        8, 0, 0, 9, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 19
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 0, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 20
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 0, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 21
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 0, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 22
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 0, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 23
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 24
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 0, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 25
-        0, 0, 0, 0, -65, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 86,
-        // State 26
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 27
-        8, 0, 0, 9, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -61, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 28
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 29
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 86,
-        // State 31
-        0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 32
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 33
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86,
-        // State 35
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 36
-        8, 0, 0, 9, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 37
-        8, 0, 0, 9, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 38
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 0, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 39
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 41
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 42
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 44
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 45
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 73, 0, 11, 12, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 15, 81, 82, 83, 0, 0, 0, 84, 85, 86,
-        // State 46
-        0, -93, -93, 0, -93, -93, -93, -93, -93, 0, -93, 0, -93, -93, -93, 0, -93, -93, -93, -38, -93, 0, -93, 0, 0, -93, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0,
-        // State 47
-        0, -18, -18, -39, -18, -18, -18, -18, -18, -39, -18, 0, -18, -18, -18, 0, -18, -18, -18, -18, -18, 0, -18, 0, 0, -18, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0,
-        // State 48
-        0, 0, 0, 0, -111, 0, 0, -111, 0, 0, -111, 0, 16, 0, 0, 0, 0, 0, 0, 0, -111, 0, -111, 0, 0, -111, -111, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 49
-        0, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, -23, -23, -23, -23, 0, -23, 0, 0, -23, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0,
-        // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 51
-        0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 52
-        0, 0, 0, 0, -69, 0, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, -69, 0, 0, -69, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 53
-        0, 0, -40, 0, -109, 0, 0, -109, 0, 0, -109, 0, -109, 0, 0, 0, 0, 0, 0, 0, -109, 0, -109, 0, 0, -109, -109, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0,
-        // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 55
-        0, 0, 0, 0, -68, 0, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, -68, 0, 0, -68, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 57
-        0, 0, 0, 0, -66, 0, 0, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, 0, -66, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 58
-        0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, 25, -22, -22, -22, 0, -22, -22, -22, -22, -22, 0, -22, 0, 0, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0,
-        // State 59
-        0, -42, -57, 0, -57, 0, 0, -57, 0, 0, -57, 0, -57, -42, -42, 0, -42, -42, -42, 0, -57, 0, -57, 0, 0, -57, -57, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0,
-        // State 60
-        0, -112, -112, 0, -112, 0, -43, -112, -43, 0, -112, 0, -112, -112, -112, 0, -112, -112, -112, 0, -112, 0, -112, 0, 0, -112, -112, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0,
-        // State 61
-        0, -106, -106, 0, -106, -44, -106, -106, -106, 0, -106, 0, -106, -106, -106, 0, -106, -106, -106, 0, -106, 0, -106, 0, 0, -106, -106, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0,
-        // State 62
-        0, 0, 0, 0, -67, 0, 0, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, -67, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 63
-        0, 0, 0, 0, -32, 0, 0, -32, 0, 0, -32, 0, -32, 0, 0, 0, 0, 0, 0, 0, -32, 0, -32, 0, 0, -32, -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 64
-        0, -87, -87, 0, -87, -87, -87, -87, -87, 0, -87, 0, -87, -87, -87, 0, -87, -87, -87, 0, -87, 0, -87, 0, 0, -87, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0,
-        // State 65
-        0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, 0, -24, -24, -24, -24, -24, 0, -24, 0, 0, -24, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0,
-        // State 66
-        0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 67
-        0, -85, -85, 0, -85, 0, -85, -85, -85, 0, -85, 0, -85, -85, -85, 0, -85, -85, -85, 0, -85, 0, -85, 0, 0, -85, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0,
-        // State 68
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 69
-        0, 0, 0, 0, -91, 0, 0, -91, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, -91, 0, -91, 0, 0, -91, -91, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 70
-        0, 0, 0, 0, -70, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, -70, 0, 0, -70, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 71
-        0, -83, -83, 0, -83, 0, 0, -83, 0, 0, -83, 0, -83, -83, -83, 0, -83, -83, -83, 0, -83, 0, -83, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0,
-        // State 72
-        0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 73
-        0, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, -37, -37, 0, -37, -37, -37, -37, -37, 0, -37, 0, 0, -37, -37, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0,
-        // State 74
-        0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 75
-        0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 76
-        0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 77
-        0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 78
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80,
-        // State 79
-        0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 80
-        0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 81
-        0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 82
-        0, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, 0, -36, -36, -36, 0, -36, -36, -36, -36, -36, 0, -36, 0, 0, -36, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0,
-        // State 83
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81,
-        // State 84
-        0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, 0, -94, -94, -94, -94, -94, 0, -94, 0, 0, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0,
-        // State 85
-        0, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, 0, -77, 0, 0, -77, -77, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, -77, 0, 0, 0,
-        // State 86
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 87
-        -89, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, -89, 0, 0, 0, 0, 0, -89, 0, 0, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, -89, 0, 0, 0, -89, -89, -89,
-        // State 88
-        -90, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, -90, 0, 0, 0, 0, 0, -90, 0, 0, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, -90, 0, 0, 0, -90, -90, -90,
-        // State 89
-        -56, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, -56, 0, 0, 0, 0, 0, -56, 0, 0, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, 0, 0, 0, -56, -56, -56,
-        // State 90
-        -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, -51, 0, 0, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, 0, 0, 0, -51, -51, -51,
-        // State 91
-        -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, -52, 0, 0, -52, -52, -52, -52, -52, -52, 0, -52, -52, -52, -52, 0, 0, 0, -52, -52, -52,
-        // State 92
-        -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, -55, 0, 0, 0, 0, 0, -55, 0, 0, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, 0, 0, 0, -55, -55, -55,
-        // State 93
-        -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, -53, 0, 0, -53, -53, -53, -53, -53, -53, 0, -53, -53, -53, -53, 0, 0, 0, -53, -53, -53,
-        // State 94
-        -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, -54, 0, 0, -54, -54, -54, -54, -54, -54, 0, -54, -54, -54, -54, 0, 0, 0, -54, -54, -54,
-        // State 95
-        -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, -96, 0, 0, 0, 0, 0, -96, 0, 0, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, -96, 0, 0, 0, -96, -96, -96,
-        // State 96
-        -97, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, -97, 0, 0, 0, 0, 0, -97, 0, 0, -97, -97, -97, -97, -97, -97, 0, -97, -97, -97, -97, 0, 0, 0, -97, -97, -97,
-        // State 97
-        -113, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, -113, 0, 0, 0, 0, 0, -113, 0, 0, -113, -113, -113, -113, -113, -113, 0, -113, -113, -113, -113, 0, 0, 0, -113, -113, -113,
-        // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 99
-        0, 0, 0, 0, -95, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 100
-        0, 0, 0, 0, -62, 0, 0, 133, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0,
-        // State 101
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 102
-        0, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, -39, -39, -39, 0, -39, -39, -39, -39, -39, 0, -39, 0, 0, -39, -39, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0,
-        // State 103
-        0, -92, -92, 18, -92, -92, -92, -92, -92, 19, -92, 0, -92, -92, -92, 0, -92, -92, -92, 0, -92, 0, -92, 0, 0, -92, -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0,
-        // State 104
-        0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, -22, -22, -22, -22, 0, -22, 0, 0, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0,
-        // State 105
-        0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 106
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 107
-        0, 0, 0, 0, -58, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 108
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0,
-        // State 109
-        0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 110
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 111
-        0, 0, 0, 0, -41, 0, 0, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, -41, 0, 0, -41, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 112
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 113
-        0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 114
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 115
-        0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 116
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 117
-        0, 0, 0, 0, -110, 0, 0, -110, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, -110, 0, 0, -110, -110, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 118
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 119
-        0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 120
-        0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 121
-        0, 0, 0, 0, -108, 0, 0, -108, 0, 0, -108, 0, -108, 0, 0, 0, 0, 0, 0, 0, -108, 0, -108, 0, 0, -108, -108, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 122
-        0, 0, 0, 0, -47, 0, 0, -47, 0, 0, -47, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, -47, 0, 0, -47, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 123
-        0, -82, -82, 0, -82, 0, 0, -82, 0, 0, -82, 0, -82, -82, -82, 0, -82, -82, -82, 0, -82, 0, -82, 0, 0, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0,
-        // State 124
-        0, -48, -48, 0, -48, 0, 0, -48, 0, 0, -48, 0, -48, -48, -48, 0, -48, -48, -48, 0, -48, 0, -48, 0, 0, -48, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0,
-        // State 125
-        0, -84, -84, 0, -84, 0, -84, -84, -84, 0, -84, 0, -84, -84, -84, 0, -84, -84, -84, 0, -84, 0, -84, 0, 0, -84, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0,
-        // State 126
-        0, -46, -46, 0, -46, 0, -46, -46, -46, 0, -46, 0, -46, -46, -46, 0, -46, -46, -46, 0, -46, 0, -46, 0, 0, -46, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0,
-        // State 127
-        0, -86, -86, 0, -86, -86, -86, -86, -86, 0, -86, 0, -86, -86, -86, 0, -86, -86, -86, 0, -86, 0, -86, 0, 0, -86, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0,
-        // State 128
-        0, -45, -45, 0, -45, -45, -45, -45, -45, 0, -45, 0, -45, -45, -45, 0, -45, -45, -45, 0, -45, 0, -45, 0, 0, -45, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0,
-        // State 129
-        0, 0, 0, 0, -73, 0, 0, -73, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, -73, 0, 0, -73, -73, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 130
-        0, -21, -21, 18, -21, -21, -21, -21, -21, 19, -21, 0, -21, -21, -21, 0, -21, -21, -21, -21, -21, 0, -21, 0, 0, -21, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0,
-        // State 131
-        0, 0, 0, 0, -64, 0, 0, 142, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0,
-        // State 132
-        0, 0, 0, 0, -9, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, -9,
-        // State 133
-        0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 134
-        0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, -27, -27, -27, -27, 0, -27, 0, 0, -27, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0,
-        // State 135
-        0, 0, 0, 0, -60, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 136
-        0, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 0, -31, -31, -31, 0, -31, -31, -31, -31, -31, 0, -31, 0, 0, -31, -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0,
-        // State 137
-        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, -4, -4, 0, -4, -4, 0, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, 0, -4, -4, -4,
-        // State 138
-        0, 0, 0, 0, -74, 0, 0, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, -74, 0, 0, -74, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 139
-        0, -19, -19, 0, -19, -19, -19, -19, -19, 0, -19, 39, -19, -19, -19, 0, -19, -19, -19, -19, -19, 0, -19, 0, 0, -19, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0,
-        // State 140
-        0, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, -28, -28, -28, -28, 0, -28, 0, 0, -28, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0,
-        // State 141
-        0, 0, 0, 0, -10, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, -10,
-        // State 142
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 143
-        0, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, 0, -26, -26, -26, -26, -26, 0, -26, 0, 0, -26, -26, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0,
-        // State 144
-        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, -5, -5, 0, -5, -5, 0, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, 0, -5, -5, -5,
-        // State 145
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0,
-        // State 146
-        0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 147
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 148
-        0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 149
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 150
-        0, 0, 0, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, -88, 0, 0, -88, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 151
-        0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 152
-        0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 153
-        0, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, 0, -30, -30, -30, 0, -30, -30, -30, -30, -30, 0, -30, 0, 0, -30, -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0,
-        // State 154
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 155
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 156
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 157
-        0, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, 0, -25, -25, -25, -25, -25, 0, -25, 0, 0, -25, -25, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0,
-        // State 158
-        0, -20, -20, 18, -20, -20, -20, -20, -20, 19, -20, 0, -20, -20, -20, 0, -20, -20, -20, -20, -20, 0, -20, 0, 0, -20, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0,
-        // State 159
-        0, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, 0, -29, -29, -29, 0, -29, -29, -29, -29, -29, 0, -29, 0, 0, -29, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0,
-        // State 160
-        0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 161
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 162
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 163
-        0, 0, 0, 0, -76, 0, 0, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, -76, 0, 0, -76, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 164
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 165
-        0, 0, 0, 0, -49, 0, 0, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, -49, 0, 0, -49, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 166
-        0, 0, 0, 0, -50, 0, 0, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, -50, 0, 0, -50, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 47 + integer]
+    pub(crate) fn __reduce122<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Import = "import", Str => ActionFn(263);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action263::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (2, 59)
     }
-    const __EOF_ACTION: &[i16] = &[
-        // State 0
-        0,
-        // State 1
-        0,
-        // State 2
-        0,
-        // State 3
-        0,
-        // State 4
-        0,
-        // State 5
-        0,
-        // State 6
-        0,
-        // State 7
-        0,
-        // State 8
-        0,
-        // State 9
-        0,
-        // State 10
-        0,
-        // State 11
-        0,
-        // State 12
-        0,
-        // State 13
-        0,
-        // State 14
-        0,
-        // State 15
-        0,
-        // State 16
-        0,
-        // State 17
-        0,
-        // State 18
-        0,
-        // State 19
-        0,
-        // State 20
-        0,
-        // State 21
-        0,
-        // State 22
-        0,
-        // State 23
-        0,
-        // State 24
-        0,
-        // State 25
-        0,
-        // State 26
-        0,
-        // State 27
-        0,
-        // State 28
-        0,
-        // State 29
-        0,
-        // State 30
-        0,
-        // State 31
-        0,
-        // State 32
-        0,
-        // State 33
-        0,
-        // State 34
-        0,
-        // State 35
-        0,
-        // State 36
-        0,
-        // State 37
-        0,
-        // State 38
-        0,
-        // State 39
-        0,
-        // State 40
-        0,
-        // State 41
-        0,
-        // State 42
-        0,
-        // State 43
-        0,
-        // State 44
-        0,
-        // State 45
-        0,
-        // State 46
-        -93,
-        // State 47
-        -18,
-        // State 48
-        -111,
-        // State 49
-        -23,
-        // State 50
-        0,
-        // State 51
-        0,
-        // State 52
-        -69,
-        // State 53
-        -109,
-        // State 54
-        -107,
-        // State 55
-        -68,
-        // State 56
-        0,
-        // State 57
-        -66,
-        // State 58
-        -22,
-        // State 59
-        -57,
-        // State 60
-        -112,
-        // State 61
-        -106,
-        // State 62
-        -67,
-        // State 63
-        -32,
-        // State 64
-        -87,
-        // State 65
-        -24,
-        // State 66
-        0,
-        // State 67
-        -85,
-        // State 68
-        -115,
-        // State 69
-        -91,
-        // State 70
-        -70,
-        // State 71
-        -83,
-        // State 72
-        0,
-        // State 73
-        -37,
-        // State 74
-        0,
-        // State 75
-        0,
-        // State 76
-        0,
-        // State 77
-        0,
-        // State 78
-        0,
-        // State 79
-        0,
-        // State 80
-        0,
-        // State 81
-        0,
-        // State 82
-        -36,
-        // State 83
-        0,
-        // State 84
-        -94,
-        // State 85
-        -77,
-        // State 86
-        0,
-        // State 87
-        0,
-        // State 88
-        0,
-        // State 89
-        0,
-        // State 90
-        0,
-        // State 91
-        0,
-        // State 92
-        0,
-        // State 93
-        0,
-        // State 94
-        0,
-        // State 95
-        0,
-        // State 96
-        0,
-        // State 97
-        0,
-        // State 98
-        0,
-        // State 99
-        0,
-        // State 100
-        0,
-        // State 101
-        0,
-        // State 102
-        -39,
-        // State 103
-        -92,
-        // State 104
-        -22,
-        // State 105
-        0,
-        // State 106
-        0,
-        // State 107
-        0,
-        // State 108
-        0,
-        // State 109
-        0,
-        // State 110
-        0,
-        // State 111
-        -41,
-        // State 112
-        0,
-        // State 113
-        0,
-        // State 114
-        0,
-        // State 115
-        0,
-        // State 116
-        0,
-        // State 117
-        -110,
-        // State 118
-        0,
-        // State 119
-        0,
-        // State 120
-        0,
-        // State 121
-        -108,
-        // State 122
-        -47,
-        // State 123
-        -82,
-        // State 124
-        -48,
-        // State 125
-        -84,
-        // State 126
-        -46,
-        // State 127
-        -86,
-        // State 128
-        -45,
-        // State 129
-        -73,
-        // State 130
-        -21,
-        // State 131
-        0,
-        // State 132
-        0,
-        // State 133
-        0,
-        // State 134
-        -27,
-        // State 135
-        0,
-        // State 136
-        -31,
-        // State 137
-        0,
-        // State 138
-        -74,
-        // State 139
-        -19,
-        // State 140
-        -28,
-        // State 141
-        0,
-        // State 142
-        0,
-        // State 143
-        -26,
-        // State 144
-        0,
-        // State 145
-        0,
-        // State 146
-        0,
-        // State 147
-        0,
-        // State 148
-        0,
-        // State 149
-        0,
-        // State 150
-        -88,
-        // State 151
-        0,
-        // State 152
-        0,
-        // State 153
-        -30,
-        // State 154
-        0,
-        // State 155
-        0,
-        // State 156
-        0,
-        // State 157
-        -25,
-        // State 158
-        -20,
-        // State 159
-        -29,
-        // State 160
-        0,
-        // State 161
-        0,
-        // State 162
-        0,
-        // State 163
-        -76,
-        // State 164
-        0,
-        // State 165
-        -49,
-        // State 166
-        -50,
-    ];
-    fn __goto(state: i16, nt: usize) -> i16 {
-        match nt {
-            2 => 27,
-            5 => 25,
-            8 => match state {
-                40 => 43,
-                _ => 1,
-            },
-            11 => 46,
-            12 => match state {
-                7 | 24 | 38 => 102,
-                _ => 47,
-            },
-            13 => 48,
-            14 => match state {
-                34 => 149,
-                _ => 112,
-            },
-            15 => 113,
-            16 => 49,
-            17 => 50,
-            18 => match state {
-                7 => 103,
-                24 => 130,
-                38 => 158,
-                _ => 51,
-            },
-            19 => 2,
-            20 => match state {
-                16 => 118,
-                23 => 129,
-                26 => 133,
-                28 => 138,
-                29 => 142,
-                32 => 147,
-                35 => 150,
-                42 => 163,
-                44 => 165,
-                45 => 166,
-                _ => 110,
-            },
-            21 => 3,
-            22 => 4,
-            23 => 5,
-            24 => 127,
-            25 => 125,
-            26 => 121,
-            27 => 123,
-            28 => 52,
-            29 => 20,
-            30 => 53,
-            31 => match state {
-                17 => 119,
-                36 => 151,
-                37 => 152,
-                _ => 106,
-            },
-            32 => match state {
-                30 => 145,
-                _ => 99,
-            },
-            33 => match state {
-                0 => 54,
-                8 => 105,
-                9 | 17 | 36..=37 => 107,
-                15 => 117,
-                27 => 135,
-                33 => 148,
-                39 => 160,
-                41 => 162,
-                _ => 111,
-            },
-            35 => 55,
-            36 => match state {
-                1 => 86,
-                40 => 161,
-                43 => 164,
-                _ => 56,
-            },
-            37 => 57,
-            38 => match state {
-                6 | 30..=31 => 100,
-                7 | 24 | 38 => 104,
-                10 => 108,
-                11 => 109,
-                13 | 34 => 114,
-                14 => 115,
-                18 => 120,
-                25 => 131,
-                _ => 58,
-            },
-            40 => 6,
-            41 => 59,
-            42 => 60,
-            43 => 61,
-            44 => 62,
-            45 => 19,
-            46 => 63,
-            47 => match state {
-                22 => 128,
-                _ => 64,
-            },
-            48 => 65,
-            49 => match state {
-                31 => 146,
-                _ => 101,
-            },
-            50 => 21,
-            51 => 66,
-            52 => match state {
-                21 => 126,
-                _ => 67,
-            },
-            53 => 68,
-            54 => match state {
-                19 => 122,
-                _ => 69,
-            },
-            55 => 70,
-            56 => match state {
-                20 => 124,
-                _ => 71,
-            },
-            57 => 22,
-            _ => 0,
-        }
+    pub(crate) fn __reduce123<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Import* =  => ActionFn(117);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action117::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (0, 60)
     }
-    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
-        const __TERMINAL: &[&str] = &[
-            r###""!""###,
-            r###""!=""###,
-            r###""&&""###,
-            r###""(""###,
-            r###"")""###,
-            r###""*""###,
-            r###""+""###,
-            r###"",""###,
-            r###""-""###,
-            r###"".""###,
-            r###"":""###,
-            r###"":=""###,
-            r###"";""###,
-            r###""<""###,
-            r###""<=""###,
-            r###""=""###,
-            r###""==""###,
-            r###"">""###,
-            r###"">=""###,
-            r###""[""###,
-            r###""]""###,
-            r###""add1""###,
-            r###""and""###,
-            r###""class""###,
-            r###""def""###,
-            r###""else""###,
-            r###""end""###,
-            r###""false""###,
-            r###""if""###,
-            r###""in""###,
-            r###""isarray""###,
-            r###""isbool""###,
-            r###""isfun""###,
-            r###""isnum""###,
-            r###""lambda""###,
-            r###""length""###,
-            r###""let""###,
-            r###""new""###,
-            r###""print""###,
-            r###""sub1""###,
-            r###""true""###,
-            r###""{""###,
-            r###""||""###,
-            r###""}""###,
-            r###""λ""###,
-            r###"r#"[+-]?[0-9]+"#"###,
-            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
-        ];
-        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            let next_state = __action(__state, index);
-            if next_state == 0 {
-                None
-            } else {
-                Some(alloc::string::ToString::to_string(terminal))
-            }
-        }).collect()
+    pub(crate) fn __reduce124<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Import* = Import+ => ActionFn(118);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action118::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 60)
     }
-    pub(crate) struct __StateMachine<'input>
-    where 
+    pub(crate) fn __reduce125<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
     {
+        // Import+ = Import => ActionFn(119);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action119::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 61)
+    }
+    pub(crate) fn __reduce126<
+        'input,
+    >(
         input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Import+ = Import+, Import => ActionFn(120);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action120::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (2, 61)
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+    pub(crate) fn __reduce127<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
     {
-        type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
-        type TokenIndex = usize;
-        type Symbol = __Symbol<'input>;
-        type Success = SurfProg<Span1>;
-        type StateIndex = i16;
-        type Action = i16;
-        type ReduceIndex = i16;
-        type NonterminalIndex = usize;
-
-        #[inline]
-        fn start_location(&self) -> Self::Location {
-              Default::default()
-        }
-
-        #[inline]
-        fn start_state(&self) -> Self::StateIndex {
-              0
-        }
-
-        #[inline]
-        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
-        }
-
-        #[inline]
-        fn action(&self, state: i16, integer: usize) -> i16 {
-            __action(state, integer)
-        }
-
-        #[inline]
-        fn error_action(&self, state: i16) -> i16 {
-            __action(state, 47 - 1)
-        }
-
-        #[inline]
-        fn eof_action(&self, state: i16) -> i16 {
-            __EOF_ACTION[state as usize]
-        }
-
-        #[inline]
-        fn goto(&self, state: i16, nt: usize) -> i16 {
-            __goto(state, nt)
-        }
-
-        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
-        }
-
-        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
-            __expected_tokens(state)
-        }
-
-        #[inline]
-        fn uses_error_recovery(&self) -> bool {
-            false
-        }
-
-        #[inline]
-        fn error_recovery_symbol(
-            &self,
-            recovery: __state_machine::ErrorRecovery<Self>,
-        ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
-        }
-
-        fn reduce(
-            &mut self,
-            action: i16,
-            start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i16>,
-            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
-        ) -> Option<__state_machine::ParseResult<Self>> {
-            __reduce(
-                self.input,
-                action,
-                start_location,
-                states,
-                symbols,
-                core::marker::PhantomData::<(&())>,
-            )
-        }
-
-        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
-            panic!("error recovery not enabled for this grammar")
-        }
+        // LAMBDA = "lambda" => ActionFn(67);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 62)
     }
-    fn __token_to_integer<
+    pub(crate) fn __reduce128<
         'input,
     >(
-        __token: &Token<'input>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<usize>
+    ) -> (usize, usize)
     {
-        match *__token {
-            Token(2, _) if true => Some(0),
-            Token(3, _) if true => Some(1),
-            Token(4, _) if true => Some(2),
-            Token(5, _) if true => Some(3),
-            Token(6, _) if true => Some(4),
-            Token(7, _) if true => Some(5),
-            Token(8, _) if true => Some(6),
-            Token(9, _) if true => Some(7),
-            Token(10, _) if true => Some(8),
-            Token(11, _) if true => Some(9),
-            Token(12, _) if true => Some(10),
-            Token(13, _) if true => Some(11),
-            Token(14, _) if true => Some(12),
-            Token(15, _) if true => Some(13),
-            Token(16, _) if true => Some(14),
-            Token(17, _) if true => Some(15),
-            Token(18, _) if true => Some(16),
-            Token(19, _) if true => Some(17),
-            Token(20, _) if true => Some(18),
-            Token(21, _) if true => Some(19),
-            Token(22, _) if true => Some(20),
-            Token(23, _) if true => Some(21),
-            Token(24, _) if true => Some(22),
-            Token(25, _) if true => Some(23),
-            Token(26, _) if true => Some(24),
-            Token(27, _) if true => Some(25),
-            Token(28, _) if true => Some(26),
-            Token(29, _) if true => Some(27),
-            Token(30, _) if true => Some(28),
-            Token(31, _) if true => Some(29),
-            Token(32, _) if true => Some(30),
-            Token(33, _) if true => Some(31),
-            Token(34, _) if true => Some(32),
-            Token(35, _) if true => Some(33),
-            Token(36, _) if true => Some(34),
-            Token(37, _) if true => Some(35),
-            Token(38, _) if true => Some(36),
-            Token(39, _) if true => Some(37),
-            Token(40, _) if true => Some(38),
-            Token(41, _) if true => Some(39),
-            Token(42, _) if true => Some(40),
-            Token(43, _) if true => Some(41),
-            Token(44, _) if true => Some(42),
-            Token(45, _) if true => Some(43),
-            Token(46, _) if true => Some(44),
-            Token(0, _) if true => Some(45),
-            Token(1, _) if true => Some(46),
-            _ => None,
-        }
+        // LAMBDA = "λ" => ActionFn(68);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 62)
     }
-    fn __token_to_symbol<
+    pub(crate) fn __reduce129<
         'input,
     >(
-        __token_index: usize,
-        __token: Token<'input>,
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
-    ) -> __Symbol<'input>
+    ) -> (usize, usize)
     {
-        match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(41, __tok0) | Token(42, __tok0) | Token(43, __tok0) | Token(44, __tok0) | Token(45, __tok0) | Token(46, __tok0) | Token(0, __tok0) | Token(1, __tok0) if true => __Symbol::Variant0(__tok0),
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
-        }
+        // LAssoc<Cmp, Sum> = Boxed<LAssoc<Cmp, Sum>>, Cmp, Boxed<Sum> => ActionFn(264);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action264::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 63)
     }
-    pub struct ProgParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
-        _priv: (),
+    pub(crate) fn __reduce130<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Cmp, Sum> = Sum => ActionFn(97);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action97::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 63)
     }
-
-    impl ProgParser {
-        pub fn new() -> ProgParser {
-            let __builder = super::__intern_token::new_builder();
-            ProgParser {
-                builder: __builder,
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<SurfProg<Span1>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
-                },
-                __tokens,
-            )
-        }
+    pub(crate) fn __reduce131<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<PlusMinus, Product> = Boxed<LAssoc<PlusMinus, Product>>, PlusMinus, Boxed<Product> => ActionFn(265);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action265::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 64)
     }
-    pub(crate) fn __reduce<
+    pub(crate) fn __reduce132<
         'input,
     >(
         input: &'input str,
-        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<SurfProg<Span1>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    ) -> (usize, usize)
     {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+        // LAssoc<PlusMinus, Product> = Product => ActionFn(95);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action95::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 64)
+    }
+    pub(crate) fn __reduce133<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExp> = Boxed<LAssoc<Times, NotExp>>, Times, Boxed<NotExp> => ActionFn(266);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action266::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 65)
+    }
+    pub(crate) fn __reduce134<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExp> = NotExp => ActionFn(93);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action93::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 65)
+    }
+    pub(crate) fn __reduce135<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LET = "let", Bindings, "in", Boxed<Exp> => ActionFn(267);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action267::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 66)
+    }
+    pub(crate) fn __reduce136<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Log = "&&" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 67)
+    }
+    pub(crate) fn __reduce137<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Log = "||" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 67)
+    }
+    pub(crate) fn __reduce138<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LogExp = RAssoc<Log, CmpExp> => ActionFn(26);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 68)
+    }
+    pub(crate) fn __reduce139<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // MatchArm = "|", Pattern, "=>", Exp => ActionFn(60);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action60::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 69)
+    }
+    pub(crate) fn __reduce140<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // MatchArm+ = MatchArm => ActionFn(88);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action88::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 70)
+    }
+    pub(crate) fn __reduce141<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // MatchArm+ = MatchArm+, MatchArm => ActionFn(89);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant31(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action89::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (2, 70)
+    }
+    pub(crate) fn __reduce142<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // NotExp = "!", Boxed<BaseExp> => ActionFn(268);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action268::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 71)
+    }
+    pub(crate) fn __reduce143<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // NotExp = ArrExp => ActionFn(42);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 71)
+    }
+    pub(crate) fn __reduce144<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Num = r#"[+-]?[0-9]+"# => ActionFn(82);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action82::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 72)
+    }
+    pub(crate) fn __reduce145<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Params = Comma<Id> => ActionFn(6);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 73)
+    }
+    pub(crate) fn __reduce146<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern = "_" => ActionFn(269);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action269::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
+    }
+    pub(crate) fn __reduce147<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern = Id => ActionFn(270);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action270::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
+    }
+    pub(crate) fn __reduce148<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern = Num => ActionFn(271);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action271::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
+    }
+    pub(crate) fn __reduce149<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern = Bool => ActionFn(272);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action272::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
+    }
+    pub(crate) fn __reduce150<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern = "[", Comma<Pattern>, "]" => ActionFn(273);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action273::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 74)
+    }
+    pub(crate) fn __reduce151<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern = Id, "(", Comma<Pattern>, ")" => ActionFn(274);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant22(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action274::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 74)
+    }
+    pub(crate) fn __reduce152<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern? = Pattern => ActionFn(151);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action151::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 75)
+    }
+    pub(crate) fn __reduce153<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern? =  => ActionFn(152);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action152::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (0, 75)
+    }
+    pub(crate) fn __reduce154<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "+" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 76)
+    }
+    pub(crate) fn __reduce155<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "-" => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 76)
+    }
+    pub(crate) fn __reduce156<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "add1" => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action69::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce157<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "sub1" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce158<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "print" => ActionFn(71);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce159<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isnum" => ActionFn(72);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce160<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isbool" => ActionFn(73);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action73::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce161<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isarray" => ActionFn(74);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce162<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "length" => ActionFn(75);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce163<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isfun" => ActionFn(76);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
+    }
+    pub(crate) fn __reduce164<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Product = LAssoc<Times, NotExp> => ActionFn(29);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 78)
+    }
+    pub(crate) fn __reduce165<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prog = Exp => ActionFn(286);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action286::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 79)
+    }
+    pub(crate) fn __reduce166<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prog = Import+, Exp => ActionFn(287);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action287::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (2, 79)
+    }
+    pub(crate) fn __reduce167<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // RAISE = "raise", Boxed<BinOps> => ActionFn(275);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action275::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 80)
+    }
+    pub(crate) fn __reduce168<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // RAISE = ASSIGN => ActionFn(21);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 80)
+    }
+    pub(crate) fn __reduce169<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExp> = Boxed<CmpExp>, Log, Boxed<RAssoc<Log, CmpExp>> => ActionFn(276);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action276::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 81)
+    }
+    pub(crate) fn __reduce170<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(99);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 81)
+    }
+    pub(crate) fn __reduce171<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // SEQ = RAISE, ";", Exp => ActionFn(277);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action277::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 82)
+    }
+    pub(crate) fn __reduce172<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // SEQ = RAISE => ActionFn(19);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action19::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 82)
+    }
+    pub(crate) fn __reduce173<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Str = r#"\"[^\"\\n]*\""# => ActionFn(83);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 83)
+    }
+    pub(crate) fn __reduce174<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Sum = LAssoc<PlusMinus, Product> => ActionFn(28);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 84)
+    }
+    pub(crate) fn __reduce175<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Times = "*" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 85)
+    }
+    pub(crate) fn __reduce176<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Type = Id => ActionFn(9);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (1, 86)
+    }
+    pub(crate) fn __reduce177<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "(", Comma<Type>, ")", "->", Type => ActionFn(10);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant1(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action10::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (5, 86)
+    }
+    pub(crate) fn __reduce178<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Type? = Type => ActionFn(133);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action133::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 87)
+    }
+    pub(crate) fn __reduce179<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Type? =  => ActionFn(134);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action134::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 87)
+    }
+    pub(crate) fn __reduce180<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // TypedParam = Id, ":", Type => ActionFn(170);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 88)
+    }
+    pub(crate) fn __reduce181<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // TypedParam = Id => ActionFn(171);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action171::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 88)
+    }
+    pub(crate) fn __reduce182<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // TypedParam? = TypedParam => ActionFn(123);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action123::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 89)
+    }
+    pub(crate) fn __reduce183<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // TypedParam? =  => ActionFn(124);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action124::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 89)
+    }
+    pub(crate) fn __reduce185<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // __Prog = Prog => ActionFn(0);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action0::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 91)
+    }
+}
+pub use self::__parse__Exp::ExpParser;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __parse__Prog {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
+
+    use std::str::FromStr;
+    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Pattern, Prim1, Prim2, Signature, SnakeType};
+    use crate::span::Span1;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    use self::__lalrpop_util::lexer::Token;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<'input>
+     {
+        Variant0(&'input str),
+        Variant1(SnakeType),
+        Variant2(core::option::Option<SnakeType>),
+        Variant3(Exp<Span1>),
+        Variant4(alloc::vec::Vec<Exp<Span1>>),
+        Variant5(String),
+        Variant6(alloc::vec::Vec<String>),
+        Variant7(Pattern<Span1>),
+        Variant8(alloc::vec::Vec<Pattern<Span1>>),
+        Variant9(alloc::vec::Vec<SnakeType>),
+        Variant10((String, Option<SnakeType>)),
+        Variant11(alloc::vec::Vec<(String, Option<SnakeType>)>),
+        Variant12((SurfFunDecl<Span1>, &'input str)),
+        Variant13(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
+        Variant14(usize),
+        Variant15((Pattern<Span1>, Exp<Span1>)),
+        Variant16(Vec<(Pattern<Span1>, Exp<Span1>)>),
+        Variant17(bool),
+        Variant18(Box<Exp<Span1>>),
+        Variant19(Prim2),
+        Variant20(Vec<Exp<Span1>>),
+        Variant21(Vec<String>),
+        Variant22(Vec<Pattern<Span1>>),
+        Variant23(Vec<SnakeType>),
+        Variant24(Vec<(String, Option<SnakeType>)>),
+        Variant25(core::option::Option<Exp<Span1>>),
+        Variant26(SurfFunDecl<Span1>),
+        Variant27(core::option::Option<String>),
+        Variant28((String, Span1)),
+        Variant29(alloc::vec::Vec<(String, Span1)>),
+        Variant30(()),
+        Variant31(alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>),
+        Variant32(i64),
+        Variant33(core::option::Option<Pattern<Span1>>),
+        Variant34(Prim1),
+        Variant35((Vec<(String, Span1)>, SurfProg<Span1>)),
+        Variant36(core::option::Option<(String, Option<SnakeType>)>),
+    }
+    const __ACTION: &[i16] = &[
+        // State 0
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 16, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0,
+        // State 3
+        0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 127, 0, 128, 0, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 131, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 16, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 8
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 9
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 10
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, -89, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 14
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 160, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 120, 121,
+        // State 17
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 19
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 20
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 21
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 22
+        9, 0, 0, 10, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 24
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 25
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 26
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 27
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 28
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 29
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 30
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 31
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 32
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 33
+        9, 0, 0, 10, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, -91, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, -97, 160, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 120, 121,
+        // State 35
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 36
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 37
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 121,
+        // State 39
+        0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 40
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 204, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 41
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 42
+        0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 43
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 44
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 160, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 120, 121,
+        // State 45
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 46
+        0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 160, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 120, 121,
+        // State 47
+        0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, -99, 160, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 120, 121,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0,
+        // State 49
+        9, 0, 0, 10, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 50
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 51
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 52
+        9, 0, 0, 10, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 53
+        0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 121,
+        // State 54
+        0, 0, 0, 55, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 55
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 160, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 120, 121,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 58
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 0, 0, 0, 0, 108, 14, 0, 0, 0, 109, 110, 111, 112, 113, 114, 0, 18, 19, 115, 0, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 59
+        0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 60
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 61
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 62
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 63
+        0, 0, 0, 55, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 66
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 67
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 68
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 69
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 72
+        0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121,
+        // State 73
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 74
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 75
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 76
+        9, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 107, 0, 0, 12, 13, 0, 0, 108, 14, 15, 0, 0, 109, 110, 111, 112, 113, 114, 17, 18, 19, 115, 20, 0, 116, 117, 118, 21, 0, 0, 0, 0, 119, 0, 120, 121,
+        // State 77
+        0, 0, 0, 0, -169, 0, 0, -169, 0, 0, 0, -169, 0, -169, 0, 0, 0, 0, 0, 0, 0, 0, -169, 0, 0, -169, -169, 0, 0, -169, -169, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, -144, -144, 0, -144, -144, -144, -144, -144, 0, 0, -144, 0, -144, -144, -144, 0, -144, 0, -144, -144, -67, -144, 0, 0, -144, -144, 0, 0, -144, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, -144, 0, 0, 0, 0, 0,
+        // State 79
+        0, -42, -42, -68, -42, -42, -42, -42, -42, 0, -68, -42, 0, -42, -42, -42, 0, -42, 0, -42, -42, -42, -42, 0, 0, -42, -42, 0, 0, -42, -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, -41, 0, 0, -41, 0, 0, 0, -41, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, -41, -41, 0, 0, -41, -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, 0, -45, -45, -45, 0, -45, 0, -45, -45, -45, -45, 0, 0, -45, -45, 0, 0, -45, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, -45, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, -111, 0, 0, -111, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, -111, -111, 0, 0, -111, -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, -70, 0, -171, 0, 0, -171, 0, 0, 0, -171, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, -171, 0, 0, -171, -171, 0, 0, -171, -171, 0, 0, 0, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -171, -70, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, -110, 0, 0, -110, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, -110, -110, 0, 0, -110, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, -108, -108, 0, 0, -108, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, 30, -44, -44, -44, 0, -44, 0, -44, -44, -44, -44, 0, 0, -44, -44, 0, 0, -44, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0, 0,
+        // State 91
+        -126, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, 0, 0, -126, 0, 0, -126, -126, 0, 0, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, 0, 0, 0, 0, -126, 0, -126, -126,
+        // State 92
+        0, -72, -87, 0, -87, 0, 0, -87, 0, 0, 0, -87, 0, -87, -72, -72, 0, -72, 0, -72, -72, 0, -87, 0, 0, -87, -87, 0, 0, -87, -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0, 0,
+        // State 93
+        0, -175, -175, 0, -175, 0, -73, -175, -73, 0, 0, -175, 0, -175, -175, -175, 0, -175, 0, -175, -175, 0, -175, 0, 0, -175, -175, 0, 0, -175, -175, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, -175, 0, 0, 0, 0, 0,
+        // State 94
+        0, -165, -165, 0, -165, -74, -165, -165, -165, 0, 0, -165, 0, -165, -165, -165, 0, -165, 0, -165, -165, 0, -165, 0, 0, -165, -165, 0, 0, -165, -165, 0, 0, 0, 0, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, -165, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, -109, 0, 0, -109, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, -109, -109, 0, 0, -109, -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, -59, 0, 0, -59, 0, 0, 0, -59, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, -59, -59, 0, 0, -59, -59, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, -135, -135, 0, -135, -135, -135, -135, -135, 0, 0, -135, 0, -135, -135, -135, 0, -135, 0, -135, -135, 0, -135, 0, 0, -135, -135, 0, 0, -135, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, 0, 0, 0, 0, 0,
+        // State 98
+        0, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, 0, -46, -46, -46, 0, -46, 0, -46, -46, -46, -46, 0, 0, -46, -46, 0, 0, -46, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, -133, -133, 0, -133, 0, -133, -133, -133, 0, 0, -133, 0, -133, -133, -133, 0, -133, 0, -133, -133, 0, -133, 0, 0, -133, -133, 0, 0, -133, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, 0, 0, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        0, 0, 0, 0, -173, 0, 0, -173, 0, 0, 0, -173, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, -173, -173, 0, 0, -173, -173, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, -139, 0, 0, -139, 0, 0, 0, -139, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, -139, -139, 0, 0, -139, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, -112, 0, 0, -112, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, -112, -112, 0, 0, -112, -112, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, -131, -131, 0, -131, 0, 0, -131, 0, 0, 0, -131, 0, -131, -131, -131, 0, -131, 0, -131, -131, 0, -131, 0, 0, -131, -131, 0, 0, -131, -131, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, 0, -66, -66, 0, 0, -66, -66, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, -66, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128,
+        // State 113
+        0, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 116
+        0, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, -65, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, 0, -65, -65, 0, 0, -65, -65, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, -65, 0, 0, 0, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129,
+        // State 119
+        0, -145, -145, -145, -145, -145, -145, -145, -145, 0, -145, -145, 0, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, 0, 0, -145, -145, 0, 0, -145, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, -145, 0, 0, 0, 0, 0,
+        // State 120
+        0, -120, -120, -120, -120, -120, -120, -120, -120, 0, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, 0, 0, -120, -120, 0, 0, -120, -120, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, -120, -120, -120, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        -137, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, 0, -137, 0, 0, 0, 0, 0, 0, -137, -137, 0, 0, 0, -137, -137, -137, -137, -137, -137, 0, -137, -137, -137, 0, 0, -137, -137, -137, -137, 0, 0, 0, 0, -137, 0, -137, -137,
+        // State 123
+        -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, -138, -138, 0, 0, 0, -138, -138, -138, -138, -138, -138, 0, -138, -138, -138, 0, 0, -138, -138, -138, -138, 0, 0, 0, 0, -138, 0, -138, -138,
+        // State 124
+        -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, 0, 0, -86, -86, -86, -86, 0, 0, 0, 0, -86, 0, -86, -86,
+        // State 125
+        -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, -81, -81, 0, 0, 0, -81, -81, -81, -81, -81, -81, 0, -81, -81, -81, 0, 0, -81, -81, -81, -81, 0, 0, 0, 0, -81, 0, -81, -81,
+        // State 126
+        -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, -82, -82, -82, -82, -82, -82, 0, -82, -82, -82, 0, 0, -82, -82, -82, -82, 0, 0, 0, 0, -82, 0, -82, -82,
+        // State 127
+        -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, -85, -85, -85, -85, -85, -85, 0, -85, -85, -85, 0, 0, -85, -85, -85, -85, 0, 0, 0, 0, -85, 0, -85, -85,
+        // State 128
+        -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, -83, -83, 0, 0, 0, -83, -83, -83, -83, -83, -83, 0, -83, -83, -83, 0, 0, -83, -83, -83, -83, 0, 0, 0, 0, -83, 0, -83, -83,
+        // State 129
+        -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, -84, -84, 0, 0, 0, -84, -84, -84, -84, -84, -84, 0, -84, -84, -84, 0, 0, -84, -84, -84, -84, 0, 0, 0, 0, -84, 0, -84, -84,
+        // State 130
+        -155, 0, 0, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0, 0, -155, 0, 0, 0, 0, 0, 0, -155, -155, 0, 0, 0, -155, -155, -155, -155, -155, -155, 0, -155, -155, -155, 0, 0, -155, -155, -155, -155, 0, 0, 0, 0, -155, 0, -155, -155,
+        // State 131
+        -156, 0, 0, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -156, 0, 0, -156, 0, 0, 0, 0, 0, 0, -156, -156, 0, 0, 0, -156, -156, -156, -156, -156, -156, 0, -156, -156, -156, 0, 0, -156, -156, -156, -156, 0, 0, 0, 0, -156, 0, -156, -156,
+        // State 132
+        -176, 0, 0, -176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176, 0, 0, -176, 0, 0, 0, 0, 0, 0, -176, -176, 0, 0, 0, -176, -176, -176, -176, -176, -176, 0, -176, -176, -176, 0, 0, -176, -176, -176, -176, 0, 0, 0, 0, -176, 0, -176, -176,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        -127, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, 0, 0, -127, 0, 0, -127, -127, 0, 0, -127, -127, -127, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, 0, -127, -127, -127, -127, 0, 0, 0, 0, -127, 0, -127, -127,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, -68, 0, -68, 0, 0, -68, -68, 0, 0, -68, -68, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, -68, 0, 0, 0, 0, 0,
+        // State 140
+        0, -143, -143, 23, -143, -143, -143, -143, -143, 0, 24, -143, 0, -143, -143, -143, 0, -143, 0, -143, -143, 0, -143, 0, 0, -143, -143, 0, 0, -143, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, -143, 0, 0, 0, 0, 0,
+        // State 141
+        0, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, 0, -44, -44, -44, 0, -44, 0, -44, -44, -44, -44, 0, 0, -44, -44, 0, 0, -44, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, -88, 0, 0, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, -71, -71, 0, 0, -71, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0,
+        // State 150
+        -123, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, -123, 0, 0, -123, -123, 0, 0, -123, -123, -123, -123, 0, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, 0, -123, -123, -123, -123, 0, 0, 0, 0, -123, 0, -123, -123,
+        // State 151
+        -174, 0, 0, -174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -174, 0, 0, -174, 0, 0, -174, -174, 0, 0, -174, -174, -174, -174, 0, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, 0, -174, -174, -174, -174, 0, 0, 0, 0, -174, 0, -174, -174,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, -150, 0, 0, -150, 0, 0, 0, -150, 0, 0, 0, 0, -150, 0, -150, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 47, -148, 0, 0, -148, 0, 0, 0, -148, 0, 0, 0, 0, -148, 0, -148, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, -149, 0, 0, -149, 0, 0, 0, -149, 0, 0, 0, 0, -149, 0, -149, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, -147, 0, 0, -147, 0, 0, 0, -147, 0, 0, 0, 0, -147, 0, -147, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 0, -69, 0, 0, -69, 0, 0, 0, -69, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, -69, -69, 0, 0, -69, -69, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, 0, -168, 0, 0, -168, 0, 0, 0, -168, 0, -168, 0, 0, 0, 0, 0, 0, 0, 0, -168, 0, 0, -168, -168, 0, 0, -168, -168, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 168
+        0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, 0, 0, 0, -170, 0, 0, -170, 0, 0, 0, -170, 0, -170, 0, 0, 0, 0, 0, 0, 0, 0, -170, 0, 0, -170, -170, 0, 0, -170, -170, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, -77, 0, 0, -77, 0, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, -77, -77, 0, 0, -77, -77, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, -130, -130, 0, -130, 0, 0, -130, 0, 0, 0, -130, 0, -130, -130, -130, 0, -130, 0, -130, -130, 0, -130, 0, 0, -130, -130, 0, 0, -130, -130, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, 0, 0, 0, 0, 0,
+        // State 173
+        0, -78, -78, 0, -78, 0, 0, -78, 0, 0, 0, -78, 0, -78, -78, -78, 0, -78, 0, -78, -78, 0, -78, 0, 0, -78, -78, 0, 0, -78, -78, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0, 0,
+        // State 174
+        0, -132, -132, 0, -132, 0, -132, -132, -132, 0, 0, -132, 0, -132, -132, -132, 0, -132, 0, -132, -132, 0, -132, 0, 0, -132, -132, 0, 0, -132, -132, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, 0, 0, 0, 0, 0,
+        // State 175
+        0, -76, -76, 0, -76, 0, -76, -76, -76, 0, 0, -76, 0, -76, -76, -76, 0, -76, 0, -76, -76, 0, -76, 0, 0, -76, -76, 0, 0, -76, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0, 0,
+        // State 176
+        0, -134, -134, 0, -134, -134, -134, -134, -134, 0, 0, -134, 0, -134, -134, -134, 0, -134, 0, -134, -134, 0, -134, 0, 0, -134, -134, 0, 0, -134, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, 0, 0, 0, 0, 0,
+        // State 177
+        0, -75, -75, 0, -75, -75, -75, -75, -75, 0, 0, -75, 0, -75, -75, -75, 0, -75, 0, -75, -75, 0, -75, 0, 0, -75, -75, 0, 0, -75, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0,
+        // State 178
+        0, 0, 0, 0, -115, 0, 0, -115, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, -115, -115, 0, 0, -115, -115, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0,
+        // State 179
+        0, 0, 0, 0, -40, 0, 0, -40, 0, 0, 0, -40, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, -40, -40, 0, 0, -40, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15,
+        // State 182
+        0, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, -172, 0, 0, -172, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -172, 0, 0, -172, -172, 0, 0, -172, -172, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, 0, -49, -49, -49, 0, -49, 0, -49, -49, -49, -49, 0, 0, -49, -49, 0, 0, -49, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, -49, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, -90, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, 0, -58, -58, -58, 0, -58, 0, -58, -58, -58, -58, 0, 0, -58, -58, 0, 0, -58, -58, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, -58, 0, 0, 0, 0, 0,
+        // State 187
+        -10, 0, 0, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, -10, 0, -10, 0, 0, -10, -10, 0, 0, -10, -10, -10, 0, 0, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, 0, -10, -10, -10, -10, 0, 0, 0, 0, -10, 0, -10, -10,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 189
+        0, 0, 0, 0, -96, 0, 0, 214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, -116, 0, 0, -116, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, -116, -116, 0, 0, -116, -116, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, -43, -43, 0, -43, -43, -43, -43, -43, 0, 0, -43, 59, -43, -43, -43, 0, -43, 0, -43, -43, -43, -43, 0, 0, -43, -43, 0, 0, -43, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0, 0,
+        // State 193
+        0, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, 0, -50, -50, -50, 0, -50, 0, -50, -50, -50, -50, 0, 0, -50, -50, 0, 0, -50, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, 0, 0, 0, 0, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, 0, -48, -48, -48, 0, -48, 0, -48, -48, -48, -48, 0, 0, -48, -48, 0, 0, -48, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, -48, 0, 0, 0, 0, 0,
+        // State 197
+        -11, 0, 0, -11, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, -11, 0, -11, 0, 0, -11, -11, 0, 0, -11, -11, -11, 0, 0, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, 0, -11, -11, -11, -11, 0, 0, 0, 0, -11, 0, -11, -11,
+        // State 198
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 222, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, 0, -182, 0, 0, -182, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 0, -104, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 202
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        0, 0, 0, 0, -177, 0, 0, -177, 0, 0, 0, -177, 0, 0, 0, 0, -177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -177, 0, 0, 0, 0,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 207
+        0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 208
+        0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 209
+        0, 0, 0, 0, -136, 0, 0, -136, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, -136, -136, 0, 0, -136, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0,
+        // State 210
+        0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 211
+        0, 0, 0, 0, -98, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 212
+        0, 0, 0, 0, -151, 0, 0, -151, 0, 0, 0, -151, 0, 0, 0, 0, -151, 0, -151, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 213
+        0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20, -20, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20,
+        // State 214
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0,
+        // State 215
+        0, 0, 0, 0, 233, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 217
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        0, 0, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 219
+        0, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, 0, -52, -52, -52, 0, -52, 0, -52, -52, -52, -52, 0, 0, -52, -52, 0, 0, -52, -52, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, 0, 0, 0, 0, 0,
+        // State 220
+        0, 0, 0, 0, -106, 0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0,
+        // State 221
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 222
+        0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30,
+        // State 223
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        0, 0, 0, 0, 245, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 226
+        0, 0, 0, 0, -100, 0, 0, 246, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 227
+        0, 0, 0, 0, -152, 0, 0, -152, 0, 0, 0, -152, 0, 0, 0, 0, -152, 0, -152, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21, -21, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21,
+        // State 229
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0,
+        // State 230
+        0, -57, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, 0, -57, -57, -57, 0, -57, 0, -57, -57, -57, -57, 0, 0, -57, -57, 0, 0, -57, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, 0, 0, 0, 0, 0,
+        // State 231
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 232
+        0, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, 0, -47, -47, -47, 0, -47, 0, -47, -47, -47, -47, 0, 0, -47, -47, 0, 0, -47, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0, 0, 0, 0, 0,
+        // State 233
+        0, -43, -43, 0, -43, -43, -43, -43, -43, 0, 0, -43, 0, -43, -43, -43, 0, -43, 0, -43, -43, -43, -43, 0, 0, -43, -43, 0, 0, -43, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0, 0,
+        // State 234
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 235
+        0, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, 0, -53, -53, -53, 0, -53, 0, -53, -53, -53, -53, 0, 0, -53, -53, 0, 0, -53, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, 0, 0, 0, 0, 0,
+        // State 236
+        0, 0, 0, 0, -39, 0, 0, -39, 0, 0, 0, -39, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, -39, -39, 0, 0, -39, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0,
+        // State 237
+        0, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, 0, -51, -51, -51, 0, -51, 0, -51, -51, -51, -51, 0, 0, -51, -51, 0, 0, -51, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, 0, 0, 0, 0, 0,
+        // State 238
+        0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31,
+        // State 239
+        0, 0, 0, 0, -181, 0, 0, -181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -181, 0, 0, 0, 0,
+        // State 240
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 250, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 241
+        0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 242
+        0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 243
+        0, 0, 0, 0, -102, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 244
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, -25, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25,
+        // State 246
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 247
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 248
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 249
+        0, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, 0, -54, -54, -54, 0, -54, 0, -54, -54, -54, -54, 0, 0, -54, -54, 0, 0, -54, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0,
+        // State 250
+        0, 0, 0, 0, -119, 0, 0, -119, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, -119, -119, 0, 0, -119, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0,
+        // State 251
+        0, 0, 0, -26, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26,
+        // State 252
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0,
+        // State 253
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 258, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 254
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 255
+        0, 0, 0, 0, 261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 256
+        0, 0, 0, 0, -178, 0, 0, -178, 0, 0, 0, -178, 0, 0, 0, 0, -178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -178, 0, 0, 0, 0,
+        // State 257
+        0, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, 0, -56, -56, -56, 0, -56, 0, -56, -56, -56, -56, 0, 0, -56, -56, 0, 0, -56, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, -56, 0, 0, 0, 0, 0,
+        // State 258
+        0, 0, 0, 0, -79, 0, 0, -79, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, -79, -79, 0, 0, -79, -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0,
+        // State 259
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 260
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 261
+        0, 0, 0, 0, -80, 0, 0, -80, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, -80, -80, 0, 0, -80, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0,
+        // State 262
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 263
+        0, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, 0, -55, -55, -55, 0, -55, 0, -55, -55, -55, -55, 0, 0, -55, -55, 0, 0, -55, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0,
+    ];
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 60 + integer]
+    }
+    const __EOF_ACTION: &[i16] = &[
+        // State 0
+        0,
+        // State 1
+        0,
+        // State 2
+        0,
+        // State 3
+        0,
+        // State 4
+        0,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        0,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        0,
+        // State 16
+        0,
+        // State 17
+        0,
+        // State 18
+        0,
+        // State 19
+        0,
+        // State 20
+        0,
+        // State 21
+        0,
+        // State 22
+        0,
+        // State 23
+        0,
+        // State 24
+        0,
+        // State 25
+        0,
+        // State 26
+        0,
+        // State 27
+        0,
+        // State 28
+        0,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        0,
+        // State 32
+        0,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        0,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        0,
+        // State 48
+        0,
+        // State 49
+        0,
+        // State 50
+        0,
+        // State 51
+        0,
+        // State 52
+        0,
+        // State 53
+        0,
+        // State 54
+        0,
+        // State 55
+        0,
+        // State 56
+        0,
+        // State 57
+        0,
+        // State 58
+        0,
+        // State 59
+        0,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        0,
+        // State 63
+        0,
+        // State 64
+        0,
+        // State 65
+        0,
+        // State 66
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        0,
+        // State 74
+        0,
+        // State 75
+        0,
+        // State 76
+        0,
+        // State 77
+        -169,
+        // State 78
+        -144,
+        // State 79
+        -42,
+        // State 80
+        -41,
+        // State 81
+        -45,
+        // State 82
+        0,
+        // State 83
+        0,
+        // State 84
+        -111,
+        // State 85
+        -171,
+        // State 86
+        -166,
+        // State 87
+        -110,
+        // State 88
+        0,
+        // State 89
+        -108,
+        // State 90
+        -44,
+        // State 91
+        0,
+        // State 92
+        -87,
+        // State 93
+        -175,
+        // State 94
+        -165,
+        // State 95
+        -109,
+        // State 96
+        -59,
+        // State 97
+        -135,
+        // State 98
+        -46,
+        // State 99
+        0,
+        // State 100
+        -133,
+        // State 101
+        -186,
+        // State 102
+        -173,
+        // State 103
+        -139,
+        // State 104
+        -112,
+        // State 105
+        -131,
+        // State 106
+        0,
+        // State 107
+        -66,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        -65,
+        // State 117
+        0,
+        // State 118
+        0,
+        // State 119
+        -145,
+        // State 120
+        -120,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        0,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        -167,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        -68,
+        // State 140
+        -143,
+        // State 141
+        -44,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        -71,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        0,
+        // State 155
+        0,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        -69,
+        // State 163
+        0,
+        // State 164
+        -168,
+        // State 165
+        0,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        -170,
+        // State 171
+        -77,
+        // State 172
+        -130,
+        // State 173
+        -78,
+        // State 174
+        -132,
+        // State 175
+        -76,
+        // State 176
+        -134,
+        // State 177
+        -75,
+        // State 178
+        -115,
+        // State 179
+        -40,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        -172,
+        // State 184
+        -49,
+        // State 185
+        0,
+        // State 186
+        -58,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        -116,
+        // State 192
+        -43,
+        // State 193
+        -50,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        -48,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        0,
+        // State 207
+        0,
+        // State 208
+        0,
+        // State 209
+        -136,
+        // State 210
+        0,
+        // State 211
+        0,
+        // State 212
+        0,
+        // State 213
+        0,
+        // State 214
+        0,
+        // State 215
+        0,
+        // State 216
+        0,
+        // State 217
+        0,
+        // State 218
+        0,
+        // State 219
+        -52,
+        // State 220
+        0,
+        // State 221
+        0,
+        // State 222
+        0,
+        // State 223
+        0,
+        // State 224
+        0,
+        // State 225
+        0,
+        // State 226
+        0,
+        // State 227
+        0,
+        // State 228
+        0,
+        // State 229
+        0,
+        // State 230
+        -57,
+        // State 231
+        0,
+        // State 232
+        -47,
+        // State 233
+        -43,
+        // State 234
+        0,
+        // State 235
+        -53,
+        // State 236
+        -39,
+        // State 237
+        -51,
+        // State 238
+        0,
+        // State 239
+        0,
+        // State 240
+        0,
+        // State 241
+        0,
+        // State 242
+        0,
+        // State 243
+        0,
+        // State 244
+        0,
+        // State 245
+        0,
+        // State 246
+        0,
+        // State 247
+        0,
+        // State 248
+        0,
+        // State 249
+        -54,
+        // State 250
+        -119,
+        // State 251
+        0,
+        // State 252
+        0,
+        // State 253
+        0,
+        // State 254
+        0,
+        // State 255
+        0,
+        // State 256
+        0,
+        // State 257
+        -56,
+        // State 258
+        -79,
+        // State 259
+        0,
+        // State 260
+        0,
+        // State 261
+        -80,
+        // State 262
+        0,
+        // State 263
+        -55,
+    ];
+    fn __goto(state: i16, nt: usize) -> i16 {
+        match nt {
+            6 => 33,
+            9 => 30,
+            12 => 47,
+            15 => 63,
+            18 => 53,
+            21 => match state {
+                64 => 70,
+                _ => 1,
+            },
+            24 => 77,
+            25 => 78,
+            26 => match state {
+                8 => 139,
+                _ => 79,
+            },
+            27 => match state {
+                19 | 29 | 58 => 162,
+                _ => 80,
+            },
+            28 => 152,
+            29 => match state {
+                44 => 208,
+                _ => 153,
+            },
+            30 => 154,
+            31 => match state {
+                16 | 34 | 44 | 46..=47 | 56 => 155,
+                _ => 81,
+            },
+            32 => match state {
+                19 | 24..=27 | 29 | 58 => 163,
+                _ => 82,
+            },
+            33 => match state {
+                8 => 140,
+                _ => 83,
+            },
+            34 => match state {
+                29 => 179,
+                58 => 236,
+                _ => 164,
+            },
+            35 => 2,
+            36 => match state {
+                17 => 160,
+                20 => 165,
+                21 => 167,
+                28 => 178,
+                31 => 182,
+                35 => 190,
+                36 => 191,
+                37 => 195,
+                40 => 202,
+                41 => 204,
+                45 => 209,
+                50 => 216,
+                51 => 217,
+                60 => 240,
+                61 => 241,
+                67 => 250,
+                69 => 253,
+                71 => 255,
+                73 => 258,
+                75 => 261,
+                76 => 262,
+                _ => 148,
+            },
+            37 => 3,
+            38 => 4,
+            39 => 5,
+            40 => 176,
+            41 => 174,
+            42 => 170,
+            43 => 172,
+            44 => 84,
+            45 => 25,
+            46 => 85,
+            47 => match state {
+                22 => 168,
+                49 => 215,
+                52 => 218,
+                _ => 143,
+            },
+            48 => 136,
+            49 => match state {
+                46 => 210,
+                _ => 188,
+            },
+            50 => 225,
+            51 => match state {
+                39 => 201,
+                _ => 198,
+            },
+            52 => match state {
+                0 => 86,
+                6 => 134,
+                9 => 142,
+                10 | 22 | 49 | 52 => 144,
+                32 => 183,
+                33 => 185,
+                43 => 207,
+                62 => 242,
+                66 => 248,
+                68 => 252,
+                74 => 259,
+                _ => 149,
+            },
+            54 => 87,
+            55 => match state {
+                1 => 121,
+                64 => 246,
+                70 => 254,
+                _ => 88,
+            },
+            56 => 89,
+            57 => match state {
+                7 => 137,
+                8 | 19 | 24..=27 | 29 | 58 => 141,
+                11 => 145,
+                12 => 146,
+                13 => 147,
+                16 | 34 | 44 | 46..=47 | 56 => 156,
+                18 => 161,
+                23 => 169,
+                30 => 180,
+                38..=39 | 53 => 199,
+                42 | 54 | 59 | 63 | 65 | 72 => 205,
+                57 => 234,
+                _ => 90,
+            },
+            59 => match state {
+                6 => 135,
+                _ => 91,
+            },
+            61 => 6,
+            62 => 7,
+            63 => 92,
+            64 => 93,
+            65 => 94,
+            66 => 95,
+            67 => 24,
+            68 => 96,
+            69 => match state {
+                55 => 229,
+                _ => 214,
+            },
+            70 => 55,
+            71 => match state {
+                27 => 177,
+                _ => 97,
+            },
+            72 => match state {
+                16 | 34 | 44 | 46..=47 | 56 => 157,
+                _ => 98,
+            },
+            73 => 138,
+            74 => match state {
+                34 | 46 => 189,
+                47 => 211,
+                56 => 231,
+                _ => 158,
+            },
+            76 => 26,
+            77 => 99,
+            78 => match state {
+                26 => 175,
+                _ => 100,
+            },
+            79 => 101,
+            80 => 102,
+            81 => match state {
+                24 => 171,
+                _ => 103,
+            },
+            82 => 104,
+            83 => 150,
+            84 => match state {
+                25 => 173,
+                _ => 105,
+            },
+            85 => 27,
+            86 => match state {
+                54 => 226,
+                59 => 239,
+                63 => 243,
+                65 => 247,
+                72 => 256,
+                _ => 206,
+            },
+            88 => match state {
+                53 => 220,
+                _ => 200,
+            },
+            _ => 0,
+        }
+    }
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
+        const __TERMINAL: &[&str] = &[
+            r###""!""###,
+            r###""!=""###,
+            r###""&&""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###"",""###,
+            r###""-""###,
+            r###""->""###,
+            r###"".""###,
+            r###"":""###,
+            r###"":=""###,
+            r###"";""###,
+            r###""<""###,
+            r###""<=""###,
+            r###""=""###,
+            r###""==""###,
+            r###""=>""###,
+            r###"">""###,
+            r###"">=""###,
+            r###""[""###,
+            r###""]""###,
+            r###""_""###,
+            r###""add1""###,
+            r###""and""###,
+            r###""catch""###,
+            r###""class""###,
+            r###""def""###,
+            r###""else""###,
+            r###""end""###,
+            r###""false""###,
+            r###""for""###,
+            r###""if""###,
+            r###""import""###,
+            r###""in""###,
+            r###""isarray""###,
+            r###""isbool""###,
+            r###""isfun""###,
+            r###""isnum""###,
+            r###""lambda""###,
+            r###""length""###,
+            r###""let""###,
+            r###""match""###,
+            r###""new""###,
+            r###""print""###,
+            r###""raise""###,
+            r###""range""###,
+            r###""sub1""###,
+            r###""true""###,
+            r###""try""###,
+            r###""while""###,
+            r###""{""###,
+            r###""|""###,
+            r###""||""###,
+            r###""}""###,
+            r###""λ""###,
+            r###"r#"\"[^\"\\n]*\""#"###,
+            r###"r#"[+-]?[0-9]+"#"###,
+            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
+        ];
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    pub(crate) struct __StateMachine<'input>
+    where 
+    {
+        input: &'input str,
+        __phantom: core::marker::PhantomData<(&'input ())>,
+    }
+    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
+    where 
+    {
+        type Location = usize;
+        type Error = &'static str;
+        type Token = Token<'input>;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<'input>;
+        type Success = (Vec<(String, Span1)>, SurfProg<Span1>);
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
+        type NonterminalIndex = usize;
+
+        #[inline]
+        fn start_location(&self) -> Self::Location {
+              Default::default()
+        }
+
+        #[inline]
+        fn start_state(&self) -> Self::StateIndex {
+              0
+        }
+
+        #[inline]
+        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
+            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+        }
+
+        #[inline]
+        fn action(&self, state: i16, integer: usize) -> i16 {
+            __action(state, integer)
+        }
+
+        #[inline]
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 60 - 1)
+        }
+
+        #[inline]
+        fn eof_action(&self, state: i16) -> i16 {
+            __EOF_ACTION[state as usize]
+        }
+
+        #[inline]
+        fn goto(&self, state: i16, nt: usize) -> i16 {
+            __goto(state, nt)
+        }
+
+        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+        }
+
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens(state)
+        }
+
+        #[inline]
+        fn uses_error_recovery(&self) -> bool {
+            false
+        }
+
+        #[inline]
+        fn error_recovery_symbol(
+            &self,
+            recovery: __state_machine::ErrorRecovery<Self>,
+        ) -> Self::Symbol {
+            panic!("error recovery not enabled for this grammar")
+        }
+
+        fn reduce(
+            &mut self,
+            action: i16,
+            start_location: Option<&Self::Location>,
+            states: &mut alloc::vec::Vec<i16>,
+            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
+        ) -> Option<__state_machine::ParseResult<Self>> {
+            __reduce(
+                self.input,
+                action,
+                start_location,
+                states,
+                symbols,
+                core::marker::PhantomData::<(&())>,
+            )
+        }
+
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
+            panic!("error recovery not enabled for this grammar")
+        }
+    }
+    fn __token_to_integer<
+        'input,
+    >(
+        __token: &Token<'input>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> Option<usize>
+    {
+        match *__token {
+            Token(3, _) if true => Some(0),
+            Token(4, _) if true => Some(1),
+            Token(5, _) if true => Some(2),
+            Token(6, _) if true => Some(3),
+            Token(7, _) if true => Some(4),
+            Token(8, _) if true => Some(5),
+            Token(9, _) if true => Some(6),
+            Token(10, _) if true => Some(7),
+            Token(11, _) if true => Some(8),
+            Token(12, _) if true => Some(9),
+            Token(13, _) if true => Some(10),
+            Token(14, _) if true => Some(11),
+            Token(15, _) if true => Some(12),
+            Token(16, _) if true => Some(13),
+            Token(17, _) if true => Some(14),
+            Token(18, _) if true => Some(15),
+            Token(19, _) if true => Some(16),
+            Token(20, _) if true => Some(17),
+            Token(21, _) if true => Some(18),
+            Token(22, _) if true => Some(19),
+            Token(23, _) if true => Some(20),
+            Token(24, _) if true => Some(21),
+            Token(25, _) if true => Some(22),
+            Token(26, _) if true => Some(23),
+            Token(27, _) if true => Some(24),
+            Token(28, _) if true => Some(25),
+            Token(29, _) if true => Some(26),
+            Token(30, _) if true => Some(27),
+            Token(31, _) if true => Some(28),
+            Token(32, _) if true => Some(29),
+            Token(33, _) if true => Some(30),
+            Token(34, _) if true => Some(31),
+            Token(35, _) if true => Some(32),
+            Token(36, _) if true => Some(33),
+            Token(37, _) if true => Some(34),
+            Token(38, _) if true => Some(35),
+            Token(39, _) if true => Some(36),
+            Token(40, _) if true => Some(37),
+            Token(41, _) if true => Some(38),
+            Token(42, _) if true => Some(39),
+            Token(43, _) if true => Some(40),
+            Token(44, _) if true => Some(41),
+            Token(45, _) if true => Some(42),
+            Token(46, _) if true => Some(43),
+            Token(47, _) if true => Some(44),
+            Token(48, _) if true => Some(45),
+            Token(49, _) if true => Some(46),
+            Token(50, _) if true => Some(47),
+            Token(51, _) if true => Some(48),
+            Token(52, _) if true => Some(49),
+            Token(53, _) if true => Some(50),
+            Token(54, _) if true => Some(51),
+            Token(55, _) if true => Some(52),
+            Token(56, _) if true => Some(53),
+            Token(57, _) if true => Some(54),
+            Token(58, _) if true => Some(55),
+            Token(59, _) if true => Some(56),
+            Token(0, _) if true => Some(57),
+            Token(1, _) if true => Some(58),
+            Token(2, _) if true => Some(59),
+            _ => None,
+        }
+    }
+    fn __token_to_symbol<
+        'input,
+    >(
+        __token_index: usize,
+        __token: Token<'input>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> __Symbol<'input>
+    {
+        match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 => match __token {
+                Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(41, __tok0) | Token(42, __tok0) | Token(43, __tok0) | Token(44, __tok0) | Token(45, __tok0) | Token(46, __tok0) | Token(47, __tok0) | Token(48, __tok0) | Token(49, __tok0) | Token(50, __tok0) | Token(51, __tok0) | Token(52, __tok0) | Token(53, __tok0) | Token(54, __tok0) | Token(55, __tok0) | Token(56, __tok0) | Token(57, __tok0) | Token(58, __tok0) | Token(59, __tok0) | Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) if true => __Symbol::Variant0(__tok0),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+    pub struct ProgParser {
+        builder: __lalrpop_util::lexer::MatcherBuilder,
+        _priv: (),
+    }
+
+    impl ProgParser {
+        pub fn new() -> ProgParser {
+            let __builder = super::__intern_token::new_builder();
+            ProgParser {
+                builder: __builder,
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+        >(
+            &self,
+            input: &'input str,
+        ) -> Result<(Vec<(String, Span1)>, SurfProg<Span1>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = self.builder.matcher(input);
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    input,
+                    __phantom: core::marker::PhantomData::<(&())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    pub(crate) fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> Option<Result<(Vec<(String, Span1)>, SurfProg<Span1>),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            1 => {
+                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            2 => {
+                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            3 => {
+                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            4 => {
+                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            5 => {
+                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            6 => {
+                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            7 => {
+                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            8 => {
+                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             9 => {
                 __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -5201,48 +7865,261 @@ mod __parse__Prog {
             100 => {
                 __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            101 => {
-                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            101 => {
+                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            102 => {
+                __reduce102(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            103 => {
+                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            104 => {
+                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            105 => {
+                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            106 => {
+                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            107 => {
+                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            108 => {
+                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            109 => {
+                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            110 => {
+                __reduce110(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            111 => {
+                __reduce111(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            112 => {
+                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            113 => {
+                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            114 => {
+                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            115 => {
+                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            116 => {
+                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            117 => {
+                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            118 => {
+                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            119 => {
+                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            120 => {
+                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            121 => {
+                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            122 => {
+                __reduce122(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            123 => {
+                __reduce123(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            124 => {
+                __reduce124(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            125 => {
+                __reduce125(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            126 => {
+                __reduce126(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            127 => {
+                __reduce127(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            128 => {
+                __reduce128(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            129 => {
+                __reduce129(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            130 => {
+                __reduce130(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            131 => {
+                __reduce131(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            132 => {
+                __reduce132(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            133 => {
+                __reduce133(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            134 => {
+                __reduce134(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            135 => {
+                __reduce135(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            136 => {
+                __reduce136(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            137 => {
+                __reduce137(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            138 => {
+                __reduce138(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            139 => {
+                __reduce139(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            140 => {
+                __reduce140(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            141 => {
+                __reduce141(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            142 => {
+                __reduce142(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            143 => {
+                __reduce143(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            144 => {
+                __reduce144(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            145 => {
+                __reduce145(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            146 => {
+                __reduce146(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            147 => {
+                __reduce147(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            148 => {
+                __reduce148(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            149 => {
+                __reduce149(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            150 => {
+                __reduce150(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            151 => {
+                __reduce151(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            152 => {
+                __reduce152(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            153 => {
+                __reduce153(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            154 => {
+                __reduce154(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            155 => {
+                __reduce155(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            156 => {
+                __reduce156(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            157 => {
+                __reduce157(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            158 => {
+                __reduce158(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            159 => {
+                __reduce159(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            160 => {
+                __reduce160(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            161 => {
+                __reduce161(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            162 => {
+                __reduce162(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            163 => {
+                __reduce163(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            164 => {
+                __reduce164(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            165 => {
+                __reduce165(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            166 => {
+                __reduce166(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            167 => {
+                __reduce167(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            168 => {
+                __reduce168(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            169 => {
+                __reduce169(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            170 => {
+                __reduce170(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            171 => {
+                __reduce171(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            172 => {
+                __reduce172(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            102 => {
-                __reduce102(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            173 => {
+                __reduce173(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            103 => {
-                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            174 => {
+                __reduce174(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            104 => {
-                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            175 => {
+                __reduce175(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            105 => {
-                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            176 => {
+                __reduce176(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            106 => {
-                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            177 => {
+                __reduce177(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            107 => {
-                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            178 => {
+                __reduce178(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            108 => {
-                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            179 => {
+                __reduce179(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            109 => {
-                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            180 => {
+                __reduce180(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            110 => {
-                __reduce110(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            181 => {
+                __reduce181(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            111 => {
-                __reduce111(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            182 => {
+                __reduce182(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            112 => {
-                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            183 => {
+                __reduce183(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            113 => {
-                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            184 => {
+                __reduce184(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            114 => {
+            185 => {
                 // __Prog = Prog => ActionFn(0);
-                let __sym0 = __pop_Variant20(__symbols);
+                let __sym0 = __pop_Variant35(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
                 let __nt = super::__action0::<>(input, __sym0);
@@ -5257,242 +8134,1824 @@ mod __parse__Prog {
         __states.push(__next_state);
         None
     }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant30<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Pattern<Span1>, Exp<Span1>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Option<SnakeType>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Span1), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant35<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Vec<(String, Span1)>, SurfProg<Span1>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant18<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Exp<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Pattern<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant34<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Prim1, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Prim2, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SnakeType, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant26<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfFunDecl<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<(Pattern<Span1>, Exp<Span1>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant24<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<(String, Option<SnakeType>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Pattern<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant23<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<SnakeType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant31<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant29<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(String, Span1)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Pattern<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<SnakeType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant36<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<(String, Option<SnakeType>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant25<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant33<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Pattern<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<SnakeType>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant27<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant32<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    pub(crate) fn __reduce0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ("->" <Type>) = "->", Type => ActionFn(105);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action105::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 0)
+    }
+    pub(crate) fn __reduce1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ("->" <Type>)? = "->", Type => ActionFn(166);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action166::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 1)
+    }
+    pub(crate) fn __reduce2<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ("->" <Type>)? =  => ActionFn(104);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action104::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 1)
+    }
+    pub(crate) fn __reduce3<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (":" <Type>) = ":", Type => ActionFn(108);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action108::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 2)
+    }
+    pub(crate) fn __reduce4<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (":" <Type>)? = ":", Type => ActionFn(169);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action169::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 3)
+    }
+    pub(crate) fn __reduce5<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (":" <Type>)? =  => ActionFn(107);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action107::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 3)
+    }
+    pub(crate) fn __reduce6<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",") = Exp, "," => ActionFn(150);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action150::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 4)
+    }
+    pub(crate) fn __reduce7<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")* =  => ActionFn(148);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action148::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 5)
+    }
+    pub(crate) fn __reduce8<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(149);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action149::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 5)
+    }
+    pub(crate) fn __reduce9<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")+ = Exp, "," => ActionFn(172);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action172::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 6)
+    }
+    pub(crate) fn __reduce10<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(173);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action173::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 6)
+    }
+    pub(crate) fn __reduce11<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",") = Id, "," => ActionFn(132);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action132::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 7)
+    }
+    pub(crate) fn __reduce12<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")* =  => ActionFn(130);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action130::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 8)
+    }
+    pub(crate) fn __reduce13<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")* = (<Id> ",")+ => ActionFn(131);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action131::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 8)
+    }
+    pub(crate) fn __reduce14<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")+ = Id, "," => ActionFn(176);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action176::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 9)
+    }
+    pub(crate) fn __reduce15<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(177);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action177::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 9)
+    }
+    pub(crate) fn __reduce16<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",") = Pattern, "," => ActionFn(155);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action155::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 10)
+    }
+    pub(crate) fn __reduce17<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",")* =  => ActionFn(153);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action153::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 11)
+    }
+    pub(crate) fn __reduce18<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",")* = (<Pattern> ",")+ => ActionFn(154);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action154::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce19<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",")+ = Pattern, "," => ActionFn(180);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action180::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 12)
+    }
+    pub(crate) fn __reduce20<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Pattern> ",")+ = (<Pattern> ",")+, Pattern, "," => ActionFn(181);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action181::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 12)
+    }
+    pub(crate) fn __reduce21<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Type> ",") = Type, "," => ActionFn(137);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action137::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 13)
+    }
+    pub(crate) fn __reduce22<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Type> ",")* =  => ActionFn(135);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action135::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 14)
+    }
+    pub(crate) fn __reduce23<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Type> ",")* = (<Type> ",")+ => ActionFn(136);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action136::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 14)
+    }
+    pub(crate) fn __reduce24<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Type> ",")+ = Type, "," => ActionFn(184);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action184::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 15)
+    }
+    pub(crate) fn __reduce25<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Type> ",")+ = (<Type> ",")+, Type, "," => ActionFn(185);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action185::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 15)
+    }
+    pub(crate) fn __reduce26<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypedParam> ",") = TypedParam, "," => ActionFn(127);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action127::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 16)
+    }
+    pub(crate) fn __reduce27<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypedParam> ",")* =  => ActionFn(125);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action125::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 17)
+    }
+    pub(crate) fn __reduce28<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypedParam> ",")* = (<TypedParam> ",")+ => ActionFn(126);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action126::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 17)
+    }
+    pub(crate) fn __reduce29<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypedParam> ",")+ = TypedParam, "," => ActionFn(188);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action188::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 18)
+    }
+    pub(crate) fn __reduce30<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<TypedParam> ",")+ = (<TypedParam> ",")+, TypedParam, "," => ActionFn(189);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action189::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 18)
+    }
+    pub(crate) fn __reduce31<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and") = FunDecl, "and" => ActionFn(114);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action114::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 19)
+    }
+    pub(crate) fn __reduce32<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")* =  => ActionFn(112);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action112::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 20)
+    }
+    pub(crate) fn __reduce33<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(113);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 20)
+    }
+    pub(crate) fn __reduce34<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(192);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action192::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 21)
+    }
+    pub(crate) fn __reduce35<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(193);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action193::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (3, 21)
+    }
+    pub(crate) fn __reduce36<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(116);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action116::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 22)
+    }
+    pub(crate) fn __reduce37<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(115);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action115::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 23)
+    }
+    pub(crate) fn __reduce38<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ASSIGN = Boxed<ArrExp>, "[", Boxed<Exp>, "]", ":=", Boxed<BinOps> => ActionFn(238);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant18(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action238::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (6, 24)
+    }
+    pub(crate) fn __reduce39<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ASSIGN = Id, ":=", Boxed<BinOps> => ActionFn(239);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action239::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 24)
+    }
+    pub(crate) fn __reduce40<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ASSIGN = BinOps => ActionFn(24);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 24)
+    }
+    pub(crate) fn __reduce41<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ArrExp = BaseExp => ActionFn(43);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 25)
+    }
+    pub(crate) fn __reduce42<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]" => ActionFn(240);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action240::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 25)
     }
-    fn __pop_Variant17<
-      'input,
+    pub(crate) fn __reduce43<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Id => ActionFn(241);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action241::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 26)
     }
-    fn __pop_Variant5<
-      'input,
+    pub(crate) fn __reduce44<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Bool => ActionFn(242);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action242::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 26)
     }
-    fn __pop_Variant10<
-      'input,
+    pub(crate) fn __reduce45<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Num => ActionFn(243);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action243::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce46<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "new", Id, "(", Comma<Exp>, ")" => ActionFn(244);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant20(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action244::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
+    }
+    pub(crate) fn __reduce47<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Prim1, "(", Boxed<Exp>, ")" => ActionFn(245);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant34(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action245::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 26)
+    }
+    pub(crate) fn __reduce48<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "(", Exp, ")" => ActionFn(50);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action50::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 26)
+    }
+    pub(crate) fn __reduce49<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Boxed<BaseExp>, "(", Comma<Exp>, ")" => ActionFn(246);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action246::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 26)
+    }
+    pub(crate) fn __reduce50<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Boxed<BaseExp>, ".", Id, "(", Comma<Exp>, ")" => ActionFn(247);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action247::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (6, 26)
+    }
+    pub(crate) fn __reduce51<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = LAMBDA, Params, ":", Boxed<Exp>, "end" => ActionFn(248);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action248::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
+    }
+    pub(crate) fn __reduce52<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "while", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(249);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action249::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
+    }
+    pub(crate) fn __reduce53<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "for", Id, "in", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(250);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant18(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action250::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (7, 26)
+    }
+    pub(crate) fn __reduce54<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "for", Id, "in", "range", "(", Boxed<Exp>, ",", Boxed<Exp>, ")", ":", Boxed<Exp>, "end" => ActionFn(251);
+        assert!(__symbols.len() >= 12);
+        let __sym11 = __pop_Variant0(__symbols);
+        let __sym10 = __pop_Variant18(__symbols);
+        let __sym9 = __pop_Variant0(__symbols);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant18(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant18(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym11.2.clone();
+        let __nt = super::__action251::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9, __sym10, __sym11);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (12, 26)
+    }
+    pub(crate) fn __reduce55<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "try", ":", Boxed<Exp>, "catch", Id, ":", Boxed<Exp>, "end" => ActionFn(252);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant18(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action252::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (8, 26)
+    }
+    pub(crate) fn __reduce56<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "match", Boxed<Exp>, ":", MatchArm+, "end" => ActionFn(253);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant31(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action253::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 26)
     }
-    fn __pop_Variant1<
-      'input,
+    pub(crate) fn __reduce57<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Exp<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "[", Comma<Exp>, "]" => ActionFn(254);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action254::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 26)
     }
-    fn __pop_Variant19<
-      'input,
+    pub(crate) fn __reduce58<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim1, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BinOps = LogExp => ActionFn(25);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 27)
     }
-    fn __pop_Variant11<
-      'input,
+    pub(crate) fn __reduce59<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim2, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BindExp = Pattern => ActionFn(81);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 28)
     }
-    fn __pop_Variant3<
-      'input,
+    pub(crate) fn __reduce60<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Binding = BindExp, "=", Exp => ActionFn(79);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action79::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 29)
     }
-    fn __pop_Variant15<
-      'input,
+    pub(crate) fn __reduce61<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfFunDecl<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Binding = BindExp, ":", Type, "=", Exp => ActionFn(255);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant3(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action255::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (5, 29)
     }
-    fn __pop_Variant20<
-      'input,
+    pub(crate) fn __reduce62<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfProg<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Bindings = Binding => ActionFn(77);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 30)
     }
-    fn __pop_Variant8<
-      'input,
+    pub(crate) fn __reduce63<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Bindings = Bindings, ",", Binding => ActionFn(78);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action78::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 30)
     }
-    fn __pop_Variant12<
-      'input,
+    pub(crate) fn __reduce64<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Bool = "true" => ActionFn(85);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 31)
     }
-    fn __pop_Variant13<
-      'input,
+    pub(crate) fn __reduce65<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Bool = "false" => ActionFn(86);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action86::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 31)
     }
-    fn __pop_Variant6<
-      'input,
+    pub(crate) fn __reduce66<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<ArrExp> = ArrExp => ActionFn(100);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action100::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 32)
     }
-    fn __pop_Variant2<
-      'input,
+    pub(crate) fn __reduce67<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<BaseExp> = BaseExp => ActionFn(91);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action91::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 33)
     }
-    fn __pop_Variant4<
-      'input,
+    pub(crate) fn __reduce68<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<BinOps> = BinOps => ActionFn(101);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action101::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 34)
     }
-    fn __pop_Variant9<
-      'input,
+    pub(crate) fn __reduce69<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<CmpExp> = CmpExp => ActionFn(139);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action139::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
-    fn __pop_Variant14<
-      'input,
+    pub(crate) fn __reduce70<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<Exp> = Exp => ActionFn(111);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action111::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 36)
     }
-    fn __pop_Variant16<
-      'input,
+    pub(crate) fn __reduce71<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<LAssoc<Cmp, Sum>> = LAssoc<Cmp, Sum> => ActionFn(141);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action141::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 37)
     }
-    fn __pop_Variant18<
-      'input,
+    pub(crate) fn __reduce72<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<LAssoc<PlusMinus, Product>> = LAssoc<PlusMinus, Product> => ActionFn(143);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action143::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 38)
     }
-    fn __pop_Variant7<
-      'input,
+    pub(crate) fn __reduce73<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<LAssoc<Times, NotExp>> = LAssoc<Times, NotExp> => ActionFn(145);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action145::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 39)
     }
-    fn __pop_Variant0<
-      'input,
+    pub(crate) fn __reduce74<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<NotExp> = NotExp => ActionFn(144);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action144::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce0<
+    pub(crate) fn __reduce75<
         'input,
     >(
         input: &'input str,
@@ -5501,17 +9960,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",") = Exp, "," => ActionFn(102);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<Product> = Product => ActionFn(142);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action102::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action142::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 41)
     }
-    pub(crate) fn __reduce1<
+    pub(crate) fn __reduce76<
         'input,
     >(
         input: &'input str,
@@ -5520,14 +9977,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* =  => ActionFn(100);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action100::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        // Boxed<RAssoc<Log, CmpExp>> = RAssoc<Log, CmpExp> => ActionFn(138);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action138::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 42)
     }
-    pub(crate) fn __reduce2<
+    pub(crate) fn __reduce77<
         'input,
     >(
         input: &'input str,
@@ -5536,15 +9994,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(101);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Boxed<Sum> = Sum => ActionFn(140);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action101::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        let __nt = super::__action140::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce3<
+    pub(crate) fn __reduce78<
         'input,
     >(
         input: &'input str,
@@ -5553,17 +10011,24 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = Exp, "," => ActionFn(107);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // CLASSDEF = "class", Id, "{", Comma<TypedParam>, "}", ":", FunDecl, "in", Boxed<Exp> => ActionFn(256);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant18(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant26(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action107::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        let __end = __sym8.2.clone();
+        let __nt = super::__action256::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (9, 44)
     }
-    pub(crate) fn __reduce4<
+    pub(crate) fn __reduce79<
         'input,
     >(
         input: &'input str,
@@ -5572,18 +10037,25 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(108);
-        assert!(__symbols.len() >= 3);
+        // CLASSDEF = "class", Id, "{", Comma<TypedParam>, "}", ":", (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(257);
+        assert!(__symbols.len() >= 10);
+        let __sym9 = __pop_Variant18(__symbols);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant26(__symbols);
+        let __sym6 = __pop_Variant13(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action108::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        let __end = __sym9.2.clone();
+        let __nt = super::__action257::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (10, 44)
     }
-    pub(crate) fn __reduce5<
+    pub(crate) fn __reduce80<
         'input,
     >(
         input: &'input str,
@@ -5592,17 +10064,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",") = Id, "," => ActionFn(89);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Cmp = "<" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action89::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce6<
+    pub(crate) fn __reduce81<
         'input,
     >(
         input: &'input str,
@@ -5611,14 +10081,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* =  => ActionFn(87);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action87::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        // Cmp = "<=" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce7<
+    pub(crate) fn __reduce82<
         'input,
     >(
         input: &'input str,
@@ -5627,15 +10098,32 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* = (<Id> ",")+ => ActionFn(88);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Cmp = ">" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
+    }
+    pub(crate) fn __reduce83<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Cmp = ">=" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action88::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        let __nt = super::__action38::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce8<
+    pub(crate) fn __reduce84<
         'input,
     >(
         input: &'input str,
@@ -5644,17 +10132,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = Id, "," => ActionFn(111);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Cmp = "==" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action111::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 5)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce9<
+    pub(crate) fn __reduce85<
         'input,
     >(
         input: &'input str,
@@ -5663,18 +10149,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(112);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Cmp = "!=" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action112::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce86<
         'input,
     >(
         input: &'input str,
@@ -5683,17 +10166,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and") = FunDecl, "and" => ActionFn(81);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(27);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action81::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce87<
         'input,
     >(
         input: &'input str,
@@ -5702,14 +10183,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* =  => ActionFn(79);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action79::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        // Comma<Exp> = Exp => ActionFn(278);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action278::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce88<
         'input,
     >(
         input: &'input str,
@@ -5718,15 +10200,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(80);
-        let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action80::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        // Comma<Exp> =  => ActionFn(279);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action279::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 47)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce89<
         'input,
     >(
         input: &'input str,
@@ -5735,17 +10216,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(115);
+        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(280);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action115::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        let __nt = super::__action280::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 47)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce90<
         'input,
     >(
         input: &'input str,
@@ -5754,18 +10235,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(116);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Comma<Exp> = (<Exp> ",")+ => ActionFn(281);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action281::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce91<
         'input,
     >(
         input: &'input str,
@@ -5774,14 +10252,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(82);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action82::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 9)
+        // Comma<Id> = Id => ActionFn(282);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action282::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 48)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce92<
         'input,
     >(
         input: &'input str,
@@ -5790,14 +10269,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(77);
+        // Comma<Id> =  => ActionFn(283);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action77::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 10)
+        let __nt = super::__action283::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (0, 48)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce93<
         'input,
     >(
         input: &'input str,
@@ -5806,15 +10285,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = BaseExp => ActionFn(34);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<Id> = (<Id> ",")+, Id => ActionFn(284);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action284::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 48)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce94<
         'input,
     >(
         input: &'input str,
@@ -5823,19 +10304,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]" => ActionFn(146);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Comma<Id> = (<Id> ",")+ => ActionFn(285);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action146::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action285::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 48)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce95<
         'input,
     >(
         input: &'input str,
@@ -5844,21 +10321,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]", ":=", Boxed<BaseExp> => ActionFn(147);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant10(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Comma<Pattern> = Pattern => ActionFn(288);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action147::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (6, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action288::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 49)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce96<
         'input,
     >(
         input: &'input str,
@@ -5867,18 +10338,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ArrExp = Id, ":=", Boxed<BaseExp> => ActionFn(148);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action148::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 11)
+        // Comma<Pattern> =  => ActionFn(289);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action289::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 49)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce97<
         'input,
     >(
         input: &'input str,
@@ -5887,15 +10354,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id => ActionFn(149);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Comma<Pattern> = (<Pattern> ",")+, Pattern => ActionFn(290);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action149::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action290::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 49)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce98<
         'input,
     >(
         input: &'input str,
@@ -5904,15 +10373,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Bool => ActionFn(150);
-        let __sym0 = __pop_Variant9(__symbols);
+        // Comma<Pattern> = (<Pattern> ",")+ => ActionFn(291);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action150::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __nt = super::__action291::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 49)
     }
-    pub(crate) fn __reduce23<
+    pub(crate) fn __reduce99<
         'input,
     >(
         input: &'input str,
@@ -5921,15 +10390,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Num => ActionFn(151);
-        let __sym0 = __pop_Variant18(__symbols);
+        // Comma<Type> = Type => ActionFn(292);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action151::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __nt = super::__action292::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 50)
     }
-    pub(crate) fn __reduce24<
+    pub(crate) fn __reduce100<
         'input,
     >(
         input: &'input str,
@@ -5938,20 +10407,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "new", Id, "(", Comma<Exp>, ")" => ActionFn(152);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action152::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 12)
+        // Comma<Type> =  => ActionFn(293);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action293::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (0, 50)
     }
-    pub(crate) fn __reduce25<
+    pub(crate) fn __reduce101<
         'input,
     >(
         input: &'input str,
@@ -5960,19 +10423,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Prim1, "(", Boxed<Exp>, ")" => ActionFn(153);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        // Comma<Type> = (<Type> ",")+, Type => ActionFn(294);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action153::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action294::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 50)
     }
-    pub(crate) fn __reduce26<
+    pub(crate) fn __reduce102<
         'input,
     >(
         input: &'input str,
@@ -5981,18 +10442,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "(", Exp, ")" => ActionFn(43);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Type> = (<Type> ",")+ => ActionFn(295);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action43::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action295::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 50)
     }
-    pub(crate) fn __reduce27<
+    pub(crate) fn __reduce103<
         'input,
     >(
         input: &'input str,
@@ -6001,19 +10459,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Boxed<BaseExp>, "(", Comma<Exp>, ")" => ActionFn(154);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Comma<TypedParam> = TypedParam => ActionFn(296);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action154::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 12)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action296::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 51)
     }
-    pub(crate) fn __reduce28<
+    pub(crate) fn __reduce104<
         'input,
     >(
         input: &'input str,
@@ -6022,21 +10476,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Boxed<BaseExp>, ".", Id, "(", Comma<Exp>, ")" => ActionFn(155);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant12(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action155::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (6, 12)
+        // Comma<TypedParam> =  => ActionFn(297);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action297::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (0, 51)
     }
-    pub(crate) fn __reduce29<
+    pub(crate) fn __reduce105<
         'input,
     >(
         input: &'input str,
@@ -6045,20 +10492,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = LAMBDA, Params, ":", Boxed<Exp>, "end" => ActionFn(156);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
+        // Comma<TypedParam> = (<TypedParam> ",")+, TypedParam => ActionFn(298);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action156::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action298::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (2, 51)
     }
-    pub(crate) fn __reduce30<
+    pub(crate) fn __reduce106<
         'input,
     >(
         input: &'input str,
@@ -6067,18 +10511,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "[", Comma<Exp>, "]" => ActionFn(157);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<TypedParam> = (<TypedParam> ",")+ => ActionFn(299);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action157::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action299::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 51)
     }
-    pub(crate) fn __reduce31<
+    pub(crate) fn __reduce107<
         'input,
     >(
         input: &'input str,
@@ -6087,15 +10528,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExp => ActionFn(16);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = IF => ActionFn(11);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 13)
+        let __nt = super::__action11::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce32<
+    pub(crate) fn __reduce108<
         'input,
     >(
         input: &'input str,
@@ -6104,15 +10545,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BindExp = Id => ActionFn(60);
+        // Exp = LET => ActionFn(12);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action60::<>(input, __sym0);
+        let __nt = super::__action12::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        (1, 52)
     }
-    pub(crate) fn __reduce33<
+    pub(crate) fn __reduce109<
         'input,
     >(
         input: &'input str,
@@ -6121,18 +10562,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = BindExp, "=", Exp => ActionFn(58);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Exp = FUNDEFS => ActionFn(13);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action58::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 15)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce34<
+    pub(crate) fn __reduce110<
         'input,
     >(
         input: &'input str,
@@ -6141,20 +10579,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Bindings, ",", BindExp, "=", Exp => ActionFn(59);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Exp = CLASSDEF => ActionFn(14);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action59::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (5, 15)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce35<
+    pub(crate) fn __reduce111<
         'input,
     >(
         input: &'input str,
@@ -6163,15 +10596,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(63);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp = SEQ => ActionFn(15);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action63::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        let __nt = super::__action15::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce36<
+    pub(crate) fn __reduce112<
         'input,
     >(
         input: &'input str,
@@ -6180,15 +10613,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(64);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp? = Exp => ActionFn(146);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        let __nt = super::__action146::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 53)
     }
-    pub(crate) fn __reduce37<
+    pub(crate) fn __reduce113<
         'input,
     >(
         input: &'input str,
@@ -6197,15 +10630,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<ArrExp> = ArrExp => ActionFn(66);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action66::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
+        // Exp? =  => ActionFn(147);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action147::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 53)
     }
-    pub(crate) fn __reduce38<
+    pub(crate) fn __reduce114<
         'input,
     >(
         input: &'input str,
@@ -6214,15 +10646,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<BaseExp> = BaseExp => ActionFn(67);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(258);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 18)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action258::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 54)
     }
-    pub(crate) fn __reduce39<
+    pub(crate) fn __reduce115<
         'input,
     >(
         input: &'input str,
@@ -6231,15 +10666,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<CmpExp> = CmpExp => ActionFn(91);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(259);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action91::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 19)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action259::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 54)
     }
-    pub(crate) fn __reduce40<
+    pub(crate) fn __reduce116<
         'input,
     >(
         input: &'input str,
@@ -6248,15 +10687,24 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Exp> = Exp => ActionFn(78);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FunDecl = "def", Id, "(", Comma<TypedParam>, ")", "->", Type, ":", Exp => ActionFn(260);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant3(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant1(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action78::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 20)
+        let __end = __sym8.2.clone();
+        let __nt = super::__action260::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (9, 55)
     }
-    pub(crate) fn __reduce41<
+    pub(crate) fn __reduce117<
         'input,
     >(
         input: &'input str,
@@ -6265,15 +10713,22 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<Cmp, Sum>> = LAssoc<Cmp, Sum> => ActionFn(93);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FunDecl = "def", Id, "(", Comma<TypedParam>, ")", ":", Exp => ActionFn(261);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant3(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant24(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action93::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 21)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action261::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (7, 55)
     }
-    pub(crate) fn __reduce42<
+    pub(crate) fn __reduce118<
         'input,
     >(
         input: &'input str,
@@ -6282,15 +10737,22 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<PlusMinus, Product>> = LAssoc<PlusMinus, Product> => ActionFn(95);
-        let __sym0 = __pop_Variant1(__symbols);
+        // IF = "if", Boxed<Exp>, ":", Boxed<Exp>, "else", ":", Boxed<Exp> => ActionFn(262);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant18(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action95::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 22)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action262::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (7, 56)
     }
-    pub(crate) fn __reduce43<
+    pub(crate) fn __reduce119<
         'input,
     >(
         input: &'input str,
@@ -6299,15 +10761,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<Times, NotExp>> = LAssoc<Times, NotExp> => ActionFn(97);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(84);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action97::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 23)
+        let __nt = super::__action84::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce44<
+    pub(crate) fn __reduce120<
         'input,
     >(
         input: &'input str,
@@ -6316,15 +10778,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<NotExp> = NotExp => ActionFn(96);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Id? = Id => ActionFn(128);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action96::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 24)
+        let __nt = super::__action128::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 58)
     }
-    pub(crate) fn __reduce45<
+    pub(crate) fn __reduce121<
         'input,
     >(
         input: &'input str,
@@ -6333,15 +10795,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Product> = Product => ActionFn(94);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action94::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 25)
+        // Id? =  => ActionFn(129);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action129::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (0, 58)
     }
-    pub(crate) fn __reduce46<
+    pub(crate) fn __reduce122<
         'input,
     >(
         input: &'input str,
@@ -6350,15 +10811,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<RAssoc<Log, CmpExp>> = RAssoc<Log, CmpExp> => ActionFn(90);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Import = "import", Str => ActionFn(263);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action90::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 26)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action263::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (2, 59)
     }
-    pub(crate) fn __reduce47<
+    pub(crate) fn __reduce123<
         'input,
     >(
         input: &'input str,
@@ -6367,15 +10830,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Sum> = Sum => ActionFn(92);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action92::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 27)
+        // Import* =  => ActionFn(117);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action117::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (0, 60)
     }
-    pub(crate) fn __reduce48<
+    pub(crate) fn __reduce124<
         'input,
     >(
         input: &'input str,
@@ -6384,24 +10846,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CLASSDEF = "class", Id, "{", Comma<Id>, "}", ":", FunDecl, "in", Boxed<Exp> => ActionFn(158);
-        assert!(__symbols.len() >= 9);
-        let __sym8 = __pop_Variant10(__symbols);
-        let __sym7 = __pop_Variant0(__symbols);
-        let __sym6 = __pop_Variant15(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Import* = Import+ => ActionFn(118);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym8.2.clone();
-        let __nt = super::__action158::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (9, 28)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action118::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 60)
     }
-    pub(crate) fn __reduce49<
+    pub(crate) fn __reduce125<
         'input,
     >(
         input: &'input str,
@@ -6410,25 +10863,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CLASSDEF = "class", Id, "{", Comma<Id>, "}", ":", (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(159);
-        assert!(__symbols.len() >= 10);
-        let __sym9 = __pop_Variant10(__symbols);
-        let __sym8 = __pop_Variant0(__symbols);
-        let __sym7 = __pop_Variant15(__symbols);
-        let __sym6 = __pop_Variant6(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Import+ = Import => ActionFn(119);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym9.2.clone();
-        let __nt = super::__action159::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (10, 28)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action119::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 61)
     }
-    pub(crate) fn __reduce50<
+    pub(crate) fn __reduce126<
         'input,
     >(
         input: &'input str,
@@ -6437,15 +10880,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Import+ = Import+, Import => ActionFn(120);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action120::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (2, 61)
     }
-    pub(crate) fn __reduce51<
+    pub(crate) fn __reduce127<
         'input,
     >(
         input: &'input str,
@@ -6454,15 +10899,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(27);
+        // LAMBDA = "lambda" => ActionFn(67);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __nt = super::__action67::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce52<
+    pub(crate) fn __reduce128<
         'input,
     >(
         input: &'input str,
@@ -6471,15 +10916,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(28);
+        // LAMBDA = "λ" => ActionFn(68);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __nt = super::__action68::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 62)
     }
-    pub(crate) fn __reduce53<
+    pub(crate) fn __reduce129<
         'input,
     >(
         input: &'input str,
@@ -6488,15 +10933,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<Cmp, Sum> = Boxed<LAssoc<Cmp, Sum>>, Cmp, Boxed<Sum> => ActionFn(264);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action264::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 63)
     }
-    pub(crate) fn __reduce54<
+    pub(crate) fn __reduce130<
         'input,
     >(
         input: &'input str,
@@ -6505,15 +10953,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(30);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<Cmp, Sum> = Sum => ActionFn(97);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __nt = super::__action97::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 63)
     }
-    pub(crate) fn __reduce55<
+    pub(crate) fn __reduce131<
         'input,
     >(
         input: &'input str,
@@ -6522,15 +10970,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(31);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<PlusMinus, Product> = Boxed<LAssoc<PlusMinus, Product>>, PlusMinus, Boxed<Product> => ActionFn(265);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action31::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 29)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action265::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 64)
     }
-    pub(crate) fn __reduce56<
+    pub(crate) fn __reduce132<
         'input,
     >(
         input: &'input str,
@@ -6539,15 +10990,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(18);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<PlusMinus, Product> = Product => ActionFn(95);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 30)
+        let __nt = super::__action95::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 64)
     }
-    pub(crate) fn __reduce57<
+    pub(crate) fn __reduce133<
         'input,
     >(
         input: &'input str,
@@ -6556,15 +11007,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = Exp => ActionFn(171);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<Times, NotExp> = Boxed<LAssoc<Times, NotExp>>, Times, Boxed<NotExp> => ActionFn(266);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action171::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 31)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action266::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 65)
     }
-    pub(crate) fn __reduce58<
+    pub(crate) fn __reduce134<
         'input,
     >(
         input: &'input str,
@@ -6573,14 +11027,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> =  => ActionFn(172);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action172::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 31)
+        // LAssoc<Times, NotExp> = NotExp => ActionFn(93);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action93::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 65)
     }
-    pub(crate) fn __reduce59<
+    pub(crate) fn __reduce135<
         'input,
     >(
         input: &'input str,
@@ -6589,17 +11044,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(173);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // LET = "let", Bindings, "in", Boxed<Exp> => ActionFn(267);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action173::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 31)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action267::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 66)
     }
-    pub(crate) fn __reduce60<
+    pub(crate) fn __reduce136<
         'input,
     >(
         input: &'input str,
@@ -6608,15 +11065,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+ => ActionFn(174);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Log = "&&" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action174::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 31)
+        let __nt = super::__action33::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce61<
+    pub(crate) fn __reduce137<
         'input,
     >(
         input: &'input str,
@@ -6625,15 +11082,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = Id => ActionFn(175);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Log = "||" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action175::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 32)
+        let __nt = super::__action34::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce62<
+    pub(crate) fn __reduce138<
         'input,
     >(
         input: &'input str,
@@ -6642,14 +11099,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> =  => ActionFn(176);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action176::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 32)
+        // LogExp = RAssoc<Log, CmpExp> => ActionFn(26);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 68)
     }
-    pub(crate) fn __reduce63<
+    pub(crate) fn __reduce139<
         'input,
     >(
         input: &'input str,
@@ -6658,17 +11116,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+, Id => ActionFn(177);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // MatchArm = "|", Pattern, "=>", Exp => ActionFn(60);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action177::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 32)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action60::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 69)
     }
-    pub(crate) fn __reduce64<
+    pub(crate) fn __reduce140<
         'input,
     >(
         input: &'input str,
@@ -6677,15 +11137,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+ => ActionFn(178);
-        let __sym0 = __pop_Variant4(__symbols);
+        // MatchArm+ = MatchArm => ActionFn(88);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action178::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 32)
+        let __nt = super::__action88::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 70)
     }
-    pub(crate) fn __reduce65<
+    pub(crate) fn __reduce141<
         'input,
     >(
         input: &'input str,
@@ -6694,15 +11154,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = IF => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // MatchArm+ = MatchArm+, MatchArm => ActionFn(89);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant31(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action89::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (2, 70)
     }
-    pub(crate) fn __reduce66<
+    pub(crate) fn __reduce142<
         'input,
     >(
         input: &'input str,
@@ -6711,15 +11173,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = LET => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // NotExp = "!", Boxed<BaseExp> => ActionFn(268);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action268::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 71)
     }
-    pub(crate) fn __reduce67<
+    pub(crate) fn __reduce143<
         'input,
     >(
         input: &'input str,
@@ -6728,15 +11192,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = FUNDEFS => ActionFn(9);
-        let __sym0 = __pop_Variant1(__symbols);
+        // NotExp = ArrExp => ActionFn(42);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action42::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 71)
     }
-    pub(crate) fn __reduce68<
+    pub(crate) fn __reduce144<
         'input,
     >(
         input: &'input str,
@@ -6745,15 +11209,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = CLASSDEF => ActionFn(10);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Num = r#"[+-]?[0-9]+"# => ActionFn(82);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action82::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 72)
     }
-    pub(crate) fn __reduce69<
+    pub(crate) fn __reduce145<
         'input,
     >(
         input: &'input str,
@@ -6762,15 +11226,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp = SEQ => ActionFn(11);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Params = Comma<Id> => ActionFn(6);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action6::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 73)
     }
-    pub(crate) fn __reduce70<
+    pub(crate) fn __reduce146<
         'input,
     >(
         input: &'input str,
@@ -6779,15 +11243,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp? = Exp => ActionFn(98);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Pattern = "_" => ActionFn(269);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action98::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 34)
+        let __nt = super::__action269::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
     }
-    pub(crate) fn __reduce71<
+    pub(crate) fn __reduce147<
         'input,
     >(
         input: &'input str,
@@ -6796,14 +11260,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Exp? =  => ActionFn(99);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action99::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 34)
+        // Pattern = Id => ActionFn(270);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action270::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
     }
-    pub(crate) fn __reduce72<
+    pub(crate) fn __reduce148<
         'input,
     >(
         input: &'input str,
@@ -6812,18 +11277,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(160);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // Pattern = Num => ActionFn(271);
+        let __sym0 = __pop_Variant32(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action160::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 35)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action271::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
     }
-    pub(crate) fn __reduce73<
+    pub(crate) fn __reduce149<
         'input,
     >(
         input: &'input str,
@@ -6832,19 +11294,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(161);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Pattern = Bool => ActionFn(272);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action161::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 35)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action272::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 74)
     }
-    pub(crate) fn __reduce74<
+    pub(crate) fn __reduce150<
         'input,
     >(
         input: &'input str,
@@ -6853,22 +11311,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Params, ")", ":", Exp => ActionFn(162);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
+        // Pattern = "[", Comma<Pattern>, "]" => ActionFn(273);
+        assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action162::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (7, 36)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action273::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 74)
     }
-    pub(crate) fn __reduce75<
+    pub(crate) fn __reduce151<
         'input,
     >(
         input: &'input str,
@@ -6877,22 +11331,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // IF = "if", Boxed<Exp>, ":", Boxed<Exp>, "else", ":", Boxed<Exp> => ActionFn(163);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant10(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Pattern = Id, "(", Comma<Pattern>, ")" => ActionFn(274);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant22(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action163::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 37)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action274::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 74)
     }
-    pub(crate) fn __reduce76<
+    pub(crate) fn __reduce152<
         'input,
     >(
         input: &'input str,
@@ -6901,15 +11352,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(62);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Pattern? = Pattern => ActionFn(151);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action62::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 38)
+        let __nt = super::__action151::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 75)
     }
-    pub(crate) fn __reduce77<
+    pub(crate) fn __reduce153<
         'input,
     >(
         input: &'input str,
@@ -6918,15 +11369,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id? = Id => ActionFn(85);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action85::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 39)
+        // Pattern? =  => ActionFn(152);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action152::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (0, 75)
     }
-    pub(crate) fn __reduce78<
+    pub(crate) fn __reduce154<
         'input,
     >(
         input: &'input str,
@@ -6935,14 +11385,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id? =  => ActionFn(86);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action86::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 39)
+        // PlusMinus = "+" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 76)
     }
-    pub(crate) fn __reduce79<
+    pub(crate) fn __reduce155<
         'input,
     >(
         input: &'input str,
@@ -6951,15 +11402,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAMBDA = "lambda" => ActionFn(48);
+        // PlusMinus = "-" => ActionFn(31);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 40)
+        let __nt = super::__action31::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 76)
     }
-    pub(crate) fn __reduce80<
+    pub(crate) fn __reduce156<
         'input,
     >(
         input: &'input str,
@@ -6968,15 +11419,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAMBDA = "λ" => ActionFn(49);
+        // Prim1 = "add1" => ActionFn(69);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action49::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 40)
+        let __nt = super::__action69::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce81<
+    pub(crate) fn __reduce157<
         'input,
     >(
         input: &'input str,
@@ -6985,18 +11436,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Boxed<LAssoc<Cmp, Sum>>, Cmp, Boxed<Sum> => ActionFn(164);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Prim1 = "sub1" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action164::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 41)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce82<
+    pub(crate) fn __reduce158<
         'input,
     >(
         input: &'input str,
@@ -7005,15 +11453,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Sum => ActionFn(73);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "print" => ActionFn(71);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action73::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 41)
+        let __nt = super::__action71::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce83<
+    pub(crate) fn __reduce159<
         'input,
     >(
         input: &'input str,
@@ -7022,18 +11470,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Boxed<LAssoc<PlusMinus, Product>>, PlusMinus, Boxed<Product> => ActionFn(165);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Prim1 = "isnum" => ActionFn(72);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action165::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 42)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce84<
+    pub(crate) fn __reduce160<
         'input,
     >(
         input: &'input str,
@@ -7042,15 +11487,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Product => ActionFn(71);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "isbool" => ActionFn(73);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action71::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 42)
+        let __nt = super::__action73::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce85<
+    pub(crate) fn __reduce161<
         'input,
     >(
         input: &'input str,
@@ -7059,18 +11504,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = Boxed<LAssoc<Times, NotExp>>, Times, Boxed<NotExp> => ActionFn(166);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Prim1 = "isarray" => ActionFn(74);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action166::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 43)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce86<
+    pub(crate) fn __reduce162<
         'input,
     >(
         input: &'input str,
@@ -7079,15 +11521,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = NotExp => ActionFn(69);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "length" => ActionFn(75);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 43)
+        let __nt = super::__action75::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce87<
+    pub(crate) fn __reduce163<
         'input,
     >(
         input: &'input str,
@@ -7096,19 +11538,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LET = "let", Bindings, "in", Boxed<Exp> => ActionFn(167);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
+        // Prim1 = "isfun" => ActionFn(76);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action167::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 44)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 77)
     }
-    pub(crate) fn __reduce88<
+    pub(crate) fn __reduce164<
         'input,
     >(
         input: &'input str,
@@ -7117,15 +11555,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Product = LAssoc<Times, NotExp> => ActionFn(29);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 45)
+        let __nt = super::__action29::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 78)
     }
-    pub(crate) fn __reduce89<
+    pub(crate) fn __reduce165<
         'input,
     >(
         input: &'input str,
@@ -7134,15 +11572,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Prog = Exp => ActionFn(286);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 45)
+        let __nt = super::__action286::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 79)
     }
-    pub(crate) fn __reduce90<
+    pub(crate) fn __reduce166<
         'input,
     >(
         input: &'input str,
@@ -7151,15 +11589,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LogExp = RAssoc<Log, CmpExp> => ActionFn(17);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prog = Import+, Exp => ActionFn(287);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 46)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action287::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (2, 79)
     }
-    pub(crate) fn __reduce91<
+    pub(crate) fn __reduce167<
         'input,
     >(
         input: &'input str,
@@ -7168,17 +11608,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExp = "!", Boxed<BaseExp> => ActionFn(168);
+        // RAISE = "raise", Boxed<BinOps> => ActionFn(275);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action168::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 47)
+        let __nt = super::__action275::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 80)
     }
-    pub(crate) fn __reduce92<
+    pub(crate) fn __reduce168<
         'input,
     >(
         input: &'input str,
@@ -7187,15 +11627,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExp = ArrExp => ActionFn(33);
-        let __sym0 = __pop_Variant1(__symbols);
+        // RAISE = ASSIGN => ActionFn(21);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 47)
+        let __nt = super::__action21::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 80)
     }
-    pub(crate) fn __reduce93<
+    pub(crate) fn __reduce169<
         'input,
     >(
         input: &'input str,
@@ -7204,15 +11644,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(61);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RAssoc<Log, CmpExp> = Boxed<CmpExp>, Log, Boxed<RAssoc<Log, CmpExp>> => ActionFn(276);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action61::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 48)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action276::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 81)
     }
-    pub(crate) fn __reduce94<
+    pub(crate) fn __reduce170<
         'input,
     >(
         input: &'input str,
@@ -7221,15 +11664,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Params = Comma<Id> => ActionFn(5);
-        let __sym0 = __pop_Variant13(__symbols);
+        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(99);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 49)
+        let __nt = super::__action99::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 81)
     }
-    pub(crate) fn __reduce95<
+    pub(crate) fn __reduce171<
         'input,
     >(
         input: &'input str,
@@ -7238,15 +11681,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(21);
-        let __sym0 = __pop_Variant0(__symbols);
+        // SEQ = RAISE, ";", Exp => ActionFn(277);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 50)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action277::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 82)
     }
-    pub(crate) fn __reduce96<
+    pub(crate) fn __reduce172<
         'input,
     >(
         input: &'input str,
@@ -7255,15 +11701,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
+        // SEQ = RAISE => ActionFn(19);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 50)
+        let __nt = super::__action19::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 82)
     }
-    pub(crate) fn __reduce97<
+    pub(crate) fn __reduce173<
         'input,
     >(
         input: &'input str,
@@ -7272,15 +11718,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(50);
+        // Str = r#"\"[^\"\\n]*\""# => ActionFn(83);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __nt = super::__action83::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 83)
     }
-    pub(crate) fn __reduce98<
+    pub(crate) fn __reduce174<
         'input,
     >(
         input: &'input str,
@@ -7289,15 +11735,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(51);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sum = LAssoc<PlusMinus, Product> => ActionFn(28);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action51::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __nt = super::__action28::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 84)
     }
-    pub(crate) fn __reduce99<
+    pub(crate) fn __reduce175<
         'input,
     >(
         input: &'input str,
@@ -7306,15 +11752,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "print" => ActionFn(52);
+        // Times = "*" => ActionFn(32);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
+        let __nt = super::__action32::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        (1, 85)
     }
-    pub(crate) fn __reduce100<
+    pub(crate) fn __reduce176<
         'input,
     >(
         input: &'input str,
@@ -7323,15 +11769,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isnum" => ActionFn(53);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Type = Id => ActionFn(9);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action53::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __nt = super::__action9::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (1, 86)
     }
-    pub(crate) fn __reduce101<
+    pub(crate) fn __reduce177<
         'input,
     >(
         input: &'input str,
@@ -7340,15 +11786,20 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isbool" => ActionFn(54);
+        // Type = "(", Comma<Type>, ")", "->", Type => ActionFn(10);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant1(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action10::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (5, 86)
     }
-    pub(crate) fn __reduce102<
+    pub(crate) fn __reduce178<
         'input,
     >(
         input: &'input str,
@@ -7357,15 +11808,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isarray" => ActionFn(55);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Type? = Type => ActionFn(133);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action55::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __nt = super::__action133::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 87)
     }
-    pub(crate) fn __reduce103<
+    pub(crate) fn __reduce179<
         'input,
     >(
         input: &'input str,
@@ -7374,15 +11825,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "length" => ActionFn(56);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action56::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        // Type? =  => ActionFn(134);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action134::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 87)
     }
-    pub(crate) fn __reduce104<
+    pub(crate) fn __reduce180<
         'input,
     >(
         input: &'input str,
@@ -7391,15 +11841,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isfun" => ActionFn(57);
-        let __sym0 = __pop_Variant0(__symbols);
+        // TypedParam = Id, ":", Type => ActionFn(170);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 51)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 88)
     }
-    pub(crate) fn __reduce105<
+    pub(crate) fn __reduce181<
         'input,
     >(
         input: &'input str,
@@ -7408,15 +11861,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Product = LAssoc<Times, NotExp> => ActionFn(20);
-        let __sym0 = __pop_Variant1(__symbols);
+        // TypedParam = Id => ActionFn(171);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 52)
+        let __nt = super::__action171::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 88)
     }
-    pub(crate) fn __reduce106<
+    pub(crate) fn __reduce182<
         'input,
     >(
         input: &'input str,
@@ -7425,15 +11878,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prog = Exp => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
+        // TypedParam? = TypedParam => ActionFn(123);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 53)
+        let __nt = super::__action123::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 89)
     }
-    pub(crate) fn __reduce107<
+    pub(crate) fn __reduce183<
         'input,
     >(
         input: &'input str,
@@ -7442,18 +11895,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = Boxed<CmpExp>, Log, Boxed<RAssoc<Log, CmpExp>> => ActionFn(169);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action169::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 54)
+        // TypedParam? =  => ActionFn(124);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action124::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 89)
     }
-    pub(crate) fn __reduce108<
+    pub(crate) fn __reduce184<
         'input,
     >(
         input: &'input str,
@@ -7462,1896 +11911,4340 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(75);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action75::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 54)
+        // __Exp = Exp => ActionFn(1);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 90)
+    }
+}
+pub use self::__parse__Prog::ProgParser;
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Pattern, Prim1, Prim2, Signature, SnakeType};
+    use crate::span::Span1;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
+        let __strs: &[(&str, bool)] = &[
+            ("^(\"[\0-\t\u{b}-!\\#-\u{10ffff}]*\")", false),
+            ("^([\\+\\-]?[0-9]+)", false),
+            ("^([A-Za-z][0-9A-Z_a-z]*)", false),
+            ("^(!)", false),
+            ("^(!=)", false),
+            ("^(\\&\\&)", false),
+            ("^(\\()", false),
+            ("^(\\))", false),
+            ("^(\\*)", false),
+            ("^(\\+)", false),
+            ("^(,)", false),
+            ("^(\\-)", false),
+            ("^(\\->)", false),
+            ("^(\\.)", false),
+            ("^(:)", false),
+            ("^(:=)", false),
+            ("^(;)", false),
+            ("^(<)", false),
+            ("^(<=)", false),
+            ("^(=)", false),
+            ("^(==)", false),
+            ("^(=>)", false),
+            ("^(>)", false),
+            ("^(>=)", false),
+            ("^(\\[)", false),
+            ("^(\\])", false),
+            ("^(_)", false),
+            ("^(add1)", false),
+            ("^(and)", false),
+            ("^(catch)", false),
+            ("^(class)", false),
+            ("^(def)", false),
+            ("^(else)", false),
+            ("^(end)", false),
+            ("^(false)", false),
+            ("^(for)", false),
+            ("^(if)", false),
+            ("^(import)", false),
+            ("^(in)", false),
+            ("^(isarray)", false),
+            ("^(isbool)", false),
+            ("^(isfun)", false),
+            ("^(isnum)", false),
+            ("^(lambda)", false),
+            ("^(length)", false),
+            ("^(let)", false),
+            ("^(match)", false),
+            ("^(new)", false),
+            ("^(print)", false),
+            ("^(raise)", false),
+            ("^(range)", false),
+            ("^(sub1)", false),
+            ("^(true)", false),
+            ("^(try)", false),
+            ("^(while)", false),
+            ("^(\\{)", false),
+            ("^(\\|)", false),
+            ("^(\\|\\|)", false),
+            ("^(\\})", false),
+            ("^(λ)", false),
+            (r"^(\s*)", true),
+        ];
+        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+    }
+}
+pub(crate) use self::__lalrpop_util::lexer::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (Vec<(String, Span1)>, SurfProg<Span1>), usize),
+) -> (Vec<(String, Span1)>, SurfProg<Span1>)
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, imports, _): (usize, alloc::vec::Vec<(String, Span1)>, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+) -> (Vec<(String, Span1)>, SurfProg<Span1>)
+{
+    (imports, body)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, path, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> (String, Span1)
+{
+    (path, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    {
+        let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
+        decls.push(last);
+        Exp::FunDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r} }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, es, _): (usize, Vec<(String, Option<SnakeType>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    {
+        let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
+        decls.push(last);
+        let (fields, field_types) = es.into_iter().map(|(x, ty)| (x, ty.unwrap_or(SnakeType::Any))).unzip();
+        Exp::ClassDef { name: name, fields, field_types, methods: decls, body: body, ann: Span1 { start_ix: l, end_ix: r}}
+    }
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<String>, usize),
+) -> Vec<String>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, x, _): (usize, String, usize),
+    (_, ty, _): (usize, core::option::Option<SnakeType>, usize),
+) -> (String, Option<SnakeType>)
+{
+    (x, ty)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, params, _): (usize, Vec<(String, Option<SnakeType>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, ret, _): (usize, core::option::Option<SnakeType>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfFunDecl<Span1>
+{
+    {
+        // Only fully unannotated functions keep their dynamic behaviour
+        let annotated = ret.is_some() || params.iter().any(|(_, ty)| ty.is_some());
+        let (parameters, types): (Vec<String>, Vec<SnakeType>) = params
+            .into_iter()
+            .map(|(x, ty)| (x, ty.unwrap_or(SnakeType::Any)))
+            .unzip();
+        let signature = if annotated {
+            Some(Signature { parameters: types, ret: ret.unwrap_or(SnakeType::Any) })
+        } else {
+            None
+        };
+        FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}, signature }
     }
-    pub(crate) fn __reduce109<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // SEQ = BinOps, ";", Exp => ActionFn(170);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 55)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, x, _): (usize, String, usize),
+) -> SnakeType
+{
+    match x.as_str() {
+        "Num" => SnakeType::Num,
+        "Bool" => SnakeType::Bool,
+        "Array" => SnakeType::Array,
+        "Any" => SnakeType::Any,
+        _ => SnakeType::Class(x),
     }
-    pub(crate) fn __reduce110<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, ps, _): (usize, Vec<SnakeType>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, ret, _): (usize, SnakeType, usize),
+) -> SnakeType
+{
+    SnakeType::Fun(ps, Box::new(ret))
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, thn, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, els, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::If { cond, thn, els, ann: Span1 { start_ix: l, end_ix: r }}
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, bindings, _): (usize, Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
     {
-        // SEQ = BinOps => ActionFn(15);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 55)
+        let ann = Span1 { start_ix: l, end_ix: r };
+        // Plain variable bindings stay an ordinary let
+        if bindings.iter().all(|(pat, _)| matches!(pat, Pattern::Var(..))) {
+            let bindings = bindings
+                .into_iter()
+                .map(|(pat, e)| match pat {
+                    Pattern::Var(x, _) => (x, e),
+                    _ => unreachable!(),
+                })
+                .collect();
+            Exp::Let { bindings, body, ann }
+        } else {
+            Exp::LetPattern { bindings, body, ann }
+        }
     }
-    pub(crate) fn __reduce111<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Semicolon { e1: Box::new(e1), e2: Box::new(e2), ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Raise(e, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, array, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, index, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, new_value, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::ArraySet{ ann: Span1 { start_ix: l, end_ix: r }, array, index, new_value }
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::SetField{ field: x, value: e, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Add
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Sub
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Mul
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::And
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Or
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Lt
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Le
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Gt
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Ge
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Eq
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim2
+{
+    Prim2::Neq
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim1(Prim1::Not, e, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, a, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim2(Prim2::ArrayGet, a, e, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Var(x, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, b, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Bool(b, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, i64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Num(n, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, class, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Object{class: class, fields: es, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, p, _): (usize, Prim1, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim1(p, e, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Exp<Span1>
+{
+    e
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Call(x, es, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, obj, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, method, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::CallMethod {object: obj, method: method, args: es, ann: Span1 { start_ix: l, end_ix: r }}
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, (), usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Lambda { parameters, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::While { cond, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, array, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::For { var, array, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, start, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, stop, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::ForRange { var, start, stop, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, handler, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Try { body, var, handler, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, scrutinee, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, arms, _): (usize, alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Match { scrutinee, arms, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Array(es, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, p, _): (usize, Pattern<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> (Pattern<Span1>, Exp<Span1>)
+{
+    (p, e)
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Pattern<Span1>
+{
+    Pattern::Wildcard(Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Pattern<Span1>
+{
+    Pattern::Var(x, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, i64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Pattern<Span1>
+{
+    Pattern::Num(n, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, b, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Pattern<Span1>
+{
+    Pattern::Bool(b, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, ps, _): (usize, Vec<Pattern<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Pattern<Span1>
+{
+    Pattern::Array(ps, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, class, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, fields, _): (usize, Vec<Pattern<Span1>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Pattern<Span1>
+{
+    Pattern::Object { class, fields, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ()
+{
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ()
+{
+    ()
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::Add1
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::Sub1
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::Print
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::IsNum
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::IsBool
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::IsArray
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::Length
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Prim1
+{
+    Prim1::IsFun
+}
+
+#[allow(unused_variables)]
+fn __action77<
+    'input,
+>(
+    input: &'input str,
+    (_, b, _): (usize, (Pattern<Span1>, Exp<Span1>), usize),
+) -> Vec<(Pattern<Span1>, Exp<Span1>)>
+{
+    vec![b]
+}
+
+#[allow(unused_variables)]
+fn __action78<
+    'input,
+>(
+    input: &'input str,
+    (_, mut bs, _): (usize, Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, b, _): (usize, (Pattern<Span1>, Exp<Span1>), usize),
+) -> Vec<(Pattern<Span1>, Exp<Span1>)>
+{
     {
-        // Sum = LAssoc<PlusMinus, Product> => ActionFn(19);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 56)
+        bs.push(b);
+        bs
     }
-    pub(crate) fn __reduce112<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
+}
+
+#[allow(unused_variables)]
+fn __action79<
+    'input,
+>(
+    input: &'input str,
+    (_, b, _): (usize, Pattern<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> (Pattern<Span1>, Exp<Span1>)
+{
+    (b, e)
+}
+
+#[allow(unused_variables)]
+fn __action80<
+    'input,
+>(
+    input: &'input str,
+    (_, b, _): (usize, Pattern<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, ty, _): (usize, SnakeType, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> (Pattern<Span1>, Exp<Span1>)
+{
+    (b, Exp::Ascribe(Box::new(e), ty, Span1 { start_ix: l, end_ix: r }))
+}
+
+#[allow(unused_variables)]
+fn __action81<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Pattern<Span1>, usize),
+) -> Pattern<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action82<
+    'input,
+>(
+    input: &'input str,
+    (_, s, _): (usize, &'input str, usize),
+) -> i64
+{
+    i64::from_str(s).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action83<
+    'input,
+>(
+    input: &'input str,
+    (_, s, _): (usize, &'input str, usize),
+) -> String
+{
+    String::from(&s[1..s.len() - 1])
+}
+
+#[allow(unused_variables)]
+fn __action84<
+    'input,
+>(
+    input: &'input str,
+    (_, s, _): (usize, &'input str, usize),
+) -> String
+{
+    String::from(s)
+}
+
+#[allow(unused_variables)]
+fn __action85<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> bool
+{
+    true
+}
+
+#[allow(unused_variables)]
+fn __action86<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> bool
+{
+    false
+}
+
+#[allow(unused_variables)]
+fn __action87<
+    'input,
+>(
+    input: &'input str,
+    (_, mut v, _): (usize, alloc::vec::Vec<Pattern<Span1>>, usize),
+    (_, last, _): (usize, core::option::Option<Pattern<Span1>>, usize),
+) -> Vec<Pattern<Span1>>
+{
     {
-        // Times = "*" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 57)
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
     }
-    pub(crate) fn __reduce113<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
+}
+
+#[allow(unused_variables)]
+fn __action88<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (Pattern<Span1>, Exp<Span1>), usize),
+) -> alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action89<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    (_, e, _): (usize, (Pattern<Span1>, Exp<Span1>), usize),
+) -> alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action90<
+    'input,
+>(
+    input: &'input str,
+    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
+{
     {
-        // __Exp = Exp => ActionFn(1);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 58)
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
     }
 }
-pub use self::__parse__Prog::ProgParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim1, Prim2};
-    use crate::span::Span1;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("^([\\+\\-]?[0-9]+)", false),
-            ("^([A-Za-z][0-9A-Z_a-z]*)", false),
-            ("^(!)", false),
-            ("^(!=)", false),
-            ("^(\\&\\&)", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\*)", false),
-            ("^(\\+)", false),
-            ("^(,)", false),
-            ("^(\\-)", false),
-            ("^(\\.)", false),
-            ("^(:)", false),
-            ("^(:=)", false),
-            ("^(;)", false),
-            ("^(<)", false),
-            ("^(<=)", false),
-            ("^(=)", false),
-            ("^(==)", false),
-            ("^(>)", false),
-            ("^(>=)", false),
-            ("^(\\[)", false),
-            ("^(\\])", false),
-            ("^(add1)", false),
-            ("^(and)", false),
-            ("^(class)", false),
-            ("^(def)", false),
-            ("^(else)", false),
-            ("^(end)", false),
-            ("^(false)", false),
-            ("^(if)", false),
-            ("^(in)", false),
-            ("^(isarray)", false),
-            ("^(isbool)", false),
-            ("^(isfun)", false),
-            ("^(isnum)", false),
-            ("^(lambda)", false),
-            ("^(length)", false),
-            ("^(let)", false),
-            ("^(new)", false),
-            ("^(print)", false),
-            ("^(sub1)", false),
-            ("^(true)", false),
-            ("^(\\{)", false),
-            ("^(\\|\\|)", false),
-            ("^(\\})", false),
-            ("^(λ)", false),
-            (r"^(\s*)", true),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+
+#[allow(unused_variables)]
+fn __action91<
+    'input,
+>(
+    input: &'input str,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action92<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action93<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action94<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action95<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action96<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action97<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action98<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action99<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action100<
+    'input,
+>(
+    input: &'input str,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action101<
+    'input,
+>(
+    input: &'input str,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action102<
+    'input,
+>(
+    input: &'input str,
+    (_, mut v, _): (usize, alloc::vec::Vec<SnakeType>, usize),
+    (_, last, _): (usize, core::option::Option<SnakeType>, usize),
+) -> Vec<SnakeType>
+{
+    {
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
     }
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
 
 #[allow(unused_variables)]
-fn __action0<
+fn __action103<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, SurfProg<Span1>, usize),
-) -> SurfProg<Span1>
+    (_, __0, _): (usize, SnakeType, usize),
+) -> core::option::Option<SnakeType>
 {
-    __0
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action1<
+fn __action104<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<SnakeType>
 {
-    __0
+    None
 }
 
 #[allow(unused_variables)]
-fn __action2<
+fn __action105<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> SurfProg<Span1>
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, SnakeType, usize),
+) -> SnakeType
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action3<
+fn __action106<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, SnakeType, usize),
+) -> core::option::Option<SnakeType>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action107<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<SnakeType>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action108<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
     (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, SnakeType, usize),
+) -> SnakeType
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action109<
+    'input,
+>(
+    input: &'input str,
+    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, last, _): (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
     {
-        let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
-        decls.push(last);
-        Exp::FunDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r} }
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
     }
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action110<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, mut v, _): (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize),
+    (_, last, _): (usize, core::option::Option<(String, Option<SnakeType>)>, usize),
+) -> Vec<(String, Option<SnakeType>)>
 {
     {
-        let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
-        decls.push(last);
-        Exp::ClassDef { name: name, fields: es, methods: decls, body: body, ann: Span1 { start_ix: l, end_ix: r}}
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
     }
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action111<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Vec<String>, usize),
-) -> Vec<String>
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    __0
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action112<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, parameters, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfFunDecl<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
 {
-    FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}  }
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action113<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
 {
-    __0
+    v
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action114<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, __1, _): (usize, &'input str, usize),
+) -> (SurfFunDecl<Span1>, &'input str)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action115<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action116<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action117<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(String, Span1)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action118<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(String, Span1)>, usize),
+) -> alloc::vec::Vec<(String, Span1)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action119<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (String, Span1), usize),
+) -> alloc::vec::Vec<(String, Span1)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action120<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(String, Span1)>, usize),
+    (_, e, _): (usize, (String, Span1), usize),
+) -> alloc::vec::Vec<(String, Span1)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action121<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action122<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    (_, e, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action123<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (String, Option<SnakeType>), usize),
+) -> core::option::Option<(String, Option<SnakeType>)>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action124<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<(String, Option<SnakeType>)>
 {
-    __0
+    None
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action125<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(String, Option<SnakeType>)>
 {
-    __0
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action126<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize),
+) -> alloc::vec::Vec<(String, Option<SnakeType>)>
 {
-    __0
+    v
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action127<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, (String, Option<SnakeType>), usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> (String, Option<SnakeType>)
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action128<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, thn, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, els, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, String, usize),
+) -> core::option::Option<String>
 {
-    Exp::If { cond, thn, els, ann: Span1 { start_ix: l, end_ix: r }}
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action129<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<String>
 {
-    Exp::Let { bindings, body, ann: Span1 { start_ix: l, end_ix: r }}
+    None
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action130<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<String>
 {
-    Exp::Semicolon { e1: Box::new(e1), e2: Box::new(e2), ann: Span1 { start_ix: l, end_ix: r } }
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action131<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+) -> alloc::vec::Vec<String>
 {
-    __0
+    v
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action132<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> String
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action133<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, SnakeType, usize),
+) -> core::option::Option<SnakeType>
 {
-    __0
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action134<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<SnakeType>
 {
-    __0
+    None
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action135<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<SnakeType>
 {
-    __0
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action136<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<SnakeType>, usize),
+) -> alloc::vec::Vec<SnakeType>
 {
-    __0
+    v
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action137<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, __0, _): (usize, SnakeType, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> SnakeType
 {
-    Prim2::Add
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action138<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Sub
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action139<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Mul
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action140<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::And
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action141<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Or
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action142<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Lt
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action143<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Le
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action144<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Gt
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action145<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Ge
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action146<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> core::option::Option<Exp<Span1>>
 {
-    Prim2::Eq
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action147<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Exp<Span1>>
 {
-    Prim2::Neq
+    None
 }
 
 #[allow(unused_variables)]
-fn __action32<
+fn __action148<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Prim1(Prim1::Not, e, Span1 { start_ix: l, end_ix: r })
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action33<
+fn __action149<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    __0
+    v
 }
 
 #[allow(unused_variables)]
-fn __action34<
+fn __action150<
     'input,
 >(
     input: &'input str,
     (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action35<
+fn __action151<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, a, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, Pattern<Span1>, usize),
+) -> core::option::Option<Pattern<Span1>>
 {
-    Exp::Prim2(Prim2::ArrayGet, a, e, Span1 { start_ix: l, end_ix: r })
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action36<
+fn __action152<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, array, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, index, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, new_value, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Pattern<Span1>>
 {
-    Exp::ArraySet{ ann: Span1 { start_ix: l, end_ix: r }, array, index, new_value }
+    None
 }
 
 #[allow(unused_variables)]
-fn __action37<
+fn __action153<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Pattern<Span1>>
 {
-    Exp::SetField{ field: x, value: e, ann: Span1 { start_ix: l, end_ix: r } }
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action38<
+fn __action154<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, String, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<Pattern<Span1>>, usize),
+) -> alloc::vec::Vec<Pattern<Span1>>
 {
-    Exp::Var(x, Span1 { start_ix: l, end_ix: r })
+    v
 }
 
 #[allow(unused_variables)]
-fn __action39<
+fn __action155<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, b, _): (usize, bool, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, Pattern<Span1>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Pattern<Span1>
 {
-    Exp::Bool(b, Span1 { start_ix: l, end_ix: r })
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action40<
+fn __action156<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, n, _): (usize, i64, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, Pattern<Span1>, usize),
+) -> alloc::vec::Vec<Pattern<Span1>>
 {
-    Exp::Num(n, Span1 { start_ix: l, end_ix: r })
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action41<
+fn __action157<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, class, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<Pattern<Span1>>, usize),
+    (_, e, _): (usize, Pattern<Span1>, usize),
+) -> alloc::vec::Vec<Pattern<Span1>>
 {
-    Exp::Object{class: class, fields: es, ann: Span1 { start_ix: l, end_ix: r } }
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action42<
+fn __action158<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, p, _): (usize, Prim1, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Prim1(p, e, Span1 { start_ix: l, end_ix: r })
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action43<
+fn __action159<
     'input,
 >(
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
     (_, e, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Exp<Span1>
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    e
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action44<
+fn __action160<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, SnakeType, usize),
+) -> alloc::vec::Vec<SnakeType>
 {
-    Exp::Call(x, es, Span1 { start_ix: l, end_ix: r })
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action45<
+fn __action161<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, obj, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, method, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<SnakeType>, usize),
+    (_, e, _): (usize, SnakeType, usize),
+) -> alloc::vec::Vec<SnakeType>
 {
-    Exp::CallMethod {object: obj, method: method, args: es, ann: Span1 { start_ix: l, end_ix: r }}
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action46<
+fn __action162<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, (), usize),
-    (_, parameters, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Exp::Lambda { parameters, body, ann: Span1 { start_ix: l, end_ix: r } }
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action47<
+fn __action163<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Exp::Array(es, Span1 { start_ix: l, end_ix: r })
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action48<
+fn __action164<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ()
+    (_, __0, _): (usize, (String, Option<SnakeType>), usize),
+) -> alloc::vec::Vec<(String, Option<SnakeType>)>
 {
-    ()
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action49<
+fn __action165<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ()
+    (_, v, _): (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize),
+    (_, e, _): (usize, (String, Option<SnakeType>), usize),
+) -> alloc::vec::Vec<(String, Option<SnakeType>)>
 {
-    ()
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action50<
+fn __action166<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, &'input str, usize),
+    __1: (usize, SnakeType, usize),
+) -> core::option::Option<SnakeType>
 {
-    Prim1::Add1
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action105(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action103(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action51<
+fn __action167<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, SnakeType, usize),
+    __8: (usize, &'input str, usize),
+    __9: (usize, Exp<Span1>, usize),
+    __10: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    Prim1::Sub1
+    let __start0 = __6.0.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action166(
+        input,
+        __6,
+        __7,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __8,
+        __9,
+        __10,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action52<
+fn __action168<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Exp<Span1>, usize),
+    __8: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    Prim1::Print
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __temp0 = __action104(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action53<
+fn __action169<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, &'input str, usize),
+    __1: (usize, SnakeType, usize),
+) -> core::option::Option<SnakeType>
 {
-    Prim1::IsNum
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action108(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action106(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action54<
+fn __action170<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, SnakeType, usize),
+) -> (String, Option<SnakeType>)
 {
-    Prim1::IsBool
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action169(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action171<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+) -> (String, Option<SnakeType>)
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action107(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action55<
+fn __action172<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Prim1::IsArray
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action150(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action158(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action56<
+fn __action173<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Prim1::Length
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action150(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action159(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action57<
+fn __action174<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    __0: (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    Prim1::IsFun
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action148(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action90(
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action58<
+fn __action175<
     'input,
 >(
     input: &'input str,
-    (_, b, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    vec![(b, e)]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action149(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action90(
+        input,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action59<
+fn __action176<
     'input,
 >(
     input: &'input str,
-    (_, mut bs, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, b, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<String>
 {
-    {
-        bs.push((b, e));
-        bs
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action132(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action162(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action60<
+fn __action177<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> String
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<String>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action132(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action163(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action61<
+fn __action178<
     'input,
 >(
     input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> i64
+    __0: (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    i64::from_str(s).unwrap()
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action130(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action109(
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action62<
+fn __action179<
     'input,
 >(
     input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> String
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    String::from(s)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action131(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action109(
+        input,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action63<
+fn __action180<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    __0: (usize, Pattern<Span1>, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<Pattern<Span1>>
 {
-    true
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action155(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action64<
+fn __action181<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    __0: (usize, alloc::vec::Vec<Pattern<Span1>>, usize),
+    __1: (usize, Pattern<Span1>, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<Pattern<Span1>>
 {
-    false
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action155(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action65<
+fn __action182<
     'input,
 >(
     input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, core::option::Option<Pattern<Span1>>, usize),
+) -> Vec<Pattern<Span1>>
 {
-    {
-        match last {
-            None => { },
-            Some(t) => { v.push(t); }
-        };
-        v
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action153(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action66<
+fn __action183<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, alloc::vec::Vec<Pattern<Span1>>, usize),
+    __1: (usize, core::option::Option<Pattern<Span1>>, usize),
+) -> Vec<Pattern<Span1>>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action154(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        input,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action67<
+fn __action184<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, SnakeType, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<SnakeType>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action137(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action160(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action68<
+fn __action185<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<SnakeType>, usize),
+    __1: (usize, SnakeType, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<SnakeType>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action137(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action161(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action69<
+fn __action186<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, core::option::Option<SnakeType>, usize),
+) -> Vec<SnakeType>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action135(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action102(
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action70<
+fn __action187<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<SnakeType>, usize),
+    __1: (usize, core::option::Option<SnakeType>, usize),
+) -> Vec<SnakeType>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action136(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action102(
+        input,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action71<
+fn __action188<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, (String, Option<SnakeType>), usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<(String, Option<SnakeType>)>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action127(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action72<
+fn __action189<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize),
+    __1: (usize, (String, Option<SnakeType>), usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<(String, Option<SnakeType>)>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action127(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action73<
+fn __action190<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, core::option::Option<(String, Option<SnakeType>)>, usize),
+) -> Vec<(String, Option<SnakeType>)>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action125(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action110(
+        input,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action74<
+fn __action191<
     'input,
 >(
     input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize),
+    __1: (usize, core::option::Option<(String, Option<SnakeType>)>, usize),
+) -> Vec<(String, Option<SnakeType>)>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action126(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action110(
+        input,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action75<
+fn __action192<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action114(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action121(
+        input,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action76<
+fn __action193<
     'input,
 >(
     input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, last, _): (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
 {
-    {
-        match last {
-            None => { },
-            Some(t) => { v.push(t); }
-        };
-        v
-    }
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action114(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action122(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action77<
+fn __action194<
     'input,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, SurfFunDecl<Span1>, usize),
+    __8: (usize, &'input str, usize),
+    __9: (usize, Box<Exp<Span1>>, usize),
+    __10: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __lookbehind.clone()
+    let __start0 = __6.2.clone();
+    let __end0 = __7.0.clone();
+    let __temp0 = __action112(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+        __7,
+        __8,
+        __9,
+        __10,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action78<
+fn __action195<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    __8: (usize, SurfFunDecl<Span1>, usize),
+    __9: (usize, &'input str, usize),
+    __10: (usize, Box<Exp<Span1>>, usize),
+    __11: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __7.0.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action113(
+        input,
+        __7,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+        __8,
+        __9,
+        __10,
+        __11,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action79<
+fn __action196<
     'input,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, usize, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action112(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action80<
+fn __action197<
     'input,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, usize, usize),
+    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    __2: (usize, SurfFunDecl<Span1>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action113(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action81<
+fn __action198<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, __1, _): (usize, &'input str, usize),
-) -> (SurfFunDecl<Span1>, &'input str)
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    (__0, __1)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action82<
+fn __action199<
     'input,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __lookahead.clone()
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action83<
+fn __action200<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action84<
+fn __action201<
     'input,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, e, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action85<
+fn __action202<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> core::option::Option<String>
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action86<
+fn __action203<
     'input,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<String>
+    __0: (usize, i64, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action87<
+fn __action204<
     'input,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<String>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<Exp<Span1>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action88<
+fn __action205<
     'input,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, Prim1, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action89<
+fn __action206<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> String
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Vec<Exp<Span1>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action51(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action90<
+fn __action207<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Vec<Exp<Span1>>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action52(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action91<
+fn __action208<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, (), usize),
+    __1: (usize, Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action92<
+fn __action209<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action93<
+fn __action210<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action55(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action94<
+fn __action211<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Exp<Span1>>, usize),
+    __8: (usize, &'input str, usize),
+    __9: (usize, &'input str, usize),
+    __10: (usize, Box<Exp<Span1>>, usize),
+    __11: (usize, &'input str, usize),
+    __12: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action56(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+        __10,
+        __11,
+        __12,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action95<
+fn __action212<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, String, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Exp<Span1>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action96<
+fn __action213<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action97<
+fn __action214<
     'input,
 >(
     input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<Exp<Span1>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action98<
+fn __action215<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> core::option::Option<Exp<Span1>>
+    __0: (usize, Pattern<Span1>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, SnakeType, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, usize, usize),
+) -> (Pattern<Span1>, Exp<Span1>)
 {
-    Some(__0)
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action80(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action99<
+fn __action216<
     'input,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, SurfFunDecl<Span1>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Exp<Span1>>, usize),
+    __9: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action194(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action100<
+fn __action217<
     'input,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    __7: (usize, SurfFunDecl<Span1>, usize),
+    __8: (usize, &'input str, usize),
+    __9: (usize, Box<Exp<Span1>>, usize),
+    __10: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action195(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+        __10,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action101<
+fn __action218<
     'input,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action196(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action102<
+fn __action219<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action197(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action103<
+fn __action220<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, SnakeType, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Exp<Span1>, usize),
+    __9: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action167(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action104<
+fn __action221<
     'input,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action168(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action105<
+fn __action222<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Exp<Span1>>, usize),
+    __7: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action106<
+fn __action223<
     'input,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, e, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, usize, usize),
+) -> (String, Span1)
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action107<
+fn __action224<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action102(
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(
+    __action96(
         input,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action108<
+fn __action225<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action102(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action104(
+    __action94(
         input,
-        __0,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action109<
+fn __action226<
     'input,
 >(
     input: &'input str,
-    __0: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action100(
+    let __temp0 = __action116(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action65(
+    __action92(
         input,
         __temp0,
         __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action110<
+fn __action227<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action101(
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __0,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action65(
+    __action17(
         input,
         __temp0,
+        __0,
         __1,
+        __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action111<
+fn __action228<
     'input,
 >(
     input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action89(
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(
+    __action41(
         input,
         __temp0,
+        __0,
+        __1,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action112<
+fn __action229<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Pattern<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action89(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action106(
+    __action61(
         input,
-        __0,
         __temp0,
+        __0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action113<
+fn __action230<
     'input,
 >(
     input: &'input str,
-    __0: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Pattern<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action87(
+    let __temp0 = __action116(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action76(
+    __action62(
         input,
         __temp0,
         __0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action114<
+fn __action231<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    __0: (usize, i64, usize),
+    __1: (usize, usize, usize),
+) -> Pattern<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action88(
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __0,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action76(
+    __action63(
         input,
         __temp0,
+        __0,
         __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action115<
+fn __action232<
     'input,
 >(
     input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
+) -> Pattern<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action81(
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __0,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action83(
+    __action64(
         input,
         __temp0,
+        __0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action116<
+fn __action233<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<Pattern<Span1>>, usize),
     __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __3: (usize, usize, usize),
+) -> Pattern<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action81(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __1,
-        __2,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(
+    __action65(
         input,
-        __0,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action117<
+fn __action234<
     'input,
 >(
     input: &'input str,
-    __0: (usize, usize, usize),
+    __0: (usize, String, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, String, usize),
+    __2: (usize, Vec<Pattern<Span1>>, usize),
     __3: (usize, &'input str, usize),
-    __4: (usize, Vec<String>, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, &'input str, usize),
-    __7: (usize, SurfFunDecl<Span1>, usize),
-    __8: (usize, &'input str, usize),
-    __9: (usize, Box<Exp<Span1>>, usize),
-    __10: (usize, usize, usize),
-) -> Exp<Span1>
+    __4: (usize, usize, usize),
+) -> Pattern<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __7.0.clone();
-    let __temp0 = __action79(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    __action66(
         input,
+        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
-        __6,
-        __temp0,
-        __7,
-        __8,
-        __9,
-        __10,
     )
 }
 
 #[allow(unused_variables)]
-fn __action118<
+fn __action235<
     'input,
 >(
     input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, String, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Vec<String>, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, &'input str, usize),
-    __7: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __8: (usize, SurfFunDecl<Span1>, usize),
-    __9: (usize, &'input str, usize),
-    __10: (usize, Box<Exp<Span1>>, usize),
-    __11: (usize, usize, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __7.0.clone();
-    let __end0 = __7.2.clone();
-    let __temp0 = __action80(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __7,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    __action20(
         input,
+        __temp0,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __temp0,
-        __8,
-        __9,
-        __10,
-        __11,
     )
 }
 
 #[allow(unused_variables)]
-fn __action119<
+fn __action236<
     'input,
 >(
     input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action79(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
+    __action98(
         input,
-        __0,
         __temp0,
+        __0,
         __1,
         __2,
         __3,
-        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action120<
+fn __action237<
     'input,
 >(
     input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __2: (usize, SurfFunDecl<Span1>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Box<Exp<Span1>>, usize),
-    __5: (usize, usize, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action80(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action116(
         input,
-        __1,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
+    __action18(
         input,
-        __0,
         __temp0,
+        __0,
+        __1,
         __2,
         __3,
-        __4,
-        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action121<
+fn __action238<
     'input,
 >(
     input: &'input str,
@@ -9359,169 +16252,157 @@ fn __action121<
     __1: (usize, &'input str, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
     __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action35(
+    __action198(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action122<
+fn __action239<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
+    __0: (usize, String, usize),
     __1: (usize, &'input str, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Box<Exp<Span1>>, usize),
-    __6: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action36(
+    __action199(
         input,
-        __temp0,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action123<
+fn __action240<
     'input,
 >(
     input: &'input str,
-    __0: (usize, String, usize),
+    __0: (usize, Box<Exp<Span1>>, usize),
     __1: (usize, &'input str, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action37(
+    __action200(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action124<
+fn __action241<
     'input,
 >(
     input: &'input str,
     __0: (usize, String, usize),
-    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action38(
+    __action201(
         input,
-        __temp0,
         __0,
-        __1,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action125<
+fn __action242<
     'input,
 >(
     input: &'input str,
     __0: (usize, bool, usize),
-    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action39(
+    __action202(
         input,
-        __temp0,
         __0,
-        __1,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action126<
+fn __action243<
     'input,
 >(
     input: &'input str,
     __0: (usize, i64, usize),
-    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action40(
+    __action203(
         input,
-        __temp0,
         __0,
-        __1,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action127<
+fn __action244<
     'input,
 >(
     input: &'input str,
@@ -9530,31 +16411,29 @@ fn __action127<
     __2: (usize, &'input str, usize),
     __3: (usize, Vec<Exp<Span1>>, usize),
     __4: (usize, &'input str, usize),
-    __5: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action41(
+    __action204(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action128<
+fn __action245<
     'input,
 >(
     input: &'input str,
@@ -9562,30 +16441,28 @@ fn __action128<
     __1: (usize, &'input str, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
     __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action42(
+    __action205(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action129<
+fn __action246<
     'input,
 >(
     input: &'input str,
@@ -9593,30 +16470,28 @@ fn __action129<
     __1: (usize, &'input str, usize),
     __2: (usize, Vec<Exp<Span1>>, usize),
     __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action44(
+    __action206(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action130<
+fn __action247<
     'input,
 >(
     input: &'input str,
@@ -9626,32 +16501,30 @@ fn __action130<
     __3: (usize, &'input str, usize),
     __4: (usize, Vec<Exp<Span1>>, usize),
     __5: (usize, &'input str, usize),
-    __6: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action45(
+    __action207(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
-        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action131<
+fn __action248<
     'input,
 >(
     input: &'input str,
@@ -9660,86 +16533,82 @@ fn __action131<
     __2: (usize, &'input str, usize),
     __3: (usize, Box<Exp<Span1>>, usize),
     __4: (usize, &'input str, usize),
-    __5: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
+    __action208(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action132<
+fn __action249<
     'input,
 >(
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Vec<Exp<Span1>>, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, usize, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action47(
+    __action209(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action133<
+fn __action250<
     'input,
 >(
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, String, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
     __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, SurfFunDecl<Span1>, usize),
-    __7: (usize, &'input str, usize),
-    __8: (usize, Box<Exp<Span1>>, usize),
-    __9: (usize, usize, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action117(
+    __action210(
         input,
-        __temp0,
         __0,
         __1,
         __2,
@@ -9747,41 +16616,39 @@ fn __action133<
         __4,
         __5,
         __6,
-        __7,
-        __8,
-        __9,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action134<
+fn __action251<
     'input,
 >(
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, String, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
+    __3: (usize, &'input str, usize),
     __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __7: (usize, SurfFunDecl<Span1>, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Exp<Span1>>, usize),
     __8: (usize, &'input str, usize),
-    __9: (usize, Box<Exp<Span1>>, usize),
-    __10: (usize, usize, usize),
+    __9: (usize, &'input str, usize),
+    __10: (usize, Box<Exp<Span1>>, usize),
+    __11: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __11.2.clone();
+    let __end0 = __11.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action118(
+    __action211(
         input,
-        __temp0,
         __0,
         __1,
         __2,
@@ -9793,95 +16660,163 @@ fn __action134<
         __8,
         __9,
         __10,
+        __11,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action135<
+fn __action252<
     'input,
 >(
     input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
+    __0: (usize, &'input str, usize),
     __1: (usize, &'input str, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, String, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Exp<Span1>>, usize),
+    __7: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(
+    __action212(
         input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
         __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action253<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, alloc::vec::Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    __4: (usize, &'input str, usize),
+) -> Exp<Span1>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action115(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        input,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action136<
+fn __action254<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<Exp<Span1>>, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action120(
+    __action214(
         input,
+        __0,
+        __1,
+        __2,
         __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action255<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Pattern<Span1>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, SnakeType, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Exp<Span1>, usize),
+) -> (Pattern<Span1>, Exp<Span1>)
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action115(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action215(
+        input,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action137<
+fn __action256<
     'input,
 >(
     input: &'input str,
     __0: (usize, &'input str, usize),
     __1: (usize, String, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
     __4: (usize, &'input str, usize),
     __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-    __7: (usize, usize, usize),
-) -> SurfFunDecl<Span1>
+    __6: (usize, SurfFunDecl<Span1>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Exp<Span1>>, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action216(
         input,
-        __temp0,
         __0,
         __1,
         __2,
@@ -9890,35 +16825,38 @@ fn __action137<
         __5,
         __6,
         __7,
+        __8,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action138<
+fn __action257<
     'input,
 >(
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, String, usize),
     __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
     __4: (usize, &'input str, usize),
     __5: (usize, &'input str, usize),
-    __6: (usize, Box<Exp<Span1>>, usize),
-    __7: (usize, usize, usize),
+    __6: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    __7: (usize, SurfFunDecl<Span1>, usize),
+    __8: (usize, &'input str, usize),
+    __9: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __9.2.clone();
+    let __end0 = __9.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(
+    __action217(
         input,
-        __temp0,
         __0,
         __1,
         __2,
@@ -9927,318 +16865,354 @@ fn __action138<
         __5,
         __6,
         __7,
+        __8,
+        __9,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action258<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+) -> Exp<Span1>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action218(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action139<
+fn __action259<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action72(
+    __action219(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action140<
+fn __action260<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, SnakeType, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action70(
+    __action220(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action141<
+fn __action261<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Vec<(String, Option<SnakeType>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action68(
+    __action221(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action142<
+fn __action262<
     'input,
 >(
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
     __2: (usize, &'input str, usize),
     __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(
+    __action222(
         input,
-        __temp0,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
+        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action143<
+fn __action263<
     'input,
 >(
     input: &'input str,
     __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-    __2: (usize, usize, usize),
-) -> Exp<Span1>
+    __1: (usize, String, usize),
+) -> (String, Span1)
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action32(
+    __action223(
         input,
-        __temp0,
         __0,
         __1,
-        __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action144<
+fn __action264<
     'input,
 >(
     input: &'input str,
     __0: (usize, Box<Exp<Span1>>, usize),
     __1: (usize, Prim2, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action74(
+    __action224(
         input,
-        __temp0,
         __0,
         __1,
         __2,
-        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action145<
+fn __action265<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action82(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(
+    __action225(
         input,
-        __temp0,
         __0,
         __1,
         __2,
-        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action146<
+fn __action266<
     'input,
 >(
     input: &'input str,
     __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Prim2, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action121(
+    __action226(
         input,
         __0,
         __1,
         __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action147<
+fn __action267<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Box<Exp<Span1>>, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<(Pattern<Span1>, Exp<Span1>)>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action122(
+    __action227(
         input,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action148<
+fn __action268<
     'input,
 >(
     input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action123(
+    __action228(
         input,
         __0,
         __1,
-        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action149<
+fn __action269<
     'input,
 >(
     input: &'input str,
-    __0: (usize, String, usize),
-) -> Exp<Span1>
+    __0: (usize, &'input str, usize),
+) -> Pattern<Span1>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action77(
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action124(
+    __action229(
         input,
         __0,
         __temp0,
@@ -10246,22 +17220,22 @@ fn __action149<
 }
 
 #[allow(unused_variables)]
-fn __action150<
+fn __action270<
     'input,
 >(
     input: &'input str,
-    __0: (usize, bool, usize),
-) -> Exp<Span1>
+    __0: (usize, String, usize),
+) -> Pattern<Span1>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action77(
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action125(
+    __action230(
         input,
         __0,
         __temp0,
@@ -10269,22 +17243,22 @@ fn __action150<
 }
 
 #[allow(unused_variables)]
-fn __action151<
+fn __action271<
     'input,
 >(
     input: &'input str,
     __0: (usize, i64, usize),
-) -> Exp<Span1>
+) -> Pattern<Span1>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action77(
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action126(
+    __action231(
         input,
         __0,
         __temp0,
@@ -10292,85 +17266,75 @@ fn __action151<
 }
 
 #[allow(unused_variables)]
-fn __action152<
+fn __action272<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-) -> Exp<Span1>
+    __0: (usize, bool, usize),
+) -> Pattern<Span1>
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action127(
+    __action232(
         input,
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action153<
+fn __action273<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Prim1, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-) -> Exp<Span1>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Vec<Pattern<Span1>>, usize),
+    __2: (usize, &'input str, usize),
+) -> Pattern<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action128(
+    __action233(
         input,
         __0,
         __1,
         __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action154<
+fn __action274<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
+    __0: (usize, String, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, Vec<Exp<Span1>>, usize),
+    __2: (usize, Vec<Pattern<Span1>>, usize),
     __3: (usize, &'input str, usize),
-) -> Exp<Span1>
+) -> Pattern<Span1>
 {
     let __start0 = __3.2.clone();
     let __end0 = __3.2.clone();
-    let __temp0 = __action77(
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action129(
+    __action234(
         input,
         __0,
         __1,
@@ -10381,88 +17345,76 @@ fn __action154<
 }
 
 #[allow(unused_variables)]
-fn __action155<
+fn __action275<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, String, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Vec<Exp<Span1>>, usize),
-    __5: (usize, &'input str, usize),
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action130(
+    __action235(
         input,
         __0,
         __1,
-        __2,
-        __3,
-        __4,
-        __5,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action156<
+fn __action276<
     'input,
 >(
     input: &'input str,
-    __0: (usize, (), usize),
-    __1: (usize, Vec<String>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(
+    __action236(
         input,
         __0,
         __1,
         __2,
-        __3,
-        __4,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action157<
+fn __action277<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __temp0 = __action115(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action132(
+    __action237(
         input,
         __0,
         __1,
@@ -10472,461 +17424,382 @@ fn __action157<
 }
 
 #[allow(unused_variables)]
-fn __action158<
+fn __action278<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, SurfFunDecl<Span1>, usize),
-    __7: (usize, &'input str, usize),
-    __8: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, Exp<Span1>, usize),
+) -> Vec<Exp<Span1>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action146(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action279<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Exp<Span1>>
 {
-    let __start0 = __8.2.clone();
-    let __end0 = __8.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action147(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action133(
+    __action174(
         input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
-        __8,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action159<
+fn __action280<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __7: (usize, SurfFunDecl<Span1>, usize),
-    __8: (usize, &'input str, usize),
-    __9: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, Exp<Span1>, usize),
+) -> Vec<Exp<Span1>>
 {
-    let __start0 = __9.2.clone();
-    let __end0 = __9.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action146(
         input,
-        &__start0,
-        &__end0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action134(
+    __action175(
         input,
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
-        __8,
-        __9,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action160<
+fn __action281<
     'input,
 >(
     input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action147(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action135(
+    __action175(
         input,
         __0,
-        __1,
-        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action161<
+fn __action282<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, String, usize),
+) -> Vec<String>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action128(
         input,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action136(
+    __action178(
         input,
-        __0,
-        __1,
-        __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action162<
+fn __action283<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-) -> SurfFunDecl<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action129(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(
+    __action178(
         input,
-        __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action163<
+fn __action284<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, String, usize),
+) -> Vec<String>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action128(
         input,
-        &__start0,
-        &__end0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action138(
+    __action179(
         input,
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action164<
+fn __action285<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<String>, usize),
+) -> Vec<String>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action129(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action139(
+    __action179(
         input,
         __0,
-        __1,
-        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action165<
+fn __action286<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, Exp<Span1>, usize),
+) -> (Vec<(String, Span1)>, SurfProg<Span1>)
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action117(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action140(
+    __action2(
         input,
-        __0,
-        __1,
-        __2,
         __temp0,
+        __0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action166<
+fn __action287<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<(String, Span1)>, usize),
+    __1: (usize, Exp<Span1>, usize),
+) -> (Vec<(String, Span1)>, SurfProg<Span1>)
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action118(
         input,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action141(
+    __action2(
         input,
-        __0,
-        __1,
-        __2,
         __temp0,
+        __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action167<
+fn __action288<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, Pattern<Span1>, usize),
+) -> Vec<Pattern<Span1>>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action151(
         input,
-        &__start0,
-        &__end0,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action142(
+    __action182(
         input,
-        __0,
-        __1,
-        __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action168<
+fn __action289<
     'input,
 >(
     input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Pattern<Span1>>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action152(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action143(
+    __action182(
         input,
-        __0,
-        __1,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action169<
+fn __action290<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<Pattern<Span1>>, usize),
+    __1: (usize, Pattern<Span1>, usize),
+) -> Vec<Pattern<Span1>>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action151(
         input,
-        &__start0,
-        &__end0,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action144(
+    __action183(
         input,
         __0,
-        __1,
-        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action170<
+fn __action291<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<Pattern<Span1>>, usize),
+) -> Vec<Pattern<Span1>>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action77(
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action152(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action145(
+    __action183(
         input,
         __0,
-        __1,
-        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action171<
+fn __action292<
     'input,
 >(
     input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, SnakeType, usize),
+) -> Vec<SnakeType>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action98(
+    let __temp0 = __action133(
         input,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action109(
+    __action186(
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action172<
+fn __action293<
     'input,
 >(
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<Exp<Span1>>
+) -> Vec<SnakeType>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action99(
+    let __temp0 = __action134(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action109(
+    __action186(
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action173<
+fn __action294<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, Exp<Span1>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, alloc::vec::Vec<SnakeType>, usize),
+    __1: (usize, SnakeType, usize),
+) -> Vec<SnakeType>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action98(
+    let __temp0 = __action133(
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(
+    __action187(
         input,
         __0,
         __temp0,
@@ -10934,22 +17807,22 @@ fn __action173<
 }
 
 #[allow(unused_variables)]
-fn __action174<
+fn __action295<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, alloc::vec::Vec<SnakeType>, usize),
+) -> Vec<SnakeType>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action99(
+    let __temp0 = __action134(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(
+    __action187(
         input,
         __0,
         __temp0,
@@ -10957,66 +17830,66 @@ fn __action174<
 }
 
 #[allow(unused_variables)]
-fn __action175<
+fn __action296<
     'input,
 >(
     input: &'input str,
-    __0: (usize, String, usize),
-) -> Vec<String>
+    __0: (usize, (String, Option<SnakeType>), usize),
+) -> Vec<(String, Option<SnakeType>)>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action85(
+    let __temp0 = __action123(
         input,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action113(
+    __action190(
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action176<
+fn __action297<
     'input,
 >(
     input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<String>
+) -> Vec<(String, Option<SnakeType>)>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action86(
+    let __temp0 = __action124(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action113(
+    __action190(
         input,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action177<
+fn __action298<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-) -> Vec<String>
+    __0: (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize),
+    __1: (usize, (String, Option<SnakeType>), usize),
+) -> Vec<(String, Option<SnakeType>)>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action85(
+    let __temp0 = __action123(
         input,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action114(
+    __action191(
         input,
         __0,
         __temp0,
@@ -11024,22 +17897,22 @@ fn __action177<
 }
 
 #[allow(unused_variables)]
-fn __action178<
+fn __action299<
     'input,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-) -> Vec<String>
+    __0: (usize, alloc::vec::Vec<(String, Option<SnakeType>)>, usize),
+) -> Vec<(String, Option<SnakeType>)>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action86(
+    let __temp0 = __action124(
         input,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action114(
+    __action191(
         input,
         __0,
         __temp0,
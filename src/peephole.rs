@@ -0,0 +1,270 @@
+use crate::asm::{Arg32, Arg64, BinArgs, Instr, JmpArg, MemRef, MovArgs, Offset, Reg, Reg32};
+
+fn mem_uses(m: &MemRef, r: Reg) -> bool {
+    m.reg == r
+        || match m.offset {
+            Offset::Constant(_) => false,
+            Offset::Computed { reg, .. } => reg == r,
+        }
+}
+
+fn arg64_uses(a: &Arg64, r: Reg) -> bool {
+    match a {
+        Arg64::Reg(x) => *x == r,
+        Arg64::Mem(m) => mem_uses(m, r),
+        Arg64::Signed(_) | Arg64::Unsigned(_) | Arg64::Label(_) => false,
+    }
+}
+
+fn reads_flags(i: &Instr) -> bool {
+    matches!(
+        i,
+        Instr::Je(_)
+            | Instr::Jne(_)
+            | Instr::Jl(_)
+            | Instr::Jle(_)
+            | Instr::Jg(_)
+            | Instr::Jge(_)
+            | Instr::Jz(_)
+            | Instr::Jnz(_)
+            | Instr::Jo(_)
+            | Instr::Jno(_)
+    )
+}
+
+// The index of the last instruction in out that is not a comment
+fn last_instr(out: &[Instr]) -> Option<usize> {
+    out.iter().rposition(|i| !matches!(i, Instr::Comment(_)))
+}
+
+// Whether the flags set at index i are overwritten before anything reads
+// them, only moves and comments are looked through
+fn flags_dead_after(is: &[Instr], i: usize) -> bool {
+    for next in &is[i + 1..] {
+        match next {
+            Instr::Mov(_) | Instr::Comment(_) => {}
+            next => return !reads_flags(next) && !matches!(next, Instr::Label(_)),
+        }
+    }
+    true
+}
+
+// Removes the moves that do nothing given the instruction before them
+fn remove_redundant_moves(is: &[Instr]) -> Vec<Instr> {
+    let mut out: Vec<Instr> = Vec::new();
+    for i in is {
+        if let Instr::Mov(MovArgs::ToReg(r, Arg64::Reg(s))) = i {
+            if r == s {
+                continue;
+            }
+        }
+        if let Some(prev) = last_instr(&out) {
+            match (&out[prev], i) {
+                // storing back the value just loaded
+                (
+                    Instr::Mov(MovArgs::ToReg(r, Arg64::Mem(m))),
+                    Instr::Mov(MovArgs::ToMem(n, Reg32::Reg(s))),
+                ) if r == s && m == n && !mem_uses(m, *r) => continue,
+                // loading the value just stored
+                (
+                    Instr::Mov(MovArgs::ToMem(m, Reg32::Reg(r))),
+                    Instr::Mov(MovArgs::ToReg(s, Arg64::Mem(n))),
+                ) if r == s && m == n => continue,
+                // overwriting a register before anything read it
+                (Instr::Mov(MovArgs::ToReg(r, _)), Instr::Mov(MovArgs::ToReg(s, a)))
+                    if r == s && !arg64_uses(a, *r) =>
+                {
+                    out.remove(prev);
+                }
+                _ => {}
+            }
+        }
+        out.push(i.clone());
+    }
+    out
+}
+
+// Removes an add directly undone by a sub or the other way around, as long
+// as nothing reads the flags they set
+fn remove_undone_arith(is: &[Instr]) -> Vec<Instr> {
+    let mut out: Vec<Instr> = Vec::new();
+    for (idx, i) in is.iter().enumerate() {
+        if let Some(prev) = last_instr(&out) {
+            let undone = match (&out[prev], i) {
+                (Instr::Add(BinArgs::ToReg(r, a)), Instr::Sub(BinArgs::ToReg(s, b)))
+                | (Instr::Sub(BinArgs::ToReg(r, a)), Instr::Add(BinArgs::ToReg(s, b))) => {
+                    r == s && a == b && matches!(a, Arg32::Signed(_) | Arg32::Unsigned(_))
+                }
+                _ => false,
+            };
+            if undone && flags_dead_after(is, idx) {
+                out.remove(prev);
+                continue;
+            }
+        }
+        out.push(i.clone());
+    }
+    out
+}
+
+// Removes the jumps to a label that directly follows them
+fn remove_jumps_to_next(is: &[Instr]) -> Vec<Instr> {
+    let mut out: Vec<Instr> = Vec::new();
+    for (idx, i) in is.iter().enumerate() {
+        if let Instr::Jmp(JmpArg::Label(l)) = i {
+            let next_labels = is[idx + 1..]
+                .iter()
+                .filter(|i| !matches!(i, Instr::Comment(_)))
+                .take_while(|i| matches!(i, Instr::Label(_)));
+            if next_labels
+                .into_iter()
+                .any(|next| matches!(next, Instr::Label(m) if m == l))
+            {
+                continue;
+            }
+        }
+        out.push(i.clone());
+    }
+    out
+}
+
+pub fn optimize(is: &[Instr]) -> Vec<Instr> {
+    let mut is = is.to_vec();
+    loop {
+        let next = remove_jumps_to_next(&remove_undone_arith(&remove_redundant_moves(&is)));
+        if next == is {
+            return next;
+        }
+        is = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mem(reg: Reg, disp: i32) -> MemRef {
+        MemRef {
+            reg,
+            offset: Offset::Constant(disp),
+        }
+    }
+
+    fn mov(r: Reg, a: Arg64) -> Instr {
+        Instr::Mov(MovArgs::ToReg(r, a))
+    }
+
+    fn store(m: MemRef, r: Reg) -> Instr {
+        Instr::Mov(MovArgs::ToMem(m, Reg32::Reg(r)))
+    }
+
+    fn add(r: Reg, n: u32) -> Instr {
+        Instr::Add(BinArgs::ToReg(r, Arg32::Unsigned(n)))
+    }
+
+    fn sub(r: Reg, n: u32) -> Instr {
+        Instr::Sub(BinArgs::ToReg(r, Arg32::Unsigned(n)))
+    }
+
+    fn label(l: &str) -> Instr {
+        Instr::Label(String::from(l))
+    }
+
+    fn jmp(l: &str) -> Instr {
+        Instr::Jmp(JmpArg::Label(String::from(l)))
+    }
+
+    #[test]
+    fn redundant_moves() {
+        let slot = mem(Reg::Rsp, -8);
+        assert_eq!(
+            remove_redundant_moves(&[mov(Reg::Rax, Arg64::Reg(Reg::Rax))]),
+            []
+        );
+        assert_eq!(
+            remove_redundant_moves(&[mov(Reg::Rax, Arg64::Mem(slot)), store(slot, Reg::Rax)]),
+            [mov(Reg::Rax, Arg64::Mem(slot))]
+        );
+        assert_eq!(
+            remove_redundant_moves(&[store(slot, Reg::Rax), mov(Reg::Rax, Arg64::Mem(slot))]),
+            [store(slot, Reg::Rax)]
+        );
+        assert_eq!(
+            remove_redundant_moves(&[
+                mov(Reg::Rax, Arg64::Signed(1)),
+                mov(Reg::Rax, Arg64::Signed(2))
+            ]),
+            [mov(Reg::Rax, Arg64::Signed(2))]
+        );
+    }
+
+    #[test]
+    fn redundant_moves_kept() {
+        // the second move reads the register the first one set
+        let read = [
+            mov(Reg::Rax, Arg64::Mem(mem(Reg::Rsp, -8))),
+            mov(Reg::Rax, Arg64::Mem(mem(Reg::Rax, 8))),
+        ];
+        assert_eq!(remove_redundant_moves(&read), read);
+        // the load changes the register its address is computed from
+        let through = mem(Reg::Rax, 8);
+        let reload = [mov(Reg::Rax, Arg64::Mem(through)), store(through, Reg::Rax)];
+        assert_eq!(remove_redundant_moves(&reload), reload);
+        // a different slot
+        let other = [
+            store(mem(Reg::Rsp, -8), Reg::Rax),
+            mov(Reg::Rax, Arg64::Mem(mem(Reg::Rsp, -16))),
+        ];
+        assert_eq!(remove_redundant_moves(&other), other);
+    }
+
+    #[test]
+    fn undone_arith() {
+        assert_eq!(
+            remove_undone_arith(&[add(Reg::Rsp, 16), sub(Reg::Rsp, 16), jmp("next")]),
+            [jmp("next")]
+        );
+        assert_eq!(
+            remove_undone_arith(&[sub(Reg::Rsp, 8), add(Reg::Rsp, 8)]),
+            []
+        );
+    }
+
+    #[test]
+    fn undone_arith_kept() {
+        // the flags of the sub are read
+        let flags = [
+            add(Reg::Rax, 2),
+            sub(Reg::Rax, 2),
+            Instr::Jo(JmpArg::Label(String::from("overflow"))),
+        ];
+        assert_eq!(remove_undone_arith(&flags), flags);
+        // a different amount or register
+        let amount = [add(Reg::Rsp, 16), sub(Reg::Rsp, 8)];
+        assert_eq!(remove_undone_arith(&amount), amount);
+        let reg = [add(Reg::Rsp, 16), sub(Reg::Rax, 16)];
+        assert_eq!(remove_undone_arith(&reg), reg);
+    }
+
+    #[test]
+    fn jumps_to_next() {
+        assert_eq!(
+            remove_jumps_to_next(&[jmp("done"), label("done")]),
+            [label("done")]
+        );
+        assert_eq!(
+            remove_jumps_to_next(&[jmp("done"), label("other"), label("done")]),
+            [label("other"), label("done")]
+        );
+    }
+
+    #[test]
+    fn jumps_to_next_kept() {
+        // an instruction runs between the jump and its label
+        let skip = [jmp("done"), add(Reg::Rax, 2), label("done")];
+        assert_eq!(remove_jumps_to_next(&skip), skip);
+        // the next label is another one
+        let other = [jmp("done"), label("other"), add(Reg::Rax, 2), label("done")];
+        assert_eq!(remove_jumps_to_next(&other), other);
+    }
+}
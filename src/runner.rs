@@ -1,47 +1,86 @@
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{BufReader, Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 use std::fmt::{Display, Formatter};
 
+use crate::asm::{program_to_string, Program};
+use crate::bytecode::Bytecode;
 use crate::compile;
-use crate::compile::{compile_to_string, CompileErr};
+use crate::compile::{
+    check_warnings, compile_to_bytecode, compile_to_c, compile_to_program, compile_to_wasm,
+    CompileErr, CompileStats, CompileWarning,
+};
+use crate::elf;
+use crate::jit;
+use crate::timing;
+use crate::vm;
+use crate::wasm;
 // use crate::interp;
 // use crate::interp::InterpErr;
-use crate::parser::ProgParser;
-use crate::syntax::SurfProg;
+use crate::modules::{self, LoadErr};
+use crate::syntax::{SnakeType, SurfProg};
 
-mod span {
-    use crate::span::{Span1, Span2};
+pub(crate) mod span {
+    use crate::modules::SourceFile;
+    use crate::span::{intern_file, Span1, Span2};
     use std::fmt::Display;
 
+    // The lines of the program and of the files it imports, which all share
+    // one range of offsets
     #[derive(Clone, Debug)]
     pub struct FileInfo {
+        files: Vec<FileLines>,
+    }
+
+    #[derive(Clone, Debug)]
+    struct FileLines {
+        name: Option<&'static str>,
+        base: usize,
         newlines: Vec<usize>,
         len: usize,
     }
 
-    pub fn file_info(s: &str) -> FileInfo {
+    pub fn file_info(files: &[SourceFile]) -> FileInfo {
         FileInfo {
-            newlines: s
-                .char_indices()
-                .filter(|(_i, c)| *c == '\n')
-                .map(|(i, _c)| i)
+            files: files
+                .iter()
+                .map(|file| FileLines {
+                    name: file.name.as_deref().map(intern_file),
+                    base: file.base,
+                    newlines: file
+                        .text
+                        .char_indices()
+                        .filter(|(_i, c)| *c == '\n')
+                        .map(|(i, _c)| i)
+                        .collect(),
+                    len: file.text.len(),
+                })
                 .collect(),
-            len: s.len(),
         }
     }
 
     pub fn span1_to_span2(info: &FileInfo, offsets: Span1) -> Span2 {
+        let file = info
+            .files
+            .iter()
+            .rev()
+            .find(|file| file.base <= offsets.start_ix)
+            .expect("internal error: span1_to_span2 found no file");
         let mut v = vec![0];
-        v.extend(info.newlines.iter().map(|ix| ix + 1));
-        v.push(info.len);
+        v.extend(file.newlines.iter().map(|ix| ix + 1));
+        v.push(file.len);
 
-        let (start_line, start_col) = offset_to_line_col(&v, offsets.start_ix);
-        let (end_line, end_col) = offset_to_line_col(&v, offsets.end_ix - 1);
+        let (start_line, start_col) = offset_to_line_col(&v, offsets.start_ix - file.base);
+        let (end_line, end_col) = offset_to_line_col(&v, offsets.end_ix - 1 - file.base);
         Span2 {
+            file: file.name,
             start_line,
             start_col,
             end_line,
@@ -61,6 +100,29 @@ mod span {
 
     use crate::compile::CompileErr;
     impl<Span> CompileErr<Span> {
+        // The part of the program the error is about
+        pub fn location(&self) -> &Span {
+            match self {
+                CompileErr::UnboundVariable { location, .. }
+                | CompileErr::UndefinedFunction { location, .. }
+                | CompileErr::DuplicateBinding { location, .. }
+                | CompileErr::Overflow { location, .. }
+                | CompileErr::DuplicateFunName { location, .. }
+                | CompileErr::DuplicateArgName { location, .. }
+                | CompileErr::UndefinedClass { location, .. }
+                | CompileErr::UndefinedMethod { location, .. }
+                | CompileErr::UndefinedField { location, .. }
+                | CompileErr::DuplicateField { location, .. }
+                | CompileErr::DuplicateMethod { location, .. }
+                | CompileErr::WrongFieldSize { location, .. }
+                | CompileErr::ImmutableAssignment { location, .. }
+                | CompileErr::TypeMismatch { location, .. }
+                | CompileErr::ArityMismatch { location, .. }
+                | CompileErr::UnreadableImport { location, .. }
+                | CompileErr::ImportCycle { location, .. } => location,
+            }
+        }
+
         pub fn map_span<F, SpanPrime>(self, f: F) -> CompileErr<SpanPrime>
         where
             F: FnOnce(&Span) -> SpanPrime,
@@ -150,12 +212,81 @@ mod span {
                     class: class,
                     location: f(&location),
                 },
+
+                CompileErr::ImmutableAssignment { name, location } => {
+                    CompileErr::ImmutableAssignment {
+                        name,
+                        location: f(&location),
+                    }
+                }
+
+                CompileErr::TypeMismatch {
+                    expected,
+                    found,
+                    location,
+                } => CompileErr::TypeMismatch {
+                    expected,
+                    found,
+                    location: f(&location),
+                },
+
+                CompileErr::ArityMismatch {
+                    expected,
+                    found,
+                    location,
+                } => CompileErr::ArityMismatch {
+                    expected,
+                    found,
+                    location: f(&location),
+                },
+                CompileErr::UnreadableImport {
+                    path,
+                    reason,
+                    location,
+                } => CompileErr::UnreadableImport {
+                    path,
+                    reason,
+                    location: f(&location),
+                },
+                CompileErr::ImportCycle { path, location } => CompileErr::ImportCycle {
+                    path,
+                    location: f(&location),
+                },
+            }
+        }
+    }
+
+    use crate::compile::CompileWarning;
+    impl<Span> CompileWarning<Span> {
+        pub fn location(&self) -> &Span {
+            match self {
+                CompileWarning::NonExhaustiveMatch { location }
+                | CompileWarning::RedundantArm { location } => location,
+            }
+        }
+
+        pub fn map_span<F, SpanPrime>(self, f: F) -> CompileWarning<SpanPrime>
+        where
+            F: FnOnce(&Span) -> SpanPrime,
+        {
+            match self {
+                CompileWarning::NonExhaustiveMatch { location } => {
+                    CompileWarning::NonExhaustiveMatch {
+                        location: f(&location),
+                    }
+                }
+                CompileWarning::RedundantArm { location } => CompileWarning::RedundantArm {
+                    location: f(&location),
+                },
             }
         }
     }
 
     impl Display for Span2 {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            if let Some(file) = self.file {
+                write!(f, "{}, ", file)?;
+            }
             write!(
                 f,
                 "line {}, column {} to line {}, column {}",
@@ -275,6 +406,80 @@ where
                 "Wrong number of fields applied to construct object with class \"{}\" at {}",
                 class, location
             ),
+
+            CompileErr::ImmutableAssignment { name, location } => write!(
+                f,
+                "Cannot assign to \"{}\" at {}, only let-bound variables and fields are assignable",
+                name, location
+            ),
+
+            CompileErr::TypeMismatch {
+                expected,
+                found,
+                location,
+            } => write!(
+                f,
+                "Type mismatch at {}: expected {} but found {}",
+                location, expected, found
+            ),
+
+            CompileErr::ArityMismatch {
+                expected,
+                found,
+                location,
+            } => write!(
+                f,
+                "Expected {} arguments but found {} at {}",
+                expected, found, location
+            ),
+            CompileErr::UnreadableImport {
+                path,
+                reason,
+                location,
+            } => write!(
+                f,
+                "Cannot read \"{}\" imported at {}: {}",
+                path, location, reason
+            ),
+            CompileErr::ImportCycle { path, location } => {
+                write!(f, "Import of \"{}\" at {} forms a cycle", path, location)
+            }
+        }
+    }
+}
+
+impl Display for SnakeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SnakeType::Num => write!(f, "Num"),
+            SnakeType::Bool => write!(f, "Bool"),
+            SnakeType::Array => write!(f, "Array"),
+            SnakeType::Any => write!(f, "Any"),
+            SnakeType::Class(name) => write!(f, "{}", name),
+            SnakeType::Fun(ps, ret) => {
+                let ps: Vec<String> = ps.iter().map(|p| p.to_string()).collect();
+                write!(f, "({}) -> {}", ps.join(", "), ret)
+            }
+        }
+    }
+}
+
+impl<Span> Display for CompileWarning<Span>
+where
+    Span: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CompileWarning::NonExhaustiveMatch { location } => write!(
+                f,
+                "Match at {} may not cover every value, add a wildcard arm",
+                location
+            ),
+            CompileWarning::RedundantArm { location } => write!(
+                f,
+                "Match arm at {} is unreachable, earlier arms cover it",
+                location
+            ),
         }
     }
 }
@@ -301,19 +506,25 @@ where
     Span: Display,
 {
     eprintln!("{}", e);
+    report_times();
     std::process::exit(1);
 }
 
+// Prints the times --time-passes recorded, if it did
+pub fn report_times() {
+    let times = timing::take();
+    if !times.is_empty() {
+        eprintln!("{}", timing::report(&times));
+    }
+}
+
 fn handle_errs<Span>(r: Result<String, RunnerErr<Span>>)
 where
     Span: Display,
 {
     match r {
         Ok(s) => println!("{}", s),
-        Err(e) => {
-            eprintln!("{}", e);
-            std::process::exit(1);
-        }
+        Err(e) => fail(e),
     }
 }
 
@@ -321,12 +532,73 @@ pub fn emit_assembly(p: &Path) {
     handle_errs(compile_file(p))
 }
 
+pub fn emit_object(p: &Path) {
+    let written = object_file(p).and_then(|obj| {
+        std::fs::write("compiled_code.o", obj).map_err(|e| RunnerErr::FileOpen(e.to_string()))
+    });
+    if let Err(e) = written {
+        fail(e)
+    }
+}
+
+pub fn emit_c(p: &Path) {
+    let written = compile_file_with(p, compile_to_c).and_then(|source| {
+        std::fs::write("compiled_code.c", source).map_err(|e| RunnerErr::FileOpen(e.to_string()))
+    });
+    if let Err(e) = written {
+        fail(e)
+    }
+}
+
+pub fn emit_wasm(p: &Path) {
+    let written = wasm_file(p).and_then(|module| {
+        std::fs::write("compiled_code.wasm", module).map_err(|e| RunnerErr::FileOpen(e.to_string()))
+    });
+    if let Err(e) = written {
+        fail(e)
+    }
+}
+
+pub fn emit_stats(p: &Path) {
+    match stats_file(p) {
+        Ok(stats) => {
+            println!("lifted functions: {}", stats.lifted_functions);
+            println!("lifted methods: {}", stats.lifted_methods);
+            println!("classes: {}", stats.classes);
+            println!("instructions: {}", stats.instructions);
+            println!("runtime checks: {}", stats.runtime_checks);
+            println!("removed tag checks: {}", stats.removed_checks);
+            println!("stack slots: {}", stats.stack_slots);
+        }
+        Err(e) => fail(e),
+    }
+}
+
 pub fn run(p: &Path) {
     if let Err(e) = compile_and_run_file(p, Path::new("runtime"), &mut std::io::stdout()) {
         fail(e)
     }
 }
 
+pub fn build(p: &Path, exe_fname: &Path) {
+    if let Err(e) = build_file(p, Path::new("runtime"), exe_fname) {
+        fail(e)
+    }
+}
+
+pub fn jit(p: &Path) {
+    if let Err(e) = jit_run_file(p, &mut std::io::stdout()) {
+        fail(e)
+    }
+}
+
+pub fn vm(p: &Path) {
+    let run = compile_file_to_bytecode(p).and_then(|b| vm_and_run(&b, &mut std::io::stdout()));
+    if let Err(e) = run {
+        fail(e)
+    }
+}
+
 pub fn interp<W>(p: &Path, w: &mut W)
 where
     W: std::io::Write,
@@ -348,18 +620,136 @@ where
     Ok(())
 }
 
+// Builds the executable with the runtime in dir and runs it, so a program
+// that goes wrong cannot take the compiler down with it
 pub fn compile_and_run_file<W>(p: &Path, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
 where
     W: std::io::Write,
 {
-    let asm = compile_file(p)?;
-    link_and_run(&asm, dir, out)
+    let (prog, _) = compile_file_with_stats(p)?;
+    link_and_run(&prog, dir, out)
+}
+
+// Runs the program inside the compiler, which only works on x86-64 Linux
+pub fn jit_run_file<W>(p: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let (prog, _) = compile_file_with_stats(p)?;
+    jit_and_run(&prog, out)
+}
+
+// Runs the program on the bytecode VM, which works on any platform
+pub fn vm_run_file<W>(p: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let b = compile_file_to_bytecode(p)?;
+    vm_and_run(&b, out)
+}
+
+// Runs the WebAssembly module of the program on the embedded interpreter
+pub fn wasm_run_file<W>(p: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let module = wasm_file(p)?;
+    let (stdout, failure) = timing::time("run", || wasm::run(&module)).map_err(RunnerErr::Run)?;
+    write_run(&stdout, failure, out)
+}
+
+// Builds the C source of the program with runtime/stub.c at the given
+// optimisation level, like -O2, and runs it. The compiler is cc unless the
+// CC environment variable names another one.
+pub fn c_run_file<W>(p: &Path, dir: &Path, opt: &str, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let source = compile_file_with(p, compile_to_c)?;
+    let c_fname = dir.join("compiled_code.c");
+    let exe_fname = dir.join("stub_c.exe");
+    std::fs::write(&c_fname, source).map_err(|e| RunnerErr::Link(e.to_string()))?;
+
+    let cc = std::env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    let cc_out = timing::time("cc", || {
+        Command::new(&cc)
+            .arg(opt)
+            .arg("-o")
+            .arg(&exe_fname)
+            .arg(&c_fname)
+            .arg("runtime/stub.c")
+            .arg("-pthread")
+            .output()
+    })
+    .map_err(|e| RunnerErr::Link(format!("{} err: {}", cc, e)))?;
+    if !cc_out.status.success() {
+        return Err(RunnerErr::Link(format!(
+            "Failure in {} call: {}\n{}",
+            cc,
+            cc_out.status,
+            String::from_utf8_lossy(&cc_out.stderr)
+        )));
+    }
+    timing::time("run", || run_exe(&exe_fname, out))
+}
+
+// Builds a standalone executable out of the program, which takes the
+// runtime's flags on its command line
+pub fn build_file(p: &Path, dir: &Path, exe_fname: &Path) -> Result<(), RunnerErr<Span2>> {
+    let (prog, _) = compile_file_with_stats(p)?;
+    link(&prog, dir, exe_fname)
+}
+
+// The counts --stats reports about the program
+pub fn stats_file(p: &Path) -> Result<CompileStats, RunnerErr<Span2>> {
+    compile_file_with_stats(p).map(|(_, stats)| stats)
+}
+
+// The ELF object of a program, written without going through nasm
+pub fn object_file(p: &Path) -> Result<Vec<u8>, RunnerErr<Span2>> {
+    let (prog, _) = compile_file_with_stats(p)?;
+    Ok(elf::write_object(&prog))
+}
+
+// The WebAssembly module of a program, which imports the runtime functions
+// from the host
+pub fn wasm_file(p: &Path) -> Result<Vec<u8>, RunnerErr<Span2>> {
+    compile_file_with(p, compile_to_wasm)
+}
+
+// The ELF object nasm assembles from the same program, to check the one
+// written by object_file against
+pub fn nasm_object_file(p: &Path, dir: &Path) -> Result<Vec<u8>, RunnerErr<Span2>> {
+    let (prog, _) = compile_file_with_stats(p)?;
+    let obj_fname = assemble(&program_to_string(&prog), "elf64", dir)?;
+    std::fs::read(obj_fname).map_err(|e| RunnerErr::Link(e.to_string()))
 }
 
 fn compile_file(p: &Path) -> Result<String, RunnerErr<Span2>> {
+    compile_file_with_stats(p).map(|(prog, _)| program_to_string(&prog))
+}
+
+fn compile_file_with_stats(p: &Path) -> Result<(Program, CompileStats), RunnerErr<Span2>> {
+    compile_file_with(p, compile_to_program)
+}
+
+fn compile_file_to_bytecode(p: &Path) -> Result<Bytecode, RunnerErr<Span2>> {
+    compile_file_with(p, compile_to_bytecode)
+}
+
+// Parses the file, reports its warnings and hands it to the backend
+fn compile_file_with<T, F>(p: &Path, backend: F) -> Result<T, RunnerErr<Span2>>
+where
+    F: FnOnce(&SurfProg<Span1>) -> Result<T, CompileErr<Span1>>,
+{
     let (info, prog) = parse_file(p)?;
-    compile_to_string(&prog)
-        .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
+    for warning in check_warnings(&prog) {
+        eprintln!(
+            "Warning: {}",
+            warning.map_span(|s| span1_to_span2(&info, *s))
+        );
+    }
+    backend(&prog).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
 }
 
 fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
@@ -372,30 +762,53 @@ fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
 
 fn parse_file(p: &Path) -> Result<(FileInfo, SurfProg<Span1>), RunnerErr<Span2>> {
     let s = read_file(p)?;
-    let e = ProgParser::new()
-        .parse(&s)
-        .map_err(|e| RunnerErr::Parse(e.to_string()))?;
-    Ok((file_info(&s), e))
+    match timing::time("parse", || modules::load(&s, Some(p))) {
+        Ok((files, prog)) => Ok((file_info(&files), prog)),
+        Err((_, LoadErr::Parse { message, .. })) => Err(RunnerErr::Parse(message)),
+        Err((files, LoadErr::Import(e))) => {
+            let info = file_info(&files);
+            Err(RunnerErr::CodeGen(
+                e.map_span(|s| span1_to_span2(&info, *s)),
+            ))
+        }
+    }
 }
 
-fn link_and_run<W>(assembly: &str, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+fn jit_and_run<W>(prog: &Program, out: &mut W) -> Result<(), RunnerErr<Span2>>
 where
     W: std::io::Write,
 {
-    let (nasm_format, lib_name) = if cfg!(target_os = "linux") {
-        ("elf64", "libcompiled_code.a")
-    } else if cfg!(target_os = "macos") {
-        ("macho64", "libcompiled_code.a")
-    } else if cfg!(target_os = "windows") {
-        ("win64", "compiled_code.lib")
-    } else {
-        panic!("Runner script only works on linux, macos and windows")
-    };
+    let (stdout, failure) = timing::time("run", || jit::run(prog)).map_err(RunnerErr::Run)?;
+    write_run(&stdout, failure, out)
+}
+
+fn vm_and_run<W>(b: &Bytecode, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let (stdout, failure) = timing::time("run", || vm::run(b));
+    write_run(&stdout, failure, out)
+}
 
+// Writes what a run in the compiler printed and reports its failure
+fn write_run<W>(stdout: &str, failure: Option<String>, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    write!(out, "{}", stdout).map_err(|e| RunnerErr::Run(format!("I/O error: {}", e)))?;
+    match failure {
+        None => Ok(()),
+        Some(msg) => Err(RunnerErr::Run(format!(
+            "Error when running compiled code Stderr:\n{}\n",
+            msg
+        ))),
+    }
+}
+
+// Assembles the program with nasm into compiled_code.o and returns its path
+fn assemble(assembly: &str, nasm_format: &str, dir: &Path) -> Result<PathBuf, RunnerErr<Span2>> {
     let asm_fname = dir.join("compiled_code.s");
     let obj_fname = dir.join("compiled_code.o");
-    let lib_fname = dir.join(lib_name);
-    let exe_fname = dir.join("stub.exe");
 
     // first put the assembly in a new file compiled_code.s
     let mut asm_file = File::create(&asm_fname).map_err(|e| RunnerErr::Link(e.to_string()))?;
@@ -407,14 +820,16 @@ where
         .map_err(|e| RunnerErr::Link(e.to_string()))?;
 
     // nasm -fFORMAT -o compiled_code.o compiled_code.s
-    let nasm_out = Command::new("nasm")
-        .arg("-f")
-        .arg(nasm_format)
-        .arg("-o")
-        .arg(&obj_fname)
-        .arg(&asm_fname)
-        .output()
-        .map_err(|e| RunnerErr::Link(format!("nasm err: {}", e)))?;
+    let nasm_out = timing::time("nasm", || {
+        Command::new("nasm")
+            .arg("-f")
+            .arg(nasm_format)
+            .arg("-o")
+            .arg(&obj_fname)
+            .arg(&asm_fname)
+            .output()
+    })
+    .map_err(|e| RunnerErr::Link(format!("nasm err: {}", e)))?;
     if !nasm_out.status.success() {
         return Err(RunnerErr::Link(format!(
             "Failure in nasm call: {}\n{}",
@@ -422,14 +837,52 @@ where
             std::str::from_utf8(&nasm_out.stderr).expect("nasm produced invalid UTF-8")
         )));
     }
+    Ok(obj_fname)
+}
+
+fn link_and_run<W>(prog: &Program, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let exe_fname = dir.join("stub.exe");
+    link(prog, dir, &exe_fname)?;
+    timing::time("run", || run_exe(&exe_fname, out))
+}
+
+// Links the program with the runtime into the executable exe_fname, using
+// dir for the intermediate files
+fn link(prog: &Program, dir: &Path, exe_fname: &Path) -> Result<(), RunnerErr<Span2>> {
+    let (nasm_format, lib_name) = if cfg!(target_os = "linux") {
+        ("elf64", "libcompiled_code.a")
+    } else if cfg!(target_os = "macos") {
+        ("macho64", "libcompiled_code.a")
+    } else if cfg!(target_os = "windows") {
+        ("win64", "compiled_code.lib")
+    } else {
+        panic!("Runner script only works on linux, macos and windows")
+    };
+
+    let lib_fname = dir.join(lib_name);
+
+    // ELF objects are written directly and linked against the cached
+    // runtime, the other formats go through nasm and rustc
+    if cfg!(target_os = "linux") {
+        let obj_fname = dir.join("compiled_code.o");
+        let obj = timing::time("write_object", || elf::write_object(prog));
+        std::fs::write(&obj_fname, obj).map_err(|e| RunnerErr::Link(e.to_string()))?;
+        return link_runtime(&obj_fname, exe_fname);
+    }
+    let obj_fname = assemble(&program_to_string(prog), nasm_format, dir)?;
 
     // ar r libcompiled_code.a compiled_code.o
-    let ar_out = Command::new("ar")
-        .arg("rus")
-        .arg(lib_fname)
-        .arg(&obj_fname)
-        .output()
-        .map_err(|e| RunnerErr::Link(format!("ar err: {}", e)))?;
+    let ar_out = timing::time("ar", || {
+        Command::new("ar")
+            .arg("rus")
+            .arg(lib_fname)
+            .arg(&obj_fname)
+            .output()
+    })
+    .map_err(|e| RunnerErr::Link(format!("ar err: {}", e)))?;
     if !ar_out.status.success() {
         return Err(RunnerErr::Link(format!(
             "Failure in ar call:\n{}\n{}",
@@ -440,25 +893,29 @@ where
 
     // rustc stub.rs -L tmp
     let rustc_out = if cfg!(target_os = "macos") {
-        Command::new("rustc")
-            .arg("runtime/stub.rs")
-            .arg("--target")
-            .arg("x86_64-apple-darwin")
-            .arg("-L")
-            .arg(dir)
-            .arg("-o")
-            .arg(&exe_fname)
-            .output()
-            .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
+        timing::time("rustc", || {
+            Command::new("rustc")
+                .arg("runtime/stub.rs")
+                .arg("--target")
+                .arg("x86_64-apple-darwin")
+                .arg("-L")
+                .arg(dir)
+                .arg("-o")
+                .arg(exe_fname)
+                .output()
+        })
+        .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
     } else {
-        Command::new("rustc")
-            .arg("runtime/stub.rs")
-            .arg("-L")
-            .arg(dir)
-            .arg("-o")
-            .arg(&exe_fname)
-            .output()
-            .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
+        timing::time("rustc", || {
+            Command::new("rustc")
+                .arg("runtime/stub.rs")
+                .arg("-L")
+                .arg(dir)
+                .arg("-o")
+                .arg(exe_fname)
+                .output()
+        })
+        .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
     };
     if !rustc_out.status.success() {
         return Err(RunnerErr::Link(format!(
@@ -467,8 +924,136 @@ where
             std::str::from_utf8(&rustc_out.stderr).expect("rustc produced invalid UTF-8")
         )));
     }
+    Ok(())
+}
+
+// The runtime built into a static library, along with the native libraries
+// it needs, which rustc only reports when building it
+#[derive(Clone)]
+struct RuntimeLib {
+    lib: PathBuf,
+    native_libs: Vec<String>,
+}
 
-    let mut child = Command::new(&exe_fname)
+// How rustc builds the runtime library. No -O: optimized builds drop the
+// \x01 export names of the stub.
+static RUNTIME_FLAGS: [&str; 7] = [
+    "runtime/stub.rs",
+    "--crate-type",
+    "staticlib",
+    "--cfg",
+    "snake_staticlib",
+    "--print",
+    "native-static-libs",
+];
+
+// Builds runtime/stub.rs once into a static library that provides main
+// itself. It is kept in the temp directory under a hash of the stub's
+// source, the rustc version and the flags, so it survives between runs and
+// is rebuilt when any of them changes.
+fn runtime_lib() -> Result<RuntimeLib, RunnerErr<Span2>> {
+    static RUNTIME: Mutex<Option<RuntimeLib>> = Mutex::new(None);
+    let mut cached = RUNTIME.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(runtime) = &*cached {
+        return Ok(runtime.clone());
+    }
+
+    let source = std::fs::read("runtime/stub.rs")
+        .map_err(|e| RunnerErr::Link(format!("runtime/stub.rs: {}", e)))?;
+    let version = Command::new("rustc")
+        .arg("-V")
+        .output()
+        .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?;
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    version.stdout.hash(&mut hasher);
+    RUNTIME_FLAGS.hash(&mut hasher);
+    let cache_dir = std::env::temp_dir().join(format!("snake-runtime-{:016x}", hasher.finish()));
+    let lib = cache_dir.join("libsnake_runtime.a");
+    let libs_fname = cache_dir.join("native_libs");
+
+    if !lib.exists() || !libs_fname.exists() {
+        // built in a directory of its own and moved into place, so other
+        // processes never see a partial library or list of libraries
+        let build_dir = cache_dir.join(format!("build-{}", std::process::id()));
+        std::fs::create_dir_all(&build_dir).map_err(|e| RunnerErr::Link(e.to_string()))?;
+        let build_lib = build_dir.join("libsnake_runtime.a");
+        let build_libs_fname = build_dir.join("native_libs");
+        let rustc_out = timing::time("rustc runtime", || {
+            Command::new("rustc")
+                .args(RUNTIME_FLAGS)
+                .arg("-o")
+                .arg(&build_lib)
+                .output()
+        })
+        .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?;
+        if !rustc_out.status.success() {
+            return Err(RunnerErr::Link(format!(
+                "Failure in rustc call: {}\n{}",
+                rustc_out.status,
+                std::str::from_utf8(&rustc_out.stderr).expect("rustc produced invalid UTF-8")
+            )));
+        }
+        let report = String::from_utf8_lossy(&rustc_out.stderr).into_owned()
+            + &String::from_utf8_lossy(&rustc_out.stdout);
+        let native_libs = report
+            .lines()
+            .find_map(|line| line.split("native-static-libs:").nth(1))
+            .ok_or_else(|| RunnerErr::Link("rustc did not report native-static-libs".to_string()))?
+            .trim()
+            .to_string();
+        std::fs::write(&build_libs_fname, native_libs)
+            .map_err(|e| RunnerErr::Link(e.to_string()))?;
+        std::fs::rename(&build_libs_fname, &libs_fname)
+            .map_err(|e| RunnerErr::Link(e.to_string()))?;
+        std::fs::rename(&build_lib, &lib).map_err(|e| RunnerErr::Link(e.to_string()))?;
+        let _ = std::fs::remove_dir_all(&build_dir);
+    }
+
+    let native_libs: Vec<String> = std::fs::read_to_string(&libs_fname)
+        .map_err(|e| RunnerErr::Link(e.to_string()))?
+        .split_whitespace()
+        .map(String::from)
+        .collect();
+    let runtime = RuntimeLib { lib, native_libs };
+    *cached = Some(runtime.clone());
+    Ok(runtime)
+}
+
+// cc -no-pie -o stub.exe compiled_code.o libsnake_runtime.a
+fn link_runtime(obj_fname: &Path, exe_fname: &Path) -> Result<(), RunnerErr<Span2>> {
+    let runtime = runtime_lib()?;
+    let cc = std::env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    // the compiled code loads labels as absolute addresses, which a
+    // position independent executable cannot relocate
+    let cc_out = timing::time("cc", || {
+        Command::new(&cc)
+            .arg("-no-pie")
+            .arg("-o")
+            .arg(exe_fname)
+            .arg(obj_fname)
+            .arg(&runtime.lib)
+            .args(&runtime.native_libs)
+            .output()
+    })
+    .map_err(|e| RunnerErr::Link(format!("{} err: {}", cc, e)))?;
+    if !cc_out.status.success() {
+        return Err(RunnerErr::Link(format!(
+            "Failure in {} call: {}\n{}",
+            cc,
+            cc_out.status,
+            String::from_utf8_lossy(&cc_out.stderr)
+        )));
+    }
+    Ok(())
+}
+
+// Runs a built program and copies its output
+fn run_exe<W>(exe_fname: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let mut child = Command::new(exe_fname)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
@@ -1,4 +1,4 @@
-use crate::asm::{Arg32, Arg64, BinArgs, Instr, JmpArg, MemRef, MovArgs, Offset, Reg};
+use crate::asm::{Arg32, Arg64, BinArgs, Instr, JmpArg, MemRef, MovArgs, Offset, Reg, Reg32};
 use crate::syntax::{ClassInfo, Prim1, Prim2};
 
 use std::collections::HashMap;
@@ -9,6 +9,12 @@ static BOOL_TAG: u32 = 0b111;
 static ARRAY_TAG: u32 = 0b001;
 static CLOSURE_TAG: u32 = 0b011;
 
+// Built-in failures caught by a try are raised as objects of this class,
+// whose only field is the RuntimeErr code of the failure
+pub static ERROR_CLASS: &str = "Error";
+pub static ERROR_CLASS_ID: usize = 1;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum RuntimeErr {
     IfError,
     CmpError,
@@ -23,8 +29,34 @@ pub enum RuntimeErr {
     ArityError,
     MethodTypeError,
     FieldNumError,
+    WhileError,
+    MatchError,
+    DestructureError,
+    OutOfMemoryError,
+    StackOverflowError,
 }
 
+static RUNTIME_ERRS: [RuntimeErr; 18] = [
+    RuntimeErr::IfError,
+    RuntimeErr::CmpError,
+    RuntimeErr::ArithError,
+    RuntimeErr::LogicError,
+    RuntimeErr::OverflowError,
+    RuntimeErr::ArrayError,
+    RuntimeErr::IndexError,
+    RuntimeErr::BoundingError,
+    RuntimeErr::LengthError,
+    RuntimeErr::ClosureError,
+    RuntimeErr::ArityError,
+    RuntimeErr::MethodTypeError,
+    RuntimeErr::FieldNumError,
+    RuntimeErr::WhileError,
+    RuntimeErr::MatchError,
+    RuntimeErr::DestructureError,
+    RuntimeErr::OutOfMemoryError,
+    RuntimeErr::StackOverflowError,
+];
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Type {
     Num,
@@ -33,33 +65,111 @@ pub enum Type {
     Closure,
 }
 
+// The cold stub that loads the error code into Rdi for snake_err, so the
+// checks on the fast path are just a test and a conditional jump
+fn err_label(err: RuntimeErr) -> String {
+    String::from(match err {
+        RuntimeErr::IfError => "err_if",
+        RuntimeErr::CmpError => "err_cmp",
+        RuntimeErr::ArithError => "err_arith",
+        RuntimeErr::LogicError => "err_logic",
+        RuntimeErr::OverflowError => "err_overflow",
+        RuntimeErr::ArrayError => "err_array",
+        RuntimeErr::IndexError => "err_index",
+        RuntimeErr::BoundingError => "err_bounds",
+        RuntimeErr::LengthError => "err_length",
+        RuntimeErr::ClosureError => "err_closure",
+        RuntimeErr::ArityError => "err_arity",
+        RuntimeErr::MethodTypeError => "err_method",
+        RuntimeErr::FieldNumError => "err_field_num",
+        RuntimeErr::WhileError => "err_while",
+        RuntimeErr::MatchError => "err_match",
+        RuntimeErr::DestructureError => "err_destructure",
+        RuntimeErr::OutOfMemoryError => "err_out_of_memory",
+        RuntimeErr::StackOverflowError => "err_stack_overflow",
+    })
+}
+
+// What the runtime reports for the failure with the given code, the same
+// messages as runtime/stub.rs
+pub fn error_message(code: u64) -> &'static str {
+    match RUNTIME_ERRS.get(code as usize) {
+        Some(RuntimeErr::IfError) => "if expected a boolean",
+        Some(RuntimeErr::CmpError) => "comparison expected a number",
+        Some(RuntimeErr::ArithError) => "arithmetic expected a number",
+        Some(RuntimeErr::LogicError) => "logic expected a boolean",
+        Some(RuntimeErr::OverflowError) => "overflow",
+        Some(RuntimeErr::ArrayError) => "indexed into non-array",
+        Some(RuntimeErr::IndexError) => "index not a number",
+        Some(RuntimeErr::BoundingError) => "index out of bounds",
+        Some(RuntimeErr::LengthError) => "length called with non-array",
+        Some(RuntimeErr::ClosureError) => "called a non-function",
+        Some(RuntimeErr::ArityError) => "wrong number of arguments",
+        Some(RuntimeErr::MethodTypeError) => "calling method from another class",
+        Some(RuntimeErr::FieldNumError) => "wrong number of fields when constructing object",
+        Some(RuntimeErr::WhileError) => "while expected a boolean",
+        Some(RuntimeErr::MatchError) => "no match arm matched the value",
+        Some(RuntimeErr::DestructureError) => "let pattern does not match the value",
+        Some(RuntimeErr::OutOfMemoryError) => "out of memory",
+        Some(RuntimeErr::StackOverflowError) => "stack overflow",
+        None => "Unknown Error!",
+    }
+}
+
+fn jmp_err(err: RuntimeErr) -> JmpArg {
+    JmpArg::Label(err_label(err))
+}
+
+// The number of jumps to the error labels, each one a check the program
+// makes at runtime
+pub fn count_checks(instrs: &[Instr]) -> usize {
+    let labels: Vec<String> = RUNTIME_ERRS.iter().map(|err| err_label(*err)).collect();
+    instrs
+        .iter()
+        .filter(|i| match i {
+            Instr::Jmp(JmpArg::Label(l))
+            | Instr::Je(JmpArg::Label(l))
+            | Instr::Jne(JmpArg::Label(l))
+            | Instr::Jl(JmpArg::Label(l))
+            | Instr::Jle(JmpArg::Label(l))
+            | Instr::Jg(JmpArg::Label(l))
+            | Instr::Jge(JmpArg::Label(l))
+            | Instr::Jz(JmpArg::Label(l))
+            | Instr::Jnz(JmpArg::Label(l))
+            | Instr::Jo(JmpArg::Label(l))
+            | Instr::Jno(JmpArg::Label(l)) => labels.contains(l),
+            _ => false,
+        })
+        .count()
+}
+
 pub fn check_overflow() -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     instr.push(Instr::Comment(String::from(
         "Check calculation result overflow",
     )));
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rdi,
-        Arg64::Unsigned(RuntimeErr::OverflowError as u64),
-    )));
-    instr.push(Instr::Jo(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Jo(jmp_err(RuntimeErr::OverflowError)));
+    instr
+}
+
+// An untagged number has to stay in the range of the tagged ones, so it
+// is checked by doubling it into a scratch register
+pub fn check_untagged_overflow(reg: Reg) -> Vec<Instr> {
+    let mut instr = check_overflow();
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(reg))));
+    instr.push(Instr::Add(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R11))));
+    instr.push(Instr::Jo(jmp_err(RuntimeErr::OverflowError)));
     instr
 }
 
 fn check_reg_type_num(reg: Reg, err: RuntimeErr) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     instr.push(Instr::Comment(String::from("Check Number type")));
-    // ready to call snake_error
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rdi,
-        Arg64::Unsigned(err as u64),
-    )));
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rbx,
-        Arg64::Unsigned(INT_TAG),
+    instr.push(Instr::Test(BinArgs::ToReg(
+        reg,
+        Arg32::Unsigned(INT_TAG as u32),
     )));
-    instr.push(Instr::Test(BinArgs::ToReg(Reg::Rbx, Arg32::Reg(reg))));
-    instr.push(Instr::Jnz(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Jnz(jmp_err(err)));
     instr
 }
 
@@ -68,16 +178,11 @@ fn check_reg_type_bac(reg: Reg, ty: Type, err: RuntimeErr) -> Vec<Instr> {
     instr.push(Instr::Comment(String::from(
         "Check Boolean/Array/Closure type",
     )));
-    // ready to call snake_error
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rdi,
-        Arg64::Unsigned(err as u64),
-    )));
-    instr.push(Instr::Mov(MovArgs::ToReg(
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rbx, Arg64::Reg(reg))));
+    instr.push(Instr::And(BinArgs::ToReg(
         Reg::Rbx,
-        Arg64::Unsigned(TAG_MASK),
+        Arg32::Unsigned(TAG_MASK as u32),
     )));
-    instr.push(Instr::And(BinArgs::ToReg(Reg::Rbx, Arg32::Reg(reg))));
     instr.push(Instr::Cmp(BinArgs::ToReg(
         Reg::Rbx,
         Arg32::Unsigned(match ty {
@@ -87,7 +192,7 @@ fn check_reg_type_bac(reg: Reg, ty: Type, err: RuntimeErr) -> Vec<Instr> {
             Type::Num => panic!("Num type is not supported"),
         }),
     )));
-    instr.push(Instr::Jne(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Jne(jmp_err(err)));
     instr
 }
 
@@ -98,25 +203,45 @@ fn check_reg_type(reg: Reg, ty: Type, err: RuntimeErr) -> Vec<Instr> {
     }
 }
 
-pub fn check_prim1_type(reg: Reg, p: &Prim1) -> Vec<Instr> {
+// The type a primitive expects of its operands and the error it reports otherwise
+pub fn prim1_operand(p: &Prim1) -> Option<(Type, RuntimeErr)> {
     match p {
-        Prim1::Add1 | Prim1::Sub1 => check_reg_type(reg, Type::Num, RuntimeErr::ArithError),
-        Prim1::Not => check_reg_type(reg, Type::Bool, RuntimeErr::LogicError),
-        Prim1::Length => check_reg_type(reg, Type::Array, RuntimeErr::LengthError),
-        Prim1::Print | Prim1::IsBool | Prim1::IsNum | Prim1::IsArray | Prim1::IsFun => Vec::new(),
+        Prim1::Add1 | Prim1::Sub1 => Some((Type::Num, RuntimeErr::ArithError)),
+        Prim1::Not => Some((Type::Bool, RuntimeErr::LogicError)),
+        Prim1::Length => Some((Type::Array, RuntimeErr::LengthError)),
+        Prim1::ClassIdx => Some((Type::Array, RuntimeErr::ArrayError)),
+        Prim1::Print | Prim1::IsBool | Prim1::IsNum | Prim1::IsArray | Prim1::IsFun => None,
     }
 }
 
-pub fn check_prim2_type(reg: Reg, p: &Prim2) -> Vec<Instr> {
+pub fn prim2_operand(p: &Prim2) -> Option<(Type, RuntimeErr)> {
     match p {
-        Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge => {
-            check_reg_type(reg, Type::Num, RuntimeErr::CmpError)
-        }
-        Prim2::Add | Prim2::Sub | Prim2::Mul => {
-            check_reg_type(reg, Type::Num, RuntimeErr::ArithError)
-        }
-        Prim2::And | Prim2::Or => check_reg_type(reg, Type::Bool, RuntimeErr::LogicError),
-        Prim2::Neq | Prim2::Eq | Prim2::ArrayGet => Vec::new(),
+        Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge => Some((Type::Num, RuntimeErr::CmpError)),
+        Prim2::Add | Prim2::Sub | Prim2::Mul => Some((Type::Num, RuntimeErr::ArithError)),
+        Prim2::And | Prim2::Or => Some((Type::Bool, RuntimeErr::LogicError)),
+        Prim2::Neq | Prim2::Eq | Prim2::ArrayGet => None,
+    }
+}
+
+pub fn prim1_operand_type(p: &Prim1) -> Option<Type> {
+    prim1_operand(p).map(|(ty, _)| ty)
+}
+
+pub fn prim2_operand_type(p: &Prim2) -> Option<Type> {
+    prim2_operand(p).map(|(ty, _)| ty)
+}
+
+pub fn check_prim1_type(reg: Reg, p: &Prim1) -> Vec<Instr> {
+    match prim1_operand(p) {
+        Some((ty, err)) => check_reg_type(reg, ty, err),
+        None => Vec::new(),
+    }
+}
+
+pub fn check_prim2_type(reg: Reg, p: &Prim2) -> Vec<Instr> {
+    match prim2_operand(p) {
+        Some((ty, err)) => check_reg_type(reg, ty, err),
+        None => Vec::new(),
     }
 }
 
@@ -124,6 +249,10 @@ pub fn check_if_type(reg: Reg) -> Vec<Instr> {
     check_reg_type(reg, Type::Bool, RuntimeErr::IfError)
 }
 
+pub fn check_while_type(reg: Reg) -> Vec<Instr> {
+    check_reg_type(reg, Type::Bool, RuntimeErr::WhileError)
+}
+
 pub fn check_array_type(reg: Reg) -> Vec<Instr> {
     check_reg_type(reg, Type::Array, RuntimeErr::ArrayError)
 }
@@ -135,11 +264,6 @@ pub fn check_index_type(reg: Reg) -> Vec<Instr> {
 pub fn check_bounding(index_reg: Reg, addr_reg: Reg) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     instr.push(Instr::Comment(String::from("Check Array Index Bounding")));
-    // ready to call snake_error
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rdi,
-        Arg64::Unsigned(RuntimeErr::BoundingError as u64),
-    )));
     instr.push(Instr::Cmp(BinArgs::ToReg(
         index_reg,
         Arg32::Mem(MemRef {
@@ -147,9 +271,9 @@ pub fn check_bounding(index_reg: Reg, addr_reg: Reg) -> Vec<Instr> {
             offset: Offset::Constant(8),
         }),
     )));
-    instr.push(Instr::Jge(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Jge(jmp_err(RuntimeErr::BoundingError)));
     instr.push(Instr::Cmp(BinArgs::ToReg(index_reg, Arg32::Signed(0))));
-    instr.push(Instr::Jl(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Jl(jmp_err(RuntimeErr::BoundingError)));
     instr
 }
 
@@ -160,22 +284,14 @@ pub fn check_closure_type(reg: Reg) -> Vec<Instr> {
 pub fn check_arity_number(reg: Reg, arg_num: u64) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     instr.push(Instr::Comment(String::from("Check Arity Number")));
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rdi,
-        Arg64::Unsigned(RuntimeErr::ArityError as u64),
-    )));
-    instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rbx,
-        Arg64::Unsigned(arg_num),
-    )));
-    instr.push(Instr::Cmp(BinArgs::ToReg(
-        Reg::Rbx,
-        Arg32::Mem(MemRef {
+    instr.push(Instr::Cmp(BinArgs::ToMem(
+        MemRef {
             reg: reg,
             offset: Offset::Constant(0),
-        }),
+        },
+        Reg32::Unsigned(arg_num as u32),
     )));
-    instr.push(Instr::Jne(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Jne(jmp_err(RuntimeErr::ArityError)));
     instr
 }
 
@@ -192,12 +308,6 @@ pub fn check_method_class(
             Some(i) => i.id,
             None => panic!("Class is guaranteed to be in scope. Error."),
         };
-        println!("{}", classidx);
-        // ready to call snake_error
-        instr.push(Instr::Mov(MovArgs::ToReg(
-            Reg::Rdi,
-            Arg64::Unsigned(RuntimeErr::MethodTypeError as u64),
-        )));
         instr.push(Instr::Mov(MovArgs::ToReg(
             Reg::R11,
             Arg64::Unsigned(classidx as u64),
@@ -212,28 +322,168 @@ pub fn check_method_class(
         instr.push(Instr::Je(JmpArg::Label(format!("Found_{}", ann))));
         break;
     }
-    instr.push(Instr::Jmp(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Jmp(jmp_err(RuntimeErr::MethodTypeError)));
     instr.push(Instr::Label(format!("Found_{}", ann)));
     instr
 }
 
+// Check that the heap has room for the bytes about to be allocated at R15,
+// R14 holds the end of the heap the runtime passed in
+pub fn check_heap_space(bytes: u32) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    instr.push(Instr::Comment(String::from("Check Heap Space")));
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rbx, Arg64::Reg(Reg::R15))));
+    instr.push(Instr::Add(BinArgs::ToReg(Reg::Rbx, Arg32::Unsigned(bytes))));
+    instr.push(Instr::Cmp(BinArgs::ToReg(Reg::Rbx, Arg32::Reg(Reg::R14))));
+    instr.push(Instr::Jg(jmp_err(RuntimeErr::OutOfMemoryError)));
+    instr
+}
+
+// Check that a frame of the given size fits above the stack limit the
+// runtime passed in, which R13 holds. The runtime leaves some room below the
+// limit for the arguments of calls and the runtime functions.
+pub fn check_stack_space(bytes: i32) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    instr.push(Instr::Comment(String::from("Check Stack Space")));
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rbx, Arg64::Reg(Reg::Rsp))));
+    instr.push(Instr::Sub(BinArgs::ToReg(Reg::Rbx, Arg32::Signed(bytes))));
+    instr.push(Instr::Cmp(BinArgs::ToReg(Reg::Rbx, Arg32::Reg(Reg::R13))));
+    instr.push(Instr::Jl(jmp_err(RuntimeErr::StackOverflowError)));
+    instr
+}
+
 pub fn check_field_num(actual_num: usize, correct_num: usize) -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
     if actual_num == correct_num {
         return instr;
     }
-    // ready to call snake_error
+    instr.push(Instr::Jmp(jmp_err(RuntimeErr::FieldNumError)));
+    instr
+}
+
+pub fn fail(err: RuntimeErr) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
+    instr.push(Instr::Comment(String::from("Raise runtime error")));
+    instr.push(Instr::Jmp(jmp_err(err)));
+    instr
+}
+
+// Load the innermost handler record into R10 (0 when there is none) and
+// jump to label if there is one
+fn find_handler(label: &str) -> Vec<Instr> {
+    let mut instr: Vec<Instr> = Vec::new();
     instr.push(Instr::Mov(MovArgs::ToReg(
-        Reg::Rdi,
-        Arg64::Unsigned(RuntimeErr::FieldNumError as u64),
+        Reg::Rbx,
+        Arg64::Label(String::from("HANDLER")),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToReg(
+        Reg::R10,
+        Arg64::Mem(MemRef {
+            reg: Reg::Rbx,
+            offset: Offset::Constant(0),
+        }),
     )));
-    instr.push(Instr::Jmp(JmpArg::Label(String::from("snake_err"))));
+    instr.push(Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(0))));
+    instr.push(Instr::Jne(JmpArg::Label(String::from(label))));
     instr
 }
 
+// snake_err expects the RuntimeErr code in Rdi, snake_raise expects the
+// raised value in Rax. A handler record is [catch label, saved rsp,
+// previous handler], see the codegen of Try.
 pub fn call_error() -> Vec<Instr> {
     let mut instr: Vec<Instr> = Vec::new();
+    for err in RUNTIME_ERRS {
+        instr.push(Instr::Label(err_label(err)));
+        instr.push(Instr::Mov(MovArgs::ToReg(
+            Reg::Rdi,
+            Arg64::Unsigned(err as u64),
+        )));
+        // running out of heap or stack is not caught, a handler could
+        // not even get the Error object
+        if matches!(
+            err,
+            RuntimeErr::OutOfMemoryError | RuntimeErr::StackOverflowError
+        ) {
+            instr.push(Instr::Call(JmpArg::Label(String::from("snake_error"))));
+        } else {
+            instr.push(Instr::Jmp(JmpArg::Label(String::from("snake_err"))));
+        }
+    }
     instr.push(Instr::Label(String::from("snake_err")));
+    instr.extend(find_handler("snake_err_catch"));
     instr.push(Instr::Call(JmpArg::Label(String::from("snake_error"))));
+    // Wrap the error code into an Error object and raise it
+    instr.push(Instr::Label(String::from("snake_err_catch")));
+    instr.extend(check_heap_space(3 * 8));
+    instr.push(Instr::Mov(MovArgs::ToReg(
+        Reg::Rax,
+        Arg64::Unsigned(ERROR_CLASS_ID as u64),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToMem(
+        MemRef {
+            reg: Reg::R15,
+            offset: Offset::Constant(0),
+        },
+        Reg32::Reg(Reg::Rax),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(2))));
+    instr.push(Instr::Mov(MovArgs::ToMem(
+        MemRef {
+            reg: Reg::R15,
+            offset: Offset::Constant(8),
+        },
+        Reg32::Reg(Reg::Rax),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rdi))));
+    instr.push(Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))));
+    instr.push(Instr::Mov(MovArgs::ToMem(
+        MemRef {
+            reg: Reg::R15,
+            offset: Offset::Constant(16),
+        },
+        Reg32::Reg(Reg::Rax),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::R15))));
+    instr.push(Instr::Add(BinArgs::ToReg(
+        Reg::Rax,
+        Arg32::Unsigned(ARRAY_TAG),
+    )));
+    instr.push(Instr::Add(BinArgs::ToReg(Reg::R15, Arg32::Unsigned(3 * 8))));
+    instr.push(Instr::Label(String::from("snake_raise")));
+    instr.extend(find_handler("snake_raise_catch"));
+    instr.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))));
+    instr.push(Instr::Call(JmpArg::Label(String::from("snake_uncaught"))));
+    // Pop the handler, restore the stack of its frame and jump to the catch
+    instr.push(Instr::Label(String::from("snake_raise_catch")));
+    instr.push(Instr::Mov(MovArgs::ToReg(
+        Reg::R11,
+        Arg64::Mem(MemRef {
+            reg: Reg::R10,
+            offset: Offset::Constant(16),
+        }),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToMem(
+        MemRef {
+            reg: Reg::Rbx,
+            offset: Offset::Constant(0),
+        },
+        Reg32::Reg(Reg::R11),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToReg(
+        Reg::Rsp,
+        Arg64::Mem(MemRef {
+            reg: Reg::R10,
+            offset: Offset::Constant(8),
+        }),
+    )));
+    instr.push(Instr::Mov(MovArgs::ToReg(
+        Reg::R11,
+        Arg64::Mem(MemRef {
+            reg: Reg::R10,
+            offset: Offset::Constant(0),
+        }),
+    )));
+    instr.push(Instr::Jmp(JmpArg::Reg(Reg::R11)));
     instr
 }
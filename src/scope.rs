@@ -1,11 +1,11 @@
 use core::panic;
 use std::collections::HashMap;
 
-use crate::compile::CompileErr;
-use crate::syntax::{Exp, FunDecl, SurfProg};
+use crate::compile::{CompileErr, CompileWarning};
+use crate::syntax::{Exp, FunDecl, Pattern, SurfProg};
 
-static MAX_SNAKE_INT: i64 = i64::MAX >> 1;
-static MIN_SNAKE_INT: i64 = i64::MIN >> 1;
+pub static MAX_SNAKE_INT: i64 = i64::MAX >> 1;
+pub static MIN_SNAKE_INT: i64 = i64::MIN >> 1;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Parameters {
@@ -29,6 +29,75 @@ where
 pub enum VarType {
     Var,
     Field,
+    // let-bound variables, the only locals that can be assigned with :=
+    Mutable,
+}
+
+// Check the literals and classes in a pattern, collecting the variables it binds
+fn check_pattern<'exp, Span>(
+    pat: &'exp Pattern<Span>,
+    class_env: &[(&'exp str, usize)],
+    bound: &mut Vec<(&'exp str, ())>,
+) -> Result<(), CompileErr<Span>>
+where
+    Span: Clone,
+{
+    match pat {
+        Pattern::Wildcard(_) | Pattern::Bool(..) => Ok(()),
+        Pattern::Var(x, ann) => {
+            // Check DuplicateBinding
+            match get(bound, &x) {
+                Some(()) => Err(CompileErr::DuplicateBinding {
+                    duplicated_name: x.clone(),
+                    location: ann.clone(),
+                }),
+                None => {
+                    bound.push((&x, ()));
+                    Ok(())
+                }
+            }
+        }
+        Pattern::Num(i, ann) => {
+            // Check Overflow
+            if *i <= MAX_SNAKE_INT && *i >= MIN_SNAKE_INT {
+                Ok(())
+            } else {
+                Err(CompileErr::Overflow {
+                    num: *i,
+                    location: ann.clone(),
+                })
+            }
+        }
+        Pattern::Array(pats, _) => {
+            for pat in pats.iter() {
+                check_pattern(pat, class_env, bound)?;
+            }
+            Ok(())
+        }
+        Pattern::Object { class, fields, ann } => {
+            // Check UndefinedClass
+            match get(class_env, &class) {
+                Some(len) => {
+                    if len != fields.len() {
+                        return Err(CompileErr::WrongFieldSize {
+                            class: class.clone(),
+                            location: ann.clone(),
+                        });
+                    }
+                }
+                None => {
+                    return Err(CompileErr::UndefinedClass {
+                        undefined: class.clone(),
+                        location: ann.clone(),
+                    })
+                }
+            };
+            for pat in fields.iter() {
+                check_pattern(pat, class_env, bound)?;
+            }
+            Ok(())
+        }
+    }
 }
 
 pub fn check_prog<'exp, Span>(
@@ -63,7 +132,7 @@ where
                 }),
             }
         }
-        Exp::Prim1(_, p, _) => {
+        Exp::Prim1(_, p, _) | Exp::Ascribe(p, _, _) => {
             check_prog(p, var_env.clone(), class_env.clone(), method_env.clone())
         }
         Exp::Prim2(_, p1, p2, _) => {
@@ -97,7 +166,7 @@ where
                     Ok(()) => (),
                     Err(msg) => return Err(msg),
                 };
-                var_env.push((&x, VarType::Var));
+                var_env.push((&x, VarType::Mutable));
             }
             check_prog(body, var_env.clone(), class_env.clone(), method_env.clone())
         }
@@ -172,6 +241,7 @@ where
                 parameters,
                 body: _,
                 ann,
+                signature: _,
             } in decls.iter()
             {
                 let mut env_temp_arg = Vec::new();
@@ -252,6 +322,7 @@ where
         Exp::ClassDef {
             name,
             fields,
+            field_types: _,
             methods,
             body,
             ann,
@@ -411,16 +482,140 @@ where
                 Ok(()) => (),
                 Err(msg) => return Err(msg),
             };
-            // Check UndefinedField
+            // Check UndefinedField and ImmutableAssignment
             match get(&var_env, &field) {
-                Some(VarType::Field) => Ok(()),
-                _ => Err(CompileErr::UndefinedField {
+                Some(VarType::Field) | Some(VarType::Mutable) => Ok(()),
+                Some(VarType::Var) => Err(CompileErr::ImmutableAssignment {
+                    name: field.clone(),
+                    location: ann.clone(),
+                }),
+                None => Err(CompileErr::UndefinedField {
                     undefined: field.clone(),
                     location: ann.clone(),
                 }),
             }
         }
-        Exp::MakeClosure { .. } | Exp::CallUniqMethod { .. } | Exp::MethodDefs { .. } => {
+        Exp::While { cond, body, ann: _ } => {
+            match check_prog(cond, var_env.clone(), class_env.clone(), method_env.clone()) {
+                Ok(()) => check_prog(body, var_env.clone(), class_env.clone(), method_env.clone()),
+                Err(msg) => Err(msg),
+            }
+        }
+        Exp::For {
+            var,
+            array,
+            body,
+            ann: _,
+        } => {
+            match check_prog(
+                array,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            ) {
+                Ok(()) => (),
+                Err(msg) => return Err(msg),
+            };
+            var_env.push((&var, VarType::Mutable));
+            check_prog(body, var_env.clone(), class_env.clone(), method_env.clone())
+        }
+        Exp::ForRange {
+            var,
+            start,
+            stop,
+            body,
+            ann: _,
+        } => {
+            match check_prog(
+                start,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            ) {
+                Ok(()) => (),
+                Err(msg) => return Err(msg),
+            };
+            match check_prog(stop, var_env.clone(), class_env.clone(), method_env.clone()) {
+                Ok(()) => (),
+                Err(msg) => return Err(msg),
+            };
+            var_env.push((&var, VarType::Mutable));
+            check_prog(body, var_env.clone(), class_env.clone(), method_env.clone())
+        }
+        Exp::Raise(e, _) => check_prog(e, var_env.clone(), class_env.clone(), method_env.clone()),
+        Exp::Try {
+            body,
+            var,
+            handler,
+            ann: _,
+        } => {
+            match check_prog(body, var_env.clone(), class_env.clone(), method_env.clone()) {
+                Ok(()) => (),
+                Err(msg) => return Err(msg),
+            };
+            // The caught value is bound like a function parameter
+            var_env.push((&var, VarType::Var));
+            check_prog(
+                handler,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            )
+        }
+        Exp::LetPattern {
+            bindings,
+            body,
+            ann: _,
+        } => {
+            // Names bound by all the patterns together must be distinct
+            let mut bound: Vec<(&str, ())> = Vec::new();
+            for (pat, e) in bindings.iter() {
+                match check_prog(e, var_env.clone(), class_env.clone(), method_env.clone()) {
+                    Ok(()) => (),
+                    Err(msg) => return Err(msg),
+                };
+                let prev = bound.len();
+                check_pattern(pat, &class_env, &mut bound)?;
+                for (x, ()) in bound[prev..].iter() {
+                    var_env.push((x, VarType::Mutable));
+                }
+            }
+            check_prog(body, var_env.clone(), class_env.clone(), method_env.clone())
+        }
+        Exp::Match {
+            scrutinee,
+            arms,
+            ann: _,
+        } => {
+            match check_prog(
+                scrutinee,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            ) {
+                Ok(()) => (),
+                Err(msg) => return Err(msg),
+            };
+            for (pat, body) in arms.iter() {
+                let mut bound: Vec<(&str, ())> = Vec::new();
+                check_pattern(pat, &class_env, &mut bound)?;
+                // Pattern variables behave like let-bound variables in the arm
+                let mut arm_env = var_env.clone();
+                for (x, ()) in bound.into_iter() {
+                    arm_env.push((x, VarType::Mutable));
+                }
+                match check_prog(body, arm_env, class_env.clone(), method_env.clone()) {
+                    Ok(()) => (),
+                    Err(msg) => return Err(msg),
+                };
+            }
+            Ok(())
+        }
+        Exp::MakeClosure { .. }
+        | Exp::CallUniqMethod { .. }
+        | Exp::MethodDefs { .. }
+        | Exp::Fail(..)
+        | Exp::Assign { .. } => {
             panic!("Should never exist during check prog!")
         }
     }
@@ -435,6 +630,7 @@ pub fn uniquify<'exp>(
     match e {
         Exp::Num(i, _) => Exp::Num(*i, ()),
         Exp::Bool(b, _) => Exp::Bool(*b, ()),
+        Exp::Fail(err, _) => Exp::Fail(*err, ()),
         Exp::Var(x, _) => {
             let uniq_id = match get(&var_env, &x) {
                 Some(x) => x,
@@ -601,6 +797,7 @@ pub fn uniquify<'exp>(
                         parameters: uniq_args,
                         body: uniquify(&fun.body, env_clone, class_env.clone(), method_env.clone()),
                         ann: (),
+                        signature: fun.signature.clone(),
                     }
                 })
                 .collect();
@@ -655,6 +852,7 @@ pub fn uniquify<'exp>(
                     method_env.clone(),
                 ),
                 ann: (),
+                signature: None,
             }];
             Exp::FunDefs {
                 decls: uniq_decls,
@@ -665,6 +863,7 @@ pub fn uniquify<'exp>(
         Exp::ClassDef {
             name,
             fields,
+            field_types,
             methods,
             body,
             ann,
@@ -715,11 +914,13 @@ pub fn uniquify<'exp>(
                         method_env.clone(),
                     ),
                     ann: (),
+                    signature: method.signature.clone(),
                 });
             }
             Exp::ClassDef {
                 name: uniq_name,
                 fields: uniq_fields,
+                field_types: field_types.clone(),
                 methods: uniq_methods,
                 body: Box::new(uniquify(&body, var_env_clone, class_env, method_env)),
                 ann: (),
@@ -800,8 +1001,218 @@ pub fn uniquify<'exp>(
                 ann: (),
             }
         }
-        Exp::MakeClosure { .. } | Exp::CallUniqMethod { .. } | Exp::MethodDefs { .. } => {
+        Exp::While { cond, body, ann: _ } => Exp::While {
+            cond: Box::new(uniquify(
+                cond,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            )),
+            body: Box::new(uniquify(
+                body,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            )),
+            ann: (),
+        },
+        Exp::Raise(e, _) => Exp::Raise(
+            Box::new(uniquify(
+                e,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            )),
+            (),
+        ),
+        Exp::Try {
+            body,
+            var,
+            handler,
+            ann,
+        } => {
+            let uniq_body = uniquify(body, var_env.clone(), class_env.clone(), method_env.clone());
+            let uniq_var = format!("#{}_{}", var, ann);
+            var_env.push((&var, uniq_var.clone()));
+            Exp::Try {
+                body: Box::new(uniq_body),
+                var: uniq_var,
+                handler: Box::new(uniquify(
+                    handler,
+                    var_env.clone(),
+                    class_env.clone(),
+                    method_env.clone(),
+                )),
+                ann: (),
+            }
+        }
+        Exp::Match {
+            scrutinee,
+            arms,
+            ann: _,
+        } => Exp::Match {
+            scrutinee: Box::new(uniquify(
+                scrutinee,
+                var_env.clone(),
+                class_env.clone(),
+                method_env.clone(),
+            )),
+            arms: arms
+                .iter()
+                .map(|(pat, body)| {
+                    let mut arm_env = var_env.clone();
+                    let uniq_pat = uniquify_pattern(pat, &mut arm_env, &class_env);
+                    let uniq_body = uniquify(body, arm_env, class_env.clone(), method_env.clone());
+                    (uniq_pat, uniq_body)
+                })
+                .collect(),
+            ann: (),
+        },
+        Exp::MakeClosure { .. }
+        | Exp::CallUniqMethod { .. }
+        | Exp::MethodDefs { .. }
+        | Exp::LetPattern { .. }
+        | Exp::Ascribe(..)
+        | Exp::For { .. }
+        | Exp::ForRange { .. }
+        | Exp::Assign { .. } => {
             panic!("Should never exist during uniquify!")
         }
     }
 }
+
+fn uniquify_pattern<'exp>(
+    pat: &'exp Pattern<u32>,
+    var_env: &mut Vec<(&'exp str, String)>,
+    class_env: &[(&'exp str, String)],
+) -> Pattern<()> {
+    match pat {
+        Pattern::Wildcard(_) => Pattern::Wildcard(()),
+        Pattern::Var(x, ann) => {
+            let uniq_id = format!("#{}_{}", x, ann);
+            var_env.push((&x, uniq_id.clone()));
+            Pattern::Var(uniq_id, ())
+        }
+        Pattern::Num(i, _) => Pattern::Num(*i, ()),
+        Pattern::Bool(b, _) => Pattern::Bool(*b, ()),
+        Pattern::Array(pats, _) => Pattern::Array(
+            pats.iter()
+                .map(|pat| uniquify_pattern(pat, var_env, class_env))
+                .collect(),
+            (),
+        ),
+        Pattern::Object { class, fields, .. } => Pattern::Object {
+            class: match get(class_env, &class) {
+                Some(c) => c,
+                None => panic!("Class is guaranteed to be in scope. Uniquify."),
+            },
+            fields: fields
+                .iter()
+                .map(|pat| uniquify_pattern(pat, var_env, class_env))
+                .collect(),
+            ann: (),
+        },
+    }
+}
+
+/* Match warnings */
+
+// The head of a pattern that can fail; variables and wildcards have none
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Constructor<'exp> {
+    Num(i64),
+    Bool(bool),
+    Array(usize),
+    Object(&'exp str, usize),
+}
+
+// A row of patterns, where None stands for a wildcard
+type PatternRow<'exp, Span> = Vec<Option<&'exp Pattern<Span>>>;
+
+fn constructor<Span>(pat: &Pattern<Span>) -> Option<(Constructor<'_>, Vec<&Pattern<Span>>)> {
+    match pat {
+        Pattern::Wildcard(_) | Pattern::Var(..) => None,
+        Pattern::Num(i, _) => Some((Constructor::Num(*i), Vec::new())),
+        Pattern::Bool(b, _) => Some((Constructor::Bool(*b), Vec::new())),
+        Pattern::Array(pats, _) => Some((Constructor::Array(pats.len()), pats.iter().collect())),
+        Pattern::Object { class, fields, .. } => Some((
+            Constructor::Object(class, fields.len()),
+            fields.iter().collect(),
+        )),
+    }
+}
+
+fn arity(c: &Constructor) -> usize {
+    match c {
+        Constructor::Num(_) | Constructor::Bool(_) => 0,
+        Constructor::Array(n) | Constructor::Object(_, n) => *n,
+    }
+}
+
+// Is there a value matched by row that no row of the matrix matches?
+//
+// Values are dynamically typed, so the constructors of a column never cover
+// every value and a wildcard is only covered by rows starting with a wildcard.
+fn useful<'exp, Span>(
+    matrix: &[PatternRow<'exp, Span>],
+    row: &[Option<&'exp Pattern<Span>>],
+) -> bool {
+    let (head, rest) = match row.split_first() {
+        Some(split) => split,
+        None => return matrix.is_empty(),
+    };
+    match head.and_then(constructor) {
+        Some((c, args)) => {
+            let specialized: Vec<PatternRow<Span>> = matrix
+                .iter()
+                .filter_map(|r| {
+                    let mut new_row: PatternRow<Span> = match r[0].and_then(constructor) {
+                        None => vec![None; arity(&c)],
+                        Some((c2, args2)) if c2 == c => args2.into_iter().map(Some).collect(),
+                        Some(_) => return None,
+                    };
+                    new_row.extend_from_slice(&r[1..]);
+                    Some(new_row)
+                })
+                .collect();
+            let mut new_row: PatternRow<Span> = args.into_iter().map(Some).collect();
+            new_row.extend_from_slice(rest);
+            useful(&specialized, &new_row)
+        }
+        None => {
+            let default: Vec<PatternRow<Span>> = matrix
+                .iter()
+                .filter(|r| r[0].and_then(constructor).is_none())
+                .map(|r| r[1..].to_vec())
+                .collect();
+            useful(&default, rest)
+        }
+    }
+}
+
+// Warn about match arms that can never be reached and about matches that
+// can fall through all of their arms
+pub fn match_warnings<Span>(p: &SurfProg<Span>, warnings: &mut Vec<CompileWarning<Span>>)
+where
+    Span: Clone,
+{
+    if let Exp::Match { arms, ann, .. } = p {
+        let mut matrix: Vec<PatternRow<Span>> = Vec::new();
+        for (pat, _) in arms.iter() {
+            if !useful(&matrix, &[Some(pat)]) {
+                warnings.push(CompileWarning::RedundantArm {
+                    location: pat.ann(),
+                });
+            }
+            matrix.push(vec![Some(pat)]);
+        }
+        if useful(&matrix, &[None]) {
+            warnings.push(CompileWarning::NonExhaustiveMatch {
+                location: ann.clone(),
+            });
+        }
+    }
+    for e in p.sub_exps() {
+        match_warnings(e, warnings);
+    }
+}
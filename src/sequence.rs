@@ -307,6 +307,44 @@ fn sequentialize_callmethod_help(
     )
 }
 
+fn sequentialize_assign_help(var: &str, value: &Exp<u32>, ann: u32) -> SeqExp<()> {
+    match simple_exp_to_imm(&value) {
+        Some(imm) => SeqExp::Assign {
+            var: var.to_string(),
+            value: imm,
+            ann: (),
+        },
+        None => {
+            let x = format!("#assign_{}", ann);
+            SeqExp::Let {
+                var: x.clone(),
+                bound_exp: Box::new(sequentialize(&value)),
+                body: Box::new(SeqExp::Assign {
+                    var: var.to_string(),
+                    value: ImmExp::Var(x.clone()),
+                    ann: (),
+                }),
+                ann: (),
+            }
+        }
+    }
+}
+
+fn sequentialize_raise_help(e: &Exp<u32>, ann: u32) -> SeqExp<()> {
+    match simple_exp_to_imm(&e) {
+        Some(imm) => SeqExp::Raise(imm, ()),
+        None => {
+            let x = format!("#raise_{}", ann);
+            SeqExp::Let {
+                var: x.clone(),
+                bound_exp: Box::new(sequentialize(&e)),
+                body: Box::new(SeqExp::Raise(ImmExp::Var(x.clone()), ())),
+                ann: (),
+            }
+        }
+    }
+}
+
 pub fn sequentialize(p: &Exp<u32>) -> SeqExp<()> {
     match p {
         Exp::Num(i, _) => SeqExp::Imm(ImmExp::Num(*i), ()),
@@ -349,12 +387,36 @@ pub fn sequentialize(p: &Exp<u32>) -> SeqExp<()> {
             args,
             ann,
         } => sequentialize_callmethod_help(object, uniqmethod.clone(), args, *ann),
+        Exp::While { cond, body, ann: _ } => SeqExp::While {
+            cond: Box::new(sequentialize(&cond)),
+            body: Box::new(sequentialize(&body)),
+            ann: (),
+        },
+        Exp::Assign { var, value, ann } => sequentialize_assign_help(&var, &value, *ann),
+        Exp::Raise(e, ann) => sequentialize_raise_help(&e, *ann),
+        Exp::Try {
+            body,
+            var,
+            handler,
+            ann: _,
+        } => SeqExp::Try {
+            body: Box::new(sequentialize(&body)),
+            var: var.clone(),
+            handler: Box::new(sequentialize(&handler)),
+            ann: (),
+        },
+        Exp::Fail(err, _) => SeqExp::Fail(*err, ()),
         Exp::Lambda { .. }
         | Exp::FunDefs { .. }
         | Exp::ClassDef { .. }
         | Exp::SetField { .. }
         | Exp::CallMethod { .. }
-        | Exp::MethodDefs { .. } => {
+        | Exp::MethodDefs { .. }
+        | Exp::For { .. }
+        | Exp::ForRange { .. }
+        | Exp::LetPattern { .. }
+        | Exp::Ascribe(..)
+        | Exp::Match { .. } => {
             panic!("Should never exist during lambda_lift!")
         }
     }
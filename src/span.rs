@@ -1,3 +1,5 @@
+use std::sync::Mutex;
+
 /* A Span is a region of source code.
  */
 // 1-dimensional span of source locations
@@ -12,8 +14,24 @@ pub struct Span1 {
 // This is what we use in error messages.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Span2 {
+    pub file: Option<&'static str>, // None in the program itself, else the imported file
     pub start_line: usize,
     pub start_col: usize,
     pub end_line: usize, // inclusive
     pub end_col: usize,  // exclusive
 }
+
+// The names of the imported files. Each one is leaked the first time it is
+// seen so the spans pointing into it stay Copy.
+pub fn intern_file(name: &str) -> &'static str {
+    static FILES: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+    let mut files = FILES.lock().unwrap_or_else(|e| e.into_inner());
+    match files.iter().find(|f| **f == name) {
+        Some(f) => f,
+        None => {
+            let f: &'static str = Box::leak(name.to_string().into_boxed_str());
+            files.push(f);
+            f
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use crate::runtime_error::RuntimeErr;
+use std::collections::{HashMap, HashSet};
 
 pub type SurfProg<Ann> = Exp<Ann>;
 pub type SurfFunDecl<Ann> = FunDecl<Exp<Ann>, Ann>;
@@ -11,6 +12,24 @@ pub struct FunDecl<E, Ann> {
     pub parameters: Vec<String>,
     pub body: E,
     pub ann: Ann,
+    pub signature: Option<Signature>, // None when nothing is annotated
+}
+
+/* Optional type annotations, checked by typecheck.rs and erased afterwards */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnakeType {
+    Num,
+    Bool,
+    Array,
+    Fun(Vec<SnakeType>, Box<SnakeType>),
+    Class(String),
+    Any, // unannotated, checked dynamically
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub parameters: Vec<SnakeType>,
+    pub ret: SnakeType,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -76,6 +95,7 @@ pub enum Exp<Ann> {
     ClassDef {
         name: String,
         fields: Vec<String>,
+        field_types: Vec<SnakeType>,
         methods: Vec<FunDecl<Exp<Ann>, Ann>>,
         body: Box<Exp<Ann>>,
         ann: Ann,
@@ -108,6 +128,68 @@ pub enum Exp<Ann> {
         body: Box<Exp<Ann>>,
         ann: Ann,
     },
+    While {
+        cond: Box<Exp<Ann>>,
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    For {
+        var: String,
+        array: Box<Exp<Ann>>,
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    ForRange {
+        var: String,
+        start: Box<Exp<Ann>>,
+        stop: Box<Exp<Ann>>, // exclusive
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    // let with destructuring bindings, expanded by desugar
+    LetPattern {
+        bindings: Vec<(Pattern<Ann>, Exp<Ann>)>,
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    Match {
+        scrutinee: Box<Exp<Ann>>,
+        arms: Vec<(Pattern<Ann>, Exp<Ann>)>,
+        ann: Ann,
+    },
+    Raise(Box<Exp<Ann>>, Ann),
+    Try {
+        body: Box<Exp<Ann>>,
+        var: String,
+        handler: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    // Raises a runtime error, produced when lowering patterns
+    Fail(RuntimeErr, Ann),
+    // A type annotation on a let binding, erased by desugar
+    Ascribe(Box<Exp<Ann>>, SnakeType, Ann),
+    // Assignment to a let-bound variable. The parser produces SetField
+    // for every `x := e`, class_lift turns the non-field ones into this.
+    Assign {
+        var: String,
+        value: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+}
+
+/* Patterns of match arms */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Pattern<Ann> {
+    Wildcard(Ann),
+    Var(String, Ann),
+    Num(i64, Ann),
+    Bool(bool, Ann),
+    Array(Vec<Pattern<Ann>>, Ann),
+    Object {
+        class: String,
+        fields: Vec<Pattern<Ann>>,
+        ann: Ann,
+    },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -127,6 +209,7 @@ pub enum Prim1 {
     Length,
     IsArray,
     IsFun,
+    ClassIdx, // internal, reads the class header of an array or object
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -153,6 +236,8 @@ pub enum Prim2 {
 pub struct SeqProg<Ann> {
     pub class: HashMap<String, ClassInfo>,
     pub funs: Vec<FunDecl<SeqExp<Ann>, Ann>>,
+    // functions that take no env parameter, see optimize::direct_calls
+    pub direct: HashSet<String>,
     pub methods: Vec<MethodDecl<SeqExp<Ann>, Ann>>,
     pub main: SeqExp<Ann>,
     pub ann: Ann,
@@ -190,6 +275,12 @@ pub enum SeqExp<Ann> {
         args: Vec<ImmExp>,
         ann: Ann,
     },
+    // A call to a function known statically that takes no env
+    CallDirect {
+        label: String,
+        args: Vec<ImmExp>,
+        ann: Ann,
+    },
 
     Let {
         var: String,
@@ -214,6 +305,25 @@ pub enum SeqExp<Ann> {
         args: Vec<ImmExp>,
         ann: Ann,
     },
+    // cond is re-evaluated on every iteration, so it is not an ImmExp
+    While {
+        cond: Box<SeqExp<Ann>>,
+        body: Box<SeqExp<Ann>>,
+        ann: Ann,
+    },
+    Assign {
+        var: String,
+        value: ImmExp,
+        ann: Ann,
+    },
+    Raise(ImmExp, Ann),
+    Try {
+        body: Box<SeqExp<Ann>>,
+        var: String,
+        handler: Box<SeqExp<Ann>>,
+        ann: Ann,
+    },
+    Fail(RuntimeErr, Ann),
 }
 
 /* Useful functions for Exps, BindExps, SeqExps */
@@ -242,7 +352,17 @@ impl<Ann> Exp<Ann> {
             | Exp::CallMethod { ann: a, .. }
             | Exp::CallUniqMethod { ann: a, .. }
             | Exp::SetField { ann: a, .. }
-            | Exp::MethodDefs { ann: a, .. } => a.clone(),
+            | Exp::MethodDefs { ann: a, .. }
+            | Exp::While { ann: a, .. }
+            | Exp::For { ann: a, .. }
+            | Exp::ForRange { ann: a, .. }
+            | Exp::LetPattern { ann: a, .. }
+            | Exp::Match { ann: a, .. }
+            | Exp::Raise(_, a)
+            | Exp::Try { ann: a, .. }
+            | Exp::Fail(_, a)
+            | Exp::Ascribe(_, _, a)
+            | Exp::Assign { ann: a, .. } => a.clone(),
         }
     }
 
@@ -267,7 +387,17 @@ impl<Ann> Exp<Ann> {
             | Exp::CallMethod { ann: a, .. }
             | Exp::CallUniqMethod { ann: a, .. }
             | Exp::SetField { ann: a, .. }
-            | Exp::MethodDefs { ann: a, .. } => a,
+            | Exp::MethodDefs { ann: a, .. }
+            | Exp::While { ann: a, .. }
+            | Exp::For { ann: a, .. }
+            | Exp::ForRange { ann: a, .. }
+            | Exp::LetPattern { ann: a, .. }
+            | Exp::Match { ann: a, .. }
+            | Exp::Raise(_, a)
+            | Exp::Try { ann: a, .. }
+            | Exp::Fail(_, a)
+            | Exp::Ascribe(_, _, a)
+            | Exp::Assign { ann: a, .. } => a,
         }
     }
 
@@ -356,12 +486,14 @@ impl<Ann> Exp<Ann> {
             Exp::ClassDef {
                 name,
                 fields,
+                field_types,
                 methods,
                 body,
                 ann,
             } => Exp::ClassDef {
                 name: name.clone(),
                 fields: fields.clone(),
+                field_types: field_types.clone(),
                 methods: methods.iter().map(|d| d.map_ann(f)).collect(),
                 body: Box::new(body.map_ann(f)),
                 ann: f(ann),
@@ -409,6 +541,179 @@ impl<Ann> Exp<Ann> {
                 body: Box::new(body.map_ann(f)),
                 ann: f(ann),
             },
+            Exp::While { cond, body, ann } => Exp::While {
+                cond: Box::new(cond.map_ann(f)),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::For {
+                var,
+                array,
+                body,
+                ann,
+            } => Exp::For {
+                var: var.clone(),
+                array: Box::new(array.map_ann(f)),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::ForRange {
+                var,
+                start,
+                stop,
+                body,
+                ann,
+            } => Exp::ForRange {
+                var: var.clone(),
+                start: Box::new(start.map_ann(f)),
+                stop: Box::new(stop.map_ann(f)),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::LetPattern {
+                bindings,
+                body,
+                ann,
+            } => Exp::LetPattern {
+                bindings: bindings
+                    .iter()
+                    .map(|(pat, e)| (pat.map_ann(f), e.map_ann(f)))
+                    .collect(),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::Match {
+                scrutinee,
+                arms,
+                ann,
+            } => Exp::Match {
+                scrutinee: Box::new(scrutinee.map_ann(f)),
+                arms: arms
+                    .iter()
+                    .map(|(pat, e)| (pat.map_ann(f), e.map_ann(f)))
+                    .collect(),
+                ann: f(ann),
+            },
+            Exp::Raise(e, a) => Exp::Raise(Box::new(e.map_ann(f)), f(a)),
+            Exp::Try {
+                body,
+                var,
+                handler,
+                ann,
+            } => Exp::Try {
+                body: Box::new(body.map_ann(f)),
+                var: var.clone(),
+                handler: Box::new(handler.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::Fail(err, a) => Exp::Fail(*err, f(a)),
+            Exp::Ascribe(e, ty, a) => Exp::Ascribe(Box::new(e.map_ann(f)), ty.clone(), f(a)),
+            Exp::Assign { var, value, ann } => Exp::Assign {
+                var: var.clone(),
+                value: Box::new(value.map_ann(f)),
+                ann: f(ann),
+            },
+        }
+    }
+
+    // The expressions directly below this one, including function bodies
+    pub fn sub_exps(&self) -> Vec<&Exp<Ann>> {
+        match self {
+            Exp::Num(..) | Exp::Bool(..) | Exp::Var(..) | Exp::Fail(..) => Vec::new(),
+            Exp::Prim1(_, e, _) | Exp::Raise(e, _) | Exp::Ascribe(e, _, _) => vec![e],
+            Exp::Try { body, handler, .. } => vec![body, handler],
+            Exp::Prim2(_, e1, e2, _) => vec![e1, e2],
+            Exp::Let { bindings, body, .. } => {
+                let mut es: Vec<&Exp<Ann>> = bindings.iter().map(|(_, e)| e).collect();
+                es.push(body);
+                es
+            }
+            Exp::LetPattern { bindings, body, .. } => {
+                let mut es: Vec<&Exp<Ann>> = bindings.iter().map(|(_, e)| e).collect();
+                es.push(body);
+                es
+            }
+            Exp::If { cond, thn, els, .. } => vec![cond, thn, els],
+            Exp::Array(es, _) | Exp::Object { fields: es, .. } => es.iter().collect(),
+            Exp::ArraySet {
+                array,
+                index,
+                new_value,
+                ..
+            } => vec![array, index, new_value],
+            Exp::Semicolon { e1, e2, .. } => vec![e1, e2],
+            Exp::FunDefs { decls, body, .. }
+            | Exp::MethodDefs { decls, body, .. }
+            | Exp::ClassDef {
+                methods: decls,
+                body,
+                ..
+            } => {
+                let mut es: Vec<&Exp<Ann>> = decls.iter().map(|d| &d.body).collect();
+                es.push(body);
+                es
+            }
+            Exp::Call(fun, args, _) => {
+                let mut es: Vec<&Exp<Ann>> = vec![fun];
+                es.extend(args.iter());
+                es
+            }
+            Exp::Lambda { body, .. } => vec![body],
+            Exp::MakeClosure { env, .. } => vec![env],
+            Exp::CallMethod { object, args, .. } | Exp::CallUniqMethod { object, args, .. } => {
+                let mut es: Vec<&Exp<Ann>> = vec![object];
+                es.extend(args.iter());
+                es
+            }
+            Exp::SetField { value, .. } | Exp::Assign { value, .. } => vec![value],
+            Exp::While { cond, body, .. } => vec![cond, body],
+            Exp::For { array, body, .. } => vec![array, body],
+            Exp::ForRange {
+                start, stop, body, ..
+            } => vec![start, stop, body],
+            Exp::Match {
+                scrutinee, arms, ..
+            } => {
+                let mut es: Vec<&Exp<Ann>> = vec![scrutinee];
+                es.extend(arms.iter().map(|(_, e)| e));
+                es
+            }
+        }
+    }
+}
+
+impl<Ann> Pattern<Ann> {
+    pub fn ann(&self) -> Ann
+    where
+        Ann: Clone,
+    {
+        match self {
+            Pattern::Wildcard(a)
+            | Pattern::Var(_, a)
+            | Pattern::Num(_, a)
+            | Pattern::Bool(_, a)
+            | Pattern::Array(_, a)
+            | Pattern::Object { ann: a, .. } => a.clone(),
+        }
+    }
+
+    pub fn map_ann<Ann2, F>(&self, f: &mut F) -> Pattern<Ann2>
+    where
+        F: FnMut(&Ann) -> Ann2,
+    {
+        match self {
+            Pattern::Wildcard(a) => Pattern::Wildcard(f(a)),
+            Pattern::Var(x, a) => Pattern::Var(x.clone(), f(a)),
+            Pattern::Num(n, a) => Pattern::Num(*n, f(a)),
+            Pattern::Bool(b, a) => Pattern::Bool(*b, f(a)),
+            Pattern::Array(ps, a) => {
+                Pattern::Array(ps.iter().map(|p| p.map_ann(f)).collect(), f(a))
+            }
+            Pattern::Object { class, fields, ann } => Pattern::Object {
+                class: class.clone(),
+                fields: fields.iter().map(|p| p.map_ann(f)).collect(),
+                ann: f(ann),
+            },
         }
     }
 }
@@ -426,10 +731,16 @@ impl<Ann> SeqExp<Ann> {
             | SeqExp::Array(_, a)
             | SeqExp::MakeClosure { ann: a, .. }
             | SeqExp::CallClosure { ann: a, .. }
+            | SeqExp::CallDirect { ann: a, .. }
             | SeqExp::Let { ann: a, .. }
             | SeqExp::If { ann: a, .. }
             | SeqExp::Object { ann: a, .. }
-            | SeqExp::CallMethod { ann: a, .. } => a.clone(),
+            | SeqExp::CallMethod { ann: a, .. }
+            | SeqExp::While { ann: a, .. }
+            | SeqExp::Assign { ann: a, .. }
+            | SeqExp::Raise(_, a)
+            | SeqExp::Try { ann: a, .. }
+            | SeqExp::Fail(_, a) => a.clone(),
         }
     }
 
@@ -471,6 +782,11 @@ impl<Ann> SeqExp<Ann> {
                 args: args.iter().map(|i| i.clone()).collect(),
                 ann: f(ann),
             },
+            SeqExp::CallDirect { label, args, ann } => SeqExp::CallDirect {
+                label: label.clone(),
+                args: args.clone(),
+                ann: f(ann),
+            },
             SeqExp::Let {
                 var,
                 bound_exp,
@@ -509,6 +825,29 @@ impl<Ann> SeqExp<Ann> {
                 args: args.iter().map(|i| i.clone()).collect(),
                 ann: f(ann),
             },
+            SeqExp::While { cond, body, ann } => SeqExp::While {
+                cond: Box::new(cond.map_ann(f)),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            SeqExp::Assign { var, value, ann } => SeqExp::Assign {
+                var: var.clone(),
+                value: value.clone(),
+                ann: f(ann),
+            },
+            SeqExp::Raise(imm, a) => SeqExp::Raise(imm.clone(), f(a)),
+            SeqExp::Try {
+                body,
+                var,
+                handler,
+                ann,
+            } => SeqExp::Try {
+                body: Box::new(body.map_ann(f)),
+                var: var.clone(),
+                handler: Box::new(handler.map_ann(f)),
+                ann: f(ann),
+            },
+            SeqExp::Fail(err, a) => SeqExp::Fail(*err, f(a)),
         }
     }
 }
@@ -523,6 +862,7 @@ impl<Ann> FunDecl<Exp<Ann>, Ann> {
             parameters: self.parameters.clone(),
             body: self.body.map_ann(f),
             ann: f(&self.ann),
+            signature: self.signature.clone(),
         }
     }
 }
@@ -547,6 +887,7 @@ impl<Ann> SeqProg<Ann> {
         SeqProg {
             class: self.class.clone(),
             funs: self.funs.iter().map(|d| d.map_ann(f)).collect(),
+            direct: self.direct.clone(),
             methods: self.methods.iter().map(|d| d.map_ann(f)).collect(),
             main: self.main.map_ann(f),
             ann: f(&self.ann),
@@ -564,6 +905,7 @@ impl<Ann> FunDecl<SeqExp<Ann>, Ann> {
             parameters: self.parameters.clone(),
             body: self.body.map_ann(f),
             ann: f(&self.ann),
+            signature: self.signature.clone(),
         }
     }
 }
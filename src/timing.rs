@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+// The wall times of the passes and the external tools for --time-passes.
+// Nothing is recorded until recording is turned on, and the times are kept
+// per thread so runs on other threads do not mix into them.
+
+thread_local! {
+    static TIMES: RefCell<Option<Vec<(String, Duration)>>> = const { RefCell::new(None) };
+}
+
+pub fn start_recording() {
+    TIMES.with(|t| *t.borrow_mut() = Some(Vec::new()));
+}
+
+// Runs f and records how long it took under name
+pub fn time<T, F>(name: &str, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    TIMES.with(|t| {
+        if let Some(times) = t.borrow_mut().as_mut() {
+            times.push((name.to_string(), elapsed));
+        }
+    });
+    result
+}
+
+// The times recorded so far, which stops the recording
+pub fn take() -> Vec<(String, Duration)> {
+    TIMES.with(|t| t.borrow_mut().take()).unwrap_or_default()
+}
+
+pub fn report(times: &[(String, Duration)]) -> String {
+    let width = times.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    let total: Duration = times.iter().map(|(_, d)| *d).sum();
+    let mut out = String::new();
+    for (name, d) in times.iter() {
+        out.push_str(&format!(
+            "{:width$}  {:>10.3} ms\n",
+            name,
+            d.as_secs_f64() * 1000.0,
+            width = width
+        ));
+    }
+    out.push_str(&format!(
+        "{:width$}  {:>10.3} ms",
+        "total",
+        total.as_secs_f64() * 1000.0,
+        width = width
+    ));
+    out
+}
@@ -0,0 +1,596 @@
+use std::collections::HashSet;
+
+use crate::compile::CompileErr;
+use crate::runtime_error::ERROR_CLASS;
+use crate::scope::get;
+use crate::syntax::{Exp, FunDecl, Pattern, Prim1, Prim2, Signature, SnakeType, SurfProg};
+
+// What the checker knows about a class: its fields and the signatures of its
+// annotated methods
+#[derive(Clone)]
+struct ClassType<'exp> {
+    fields: Vec<(&'exp str, SnakeType)>,
+    methods: Vec<(&'exp str, Option<&'exp Signature>)>,
+}
+
+struct Checker<'exp> {
+    // names that appear on the left of `:=`, an unannotated let binding of
+    // one of them may change its type later
+    assigned: HashSet<&'exp str>,
+    // inside an annotated function, lambda or let binding every mismatch is
+    // reported. Outside of them only the annotations themselves are checked,
+    // so unannotated code keeps failing at runtime as before.
+    strict: bool,
+}
+
+type VarEnv<'exp> = Vec<(&'exp str, SnakeType)>;
+type ClassEnv<'exp> = Vec<(&'exp str, ClassType<'exp>)>;
+
+// A value of type found can be used where expected is required
+fn consistent(found: &SnakeType, expected: &SnakeType) -> bool {
+    match (found, expected) {
+        (SnakeType::Any, _) | (_, SnakeType::Any) => true,
+        // objects are laid out like arrays
+        (SnakeType::Class(_), SnakeType::Array) => true,
+        (SnakeType::Fun(ps1, r1), SnakeType::Fun(ps2, r2)) => {
+            ps1.len() == ps2.len()
+                && ps1
+                    .iter()
+                    .zip(ps2.iter())
+                    .all(|(p1, p2)| consistent(p2, p1))
+                && consistent(r1, r2)
+        }
+        _ => found == expected,
+    }
+}
+
+fn join(t1: SnakeType, t2: SnakeType) -> SnakeType {
+    if t1 == t2 {
+        t1
+    } else {
+        SnakeType::Any
+    }
+}
+
+fn fun_type(sig: Option<&Signature>) -> SnakeType {
+    match sig {
+        Some(sig) => SnakeType::Fun(sig.parameters.clone(), Box::new(sig.ret.clone())),
+        None => SnakeType::Any,
+    }
+}
+
+fn assigned_vars<'exp, Span>(e: &'exp Exp<Span>, assigned: &mut HashSet<&'exp str>) {
+    if let Exp::SetField { field, .. } = e {
+        assigned.insert(field);
+    }
+    for sub in e.sub_exps() {
+        assigned_vars(sub, assigned);
+    }
+}
+
+fn pattern_vars<'exp, Span>(pat: &'exp Pattern<Span>, env: &mut VarEnv<'exp>) {
+    match pat {
+        Pattern::Var(x, _) => env.push((x, SnakeType::Any)),
+        Pattern::Array(ps, _) | Pattern::Object { fields: ps, .. } => {
+            for p in ps.iter() {
+                pattern_vars(p, env);
+            }
+        }
+        Pattern::Wildcard(_) | Pattern::Num(..) | Pattern::Bool(..) => {}
+    }
+}
+
+impl<'exp> Checker<'exp> {
+    // Check UndefinedClass in an annotation
+    fn check_annotation<Span>(
+        &self,
+        ty: &SnakeType,
+        class_env: &ClassEnv,
+        ann: &Span,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        match ty {
+            SnakeType::Class(name) => match get(class_env, name) {
+                Some(_) => Ok(()),
+                None => Err(CompileErr::UndefinedClass {
+                    undefined: name.clone(),
+                    location: ann.clone(),
+                }),
+            },
+            SnakeType::Fun(ps, ret) => {
+                for p in ps.iter() {
+                    self.check_annotation(p, class_env, ann)?;
+                }
+                self.check_annotation(ret, class_env, ann)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn mismatch<Span>(
+        &self,
+        found: SnakeType,
+        expected: &SnakeType,
+        report: bool,
+        ann: &Span,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        if report && !consistent(&found, expected) {
+            Err(CompileErr::TypeMismatch {
+                expected: expected.clone(),
+                found,
+                location: ann.clone(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    // The operand of a primitive, only reported in strict mode
+    fn operand<Span>(
+        &mut self,
+        e: &'exp Exp<Span>,
+        expected: SnakeType,
+        var_env: &VarEnv<'exp>,
+        class_env: &ClassEnv<'exp>,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        let report = self.strict;
+        self.check(e, &expected, report, var_env.clone(), class_env.clone())
+    }
+
+    fn bind_let<Span>(
+        &mut self,
+        bindings: &'exp [(String, Exp<Span>)],
+        var_env: &mut VarEnv<'exp>,
+        class_env: &ClassEnv<'exp>,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        for (x, e) in bindings.iter() {
+            let ty = self.synth(e, var_env.clone(), class_env.clone())?;
+            let ty = match e {
+                Exp::Ascribe(..) => ty,
+                _ if self.assigned.contains(x.as_str()) => SnakeType::Any,
+                _ => ty,
+            };
+            var_env.push((x, ty));
+        }
+        Ok(())
+    }
+
+    fn check_fun<Span>(
+        &mut self,
+        decl: &'exp FunDecl<Exp<Span>, Span>,
+        mut var_env: VarEnv<'exp>,
+        class_env: &ClassEnv<'exp>,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        match &decl.signature {
+            Some(sig) => {
+                for ty in sig.parameters.iter().chain(Some(&sig.ret)) {
+                    self.check_annotation(ty, class_env, &decl.ann)?;
+                }
+                for (x, ty) in decl.parameters.iter().zip(sig.parameters.iter()) {
+                    var_env.push((x, ty.clone()));
+                }
+                let strict = std::mem::replace(&mut self.strict, true);
+                let result = self.check(&decl.body, &sig.ret, true, var_env, class_env.clone());
+                self.strict = strict;
+                result
+            }
+            None => {
+                for x in decl.parameters.iter() {
+                    var_env.push((x, SnakeType::Any));
+                }
+                let strict = std::mem::replace(&mut self.strict, false);
+                let result = self.synth(&decl.body, var_env, class_env.clone());
+                self.strict = strict;
+                result.map(|_| ())
+            }
+        }
+    }
+
+    // The arguments of a call to a function of type fun
+    fn check_args<Span>(
+        &mut self,
+        fun: SnakeType,
+        args: &'exp [Exp<Span>],
+        var_env: &VarEnv<'exp>,
+        class_env: &ClassEnv<'exp>,
+        ann: &Span,
+    ) -> Result<SnakeType, CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        match fun {
+            SnakeType::Fun(ps, ret) => {
+                if ps.len() != args.len() {
+                    return Err(CompileErr::ArityMismatch {
+                        expected: ps.len(),
+                        found: args.len(),
+                        location: ann.clone(),
+                    });
+                }
+                for (arg, ty) in args.iter().zip(ps.iter()) {
+                    self.check(arg, ty, true, var_env.clone(), class_env.clone())?;
+                }
+                Ok(*ret)
+            }
+            found => {
+                let expected =
+                    SnakeType::Fun(vec![SnakeType::Any; args.len()], Box::new(SnakeType::Any));
+                self.mismatch(found, &expected, self.strict, ann)?;
+                for arg in args.iter() {
+                    self.synth(arg, var_env.clone(), class_env.clone())?;
+                }
+                Ok(SnakeType::Any)
+            }
+        }
+    }
+
+    // Verification: e has a type consistent with expected, the mismatch is
+    // an error if report is set
+    fn check<Span>(
+        &mut self,
+        e: &'exp Exp<Span>,
+        expected: &SnakeType,
+        report: bool,
+        mut var_env: VarEnv<'exp>,
+        class_env: ClassEnv<'exp>,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        match e {
+            Exp::If { cond, thn, els, .. } => {
+                self.operand(cond, SnakeType::Bool, &var_env, &class_env)?;
+                self.check(thn, expected, report, var_env.clone(), class_env.clone())?;
+                self.check(els, expected, report, var_env, class_env)
+            }
+            Exp::Let { bindings, body, .. } => {
+                self.bind_let(bindings, &mut var_env, &class_env)?;
+                self.check(body, expected, report, var_env, class_env)
+            }
+            Exp::Semicolon { e1, e2, .. } => {
+                self.synth(e1, var_env.clone(), class_env.clone())?;
+                self.check(e2, expected, report, var_env, class_env)
+            }
+            Exp::Lambda {
+                parameters,
+                body,
+                ann,
+            } => match expected {
+                SnakeType::Fun(ps, ret) => {
+                    if ps.len() != parameters.len() {
+                        return Err(CompileErr::ArityMismatch {
+                            expected: ps.len(),
+                            found: parameters.len(),
+                            location: ann.clone(),
+                        });
+                    }
+                    for (x, ty) in parameters.iter().zip(ps.iter()) {
+                        var_env.push((x, ty.clone()));
+                    }
+                    let strict = std::mem::replace(&mut self.strict, true);
+                    let result = self.check(body, ret, true, var_env, class_env);
+                    self.strict = strict;
+                    result
+                }
+                _ => {
+                    let found = self.synth(e, var_env, class_env)?;
+                    self.mismatch(found, expected, report, ann)
+                }
+            },
+            _ => {
+                let found = self.synth(e, var_env, class_env)?;
+                self.mismatch(found, expected, report, &e.ann())
+            }
+        }
+    }
+
+    // Synthesis: the type of e
+    fn synth<Span>(
+        &mut self,
+        e: &'exp Exp<Span>,
+        mut var_env: VarEnv<'exp>,
+        mut class_env: ClassEnv<'exp>,
+    ) -> Result<SnakeType, CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        match e {
+            Exp::Num(..) => Ok(SnakeType::Num),
+            Exp::Bool(..) => Ok(SnakeType::Bool),
+            Exp::Var(x, _) => Ok(get(&var_env, x).unwrap_or(SnakeType::Any)),
+            Exp::Prim1(prim, e, _) => match prim {
+                Prim1::Add1 | Prim1::Sub1 => {
+                    self.operand(e, SnakeType::Num, &var_env, &class_env)?;
+                    Ok(SnakeType::Num)
+                }
+                Prim1::Not => {
+                    self.operand(e, SnakeType::Bool, &var_env, &class_env)?;
+                    Ok(SnakeType::Bool)
+                }
+                Prim1::Length | Prim1::ClassIdx => {
+                    self.operand(e, SnakeType::Array, &var_env, &class_env)?;
+                    Ok(SnakeType::Num)
+                }
+                Prim1::Print => self.synth(e, var_env, class_env),
+                Prim1::IsBool | Prim1::IsNum | Prim1::IsArray | Prim1::IsFun => {
+                    self.synth(e, var_env, class_env)?;
+                    Ok(SnakeType::Bool)
+                }
+            },
+            Exp::Prim2(prim, e1, e2, _) => {
+                let (operands, ty) = match prim {
+                    Prim2::Add | Prim2::Sub | Prim2::Mul => {
+                        (Some((SnakeType::Num, SnakeType::Num)), SnakeType::Num)
+                    }
+                    Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge => {
+                        (Some((SnakeType::Num, SnakeType::Num)), SnakeType::Bool)
+                    }
+                    Prim2::And | Prim2::Or => {
+                        (Some((SnakeType::Bool, SnakeType::Bool)), SnakeType::Bool)
+                    }
+                    Prim2::Eq | Prim2::Neq => (None, SnakeType::Bool),
+                    Prim2::ArrayGet => (Some((SnakeType::Array, SnakeType::Num)), SnakeType::Any),
+                };
+                match operands {
+                    Some((ty1, ty2)) => {
+                        self.operand(e1, ty1, &var_env, &class_env)?;
+                        self.operand(e2, ty2, &var_env, &class_env)?;
+                    }
+                    None => {
+                        self.synth(e1, var_env.clone(), class_env.clone())?;
+                        self.synth(e2, var_env, class_env)?;
+                    }
+                }
+                Ok(ty)
+            }
+            Exp::Let { bindings, body, .. } => {
+                self.bind_let(bindings, &mut var_env, &class_env)?;
+                self.synth(body, var_env, class_env)
+            }
+            Exp::LetPattern { bindings, body, .. } => {
+                for (pat, e) in bindings.iter() {
+                    self.synth(e, var_env.clone(), class_env.clone())?;
+                    pattern_vars(pat, &mut var_env);
+                }
+                self.synth(body, var_env, class_env)
+            }
+            Exp::If { cond, thn, els, .. } => {
+                self.operand(cond, SnakeType::Bool, &var_env, &class_env)?;
+                let thn_ty = self.synth(thn, var_env.clone(), class_env.clone())?;
+                let els_ty = self.synth(els, var_env, class_env)?;
+                Ok(join(thn_ty, els_ty))
+            }
+            Exp::Array(es, _) => {
+                for e in es.iter() {
+                    self.synth(e, var_env.clone(), class_env.clone())?;
+                }
+                Ok(SnakeType::Array)
+            }
+            Exp::ArraySet {
+                array,
+                index,
+                new_value,
+                ..
+            } => {
+                let ty = self.synth(array, var_env.clone(), class_env.clone())?;
+                self.mismatch(ty.clone(), &SnakeType::Array, self.strict, &array.ann())?;
+                self.operand(index, SnakeType::Num, &var_env, &class_env)?;
+                self.synth(new_value, var_env, class_env)?;
+                Ok(ty)
+            }
+            Exp::Semicolon { e1, e2, .. } => {
+                self.synth(e1, var_env.clone(), class_env.clone())?;
+                self.synth(e2, var_env, class_env)
+            }
+            Exp::FunDefs { decls, body, .. } => {
+                for decl in decls.iter() {
+                    var_env.push((&decl.name, fun_type(decl.signature.as_ref())));
+                }
+                for decl in decls.iter() {
+                    self.check_fun(decl, var_env.clone(), &class_env)?;
+                }
+                self.synth(body, var_env, class_env)
+            }
+            Exp::Call(fun, args, ann) => {
+                let fun_ty = self.synth(fun, var_env.clone(), class_env.clone())?;
+                self.check_args(fun_ty, args, &var_env, &class_env, ann)
+            }
+            // Without an expected type the parameters are unknown
+            Exp::Lambda {
+                parameters, body, ..
+            } => {
+                for x in parameters.iter() {
+                    var_env.push((x, SnakeType::Any));
+                }
+                let strict = std::mem::replace(&mut self.strict, false);
+                let result = self.synth(body, var_env, class_env);
+                self.strict = strict;
+                result.map(|_| SnakeType::Any)
+            }
+            Exp::ClassDef {
+                name,
+                fields,
+                field_types,
+                methods,
+                body,
+                ann,
+            } => {
+                class_env.push((
+                    name,
+                    ClassType {
+                        fields: fields
+                            .iter()
+                            .map(|x| x.as_str())
+                            .zip(field_types.iter().cloned())
+                            .collect(),
+                        methods: methods
+                            .iter()
+                            .map(|m| (m.name.as_str(), m.signature.as_ref()))
+                            .collect(),
+                    },
+                ));
+                for ty in field_types.iter() {
+                    self.check_annotation(ty, &class_env, ann)?;
+                }
+                // Fields are variables in the methods
+                let mut method_env = var_env.clone();
+                for (x, ty) in fields.iter().zip(field_types.iter()) {
+                    method_env.push((x, ty.clone()));
+                }
+                for method in methods.iter() {
+                    method_env.push((&method.name, SnakeType::Any));
+                }
+                for method in methods.iter() {
+                    self.check_fun(method, method_env.clone(), &class_env)?;
+                }
+                self.synth(body, var_env, class_env)
+            }
+            Exp::Object { class, fields, .. } => {
+                let types = match get(&class_env, class) {
+                    Some(info) => info.fields,
+                    None => Vec::new(),
+                };
+                for (i, field) in fields.iter().enumerate() {
+                    let ty = match types.get(i) {
+                        Some((_, ty)) => ty.clone(),
+                        None => SnakeType::Any,
+                    };
+                    self.check(field, &ty, true, var_env.clone(), class_env.clone())?;
+                }
+                Ok(SnakeType::Class(class.clone()))
+            }
+            Exp::CallMethod {
+                object,
+                method,
+                args,
+                ann,
+            } => {
+                let object_ty = self.synth(object, var_env.clone(), class_env.clone())?;
+                let sig = match object_ty {
+                    SnakeType::Class(class) => get(&class_env, &class)
+                        .and_then(|info| get(&info.methods, method))
+                        .flatten(),
+                    _ => None,
+                };
+                self.check_args(fun_type(sig), args, &var_env, &class_env, ann)
+            }
+            Exp::SetField { field, value, .. } => {
+                let ty = get(&var_env, field).unwrap_or(SnakeType::Any);
+                self.check(value, &ty, true, var_env, class_env)?;
+                Ok(ty)
+            }
+            Exp::While { cond, body, .. } => {
+                self.operand(cond, SnakeType::Bool, &var_env, &class_env)?;
+                self.synth(body, var_env, class_env)?;
+                Ok(SnakeType::Bool)
+            }
+            Exp::For {
+                var, array, body, ..
+            } => {
+                self.operand(array, SnakeType::Array, &var_env, &class_env)?;
+                var_env.push((var, SnakeType::Any));
+                self.synth(body, var_env, class_env)?;
+                Ok(SnakeType::Bool)
+            }
+            Exp::ForRange {
+                var,
+                start,
+                stop,
+                body,
+                ..
+            } => {
+                self.operand(start, SnakeType::Num, &var_env, &class_env)?;
+                self.operand(stop, SnakeType::Num, &var_env, &class_env)?;
+                if self.assigned.contains(var.as_str()) {
+                    var_env.push((var, SnakeType::Any));
+                } else {
+                    var_env.push((var, SnakeType::Num));
+                }
+                self.synth(body, var_env, class_env)?;
+                Ok(SnakeType::Bool)
+            }
+            Exp::Match {
+                scrutinee, arms, ..
+            } => {
+                self.synth(scrutinee, var_env.clone(), class_env.clone())?;
+                let mut ty = None;
+                for (pat, body) in arms.iter() {
+                    let mut arm_env = var_env.clone();
+                    pattern_vars(pat, &mut arm_env);
+                    let arm_ty = self.synth(body, arm_env, class_env.clone())?;
+                    ty = Some(match ty {
+                        Some(ty) => join(ty, arm_ty),
+                        None => arm_ty,
+                    });
+                }
+                Ok(ty.unwrap_or(SnakeType::Any))
+            }
+            Exp::Raise(e, _) => {
+                self.synth(e, var_env, class_env)?;
+                Ok(SnakeType::Any)
+            }
+            Exp::Try {
+                body, var, handler, ..
+            } => {
+                let body_ty = self.synth(body, var_env.clone(), class_env.clone())?;
+                var_env.push((var, SnakeType::Class(ERROR_CLASS.to_string())));
+                let handler_ty = self.synth(handler, var_env, class_env)?;
+                Ok(join(body_ty, handler_ty))
+            }
+            Exp::Ascribe(e, ty, ann) => {
+                self.check_annotation(ty, &class_env, ann)?;
+                let strict = std::mem::replace(&mut self.strict, true);
+                let result = self.check(e, ty, true, var_env, class_env);
+                self.strict = strict;
+                result.map(|_| ty.clone())
+            }
+            Exp::MakeClosure { .. }
+            | Exp::CallUniqMethod { .. }
+            | Exp::MethodDefs { .. }
+            | Exp::Fail(..)
+            | Exp::Assign { .. } => {
+                panic!("Should never exist during type checking!")
+            }
+        }
+    }
+}
+
+// Gradual bidirectional type checking.
+//
+// Annotated functions, lambdas passed where a function type is expected and
+// annotated let bindings are checked against their annotations. Unannotated
+// variables and parameters have type Any, which is consistent with every
+// type, so programs without annotations are accepted as before.
+//
+// Precondition: p passed check_prog
+pub fn check_types<Span>(p: &SurfProg<Span>) -> Result<(), CompileErr<Span>>
+where
+    Span: Clone,
+{
+    let mut assigned = HashSet::new();
+    assigned_vars(p, &mut assigned);
+    let mut checker = Checker {
+        assigned,
+        strict: false,
+    };
+    let error_class = ClassType {
+        fields: vec![("kind", SnakeType::Num)],
+        methods: Vec::new(),
+    };
+    checker.synth(p, Vec::new(), vec![(ERROR_CLASS, error_class)])?;
+    Ok(())
+}
@@ -0,0 +1,448 @@
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+
+use crate::runtime_error::Type;
+use crate::syntax::{ClassInfo, ImmExp, Prim1, Prim2, SeqExp, SeqProg};
+
+/* What is statically known about the value of an immediate */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StaticType {
+    Num,
+    Bool,
+    Array,
+    Closure(usize), // the arity of the closure
+    Object(usize),  // the class index of the object
+    Any,
+}
+
+// The types of the operands of every expression that checks them, indexed
+// by the tag of the expression. The codegen asks the table before emitting
+// each check and the table counts the checks it made redundant.
+pub struct TypeTable {
+    operands: HashMap<u32, Vec<StaticType>>,
+    unboxed: HashSet<String>,
+    removed: Cell<usize>,
+}
+
+impl TypeTable {
+    fn operand(&self, ann: u32, i: usize) -> &StaticType {
+        match self.operands.get(&ann).and_then(|tys| tys.get(i)) {
+            Some(ty) => ty,
+            None => &StaticType::Any,
+        }
+    }
+
+    fn removed_if(&self, known: bool) -> bool {
+        if known {
+            self.removed.set(self.removed.get() + 1);
+        }
+        known
+    }
+
+    /* Operand i of expression ann always has the tag of ty */
+    pub fn has_type(&self, ann: u32, i: usize, ty: &Type) -> bool {
+        let known = matches!(
+            (ty, self.operand(ann, i)),
+            (Type::Num, StaticType::Num)
+                | (Type::Bool, StaticType::Bool)
+                | (Type::Array, StaticType::Array | StaticType::Object(_))
+                | (Type::Closure, StaticType::Closure(_))
+        );
+        self.removed_if(known)
+    }
+
+    /* Operand i of expression ann is always a closure taking arity arguments */
+    pub fn has_arity(&self, ann: u32, i: usize, arity: usize) -> bool {
+        let known = *self.operand(ann, i) == StaticType::Closure(arity);
+        self.removed_if(known)
+    }
+
+    /* Operand i of expression ann is always an object of class classidx */
+    pub fn has_class(&self, ann: u32, i: usize, classidx: usize) -> bool {
+        let known = *self.operand(ann, i) == StaticType::Object(classidx);
+        self.removed_if(known)
+    }
+
+    /* The variable holds an untagged number, see unboxed_vars */
+    pub fn is_unboxed(&self, x: &str) -> bool {
+        self.unboxed.contains(x)
+    }
+
+    pub fn removed_checks(&self) -> usize {
+        self.removed.get()
+    }
+}
+
+type TypeEnv = HashMap<String, StaticType>;
+
+struct Inference<'a> {
+    class: &'a HashMap<String, ClassInfo>,
+    assigned: HashSet<String>,
+    unboxed: &'a HashSet<String>,
+    operands: HashMap<u32, Vec<StaticType>>,
+}
+
+fn join(t1: StaticType, t2: StaticType) -> StaticType {
+    if t1 == t2 {
+        t1
+    } else {
+        StaticType::Any
+    }
+}
+
+// The facts that hold on both paths
+fn meet(env1: TypeEnv, env2: &TypeEnv) -> TypeEnv {
+    env1.into_iter()
+        .filter(|(x, ty)| env2.get(x) == Some(ty))
+        .collect()
+}
+
+fn assigned_vars(e: &SeqExp<u32>, assigned: &mut HashSet<String>) {
+    match e {
+        SeqExp::Assign { var, .. } => {
+            assigned.insert(var.clone());
+        }
+        SeqExp::Let {
+            bound_exp, body, ..
+        } => {
+            assigned_vars(bound_exp, assigned);
+            assigned_vars(body, assigned);
+        }
+        SeqExp::If { thn, els, .. } => {
+            assigned_vars(thn, assigned);
+            assigned_vars(els, assigned);
+        }
+        SeqExp::While { cond, body, .. } => {
+            assigned_vars(cond, assigned);
+            assigned_vars(body, assigned);
+        }
+        SeqExp::Try { body, handler, .. } => {
+            assigned_vars(body, assigned);
+            assigned_vars(handler, assigned);
+        }
+        _ => {}
+    }
+}
+
+// What the unboxing needs to know about the variables of a program
+#[derive(Default)]
+struct Definitions {
+    let_bound: HashSet<String>,
+    // the variables a variable is copied from, for variables that are only
+    // ever given numbers or copies
+    numeric: HashMap<String, Vec<String>>,
+    // variables given anything else
+    boxed: HashSet<String>,
+    // variables used by arithmetic, comparisons or copied
+    used: HashSet<String>,
+}
+
+impl Definitions {
+    fn define(&mut self, var: &str, e: &SeqExp<u32>) {
+        let copied = self.numeric.entry(var.to_string()).or_default();
+        match e {
+            SeqExp::Imm(ImmExp::Num(_), _)
+            | SeqExp::Prim1(Prim1::Add1 | Prim1::Sub1, _, _)
+            | SeqExp::Prim2(Prim2::Add | Prim2::Sub | Prim2::Mul, _, _, _) => {}
+            SeqExp::Imm(ImmExp::Var(y), _) => {
+                copied.push(y.clone());
+                self.used.insert(y.clone());
+            }
+            _ => {
+                self.boxed.insert(var.to_string());
+            }
+        }
+    }
+
+    fn use_imm(&mut self, imm: &ImmExp) {
+        if let ImmExp::Var(x) = imm {
+            self.used.insert(x.clone());
+        }
+    }
+
+    fn collect(&mut self, e: &SeqExp<u32>) {
+        match e {
+            SeqExp::Let {
+                var,
+                bound_exp,
+                body,
+                ..
+            } => {
+                self.let_bound.insert(var.clone());
+                self.define(var, bound_exp);
+                self.collect(bound_exp);
+                self.collect(body);
+            }
+            SeqExp::Assign { var, value, ann } => {
+                self.define(var, &SeqExp::Imm(value.clone(), *ann));
+            }
+            SeqExp::Prim1(Prim1::Add1 | Prim1::Sub1, imm, _) => self.use_imm(imm),
+            SeqExp::Prim2(
+                Prim2::Add
+                | Prim2::Sub
+                | Prim2::Mul
+                | Prim2::Lt
+                | Prim2::Gt
+                | Prim2::Le
+                | Prim2::Ge,
+                imm1,
+                imm2,
+                _,
+            ) => {
+                self.use_imm(imm1);
+                self.use_imm(imm2);
+            }
+            SeqExp::If { thn, els, .. } => {
+                self.collect(thn);
+                self.collect(els);
+            }
+            SeqExp::While { cond, body, .. } => {
+                self.collect(cond);
+                self.collect(body);
+            }
+            SeqExp::Try { body, handler, .. } => {
+                self.collect(body);
+                self.collect(handler);
+            }
+            _ => {}
+        }
+    }
+}
+
+// The let-bound variables that only ever hold the numbers computed by
+// arithmetic and are used by arithmetic. The codegen keeps them untagged,
+// and tags them only where they escape: stored into arrays and objects,
+// passed to functions or print, compared for equality with a tagged value
+// or returned. Tagging reports the overflow of the untagged number.
+//
+// Precondition: all variable names in the program are unique
+fn unboxed_vars(p: &SeqProg<u32>) -> HashSet<String> {
+    let mut defs = Definitions::default();
+    for decl in p.funs.iter() {
+        defs.collect(&decl.body);
+    }
+    for method in p.methods.iter() {
+        defs.collect(&method.fundecl.body);
+    }
+    defs.collect(&p.main);
+    let mut unboxed: HashSet<String> = defs
+        .numeric
+        .keys()
+        .filter(|x| {
+            defs.let_bound.contains(*x) && !defs.boxed.contains(*x) && defs.used.contains(*x)
+        })
+        .cloned()
+        .collect();
+    // a copy of a tagged variable is tagged too
+    loop {
+        let tagged: Vec<String> = unboxed
+            .iter()
+            .filter(|x| defs.numeric[*x].iter().any(|y| !unboxed.contains(y)))
+            .cloned()
+            .collect();
+        if tagged.is_empty() {
+            return unboxed;
+        }
+        for x in tagged {
+            unboxed.remove(&x);
+        }
+    }
+}
+
+impl<'a> Inference<'a> {
+    fn imm(&self, imm: &ImmExp, env: &TypeEnv) -> StaticType {
+        match imm {
+            ImmExp::Num(_) => StaticType::Num,
+            ImmExp::Bool(_) => StaticType::Bool,
+            ImmExp::Var(x) if self.unboxed.contains(x) => StaticType::Num,
+            ImmExp::Var(x) => env.get(x).cloned().unwrap_or(StaticType::Any),
+        }
+    }
+
+    // After a check on imm succeeded it is known to have type ty, unless
+    // it may be assigned a value of another type later
+    fn refine(&self, imm: &ImmExp, ty: StaticType, env: &mut TypeEnv) {
+        if let ImmExp::Var(x) = imm {
+            let unknown = matches!(env.get(x), None | Some(StaticType::Any));
+            if unknown && !self.assigned.contains(x) {
+                env.insert(x.clone(), ty);
+            }
+        }
+    }
+
+    fn record(&mut self, ann: u32, imms: &[&ImmExp], env: &TypeEnv) {
+        let tys = imms.iter().map(|imm| self.imm(imm, env)).collect();
+        self.operands.insert(ann, tys);
+    }
+
+    // The type of the value of e and the environment after e returns
+    fn infer(&mut self, e: &SeqExp<u32>, mut env: TypeEnv) -> (StaticType, TypeEnv) {
+        match e {
+            SeqExp::Imm(imm, _) => (self.imm(imm, &env), env),
+            SeqExp::Prim1(prim, imm, ann) => {
+                self.record(*ann, &[imm], &env);
+                let ty = match prim {
+                    Prim1::Add1 | Prim1::Sub1 | Prim1::Length | Prim1::ClassIdx => StaticType::Num,
+                    Prim1::Not | Prim1::IsBool | Prim1::IsNum | Prim1::IsArray | Prim1::IsFun => {
+                        StaticType::Bool
+                    }
+                    Prim1::Print => self.imm(imm, &env),
+                };
+                match prim {
+                    Prim1::Add1 | Prim1::Sub1 => self.refine(imm, StaticType::Num, &mut env),
+                    Prim1::Not => self.refine(imm, StaticType::Bool, &mut env),
+                    Prim1::Length | Prim1::ClassIdx => {
+                        self.refine(imm, StaticType::Array, &mut env)
+                    }
+                    _ => {}
+                }
+                (ty, env)
+            }
+            SeqExp::Prim2(prim, imm1, imm2, ann) => {
+                self.record(*ann, &[imm1, imm2], &env);
+                let (ty, operands) = match prim {
+                    Prim2::Add | Prim2::Sub | Prim2::Mul => {
+                        (StaticType::Num, Some((StaticType::Num, StaticType::Num)))
+                    }
+                    Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge => {
+                        (StaticType::Bool, Some((StaticType::Num, StaticType::Num)))
+                    }
+                    Prim2::And | Prim2::Or => {
+                        (StaticType::Bool, Some((StaticType::Bool, StaticType::Bool)))
+                    }
+                    Prim2::Eq | Prim2::Neq => (StaticType::Bool, None),
+                    Prim2::ArrayGet => {
+                        (StaticType::Any, Some((StaticType::Array, StaticType::Num)))
+                    }
+                };
+                if let Some((ty1, ty2)) = operands {
+                    self.refine(imm1, ty1, &mut env);
+                    self.refine(imm2, ty2, &mut env);
+                }
+                (ty, env)
+            }
+            SeqExp::ArraySet {
+                array, index, ann, ..
+            } => {
+                self.record(*ann, &[array, index], &env);
+                let ty = match self.imm(array, &env) {
+                    StaticType::Object(classidx) => StaticType::Object(classidx),
+                    _ => StaticType::Array,
+                };
+                self.refine(array, StaticType::Array, &mut env);
+                self.refine(index, StaticType::Num, &mut env);
+                (ty, env)
+            }
+            SeqExp::Array(..) => (StaticType::Array, env),
+            SeqExp::MakeClosure { arity, .. } => (StaticType::Closure(*arity), env),
+            SeqExp::CallClosure { fun, args, ann } => {
+                self.record(*ann, &[fun], &env);
+                self.refine(fun, StaticType::Closure(args.len()), &mut env);
+                (StaticType::Any, env)
+            }
+            SeqExp::Let {
+                var,
+                bound_exp,
+                body,
+                ..
+            } => {
+                let (ty, mut env) = self.infer(bound_exp, env);
+                if !self.assigned.contains(var) {
+                    env.insert(var.clone(), ty);
+                }
+                self.infer(body, env)
+            }
+            SeqExp::If {
+                cond,
+                thn,
+                els,
+                ann,
+            } => {
+                self.record(*ann, &[cond], &env);
+                self.refine(cond, StaticType::Bool, &mut env);
+                let (thn_ty, thn_env) = self.infer(thn, env.clone());
+                let (els_ty, els_env) = self.infer(els, env);
+                (join(thn_ty, els_ty), meet(thn_env, &els_env))
+            }
+            SeqExp::Object { class, .. } => {
+                let ty = match self.class.get(class) {
+                    Some(info) => StaticType::Object(info.id),
+                    None => StaticType::Array,
+                };
+                (ty, env)
+            }
+            SeqExp::CallMethod {
+                object,
+                method,
+                args,
+                ann,
+            } => {
+                let fun = match method.values().next() {
+                    Some(name) => ImmExp::Var(name.clone()),
+                    None => panic!("Method is guaranteed to be in scope"),
+                };
+                self.record(*ann, &[object, &fun], &env);
+                // the class check only accepts the first class with the method
+                let classidx = method.keys().next().and_then(|class| self.class.get(class));
+                if let Some(info) = classidx {
+                    self.refine(object, StaticType::Object(info.id), &mut env);
+                }
+                self.refine(&fun, StaticType::Closure(args.len() + 1), &mut env);
+                (StaticType::Any, env)
+            }
+            // The condition runs once more than the body, so the facts it
+            // establishes also hold after the loop
+            SeqExp::While { cond, body, ann } => {
+                let (cond_ty, cond_env) = self.infer(cond, env);
+                self.operands.insert(*ann, vec![cond_ty]);
+                self.infer(body, cond_env.clone());
+                (StaticType::Bool, cond_env)
+            }
+            SeqExp::CallDirect { .. } | SeqExp::Assign { .. } => (StaticType::Any, env),
+            SeqExp::Raise(..) | SeqExp::Fail(..) => (StaticType::Any, env),
+            // The handler may start at any point of the body
+            SeqExp::Try { body, handler, .. } => {
+                let (body_ty, body_env) = self.infer(body, env.clone());
+                let (handler_ty, handler_env) = self.infer(handler, env);
+                (join(body_ty, handler_ty), meet(body_env, &handler_env))
+            }
+        }
+    }
+}
+
+// Flow-sensitive type inference.
+//
+// Every let-bound variable gets the type of its bound expression, and a
+// variable is known to have a type after a check on it succeeded. Variables
+// that are assigned stay unknown, as do parameters and call results.
+//
+// Precondition: all variable names in the program are unique
+pub fn infer_types(p: &SeqProg<u32>) -> TypeTable {
+    let mut assigned = HashSet::new();
+    for decl in p.funs.iter() {
+        assigned_vars(&decl.body, &mut assigned);
+    }
+    for method in p.methods.iter() {
+        assigned_vars(&method.fundecl.body, &mut assigned);
+    }
+    assigned_vars(&p.main, &mut assigned);
+    let unboxed = unboxed_vars(p);
+    let mut inference = Inference {
+        class: &p.class,
+        assigned,
+        unboxed: &unboxed,
+        operands: HashMap::new(),
+    };
+    for decl in p.funs.iter() {
+        inference.infer(&decl.body, HashMap::new());
+    }
+    for method in p.methods.iter() {
+        inference.infer(&method.fundecl.body, HashMap::new());
+    }
+    inference.infer(&p.main, HashMap::new());
+    TypeTable {
+        operands: inference.operands,
+        unboxed,
+        removed: Cell::new(0),
+    }
+}
@@ -0,0 +1,481 @@
+use crate::bytecode::{Arg, Bytecode, Op};
+use crate::compile::HEAP_SIZE;
+use crate::runtime_error::{
+    error_message, prim1_operand, prim2_operand, RuntimeErr, Type, ERROR_CLASS_ID,
+};
+use crate::syntax::{Prim1, Prim2};
+use std::collections::HashSet;
+
+// Runs the bytecode of bytecode.rs. The values are the same 64-bit words the
+// native code works with: heap addresses are the offsets of the words in
+// the heap, so they are tagged the same way and arrays, objects and
+// closures have the native layouts. Like jit::run, the output and the
+// failure of the run are returned rather than printed.
+
+static INT_TAG: u64 = 0x00_00_00_00_00_00_00_01;
+static TAG_MASK: u64 = 0b111;
+static BOOL_TAG: u64 = 0b111;
+static ARRAY_TAG: u64 = 0b001;
+static CLOSURE_TAG: u64 = 0b011;
+
+// The words of stack a program gets, the default of the native runtime.
+// Every frame also takes the two words the native code spends on the
+// return address and the saved frame.
+static STACK_WORDS: usize = (8 << 20) / 8;
+static FRAME_WORDS: usize = 2;
+
+static XOR_NOT: u64 = 0x80_00_00_00_00_00_00_00;
+static SNAKE_TRUE: u64 = 0xFF_FF_FF_FF_FF_FF_FF_FF;
+static SNAKE_FALSE: u64 = 0x7F_FF_FF_FF_FF_FF_FF_FF;
+
+// What stops an instruction: a built-in failure or a raised value
+enum Fault {
+    Error(RuntimeErr),
+    Raise(u64),
+}
+
+struct Frame {
+    ret: usize,
+    base: usize,
+}
+
+// Where a raise returns to, and the frame and stack the Try had
+struct Handler {
+    frames: usize,
+    base: usize,
+    stack: usize,
+    catch: usize,
+    var: usize,
+}
+
+struct Vm<'a> {
+    b: &'a Bytecode,
+    heap: Vec<u64>,
+    stack: Vec<u64>,
+    frames: Vec<Frame>,
+    handlers: Vec<Handler>,
+    base: usize,
+    pc: usize,
+    acc: u64,
+    out: String,
+}
+
+fn bool_val(b: bool) -> u64 {
+    if b {
+        SNAKE_TRUE
+    } else {
+        SNAKE_FALSE
+    }
+}
+
+fn addr(v: u64) -> usize {
+    (v >> 3) as usize
+}
+
+fn has_type(v: u64, ty: &Type) -> bool {
+    match ty {
+        Type::Num => v & INT_TAG == 0,
+        Type::Bool => v & TAG_MASK == BOOL_TAG,
+        Type::Array => v & TAG_MASK == ARRAY_TAG,
+        Type::Closure => v & TAG_MASK == CLOSURE_TAG,
+    }
+}
+
+fn check(v: u64, ty: Type, err: RuntimeErr) -> Result<u64, Fault> {
+    if has_type(v, &ty) {
+        Ok(v)
+    } else {
+        Err(Fault::Error(err))
+    }
+}
+
+fn tag(n: i64) -> Result<u64, Fault> {
+    n.checked_add(n)
+        .map(|v| v as u64)
+        .ok_or(Fault::Error(RuntimeErr::OverflowError))
+}
+
+fn overflow(n: Option<i64>) -> Result<i64, Fault> {
+    n.ok_or(Fault::Error(RuntimeErr::OverflowError))
+}
+
+// An untagged result has to stay in the range of the tagged numbers
+fn untagged_overflow(n: Option<i64>) -> Result<i64, Fault> {
+    tag(overflow(n)?)?;
+    Ok(n.unwrap())
+}
+
+impl<'a> Vm<'a> {
+    fn value(&self, a: &Arg) -> Result<u64, Fault> {
+        match a {
+            Arg::Imm(v) => Ok(*v),
+            Arg::Slot(i) => Ok(self.stack[self.base + i]),
+            Arg::Raw(i) => tag(self.stack[self.base + i] as i64),
+        }
+    }
+
+    fn values(&self, args: &[Arg]) -> Result<Vec<u64>, Fault> {
+        args.iter().map(|a| self.value(a)).collect()
+    }
+
+    // The untagged number, a tagged operand is checked to be a number first
+    fn raw(&self, a: &Arg, err: Option<RuntimeErr>) -> Result<i64, Fault> {
+        let v = match a {
+            Arg::Raw(i) => return Ok(self.stack[self.base + i] as i64),
+            Arg::Imm(v) => *v,
+            Arg::Slot(i) => self.stack[self.base + i],
+        };
+        match err {
+            Some(err) => Ok(check(v, Type::Num, err)? as i64 >> 1),
+            None => Ok(v as i64 >> 1),
+        }
+    }
+
+    // The heap has the words of the native one, and running out of it is
+    // an error like there
+    fn alloc(&mut self, words: Vec<u64>, tag: u64) -> Result<u64, Fault> {
+        if self.heap.len() + words.len() > HEAP_SIZE {
+            return Err(Fault::Error(RuntimeErr::OutOfMemoryError));
+        }
+        let at = self.heap.len() as u64;
+        self.heap.extend(words);
+        Ok((at << 3) + tag)
+    }
+
+    fn alloc_array(&mut self, classidx: u64, elts: Vec<u64>) -> Result<u64, Fault> {
+        let mut words = vec![classidx, 2 * elts.len() as u64];
+        words.extend(elts);
+        self.alloc(words, ARRAY_TAG)
+    }
+
+    // The heap index of the element, after checking the array and the index
+    fn element(&self, array: u64, index: u64) -> Result<usize, Fault> {
+        let array = addr(check(array, Type::Array, RuntimeErr::ArrayError)?);
+        let index = check(index, Type::Num, RuntimeErr::IndexError)? as i64;
+        if index >= self.heap[array + 1] as i64 || index < 0 {
+            return Err(Fault::Error(RuntimeErr::BoundingError));
+        }
+        Ok(array + 2 + (index >> 1) as usize)
+    }
+
+    fn prim1(&mut self, p: Prim1, v: u64) -> Result<u64, Fault> {
+        if let Some((ty, err)) = prim1_operand(&p) {
+            check(v, ty, err)?;
+        }
+        Ok(match p {
+            Prim1::Add1 => overflow((v as i64).checked_add(2))? as u64,
+            Prim1::Sub1 => overflow((v as i64).checked_sub(2))? as u64,
+            Prim1::Not => v ^ XOR_NOT,
+            Prim1::Print => {
+                let s = self.sprint_snake_val(v);
+                self.out.push_str(&s);
+                self.out.push('\n');
+                v
+            }
+            Prim1::IsNum => bool_val(has_type(v, &Type::Num)),
+            Prim1::IsBool => bool_val(has_type(v, &Type::Bool)),
+            Prim1::IsArray => bool_val(has_type(v, &Type::Array)),
+            Prim1::IsFun => bool_val(has_type(v, &Type::Closure)),
+            Prim1::Length => self.heap[addr(v) + 1],
+            // The header holds a raw index, tag it as a number
+            Prim1::ClassIdx => self.heap[addr(v)] << 1,
+        })
+    }
+
+    fn prim2(&mut self, p: Prim2, v1: u64, v2: u64) -> Result<u64, Fault> {
+        if let Some((ty, err)) = prim2_operand(&p) {
+            check(v1, ty.clone(), err)?;
+            check(v2, ty, err)?;
+        }
+        let (n1, n2) = (v1 as i64, v2 as i64);
+        Ok(match p {
+            Prim2::Add => overflow(n1.checked_add(n2))? as u64,
+            Prim2::Sub => overflow(n1.checked_sub(n2))? as u64,
+            Prim2::Mul => overflow(n1.checked_mul(n2 >> 1))? as u64,
+            Prim2::And => v1 & v2,
+            Prim2::Or => v1 | v2,
+            Prim2::ArrayGet => self.heap[self.element(v1, v2)?],
+            _ => compare(p, n1, n2),
+        })
+    }
+
+    fn raw2(&self, p: Prim2, a1: &Arg, a2: &Arg) -> Result<u64, Fault> {
+        let err = prim2_operand(&p).map(|(_, err)| err);
+        let n1 = self.raw(a1, err)?;
+        let n2 = self.raw(a2, err)?;
+        Ok(match p {
+            Prim2::Add => untagged_overflow(n1.checked_add(n2))? as u64,
+            Prim2::Sub => untagged_overflow(n1.checked_sub(n2))? as u64,
+            Prim2::Mul => untagged_overflow(n1.checked_mul(n2))? as u64,
+            _ => compare(p, n1, n2),
+        })
+    }
+
+    fn call(
+        &mut self,
+        fun: usize,
+        env: Option<u64>,
+        args: Vec<u64>,
+        tail: bool,
+    ) -> Result<(), Fault> {
+        let f = &self.b.funs[fun];
+        let base = if tail { self.base } else { self.stack.len() };
+        let frames = self.frames.len() + usize::from(!tail);
+        if base + f.frame + FRAME_WORDS * frames > STACK_WORDS {
+            return Err(Fault::Error(RuntimeErr::StackOverflowError));
+        }
+        let mut slots = Vec::new();
+        if f.takes_env {
+            slots.extend(env);
+        }
+        slots.extend(args);
+        if tail {
+            self.stack.truncate(self.base);
+        } else {
+            self.frames.push(Frame {
+                ret: self.pc,
+                base: self.base,
+            });
+            self.base = self.stack.len();
+        }
+        self.stack.extend(slots);
+        self.stack.resize(self.base + f.frame, 0);
+        self.pc = f.entry;
+        Ok(())
+    }
+
+    // Checks the closure and the number of arguments it is called with, and
+    // returns the function and the env
+    fn closure(&self, v: u64, arg_num: usize) -> Result<(usize, u64), Fault> {
+        let at = addr(check(v, Type::Closure, RuntimeErr::ClosureError)?);
+        if self.heap[at] != arg_num as u64 {
+            return Err(Fault::Error(RuntimeErr::ArityError));
+        }
+        Ok((self.heap[at + 1] as usize, self.heap[at + 2]))
+    }
+
+    // Runs the instruction at pc, the result of main once it returns
+    fn step(&mut self) -> Result<Option<u64>, Fault> {
+        let b = self.b;
+        let op = &b.code[self.pc];
+        self.pc += 1;
+        match op {
+            Op::Load(a) => self.acc = self.value(a)?,
+            Op::Store(slot) => self.stack[self.base + slot] = self.acc,
+            Op::Prim1(p, a) => self.acc = self.prim1(*p, self.value(a)?)?,
+            Op::Prim2(p, a1, a2) => {
+                let v1 = self.value(a1)?;
+                let v2 = self.value(a2)?;
+                self.acc = self.prim2(*p, v1, v2)?
+            }
+            Op::Untag(a) => self.acc = self.raw(a, None)? as u64,
+            Op::Raw1(p, a) => {
+                let n = self.raw(a, prim1_operand(p).map(|(_, err)| err))?;
+                self.acc = match p {
+                    Prim1::Add1 => untagged_overflow(n.checked_add(1))?,
+                    Prim1::Sub1 => untagged_overflow(n.checked_sub(1))?,
+                    _ => panic!("Only Add1 and Sub1 are unboxed"),
+                } as u64
+            }
+            Op::Raw2(p, a1, a2) => self.acc = self.raw2(*p, a1, a2)?,
+            Op::Tag => self.acc = tag(self.acc as i64)?,
+            Op::Array(elts) => {
+                let elts = self.values(elts)?;
+                self.acc = self.alloc_array(0, elts)?
+            }
+            Op::Object { class, fields } => {
+                let fields = self.values(fields)?;
+                self.acc = self.alloc_array(*class as u64, fields)?
+            }
+            Op::ArraySet {
+                array,
+                index,
+                value,
+            } => {
+                let array = check(self.value(array)?, Type::Array, RuntimeErr::ArrayError)?;
+                let at = self.element(array, self.value(index)?)?;
+                self.heap[at] = self.value(value)?;
+                self.acc = array
+            }
+            Op::MakeClosure { arity, fun, env } => {
+                let env = self.value(env)?;
+                self.acc = self.alloc(vec![*arity as u64, *fun as u64, env], CLOSURE_TAG)?
+            }
+            Op::CallClosure { fun, args, tail } => {
+                let (fun, env) = self.closure(self.value(fun)?, args.len())?;
+                let args = self.values(args)?;
+                self.call(fun, Some(env), args, *tail)?
+            }
+            Op::CallDirect { fun, args, tail } => {
+                let args = self.values(args)?;
+                self.call(*fun, None, args, *tail)?
+            }
+            Op::CallMethod {
+                object,
+                class,
+                method,
+                args,
+                tail,
+            } => {
+                let object = check(self.value(object)?, Type::Array, RuntimeErr::ArrayError)?;
+                if self.heap[addr(object)] != *class as u64 {
+                    return Err(Fault::Error(RuntimeErr::MethodTypeError));
+                }
+                let (fun, env) = self.closure(self.value(method)?, args.len() + 1)?;
+                let mut all = vec![object];
+                all.extend(self.values(args)?);
+                self.call(fun, Some(env), all, *tail)?
+            }
+            Op::Return => match self.frames.pop() {
+                Some(frame) => {
+                    self.stack.truncate(self.base);
+                    self.base = frame.base;
+                    self.pc = frame.ret
+                }
+                None => return Ok(Some(self.acc)),
+            },
+            Op::Jump(target) => self.pc = *target,
+            Op::JumpUnless { err, target } => {
+                if check(self.acc, Type::Bool, *err)? == SNAKE_FALSE {
+                    self.pc = *target
+                }
+            }
+            Op::Try { catch, var } => self.handlers.push(Handler {
+                frames: self.frames.len(),
+                base: self.base,
+                stack: self.stack.len(),
+                catch: *catch,
+                var: *var,
+            }),
+            Op::EndTry => {
+                self.handlers.pop();
+            }
+            Op::Raise(a) => return Err(Fault::Raise(self.value(a)?)),
+            Op::Fail(err) => return Err(Fault::Error(*err)),
+        }
+        Ok(None)
+    }
+
+    // Built-in failures inside a try are raised as Error objects, except
+    // running out of heap or stack, which is not caught like in the native
+    // code
+    fn raise(&mut self, fault: Fault) -> Result<(), String> {
+        let v = match fault {
+            Fault::Error(err)
+                if self.handlers.is_empty()
+                    || matches!(
+                        err,
+                        RuntimeErr::OutOfMemoryError | RuntimeErr::StackOverflowError
+                    ) =>
+            {
+                return Err(String::from(error_message(err as u64)))
+            }
+            Fault::Error(err) => {
+                match self.alloc_array(ERROR_CLASS_ID as u64, vec![(err as u64) << 1]) {
+                    Ok(error) => error,
+                    Err(_) => {
+                        return Err(String::from(error_message(
+                            RuntimeErr::OutOfMemoryError as u64,
+                        )))
+                    }
+                }
+            }
+            Fault::Raise(v) => v,
+        };
+        match self.handlers.pop() {
+            Some(h) => {
+                self.frames.truncate(h.frames);
+                self.stack.truncate(h.stack);
+                self.base = h.base;
+                self.stack[self.base + h.var] = v;
+                self.acc = v;
+                self.pc = h.catch;
+                Ok(())
+            }
+            None => Err(self.uncaught(v)),
+        }
+    }
+
+    fn uncaught(&self, v: u64) -> String {
+        // a re-raised built-in error reports like the original failure
+        if has_type(v, &Type::Array) && self.heap[addr(v)] == ERROR_CLASS_ID as u64 {
+            return String::from(error_message(self.heap[addr(v) + 2] >> 1));
+        }
+        format!("uncaught exception: {}", self.sprint_snake_val(v))
+    }
+
+    fn sprint_snake_val_helper(&self, x: u64, seen: &mut HashSet<u64>) -> String {
+        if x & INT_TAG == 0 {
+            format!("{}", (x as i64) >> 1)
+        } else if x == SNAKE_TRUE {
+            String::from("true")
+        } else if x == SNAKE_FALSE {
+            String::from("false")
+        } else if x & TAG_MASK == ARRAY_TAG {
+            if !seen.insert(x) {
+                return String::from("<loop>");
+            }
+            let at = addr(x);
+            let len = (self.heap[at + 1] >> 1) as usize;
+            let elts: Vec<String> = self.heap[at + 2..at + 2 + len]
+                .iter()
+                .map(|v| self.sprint_snake_val_helper(*v, seen))
+                .collect();
+            seen.remove(&x);
+            format!("[{}]", elts.join(", "))
+        } else if x & TAG_MASK == CLOSURE_TAG {
+            String::from("<closure>")
+        } else {
+            format!("Invalid snake value 0x{:x}", x)
+        }
+    }
+
+    fn sprint_snake_val(&self, x: u64) -> String {
+        self.sprint_snake_val_helper(x, &mut HashSet::new())
+    }
+}
+
+fn compare(p: Prim2, n1: i64, n2: i64) -> u64 {
+    bool_val(match p {
+        Prim2::Lt => n1 < n2,
+        Prim2::Gt => n1 > n2,
+        Prim2::Le => n1 <= n2,
+        Prim2::Ge => n1 >= n2,
+        Prim2::Eq => n1 == n2,
+        Prim2::Neq => n1 != n2,
+        _ => panic!("Only comparisons are left"),
+    })
+}
+
+// Runs the program and returns what it printed, along with the message of
+// the failure that stopped it, if one did
+pub fn run(b: &Bytecode) -> (String, Option<String>) {
+    let main = &b.funs[0];
+    let mut vm = Vm {
+        b,
+        heap: Vec::new(),
+        stack: vec![0; main.frame],
+        frames: Vec::new(),
+        handlers: Vec::new(),
+        base: 0,
+        pc: main.entry,
+        acc: 0,
+        out: String::new(),
+    };
+    loop {
+        match vm.step() {
+            Ok(None) => {}
+            Ok(Some(result)) => {
+                let s = vm.sprint_snake_val(result);
+                vm.out.push_str(&s);
+                vm.out.push('\n');
+                return (vm.out, None);
+            }
+            Err(fault) => {
+                if let Err(msg) = vm.raise(fault) {
+                    return (vm.out, Some(msg));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1479 @@
+use crate::bytecode::Arg;
+use crate::compile::HEAP_SIZE;
+use crate::runtime_error::{
+    error_message, prim1_operand, prim2_operand, RuntimeErr, Type, ERROR_CLASS_ID,
+};
+use crate::syntax::{ClassInfo, ImmExp, Prim1, Prim2, SeqExp, SeqProg};
+use crate::types::TypeTable;
+use crate::wasm_interp::{decode, Host, Instance, Trap};
+use std::collections::{HashMap, HashSet};
+
+// Lowers a sequentialized program to a WebAssembly module. Values are the
+// same tagged 64-bit words as in the native code, and the heap lives in the
+// module's linear memory with the native layouts, so a heap address is a
+// byte offset into the memory plus the tag. The host provides
+// print_snake_val, snake_error and snake_uncaught like runtime/stub.rs
+// does, and the module exports its memory and start_here.
+//
+// Wasm has no way to unwind the stack to a handler, so a raise stores the
+// value in a global, sets the raising flag and returns. Every call that can
+// raise is followed by a check of the flag, which branches to the catch
+// block of the enclosing try or returns to the caller in turn.
+
+static INT_TAG: u64 = 0x00_00_00_00_00_00_00_01;
+static TAG_MASK: u64 = 0b111;
+static BOOL_TAG: u64 = 0b111;
+static ARRAY_TAG: u64 = 0b001;
+static CLOSURE_TAG: u64 = 0b011;
+
+static XOR_NOT: u64 = 0x80_00_00_00_00_00_00_00;
+static SNAKE_TRUE: u64 = 0xFF_FF_FF_FF_FF_FF_FF_FF;
+static SNAKE_FALSE: u64 = 0x7F_FF_FF_FF_FF_FF_FF_FF;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValType {
+    I32,
+    I64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlockType {
+    Empty,
+    Val(ValType),
+}
+
+// The instructions the backend uses
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WInstr {
+    Unreachable,
+    Block(BlockType),
+    Loop(BlockType),
+    If(BlockType),
+    Else,
+    End,
+    Br(u32),
+    BrIf(u32),
+    Return,
+    Call(u32),
+    CallIndirect(u32),
+    ReturnCall(u32),
+    ReturnCallIndirect(u32),
+    Drop,
+    LocalGet(u32),
+    LocalSet(u32),
+    LocalTee(u32),
+    GlobalGet(u32),
+    GlobalSet(u32),
+    I64Load(u32),
+    I64Store(u32),
+    MemorySize,
+    MemoryGrow,
+    I32Const(i32),
+    I64Const(i64),
+    I32Eq,
+    I32Or,
+    I32Sub,
+    I64Eqz,
+    I64Eq,
+    I64Ne,
+    I64LtS,
+    I64GtS,
+    I64GtU,
+    I64LeS,
+    I64GeS,
+    I64Add,
+    I64Sub,
+    I64Mul,
+    I64DivS,
+    I64And,
+    I64Or,
+    I64Xor,
+    I64Shl,
+    I64ShrS,
+    I64ShrU,
+    I32WrapI64,
+    I64ExtendI32U,
+}
+
+fn uleb(out: &mut Vec<u8>, mut v: u64) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if v == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn sleb(out: &mut Vec<u8>, mut v: i64) {
+    loop {
+        let byte = (v & 0x7f) as u8;
+        v >>= 7;
+        if (v == 0 && byte & 0x40 == 0) || (v == -1 && byte & 0x40 != 0) {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn val_type(t: ValType) -> u8 {
+    match t {
+        ValType::I32 => 0x7f,
+        ValType::I64 => 0x7e,
+    }
+}
+
+fn block_type(t: BlockType) -> u8 {
+    match t {
+        BlockType::Empty => 0x40,
+        BlockType::Val(t) => val_type(t),
+    }
+}
+
+fn encode_instr(out: &mut Vec<u8>, i: &WInstr) {
+    use WInstr::*;
+    match i {
+        Unreachable => out.push(0x00),
+        Block(t) => out.extend([0x02, block_type(*t)]),
+        Loop(t) => out.extend([0x03, block_type(*t)]),
+        If(t) => out.extend([0x04, block_type(*t)]),
+        Else => out.push(0x05),
+        End => out.push(0x0b),
+        Br(l) => {
+            out.push(0x0c);
+            uleb(out, *l as u64)
+        }
+        BrIf(l) => {
+            out.push(0x0d);
+            uleb(out, *l as u64)
+        }
+        Return => out.push(0x0f),
+        Call(f) => {
+            out.push(0x10);
+            uleb(out, *f as u64)
+        }
+        CallIndirect(t) => {
+            out.push(0x11);
+            uleb(out, *t as u64);
+            out.push(0x00)
+        }
+        ReturnCall(f) => {
+            out.push(0x12);
+            uleb(out, *f as u64)
+        }
+        ReturnCallIndirect(t) => {
+            out.push(0x13);
+            uleb(out, *t as u64);
+            out.push(0x00)
+        }
+        Drop => out.push(0x1a),
+        LocalGet(x) | LocalSet(x) | LocalTee(x) | GlobalGet(x) | GlobalSet(x) => {
+            out.push(match i {
+                LocalGet(_) => 0x20,
+                LocalSet(_) => 0x21,
+                LocalTee(_) => 0x22,
+                GlobalGet(_) => 0x23,
+                _ => 0x24,
+            });
+            uleb(out, *x as u64)
+        }
+        // the alignment is always 8 bytes
+        I64Load(offset) | I64Store(offset) => {
+            out.extend([if let I64Load(_) = i { 0x29 } else { 0x37 }, 3]);
+            uleb(out, *offset as u64)
+        }
+        MemorySize => out.extend([0x3f, 0x00]),
+        MemoryGrow => out.extend([0x40, 0x00]),
+        I32Const(n) => {
+            out.push(0x41);
+            sleb(out, *n as i64)
+        }
+        I64Const(n) => {
+            out.push(0x42);
+            sleb(out, *n)
+        }
+        I32Eq => out.push(0x46),
+        I32Or => out.push(0x72),
+        I32Sub => out.push(0x6b),
+        I64Eqz => out.push(0x50),
+        I64Eq => out.push(0x51),
+        I64Ne => out.push(0x52),
+        I64LtS => out.push(0x53),
+        I64GtS => out.push(0x55),
+        I64GtU => out.push(0x56),
+        I64LeS => out.push(0x57),
+        I64GeS => out.push(0x59),
+        I64Add => out.push(0x7c),
+        I64Sub => out.push(0x7d),
+        I64Mul => out.push(0x7e),
+        I64DivS => out.push(0x7f),
+        I64And => out.push(0x83),
+        I64Or => out.push(0x84),
+        I64Xor => out.push(0x85),
+        I64Shl => out.push(0x86),
+        I64ShrS => out.push(0x87),
+        I64ShrU => out.push(0x88),
+        I32WrapI64 => out.push(0xa7),
+        I64ExtendI32U => out.push(0xad),
+    }
+}
+
+// The imports come first in the function index space, then the runtime
+// helpers below, then main and the functions of the program
+const PRINT: u32 = 0;
+const SNAKE_ERROR: u32 = 1;
+const SNAKE_UNCAUGHT: u32 = 2;
+const SNAKE_ERR: u32 = 3;
+const RAISE: u32 = 4;
+const ALLOC: u32 = 5;
+const CHECK: u32 = 6;
+const ADD: u32 = 7;
+const SUB: u32 = 8;
+const MUL: u32 = 9;
+const TAG: u32 = 10;
+const ELEMENT: u32 = 11;
+const CLOSURE: u32 = 12;
+const MAIN: u32 = 13;
+
+static IMPORTS: [&str; 3] = ["print_snake_val", "snake_error", "snake_uncaught"];
+
+// The globals: the heap pointer, the number of active handlers, whether a
+// raise is unwinding and the value raised
+const HP: u32 = 0;
+const HANDLERS: u32 = 1;
+const RAISING: u32 = 2;
+const RAISED: u32 = 3;
+
+// Three scratch locals follow the parameters of every function, the last
+// one holds the value being checked
+const SCRATCH: u32 = 3;
+
+struct Func {
+    ty: u32,
+    locals: u32,
+    body: Vec<WInstr>,
+}
+
+#[derive(Default)]
+struct TypeSection {
+    types: Vec<(Vec<ValType>, Vec<ValType>)>,
+}
+
+impl TypeSection {
+    fn get(&mut self, params: Vec<ValType>, results: Vec<ValType>) -> u32 {
+        let ty = (params, results);
+        match self.types.iter().position(|t| *t == ty) {
+            Some(idx) => idx as u32,
+            None => {
+                self.types.push(ty);
+                self.types.len() as u32 - 1
+            }
+        }
+    }
+
+    // The type of a Snake function with n parameters
+    fn snake(&mut self, n: usize) -> u32 {
+        self.get(vec![ValType::I64; n], vec![ValType::I64])
+    }
+}
+
+fn err_code(err: RuntimeErr) -> WInstr {
+    WInstr::I64Const(err as i64)
+}
+
+fn type_tag(ty: &Type) -> (u64, u64) {
+    match ty {
+        Type::Num => (INT_TAG, 0),
+        Type::Bool => (TAG_MASK, BOOL_TAG),
+        Type::Array => (TAG_MASK, ARRAY_TAG),
+        Type::Closure => (TAG_MASK, CLOSURE_TAG),
+    }
+}
+
+// In a helper, return when the call before raised
+fn return_if_raising() -> Vec<WInstr> {
+    use WInstr::*;
+    vec![
+        GlobalGet(RAISING),
+        If(BlockType::Empty),
+        I64Const(0),
+        Return,
+        End,
+    ]
+}
+
+fn overflow_check(cond: Vec<WInstr>) -> Vec<WInstr> {
+    use WInstr::*;
+    let mut body = cond;
+    body.extend([
+        If(BlockType::Empty),
+        err_code(RuntimeErr::OverflowError),
+        Call(SNAKE_ERR),
+        End,
+        LocalGet(2),
+    ]);
+    body
+}
+
+fn helpers(types: &mut TypeSection) -> Vec<Func> {
+    use ValType::*;
+    use WInstr::*;
+    let untag_addr = |tag: u64| vec![I64Const(tag as i64), I64Sub];
+
+    // snake_err(code): wrap the failure into an Error object and raise it,
+    // or let the host report it when there is no handler
+    let mut snake_err = vec![
+        GlobalGet(HANDLERS),
+        I64Eqz,
+        If(BlockType::Empty),
+        LocalGet(0),
+        Call(SNAKE_ERROR),
+        Unreachable,
+        End,
+        I64Const(3),
+        Call(ALLOC),
+        LocalSet(1),
+    ];
+    for (offset, value) in [
+        (0, vec![I64Const(ERROR_CLASS_ID as i64)]),
+        (8, vec![I64Const(2)]),
+        (16, vec![LocalGet(0), I64Const(1), I64Shl]),
+    ] {
+        snake_err.extend([LocalGet(1), I32WrapI64]);
+        snake_err.extend(value);
+        snake_err.push(I64Store(offset));
+    }
+    snake_err.extend([
+        LocalGet(1),
+        I64Const(ARRAY_TAG as i64),
+        I64Add,
+        GlobalSet(RAISED),
+        I32Const(1),
+        GlobalSet(RAISING),
+        End,
+    ]);
+
+    let raise = vec![
+        GlobalGet(HANDLERS),
+        I64Eqz,
+        If(BlockType::Empty),
+        LocalGet(0),
+        Call(SNAKE_UNCAUGHT),
+        Unreachable,
+        End,
+        LocalGet(0),
+        GlobalSet(RAISED),
+        I32Const(1),
+        GlobalSet(RAISING),
+        End,
+    ];
+
+    // alloc(words): the address of the words, the memory grows as needed up
+    // to the heap size of the native runtime, past which the failure cannot
+    // be caught like there
+    let alloc = vec![
+        GlobalGet(HP),
+        LocalGet(0),
+        I64Const(3),
+        I64Shl,
+        I64Add,
+        LocalSet(1),
+        LocalGet(1),
+        I64Const((HEAP_SIZE * 8) as i64),
+        I64GtU,
+        If(BlockType::Empty),
+        err_code(RuntimeErr::OutOfMemoryError),
+        Call(SNAKE_ERROR),
+        Unreachable,
+        End,
+        LocalGet(1),
+        MemorySize,
+        I64ExtendI32U,
+        I64Const(16),
+        I64Shl,
+        I64GtU,
+        If(BlockType::Empty),
+        LocalGet(1),
+        I64Const(0xffff),
+        I64Add,
+        I64Const(16),
+        I64ShrU,
+        I32WrapI64,
+        MemorySize,
+        I32Sub,
+        MemoryGrow,
+        I32Const(-1),
+        I32Eq,
+        If(BlockType::Empty),
+        Unreachable,
+        End,
+        End,
+        GlobalGet(HP),
+        LocalGet(1),
+        GlobalSet(HP),
+        End,
+    ];
+
+    // check(v, mask, tag, err): v, after raising err unless its tag matches
+    let check = vec![
+        LocalGet(0),
+        LocalGet(1),
+        I64And,
+        LocalGet(2),
+        I64Ne,
+        If(BlockType::Empty),
+        LocalGet(3),
+        Call(SNAKE_ERR),
+        End,
+        LocalGet(0),
+        End,
+    ];
+
+    // The arithmetic raises an overflow like jo does in the native code
+    let mut add = vec![LocalGet(0), LocalGet(1), I64Add, LocalSet(2)];
+    add.extend(overflow_check(vec![
+        LocalGet(0),
+        LocalGet(2),
+        I64Xor,
+        LocalGet(1),
+        LocalGet(2),
+        I64Xor,
+        I64And,
+        I64Const(0),
+        I64LtS,
+    ]));
+    add.push(End);
+    let mut sub = vec![LocalGet(0), LocalGet(1), I64Sub, LocalSet(2)];
+    sub.extend(overflow_check(vec![
+        LocalGet(0),
+        LocalGet(1),
+        I64Xor,
+        LocalGet(0),
+        LocalGet(2),
+        I64Xor,
+        I64And,
+        I64Const(0),
+        I64LtS,
+    ]));
+    sub.push(End);
+    let mut mul = vec![LocalGet(0), LocalGet(1), I64Mul, LocalSet(2)];
+    mul.extend(overflow_check(vec![
+        LocalGet(0),
+        I64Eqz,
+        If(BlockType::Val(I32)),
+        I32Const(0),
+        Else,
+        LocalGet(0),
+        I64Const(-1),
+        I64Eq,
+        If(BlockType::Val(I32)),
+        LocalGet(1),
+        I64Const(i64::MIN),
+        I64Eq,
+        Else,
+        // the product overflowed unless dividing it gives the factor back
+        LocalGet(2),
+        LocalGet(0),
+        I64DivS,
+        LocalGet(1),
+        I64Ne,
+        End,
+        End,
+    ]));
+    mul.push(End);
+
+    let tag = vec![LocalGet(0), LocalGet(0), Call(ADD), End];
+
+    // element(array, index): the address of the element after checking the
+    // array, the index and the bounds
+    let mut element = vec![
+        LocalGet(0),
+        I64Const(TAG_MASK as i64),
+        I64Const(ARRAY_TAG as i64),
+        err_code(RuntimeErr::ArrayError),
+        Call(CHECK),
+    ];
+    element.extend(return_if_raising());
+    element.extend(untag_addr(ARRAY_TAG));
+    element.extend([
+        LocalSet(0),
+        LocalGet(1),
+        I64Const(INT_TAG as i64),
+        I64Const(0),
+        err_code(RuntimeErr::IndexError),
+        Call(CHECK),
+    ]);
+    element.extend(return_if_raising());
+    element.extend([
+        Drop,
+        LocalGet(1),
+        LocalGet(0),
+        I32WrapI64,
+        I64Load(8),
+        I64GeS,
+        LocalGet(1),
+        I64Const(0),
+        I64LtS,
+        I32Or,
+        If(BlockType::Empty),
+        err_code(RuntimeErr::BoundingError),
+        Call(SNAKE_ERR),
+        I64Const(0),
+        Return,
+        End,
+        LocalGet(0),
+        LocalGet(1),
+        I64Const(2),
+        I64Shl,
+        I64Add,
+        I64Const(16),
+        I64Add,
+        End,
+    ]);
+
+    // closure(v, arg_num): the address of the closure after checking it and
+    // the number of arguments
+    let mut closure = vec![
+        LocalGet(0),
+        I64Const(TAG_MASK as i64),
+        I64Const(CLOSURE_TAG as i64),
+        err_code(RuntimeErr::ClosureError),
+        Call(CHECK),
+    ];
+    closure.extend(return_if_raising());
+    closure.extend(untag_addr(CLOSURE_TAG));
+    closure.extend([
+        LocalSet(0),
+        LocalGet(0),
+        I32WrapI64,
+        I64Load(0),
+        LocalGet(1),
+        I64Ne,
+        If(BlockType::Empty),
+        err_code(RuntimeErr::ArityError),
+        Call(SNAKE_ERR),
+        I64Const(0),
+        Return,
+        End,
+        LocalGet(0),
+        End,
+    ]);
+
+    let unit = types.get(vec![I64], vec![]);
+    vec![
+        Func {
+            ty: unit,
+            locals: 1,
+            body: snake_err,
+        },
+        Func {
+            ty: unit,
+            locals: 0,
+            body: raise,
+        },
+        Func {
+            ty: types.snake(1),
+            locals: 1,
+            body: alloc,
+        },
+        Func {
+            ty: types.snake(4),
+            locals: 0,
+            body: check,
+        },
+        Func {
+            ty: types.snake(2),
+            locals: 1,
+            body: add,
+        },
+        Func {
+            ty: types.snake(2),
+            locals: 1,
+            body: sub,
+        },
+        Func {
+            ty: types.snake(2),
+            locals: 1,
+            body: mul,
+        },
+        Func {
+            ty: types.snake(1),
+            locals: 0,
+            body: tag,
+        },
+        Func {
+            ty: types.snake(2),
+            locals: 0,
+            body: element,
+        },
+        Func {
+            ty: types.snake(2),
+            locals: 0,
+            body: closure,
+        },
+    ]
+}
+
+struct Lowering<'a> {
+    code: Vec<WInstr>,
+    // The enclosing blocks, true for the catch block of a try
+    labels: Vec<bool>,
+    funs: &'a HashMap<&'a str, u32>,
+    table: &'a HashMap<&'a str, u32>,
+    class: &'a HashMap<String, ClassInfo>,
+    types: &'a TypeTable,
+    sigs: &'a mut TypeSection,
+    // The first of the scratch locals, which follow the parameters
+    scratch: u32,
+    // The first let slot and the number of slots used so far
+    first_slot: u32,
+    frame: u32,
+}
+
+impl<'a> Lowering<'a> {
+    fn emit(&mut self, i: WInstr) {
+        match i {
+            WInstr::Block(_) | WInstr::Loop(_) | WInstr::If(_) => self.labels.push(false),
+            WInstr::End => {
+                self.labels.pop();
+            }
+            _ => {}
+        }
+        self.code.push(i);
+    }
+
+    fn emit_all(&mut self, is: impl IntoIterator<Item = WInstr>) {
+        for i in is {
+            self.emit(i)
+        }
+    }
+
+    fn slot(&mut self, size: usize) -> u32 {
+        self.frame = self.frame.max(size as u32 + 1);
+        self.first_slot + size as u32
+    }
+
+    // Continue unwinding: branch to the catch block of the innermost try or
+    // return to the caller
+    fn propagate(&mut self) {
+        match self.labels.iter().rev().position(|catch| *catch) {
+            Some(depth) => self.emit(WInstr::Br(depth as u32)),
+            None => self.emit_all([WInstr::I64Const(0), WInstr::Return]),
+        }
+    }
+
+    fn propagate_if_raising(&mut self) {
+        self.emit_all([WInstr::GlobalGet(RAISING), WInstr::If(BlockType::Empty)]);
+        self.propagate();
+        self.emit(WInstr::End);
+    }
+
+    // Calls a helper that can raise
+    fn call_checked(&mut self, f: u32) {
+        self.emit(WInstr::Call(f));
+        self.propagate_if_raising();
+    }
+
+    fn fail(&mut self, err: RuntimeErr) {
+        self.emit_all([err_code(err), WInstr::Call(SNAKE_ERR)]);
+        self.propagate();
+    }
+
+    // Checks the tag of the value on the stack
+    fn check(&mut self, ty: &Type, err: RuntimeErr) {
+        let (mask, tag) = type_tag(ty);
+        let checked = self.scratch + 2;
+        self.emit_all([
+            WInstr::LocalTee(checked),
+            WInstr::I64Const(mask as i64),
+            WInstr::I64And,
+            WInstr::I64Const(tag as i64),
+            WInstr::I64Ne,
+            WInstr::If(BlockType::Empty),
+        ]);
+        self.fail(err);
+        self.emit_all([WInstr::End, WInstr::LocalGet(checked)]);
+    }
+
+    // Checks that the untagged number on the stack stays in the range of
+    // the tagged ones, doubling it must keep its sign
+    fn check_untagged(&mut self) {
+        let checked = self.scratch + 2;
+        self.emit_all([
+            WInstr::LocalTee(checked),
+            WInstr::LocalGet(checked),
+            WInstr::LocalGet(checked),
+            WInstr::I64Add,
+            WInstr::I64Xor,
+            WInstr::I64Const(0),
+            WInstr::I64LtS,
+            WInstr::If(BlockType::Empty),
+        ]);
+        self.fail(RuntimeErr::OverflowError);
+        self.emit_all([WInstr::End, WInstr::LocalGet(checked)]);
+    }
+
+    // Turns the i32 condition on the stack into a Snake boolean
+    fn push_bool(&mut self) {
+        self.emit_all([
+            WInstr::If(BlockType::Val(ValType::I64)),
+            WInstr::I64Const(SNAKE_TRUE as i64),
+            WInstr::Else,
+            WInstr::I64Const(SNAKE_FALSE as i64),
+            WInstr::End,
+        ]);
+    }
+
+    fn is_unboxed(&self, imm: &ImmExp) -> bool {
+        matches!(imm, ImmExp::Var(x) if self.types.is_unboxed(x))
+    }
+
+    fn arg(&self, imm: &ImmExp, env: &HashMap<&str, u32>) -> Arg {
+        match imm {
+            ImmExp::Num(n) => Arg::Imm((*n << 1) as u64),
+            ImmExp::Bool(true) => Arg::Imm(SNAKE_TRUE),
+            ImmExp::Bool(false) => Arg::Imm(SNAKE_FALSE),
+            ImmExp::Var(x) => match env.get(x.as_str()) {
+                Some(local) if self.types.is_unboxed(x) => Arg::Raw(*local as usize),
+                Some(local) => Arg::Slot(*local as usize),
+                None => panic!("Variable {} is guaranteed to be in scope", x),
+            },
+        }
+    }
+
+    // Pushes the tagged value of imm
+    fn value(&mut self, imm: &ImmExp, env: &HashMap<&str, u32>) {
+        match self.arg(imm, env) {
+            Arg::Imm(v) => self.emit(WInstr::I64Const(v as i64)),
+            Arg::Slot(local) => self.emit(WInstr::LocalGet(local as u32)),
+            Arg::Raw(local) => {
+                self.emit(WInstr::LocalGet(local as u32));
+                self.call_checked(TAG)
+            }
+        }
+    }
+
+    // Pushes the untagged number imm, a tagged operand is checked to be a
+    // number first
+    fn raw(&mut self, imm: &ImmExp, env: &HashMap<&str, u32>, err: Option<RuntimeErr>) {
+        if let Arg::Raw(local) = self.arg(imm, env) {
+            return self.emit(WInstr::LocalGet(local as u32));
+        }
+        self.value(imm, env);
+        if let Some(err) = err {
+            self.check(&Type::Num, err);
+        }
+        self.emit_all([WInstr::I64Const(1), WInstr::I64ShrS]);
+    }
+
+    // The untagged result of the arithmetic an unboxed variable is bound to
+    fn compile_unboxed(&mut self, e: &SeqExp<u32>, env: &HashMap<&str, u32>) {
+        match e {
+            SeqExp::Imm(imm, _) => self.raw(imm, env, None),
+            SeqExp::Prim1(p, imm, _) => {
+                self.raw(imm, env, prim1_operand(p).map(|(_, err)| err));
+                self.emit(WInstr::I64Const(1));
+                self.call_checked(match p {
+                    Prim1::Add1 => ADD,
+                    Prim1::Sub1 => SUB,
+                    _ => panic!("Only Add1 and Sub1 are unboxed"),
+                });
+                self.check_untagged()
+            }
+            SeqExp::Prim2(p, e1, e2, _) => {
+                let err = prim2_operand(p).map(|(_, err)| err);
+                self.raw(e1, env, err);
+                self.raw(e2, env, err);
+                match p {
+                    Prim2::Add => self.call_checked(ADD),
+                    Prim2::Sub => self.call_checked(SUB),
+                    Prim2::Mul => self.call_checked(MUL),
+                    _ => return self.compare(*p),
+                }
+                self.check_untagged()
+            }
+            _ => panic!("Only arithmetic is unboxed"),
+        }
+    }
+
+    fn compare(&mut self, p: Prim2) {
+        self.emit(match p {
+            Prim2::Lt => WInstr::I64LtS,
+            Prim2::Gt => WInstr::I64GtS,
+            Prim2::Le => WInstr::I64LeS,
+            Prim2::Ge => WInstr::I64GeS,
+            Prim2::Eq => WInstr::I64Eq,
+            Prim2::Neq => WInstr::I64Ne,
+            _ => panic!("Only comparisons are left"),
+        });
+        self.push_bool()
+    }
+
+    fn prim1(&mut self, p: Prim1) {
+        use WInstr::*;
+        match p {
+            Prim1::Add1 | Prim1::Sub1 => {
+                self.emit(I64Const(2));
+                self.call_checked(if p == Prim1::Add1 { ADD } else { SUB })
+            }
+            Prim1::Not => self.emit_all([I64Const(XOR_NOT as i64), I64Xor]),
+            Prim1::Print => self.emit(Call(PRINT)),
+            Prim1::IsNum => {
+                self.emit_all([I64Const(INT_TAG as i64), I64And, I64Eqz]);
+                self.push_bool()
+            }
+            Prim1::IsBool | Prim1::IsArray | Prim1::IsFun => {
+                let tag = match p {
+                    Prim1::IsBool => BOOL_TAG,
+                    Prim1::IsArray => ARRAY_TAG,
+                    _ => CLOSURE_TAG,
+                };
+                self.emit_all([
+                    I64Const(TAG_MASK as i64),
+                    I64And,
+                    I64Const(tag as i64),
+                    I64Eq,
+                ]);
+                self.push_bool()
+            }
+            Prim1::Length => {
+                self.emit_all([I64Const(ARRAY_TAG as i64), I64Sub, I32WrapI64, I64Load(8)])
+            }
+            // The header holds a raw index, tag it as a number
+            Prim1::ClassIdx => self.emit_all([
+                I64Const(ARRAY_TAG as i64),
+                I64Sub,
+                I32WrapI64,
+                I64Load(0),
+                I64Const(1),
+                I64Shl,
+            ]),
+        }
+    }
+
+    fn prim2(&mut self, p: Prim2) {
+        use WInstr::*;
+        match p {
+            Prim2::Add => self.call_checked(ADD),
+            Prim2::Sub => self.call_checked(SUB),
+            // one factor is untagged first, like the native code does
+            Prim2::Mul => {
+                self.emit_all([I64Const(1), I64ShrS]);
+                self.call_checked(MUL)
+            }
+            Prim2::And => self.emit(I64And),
+            Prim2::Or => self.emit(I64Or),
+            Prim2::ArrayGet => {
+                self.call_checked(ELEMENT);
+                self.emit_all([I32WrapI64, I64Load(0)])
+            }
+            _ => self.compare(p),
+        }
+    }
+
+    // Allocates an array or object in the first scratch local and fills it
+    fn heap_object(
+        &mut self,
+        header: [u64; 2],
+        elts: &[ImmExp],
+        env: &HashMap<&str, u32>,
+        tag: u64,
+    ) {
+        use WInstr::*;
+        let addr = self.scratch;
+        self.emit_all([I64Const(elts.len() as i64 + 2), Call(ALLOC), LocalSet(addr)]);
+        for (i, word) in header.iter().enumerate() {
+            self.emit_all([
+                LocalGet(addr),
+                I32WrapI64,
+                I64Const(*word as i64),
+                I64Store(8 * i as u32),
+            ]);
+        }
+        for (i, elt) in elts.iter().enumerate() {
+            self.emit_all([LocalGet(addr), I32WrapI64]);
+            self.value(elt, env);
+            self.emit(I64Store(8 * (i as u32 + header.len() as u32)));
+        }
+        self.emit_all([LocalGet(addr), I64Const(tag as i64), I64Add]);
+    }
+
+    // Calls the checked closure whose address is in the first scratch local,
+    // with the given values after its env
+    fn call_closure(&mut self, pushed: usize, tail: bool) {
+        use WInstr::*;
+        self.emit_all([LocalGet(self.scratch), I32WrapI64, I64Load(8), I32WrapI64]);
+        let ty = self.sigs.snake(pushed + 1);
+        if tail {
+            self.emit(ReturnCallIndirect(ty));
+        } else {
+            self.emit(CallIndirect(ty));
+            self.propagate_if_raising();
+        }
+    }
+
+    fn compile_exp<'exp>(
+        &mut self,
+        e: &'exp SeqExp<u32>,
+        mut env: HashMap<&'exp str, u32>,
+        size: usize,
+        tail: bool,
+    ) {
+        use WInstr::*;
+        match e {
+            SeqExp::Imm(imm, _) => self.value(imm, &env),
+            SeqExp::Prim1(Prim1::Add1 | Prim1::Sub1, imm, _) if self.is_unboxed(imm) => {
+                self.compile_unboxed(e, &env);
+                self.call_checked(TAG)
+            }
+            SeqExp::Prim1(p, imm, _) => {
+                self.value(imm, &env);
+                if let Some((ty, err)) = prim1_operand(p) {
+                    self.check(&ty, err);
+                }
+                self.prim1(*p)
+            }
+            SeqExp::Prim2(Prim2::Add | Prim2::Sub | Prim2::Mul, e1, e2, _)
+                if self.is_unboxed(e1) || self.is_unboxed(e2) =>
+            {
+                self.compile_unboxed(e, &env);
+                self.call_checked(TAG)
+            }
+            SeqExp::Prim2(Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge, e1, e2, _)
+                if self.is_unboxed(e1) || self.is_unboxed(e2) =>
+            {
+                self.compile_unboxed(e, &env)
+            }
+            SeqExp::Prim2(Prim2::Eq | Prim2::Neq, e1, e2, _)
+                if (self.is_unboxed(e1) || matches!(e1, ImmExp::Num(_)))
+                    && (self.is_unboxed(e2) || matches!(e2, ImmExp::Num(_))) =>
+            {
+                self.compile_unboxed(e, &env)
+            }
+            SeqExp::Prim2(p, e1, e2, _) => {
+                let operand = prim2_operand(p);
+                for imm in [e1, e2] {
+                    self.value(imm, &env);
+                    if let Some((ty, err)) = &operand {
+                        self.check(ty, *err);
+                    }
+                }
+                self.prim2(*p)
+            }
+            SeqExp::Array(elts, _) => {
+                self.heap_object([0, 2 * elts.len() as u64], elts, &env, ARRAY_TAG)
+            }
+            SeqExp::ArraySet {
+                array,
+                index,
+                new_value,
+                ann: _,
+            } => {
+                let array_local = self.scratch + 1;
+                self.value(array, &env);
+                self.check(&Type::Array, RuntimeErr::ArrayError);
+                self.emit(LocalTee(array_local));
+                self.value(index, &env);
+                self.call_checked(ELEMENT);
+                self.emit(I32WrapI64);
+                self.value(new_value, &env);
+                self.emit_all([I64Store(0), LocalGet(array_local)]);
+            }
+            SeqExp::MakeClosure {
+                arity,
+                label,
+                env: capture,
+                ann: _,
+            } => {
+                let addr = self.scratch;
+                self.emit_all([I64Const(3), Call(ALLOC), LocalSet(addr)]);
+                for (offset, word) in [(0, *arity as i64), (8, self.table[label.as_str()] as i64)] {
+                    self.emit_all([LocalGet(addr), I32WrapI64, I64Const(word), I64Store(offset)]);
+                }
+                self.emit_all([LocalGet(addr), I32WrapI64]);
+                self.value(capture, &env);
+                self.emit_all([
+                    I64Store(16),
+                    LocalGet(addr),
+                    I64Const(CLOSURE_TAG as i64),
+                    I64Add,
+                ]);
+            }
+            SeqExp::CallClosure { fun, args, ann: _ } => {
+                self.value(fun, &env);
+                self.emit(I64Const(args.len() as i64));
+                self.call_checked(CLOSURE);
+                self.emit_all([LocalTee(self.scratch), I32WrapI64, I64Load(16)]);
+                for arg in args {
+                    self.value(arg, &env);
+                }
+                self.call_closure(args.len(), tail)
+            }
+            SeqExp::CallDirect {
+                label,
+                args,
+                ann: _,
+            } => {
+                for arg in args {
+                    self.value(arg, &env);
+                }
+                let f = self.funs[label.as_str()];
+                if tail {
+                    self.emit(ReturnCall(f));
+                } else {
+                    self.emit(Call(f));
+                    self.propagate_if_raising();
+                }
+            }
+            SeqExp::Object {
+                class,
+                fields,
+                ann: _,
+            } => {
+                let info = match self.class.get(class) {
+                    Some(info) => info.clone(),
+                    None => panic!("class is guaranteed to be in scope. Compile."),
+                };
+                if fields.len() != info.fieldsize {
+                    self.fail(RuntimeErr::FieldNumError);
+                }
+                self.heap_object(
+                    [info.id as u64, 2 * fields.len() as u64],
+                    fields,
+                    &env,
+                    ARRAY_TAG,
+                )
+            }
+            SeqExp::CallMethod {
+                object,
+                method,
+                args,
+                ann: _,
+            } => {
+                // Like the native code, only the first class is dispatched on
+                let (class, method_name) = match method.iter().next() {
+                    Some((class, name)) => match self.class.get(class) {
+                        Some(info) => (info.id, name),
+                        None => panic!("Class is guaranteed to be in scope. Error."),
+                    },
+                    None => panic!("A method call has at least one class"),
+                };
+                let object_local = self.scratch + 1;
+                self.value(object, &env);
+                self.check(&Type::Array, RuntimeErr::ArrayError);
+                self.emit_all([
+                    LocalTee(object_local),
+                    I64Const(ARRAY_TAG as i64),
+                    I64Sub,
+                    I32WrapI64,
+                    I64Load(0),
+                    I64Const(class as i64),
+                    I64Ne,
+                    If(BlockType::Empty),
+                ]);
+                self.fail(RuntimeErr::MethodTypeError);
+                self.emit(End);
+                self.value(&ImmExp::Var(method_name.clone()), &env);
+                self.emit(I64Const(args.len() as i64 + 1));
+                self.call_checked(CLOSURE);
+                self.emit_all([
+                    LocalTee(self.scratch),
+                    I32WrapI64,
+                    I64Load(16),
+                    LocalGet(object_local),
+                ]);
+                for arg in args {
+                    self.value(arg, &env);
+                }
+                self.call_closure(args.len() + 1, tail)
+            }
+            SeqExp::Let {
+                var,
+                bound_exp,
+                body,
+                ann: _,
+            } => {
+                if self.types.is_unboxed(var) {
+                    self.compile_unboxed(bound_exp, &env);
+                } else {
+                    self.compile_exp(bound_exp, env.clone(), size, false);
+                }
+                let local = self.slot(size);
+                self.emit(LocalSet(local));
+                env.insert(var, local);
+                self.compile_exp(body, env, size + 1, tail);
+            }
+            SeqExp::If {
+                cond,
+                thn,
+                els,
+                ann: _,
+            } => {
+                self.value(cond, &env);
+                self.check(&Type::Bool, RuntimeErr::IfError);
+                self.emit_all([
+                    I64Const(SNAKE_FALSE as i64),
+                    I64Ne,
+                    If(BlockType::Val(ValType::I64)),
+                ]);
+                self.compile_exp(thn, env.clone(), size, tail);
+                self.emit(Else);
+                self.compile_exp(els, env, size, tail);
+                self.emit(End);
+            }
+            SeqExp::While { cond, body, ann: _ } => {
+                self.emit_all([Block(BlockType::Empty), Loop(BlockType::Empty)]);
+                self.compile_exp(cond, env.clone(), size, false);
+                self.check(&Type::Bool, RuntimeErr::WhileError);
+                self.emit_all([I64Const(SNAKE_FALSE as i64), I64Eq, BrIf(1)]);
+                self.compile_exp(body, env, size, false);
+                self.emit_all([Drop, Br(0), End, End, I64Const(SNAKE_FALSE as i64)]);
+            }
+            SeqExp::Assign { var, value, ann: _ } => {
+                let local = env[var.as_str()];
+                if self.types.is_unboxed(var) {
+                    self.raw(value, &env, None);
+                } else {
+                    self.value(value, &env);
+                }
+                self.emit(LocalTee(local));
+            }
+            SeqExp::Raise(imm, _) => {
+                self.value(imm, &env);
+                self.emit(Call(RAISE));
+                self.propagate();
+            }
+            SeqExp::Try {
+                body,
+                var,
+                handler,
+                ann: _,
+            } => {
+                let handlers = |op| [GlobalGet(HANDLERS), I64Const(1), op, GlobalSet(HANDLERS)];
+                self.emit(Block(BlockType::Val(ValType::I64)));
+                self.emit(Block(BlockType::Empty));
+                *self.labels.last_mut().unwrap() = true;
+                self.emit_all(handlers(I64Add));
+                // The handler has to be popped afterwards, so no tail calls
+                self.compile_exp(body, env.clone(), size, false);
+                self.emit_all(handlers(I64Sub));
+                self.emit_all([Br(1), End]);
+                let local = self.slot(size);
+                self.emit_all(handlers(I64Sub));
+                self.emit_all([
+                    I32Const(0),
+                    GlobalSet(RAISING),
+                    GlobalGet(RAISED),
+                    LocalSet(local),
+                ]);
+                env.insert(var, local);
+                self.compile_exp(handler, env, size + 1, tail);
+                self.emit(End);
+            }
+            SeqExp::Fail(err, _) => self.fail(*err),
+        }
+    }
+}
+
+fn section(out: &mut Vec<u8>, id: u8, entries: Vec<Vec<u8>>) {
+    let mut contents = Vec::new();
+    uleb(&mut contents, entries.len() as u64);
+    for e in entries {
+        contents.extend(e);
+    }
+    out.push(id);
+    uleb(out, contents.len() as u64);
+    out.extend(contents);
+}
+
+fn name(out: &mut Vec<u8>, s: &str) {
+    uleb(out, s.len() as u64);
+    out.extend(s.as_bytes());
+}
+
+pub fn compile(p: &SeqProg<u32>, types: &TypeTable) -> Vec<u8> {
+    let mut sigs = TypeSection::default();
+    let print_ty = sigs.snake(1);
+    let unit_ty = sigs.get(vec![ValType::I64], vec![]);
+    let mut funcs = helpers(&mut sigs);
+
+    // main, the functions and the methods, then the entries of the direct
+    // functions for closure calls, which drop the env
+    let mut bodies: Vec<(&str, &[String], &SeqExp<u32>)> = vec![("main", &[], &p.main)];
+    bodies.extend(
+        p.funs
+            .iter()
+            .map(|f| (f.name.as_str(), &f.parameters[..], &f.body)),
+    );
+    bodies.extend(p.methods.iter().map(|m| {
+        (
+            m.fundecl.name.as_str(),
+            &m.fundecl.parameters[..],
+            &m.fundecl.body,
+        )
+    }));
+    let mut fun_idx: HashMap<&str, u32> = HashMap::new();
+    for (i, (name, _, _)) in bodies.iter().enumerate() {
+        fun_idx.insert(name, MAIN + i as u32);
+    }
+    let mut next = MAIN + bodies.len() as u32;
+    let mut table: Vec<u32> = Vec::new();
+    let mut table_idx: HashMap<&str, u32> = HashMap::new();
+    let mut wrappers = Vec::new();
+    for (name, params, _) in bodies.iter().skip(1) {
+        table_idx.insert(name, table.len() as u32);
+        if p.direct.contains(*name) {
+            table.push(next);
+            wrappers.push((fun_idx[name], params.len()));
+            next += 1;
+        } else {
+            table.push(fun_idx[name]);
+        }
+    }
+
+    for (_, params, body) in &bodies {
+        let mut l = Lowering {
+            code: Vec::new(),
+            labels: Vec::new(),
+            funs: &fun_idx,
+            table: &table_idx,
+            class: &p.class,
+            types,
+            sigs: &mut sigs,
+            scratch: params.len() as u32,
+            first_slot: params.len() as u32 + SCRATCH,
+            frame: 0,
+        };
+        let env: HashMap<&str, u32> = params
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (x.as_str(), i as u32))
+            .collect();
+        l.compile_exp(body, env, 0, true);
+        l.code.push(WInstr::End);
+        let (code, frame) = (l.code, l.frame);
+        funcs.push(Func {
+            ty: sigs.snake(params.len()),
+            locals: SCRATCH + frame,
+            body: code,
+        });
+    }
+    for (f, arity) in wrappers {
+        let mut body: Vec<WInstr> = (1..=arity as u32).map(WInstr::LocalGet).collect();
+        body.extend([WInstr::ReturnCall(f), WInstr::End]);
+        funcs.push(Func {
+            ty: sigs.snake(arity + 1),
+            locals: 0,
+            body,
+        });
+    }
+
+    let mut out = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    section(
+        &mut out,
+        1,
+        sigs.types
+            .iter()
+            .map(|(params, results)| {
+                let mut e = vec![0x60];
+                uleb(&mut e, params.len() as u64);
+                e.extend(params.iter().map(|t| val_type(*t)));
+                uleb(&mut e, results.len() as u64);
+                e.extend(results.iter().map(|t| val_type(*t)));
+                e
+            })
+            .collect(),
+    );
+    section(
+        &mut out,
+        2,
+        IMPORTS
+            .iter()
+            .map(|import| {
+                let mut e = Vec::new();
+                name(&mut e, "env");
+                name(&mut e, import);
+                e.push(0x00);
+                let ty = if *import == "print_snake_val" {
+                    print_ty
+                } else {
+                    unit_ty
+                };
+                uleb(&mut e, ty as u64);
+                e
+            })
+            .collect(),
+    );
+    section(
+        &mut out,
+        3,
+        funcs
+            .iter()
+            .map(|f| {
+                let mut e = Vec::new();
+                uleb(&mut e, f.ty as u64);
+                e
+            })
+            .collect(),
+    );
+    let mut table_type = vec![0x70, 0x00];
+    uleb(&mut table_type, table.len() as u64);
+    section(&mut out, 4, vec![table_type]);
+    section(&mut out, 5, vec![vec![0x00, 0x01]]);
+    section(
+        &mut out,
+        6,
+        [ValType::I64, ValType::I64, ValType::I32, ValType::I64]
+            .iter()
+            .map(|t| {
+                let init = if *t == ValType::I64 { 0x42 } else { 0x41 };
+                vec![val_type(*t), 0x01, init, 0x00, 0x0b]
+            })
+            .collect(),
+    );
+    let mut exports = Vec::new();
+    for (export, kind, idx) in [("start_here", 0x00, MAIN), ("memory", 0x02, 0)] {
+        let mut e = Vec::new();
+        name(&mut e, export);
+        e.push(kind);
+        uleb(&mut e, idx as u64);
+        exports.push(e);
+    }
+    section(&mut out, 7, exports);
+    let mut elements = vec![0x00, 0x41, 0x00, 0x0b];
+    uleb(&mut elements, table.len() as u64);
+    for f in &table {
+        uleb(&mut elements, *f as u64);
+    }
+    section(&mut out, 9, vec![elements]);
+    section(
+        &mut out,
+        10,
+        funcs
+            .iter()
+            .map(|f| {
+                let mut body = Vec::new();
+                if f.locals == 0 {
+                    body.push(0x00);
+                } else {
+                    body.push(0x01);
+                    uleb(&mut body, f.locals as u64);
+                    body.push(val_type(ValType::I64));
+                }
+                for i in &f.body {
+                    encode_instr(&mut body, i);
+                }
+                let mut e = Vec::new();
+                uleb(&mut e, body.len() as u64);
+                e.extend(body);
+                e
+            })
+            .collect(),
+    );
+    out
+}
+
+fn load(memory: &[u8], addr: u64) -> Option<u64> {
+    let addr = usize::try_from(addr).ok()?;
+    let word = memory.get(addr..addr.checked_add(8)?)?;
+    Some(u64::from_le_bytes(word.try_into().ok()?))
+}
+
+fn sprint_snake_val_helper(x: u64, memory: &[u8], seen: &mut HashSet<u64>) -> String {
+    if x & INT_TAG == 0 {
+        format!("{}", (x as i64) >> 1)
+    } else if x == SNAKE_TRUE {
+        String::from("true")
+    } else if x == SNAKE_FALSE {
+        String::from("false")
+    } else if x & TAG_MASK == ARRAY_TAG {
+        let len = match load(memory, x - ARRAY_TAG + 8) {
+            Some(len) => len >> 1,
+            None => return format!("Invalid snake value 0x{:x}", x),
+        };
+        if !seen.insert(x) {
+            return String::from("<loop>");
+        }
+        let elts: Vec<String> = (1..=len)
+            .map(|i| match load(memory, x - ARRAY_TAG + 8 * (i + 1)) {
+                Some(elt) => sprint_snake_val_helper(elt, memory, seen),
+                None => format!("Invalid snake value 0x{:x}", x),
+            })
+            .collect();
+        seen.remove(&x);
+        format!("[{}]", elts.join(", "))
+    } else if x & TAG_MASK == CLOSURE_TAG {
+        String::from("<closure>")
+    } else {
+        format!("Invalid snake value 0x{:x}", x)
+    }
+}
+
+fn sprint_snake_val(x: u64, memory: &[u8]) -> String {
+    sprint_snake_val_helper(x, memory, &mut HashSet::new())
+}
+
+// The runtime the module imports, like runtime/stub.rs but printing into a
+// buffer and stopping the run on a failure
+#[derive(Default)]
+struct SnakeHost {
+    output: String,
+    failure: Option<String>,
+}
+
+impl SnakeHost {
+    fn print(&mut self, v: u64, memory: &[u8]) {
+        self.output.push_str(&sprint_snake_val(v, memory));
+        self.output.push('\n');
+    }
+}
+
+impl Host for SnakeHost {
+    fn call(&mut self, name: &str, args: &[u64], memory: &[u8]) -> Result<Option<u64>, Trap> {
+        let v = args.first().copied().unwrap_or(0);
+        match name {
+            "print_snake_val" => {
+                self.print(v, memory);
+                return Ok(Some(v));
+            }
+            "snake_error" => self.failure = Some(String::from(error_message(v))),
+            "snake_uncaught" => {
+                // a re-raised built-in error reports like the original failure
+                let msg = match (
+                    load(memory, v.wrapping_sub(ARRAY_TAG)),
+                    load(memory, v.wrapping_add(16 - ARRAY_TAG)),
+                ) {
+                    (Some(class), Some(code))
+                        if v & TAG_MASK == ARRAY_TAG && class == ERROR_CLASS_ID as u64 =>
+                    {
+                        String::from(error_message(code >> 1))
+                    }
+                    _ => format!("uncaught exception: {}", sprint_snake_val(v, memory)),
+                };
+                self.failure = Some(msg);
+            }
+            _ => return Err(Trap::Fault(format!("unknown import {}", name))),
+        }
+        Err(Trap::Exit)
+    }
+}
+
+// Runs the module on the embedded interpreter and returns what it printed,
+// along with the message of the failure that stopped it, if one did
+pub fn run(module: &[u8]) -> Result<(String, Option<String>), String> {
+    let mut instance = Instance::new(decode(module)?);
+    let mut host = SnakeHost::default();
+    match instance.invoke("start_here", &[], &mut host) {
+        Ok(results) => {
+            let result = results.first().copied().unwrap_or(0);
+            host.print(result, instance.memory());
+            Ok((host.output, None))
+        }
+        Err(Trap::Exit) => Ok((host.output, host.failure)),
+        Err(Trap::StackOverflow) => Ok((host.output, Some(String::from("stack overflow")))),
+        Err(Trap::Fault(msg)) => Err(format!("wasm trap: {}", msg)),
+    }
+}
@@ -0,0 +1,660 @@
+use std::collections::HashMap;
+
+// A small interpreter for the WebAssembly modules the wasm backend emits, so
+// they can run inside the compiler and its tests. It decodes the binary
+// format and runs the instructions directly, with side tables for where the
+// blocks end. Only the instructions and sections the backend uses are
+// supported, and the module is trusted to be valid: a malformed one stops
+// with a fault rather than being rejected up front.
+
+const PAGE_SIZE: usize = 1 << 16;
+// A 1GiB limit on the linear memory
+const MAX_PAGES: usize = 1 << 14;
+// The words of locals the frames may hold, the 8MB stack of the native
+// runtime. Every frame also counts the two words the native code spends on
+// the return address and the saved frame.
+const STACK_WORDS: usize = (8 << 20) / 8;
+const FRAME_WORDS: usize = 2;
+
+pub enum Trap {
+    // The host stopped the run
+    Exit,
+    // The frames outgrew the stack
+    StackOverflow,
+    Fault(String),
+}
+
+// Provides the functions the module imports
+pub trait Host {
+    fn call(&mut self, name: &str, args: &[u64], memory: &[u8]) -> Result<Option<u64>, Trap>;
+}
+
+fn fault<T>(msg: &str) -> Result<T, Trap> {
+    Err(Trap::Fault(String::from(msg)))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn done(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        let b = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| String::from("unexpected end of module"))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], String> {
+        if self.pos + n > self.bytes.len() {
+            return Err(String::from("unexpected end of module"));
+        }
+        self.pos += n;
+        Ok(&self.bytes[self.pos - n..self.pos])
+    }
+
+    fn uleb(&mut self) -> Result<u64, String> {
+        let (mut result, mut shift) = (0u64, 0);
+        loop {
+            let b = self.byte()?;
+            result |= ((b & 0x7f) as u64).checked_shl(shift).unwrap_or(0);
+            shift += 7;
+            if b & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(self.uleb()? as u32)
+    }
+
+    fn sleb(&mut self) -> Result<i64, String> {
+        let (mut result, mut shift) = (0i64, 0);
+        loop {
+            let b = self.byte()?;
+            result |= ((b & 0x7f) as i64).checked_shl(shift).unwrap_or(0);
+            shift += 7;
+            if b & 0x80 == 0 {
+                if shift < 64 && b & 0x40 != 0 {
+                    result |= -1 << shift;
+                }
+                return Ok(result);
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let len = self.u32()? as usize;
+        Ok(String::from_utf8_lossy(self.bytes(len)?).into_owned())
+    }
+
+    fn limits(&mut self) -> Result<u32, String> {
+        let has_max = self.byte()? == 1;
+        let min = self.u32()?;
+        if has_max {
+            self.u32()?;
+        }
+        Ok(min)
+    }
+
+    // An i32.const or i64.const initializer
+    fn const_expr(&mut self) -> Result<u64, String> {
+        let value = match self.byte()? {
+            0x41 => self.sleb()? as i32 as u32 as u64,
+            0x42 => self.sleb()? as u64,
+            op => return Err(format!("unsupported initializer 0x{:02x}", op)),
+        };
+        match self.byte()? {
+            0x0b => Ok(value),
+            _ => Err(String::from("initializer is not a constant")),
+        }
+    }
+
+    fn skip_immediates(&mut self, op: u8) -> Result<(), String> {
+        match op {
+            0x02..=0x04 | 0x3f | 0x40 => {
+                self.byte()?;
+            }
+            0x0c | 0x0d | 0x10 | 0x12 | 0x20..=0x24 => {
+                self.uleb()?;
+            }
+            0x11 | 0x13 | 0x28..=0x3e => {
+                self.uleb()?;
+                self.uleb()?;
+            }
+            0x41 | 0x42 => {
+                self.sleb()?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+struct Body {
+    locals: usize,
+    code: Vec<u8>,
+    // Where the else and the end of the block, loop, if or else at each
+    // position of the code are
+    blocks: Vec<(Option<u32>, u32)>,
+}
+
+pub struct Module {
+    types: Vec<(Vec<u8>, Vec<u8>)>,
+    // The name and type of the imported functions, which come first
+    imports: Vec<(String, u32)>,
+    funcs: Vec<u32>,
+    bodies: Vec<Body>,
+    table: Vec<Option<u32>>,
+    memory_pages: usize,
+    globals: Vec<u64>,
+    exports: HashMap<String, u32>,
+}
+
+fn decode_body(bytes: &[u8]) -> Result<Body, String> {
+    let mut r = Reader { bytes, pos: 0 };
+    let mut locals = 0;
+    for _ in 0..r.u32()? {
+        locals += r.u32()? as usize;
+        r.byte()?;
+    }
+    let code = bytes[r.pos..].to_vec();
+    let mut r = Reader {
+        bytes: &code,
+        pos: 0,
+    };
+    let mut blocks = vec![(None, 0); code.len()];
+    let mut open: Vec<(usize, Option<usize>)> = Vec::new();
+    while !r.done() {
+        let at = r.pos;
+        let op = r.byte()?;
+        match op {
+            0x02..=0x04 => open.push((at, None)),
+            0x05 => match open.last_mut() {
+                Some((_, els)) => *els = Some(at),
+                None => return Err(String::from("else outside of an if")),
+            },
+            0x0b => {
+                if let Some((start, els)) = open.pop() {
+                    blocks[start] = (els.map(|e| e as u32), at as u32);
+                    if let Some(els) = els {
+                        blocks[els] = (None, at as u32);
+                    }
+                }
+            }
+            _ => {}
+        }
+        r.skip_immediates(op)?;
+    }
+    Ok(Body {
+        locals,
+        code,
+        blocks,
+    })
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Module, String> {
+    let mut r = Reader { bytes, pos: 0 };
+    if r.bytes(8)? != [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00] {
+        return Err(String::from("not a WebAssembly module"));
+    }
+    let mut m = Module {
+        types: Vec::new(),
+        imports: Vec::new(),
+        funcs: Vec::new(),
+        bodies: Vec::new(),
+        table: Vec::new(),
+        memory_pages: 0,
+        globals: Vec::new(),
+        exports: HashMap::new(),
+    };
+    while !r.done() {
+        let id = r.byte()?;
+        let len = r.u32()? as usize;
+        let mut s = Reader {
+            bytes: r.bytes(len)?,
+            pos: 0,
+        };
+        // custom sections are skipped
+        if id == 0 {
+            continue;
+        }
+        for _ in 0..s.u32()? {
+            match id {
+                1 => {
+                    if s.byte()? != 0x60 {
+                        return Err(String::from("malformed function type"));
+                    }
+                    let n = s.u32()? as usize;
+                    let params = s.bytes(n)?.to_vec();
+                    let n = s.u32()? as usize;
+                    let results = s.bytes(n)?.to_vec();
+                    m.types.push((params, results));
+                }
+                2 => {
+                    s.name()?;
+                    let name = s.name()?;
+                    if s.byte()? != 0x00 {
+                        return Err(format!("unsupported import {}", name));
+                    }
+                    m.imports.push((name, s.u32()?));
+                }
+                3 => m.funcs.push(s.u32()?),
+                4 => {
+                    s.byte()?;
+                    m.table = vec![None; s.limits()? as usize];
+                }
+                5 => m.memory_pages = s.limits()? as usize,
+                6 => {
+                    s.bytes(2)?;
+                    m.globals.push(s.const_expr()?);
+                }
+                7 => {
+                    let name = s.name()?;
+                    let kind = s.byte()?;
+                    let idx = s.u32()?;
+                    if kind == 0x00 {
+                        m.exports.insert(name, idx);
+                    }
+                }
+                9 => {
+                    if s.u32()? != 0 {
+                        return Err(String::from("unsupported element segment"));
+                    }
+                    let offset = s.const_expr()? as usize;
+                    for i in 0..s.u32()? as usize {
+                        let f = s.u32()?;
+                        match m.table.get_mut(offset + i) {
+                            Some(entry) => *entry = Some(f),
+                            None => return Err(String::from("element segment out of bounds")),
+                        }
+                    }
+                }
+                10 => {
+                    let size = s.u32()? as usize;
+                    m.bodies.push(decode_body(s.bytes(size)?)?);
+                }
+                _ => return Err(format!("unsupported section {}", id)),
+            }
+        }
+    }
+    if m.funcs.len() != m.bodies.len() {
+        return Err(String::from("function and code sections disagree"));
+    }
+    Ok(m)
+}
+
+struct Label {
+    // Where a branch to the label continues
+    cont: usize,
+    height: usize,
+    arity: usize,
+    is_loop: bool,
+}
+
+// A function being run. Its locals and labels live on stacks shared by all
+// frames, from the given bases up.
+struct Frame {
+    func: usize,
+    pc: usize,
+    locals: usize,
+    labels: usize,
+    // The height of the operand stack below the frame
+    height: usize,
+    results: usize,
+}
+
+pub struct Instance {
+    module: Module,
+    memory: Vec<u8>,
+    globals: Vec<u64>,
+}
+
+fn pop(stack: &mut Vec<u64>) -> Result<u64, Trap> {
+    stack
+        .pop()
+        .map_or_else(|| fault("operand stack underflow"), Ok)
+}
+
+fn top(stack: &[u64], n: usize) -> Result<usize, Trap> {
+    match stack.len().checked_sub(n) {
+        Some(at) => Ok(at),
+        None => fault("operand stack underflow"),
+    }
+}
+
+fn bool_val(b: bool) -> u64 {
+    b as u64
+}
+
+impl Instance {
+    pub fn new(module: Module) -> Instance {
+        let memory = vec![0; module.memory_pages * PAGE_SIZE];
+        let globals = module.globals.clone();
+        Instance {
+            module,
+            memory,
+            globals,
+        }
+    }
+
+    pub fn memory(&self) -> &[u8] {
+        &self.memory
+    }
+
+    fn func_type(&self, f: u32) -> Result<&(Vec<u8>, Vec<u8>), Trap> {
+        let m = &self.module;
+        let ty = match (f as usize).checked_sub(m.imports.len()) {
+            None => m.imports[f as usize].1,
+            Some(i) => match m.funcs.get(i) {
+                Some(ty) => *ty,
+                None => return fault("undefined function"),
+            },
+        };
+        m.types
+            .get(ty as usize)
+            .map_or_else(|| fault("undefined type"), Ok)
+    }
+
+    // A frame for f, which takes the arguments on top of the stack as its
+    // first locals, or None when f is an import, which is called right away
+    fn enter(
+        &mut self,
+        f: u32,
+        stack: &mut Vec<u64>,
+        frame_stacks: (&mut Vec<u64>, usize),
+        depth: usize,
+        host: &mut dyn Host,
+    ) -> Result<Option<Frame>, Trap> {
+        let (params, results) = self.func_type(f)?;
+        let (params, results) = (params.len(), results.len());
+        let args = top(stack, params)?;
+        match (f as usize).checked_sub(self.module.imports.len()) {
+            None => {
+                let name = &self.module.imports[f as usize].0;
+                let result = host.call(name, &stack[args..], &self.memory)?;
+                stack.truncate(args);
+                stack.extend(result);
+                Ok(None)
+            }
+            Some(func) => {
+                let (locals, labels) = frame_stacks;
+                let base = locals.len();
+                let top = base + params + self.module.bodies[func].locals;
+                if top + FRAME_WORDS * (depth + 1) > STACK_WORDS {
+                    return Err(Trap::StackOverflow);
+                }
+                locals.extend(stack.drain(args..));
+                locals.resize(top, 0);
+                Ok(Some(Frame {
+                    func,
+                    pc: 0,
+                    locals: base,
+                    labels,
+                    height: stack.len(),
+                    results,
+                }))
+            }
+        }
+    }
+
+    fn indirect(&self, ty: u32, idx: u64) -> Result<u32, Trap> {
+        let f = match self.module.table.get(idx as usize) {
+            Some(Some(f)) => *f,
+            _ => return fault("undefined element"),
+        };
+        match self.module.types.get(ty as usize) {
+            Some(expected) if expected == self.func_type(f)? => Ok(f),
+            _ => fault("indirect call type mismatch"),
+        }
+    }
+
+    fn address(&self, stack: &mut Vec<u64>, offset: u32) -> Result<usize, Trap> {
+        let addr = pop(stack)? as u32 as usize + offset as usize;
+        if addr + 8 > self.memory.len() {
+            return fault("out of bounds memory access");
+        }
+        Ok(addr)
+    }
+
+    // Runs an exported function and returns its results
+    pub fn invoke(
+        &mut self,
+        export: &str,
+        args: &[u64],
+        host: &mut dyn Host,
+    ) -> Result<Vec<u64>, Trap> {
+        let f = match self.module.exports.get(export) {
+            Some(f) => *f,
+            None => return Err(Trap::Fault(format!("no export named {}", export))),
+        };
+        let mut stack = args.to_vec();
+        let mut locals: Vec<u64> = Vec::new();
+        let mut labels: Vec<Label> = Vec::new();
+        let mut frame = match self.enter(f, &mut stack, (&mut locals, 0), 0, host)? {
+            Some(frame) => frame,
+            None => return Ok(stack),
+        };
+        let mut calls: Vec<Frame> = Vec::new();
+        loop {
+            let body = &self.module.bodies[frame.func];
+            let at = frame.pc;
+            let mut r = Reader {
+                bytes: &body.code,
+                pos: at,
+            };
+            let block = || {
+                let (els, end) = body.blocks[at];
+                (els.map(|e| e as usize), end as usize)
+            };
+            let op = r.byte().map_err(Trap::Fault)?;
+            let imm = |r: &mut Reader| r.u32().map_err(Trap::Fault);
+            let mut next = None;
+            let mut call = None;
+            let mut tail = false;
+            let mut ret = false;
+            match op {
+                0x00 => return fault("unreachable"),
+                0x02..=0x04 => {
+                    let arity = (r.byte().map_err(Trap::Fault)? != 0x40) as usize;
+                    let (els, end) = block();
+                    let mut enter = true;
+                    if op == 0x04 && pop(&mut stack)? == 0 {
+                        // without an else branch the whole if is skipped
+                        enter = els.is_some();
+                        next = Some(els.unwrap_or(end) + 1);
+                    }
+                    if enter {
+                        let is_loop = op == 0x03;
+                        labels.push(Label {
+                            cont: if is_loop { r.pos } else { end + 1 },
+                            height: stack.len(),
+                            arity: if is_loop { 0 } else { arity },
+                            is_loop,
+                        });
+                    }
+                }
+                // the end of the then branch skips the else branch
+                0x05 => {
+                    labels.pop();
+                    next = Some(block().1 + 1);
+                }
+                0x0b => {
+                    if labels.len() > frame.labels {
+                        labels.pop();
+                    } else {
+                        ret = true;
+                    }
+                }
+                0x0c | 0x0d => {
+                    let depth = imm(&mut r)? as usize;
+                    if op == 0x0c || pop(&mut stack)? != 0 {
+                        let idx = match labels.len().checked_sub(depth + 1) {
+                            Some(idx) if idx >= frame.labels => idx,
+                            _ => return fault("branch out of the function"),
+                        };
+                        let label = &labels[idx];
+                        let results = top(&stack, label.arity)?;
+                        stack.drain(label.height..results);
+                        next = Some(label.cont);
+                        let keep = if label.is_loop { idx + 1 } else { idx };
+                        labels.truncate(keep);
+                    }
+                }
+                0x0f => ret = true,
+                0x10 | 0x12 => {
+                    call = Some(imm(&mut r)?);
+                    tail = op == 0x12;
+                }
+                0x11 | 0x13 => {
+                    let ty = imm(&mut r)?;
+                    imm(&mut r)?;
+                    let idx = pop(&mut stack)?;
+                    call = Some(self.indirect(ty, idx)?);
+                    tail = op == 0x13;
+                }
+                0x1a => {
+                    pop(&mut stack)?;
+                }
+                0x20..=0x22 => {
+                    let x = frame.locals + imm(&mut r)? as usize;
+                    if x >= locals.len() {
+                        return fault("undefined local");
+                    }
+                    match op {
+                        0x20 => stack.push(locals[x]),
+                        0x21 => locals[x] = pop(&mut stack)?,
+                        _ => locals[x] = stack[top(&stack, 1)?],
+                    }
+                }
+                0x23 | 0x24 => {
+                    let x = imm(&mut r)? as usize;
+                    if x >= self.globals.len() {
+                        return fault("undefined global");
+                    }
+                    match op {
+                        0x23 => stack.push(self.globals[x]),
+                        _ => self.globals[x] = pop(&mut stack)?,
+                    }
+                }
+                0x29 => {
+                    imm(&mut r)?;
+                    let offset = imm(&mut r)?;
+                    let addr = self.address(&mut stack, offset)?;
+                    let mut word = [0; 8];
+                    word.copy_from_slice(&self.memory[addr..addr + 8]);
+                    stack.push(u64::from_le_bytes(word));
+                }
+                0x37 => {
+                    imm(&mut r)?;
+                    let offset = imm(&mut r)?;
+                    let value = pop(&mut stack)?;
+                    let addr = self.address(&mut stack, offset)?;
+                    self.memory[addr..addr + 8].copy_from_slice(&value.to_le_bytes());
+                }
+                0x3f => {
+                    r.byte().map_err(Trap::Fault)?;
+                    stack.push((self.memory.len() / PAGE_SIZE) as u64);
+                }
+                0x40 => {
+                    r.byte().map_err(Trap::Fault)?;
+                    let pages = self.memory.len() / PAGE_SIZE;
+                    let delta = pop(&mut stack)? as u32 as usize;
+                    if pages + delta > MAX_PAGES {
+                        stack.push(u32::MAX as u64);
+                    } else {
+                        self.memory.resize((pages + delta) * PAGE_SIZE, 0);
+                        stack.push(pages as u64);
+                    }
+                }
+                0x41 => stack.push(r.sleb().map_err(Trap::Fault)? as i32 as u32 as u64),
+                0x42 => stack.push(r.sleb().map_err(Trap::Fault)? as u64),
+                0x50 => {
+                    let a = pop(&mut stack)?;
+                    stack.push(bool_val(a == 0))
+                }
+                0xa7 | 0xad => {
+                    let a = pop(&mut stack)?;
+                    stack.push(a as u32 as u64)
+                }
+                _ => {
+                    let b = pop(&mut stack)?;
+                    let a = pop(&mut stack)?;
+                    let (a32, b32) = (a as u32, b as u32);
+                    stack.push(match op {
+                        0x46 => bool_val(a32 == b32),
+                        0x6b => a32.wrapping_sub(b32) as u64,
+                        0x72 => (a32 | b32) as u64,
+                        0x51 => bool_val(a == b),
+                        0x52 => bool_val(a != b),
+                        0x53 => bool_val((a as i64) < (b as i64)),
+                        0x55 => bool_val((a as i64) > (b as i64)),
+                        0x56 => bool_val(a > b),
+                        0x57 => bool_val((a as i64) <= (b as i64)),
+                        0x59 => bool_val((a as i64) >= (b as i64)),
+                        0x7c => a.wrapping_add(b),
+                        0x7d => a.wrapping_sub(b),
+                        0x7e => a.wrapping_mul(b),
+                        0x7f => match (a as i64).checked_div(b as i64) {
+                            Some(q) => q as u64,
+                            None if b == 0 => return fault("integer divide by zero"),
+                            None => return fault("integer overflow"),
+                        },
+                        0x83 => a & b,
+                        0x84 => a | b,
+                        0x85 => a ^ b,
+                        0x86 => a.wrapping_shl(b as u32),
+                        0x87 => ((a as i64).wrapping_shr(b as u32)) as u64,
+                        0x88 => a.wrapping_shr(b as u32),
+                        _ => {
+                            return Err(Trap::Fault(format!(
+                                "unsupported instruction 0x{:02x}",
+                                op
+                            )))
+                        }
+                    })
+                }
+            }
+            frame.pc = next.unwrap_or(r.pos);
+
+            if let Some(f) = call {
+                if tail {
+                    // the arguments replace the frame before the call
+                    let args = top(&stack, self.func_type(f)?.0.len())?;
+                    stack.drain(frame.height..args);
+                    locals.truncate(frame.locals);
+                    labels.truncate(frame.labels);
+                }
+                let depth = calls.len() + usize::from(!tail);
+                match self.enter(f, &mut stack, (&mut locals, labels.len()), depth, host)? {
+                    Some(callee) if tail => {
+                        frame = Frame {
+                            results: frame.results,
+                            ..callee
+                        }
+                    }
+                    Some(callee) => calls.push(std::mem::replace(&mut frame, callee)),
+                    None => ret = tail,
+                }
+            }
+            if ret {
+                let results = top(&stack, frame.results)?;
+                stack.drain(frame.height..results);
+                locals.truncate(frame.locals);
+                labels.truncate(frame.labels);
+                match calls.pop() {
+                    Some(caller) => frame = caller,
+                    None => return Ok(stack),
+                }
+            }
+        }
+    }
+}
@@ -1,19 +1,38 @@
+use snake::api::{self, Backend, Severity};
 use snake::runner;
+use snake::timing;
 
+// A test runs its example through the native code linked with the stub
+// unless it names another target
 macro_rules! mk_test {
     ($test_name:ident, $file_name:expr, $expected_output:expr) => {
+        mk_test!($test_name, $file_name, $expected_output, Target::Native);
+    };
+    ($test_name:ident, $file_name:expr, $expected_output:expr, $target:expr) => {
         #[test]
         fn $test_name() -> std::io::Result<()> {
-            test_example_file($file_name, $expected_output)
+            test_example_on($file_name, $target, Ok($expected_output))
         }
     };
 }
 
 macro_rules! mk_fail_test {
     ($test_name:ident, $file_name:expr, $expected_output:expr) => {
+        mk_fail_test!($test_name, $file_name, $expected_output, Target::Native);
+    };
+    ($test_name:ident, $file_name:expr, $expected_output:expr, $target:expr) => {
         #[test]
         fn $test_name() -> std::io::Result<()> {
-            test_example_fail($file_name, $expected_output)
+            test_example_on($file_name, $target, Err($expected_output))
+        }
+    };
+}
+
+macro_rules! mk_nasm_test {
+    ($test_name:ident, $file_name:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            test_example_nasm($file_name)
         }
     };
 }
@@ -61,52 +80,868 @@ mk_fail_test!(
     "dup_field.garter",
     "multiple defined class field"
 );
+// Counting loop with mutable let-bound variables
+mk_test!(while_loop, "while_loop.garter", "55");
+// Assignment takes the whole sum on its right
+mk_test!(assign_sum, "assign_sum.garter", "[20, 2]\n6");
+// Closures see assignments to the variables they capture
+mk_test!(mutable_capture, "mutable_capture.garter", "10\n100");
+// Error: Assigning to a function parameter
+mk_fail_test!(
+    assign_param,
+    "assign_param.garter",
+    "Cannot assign to \"x\""
+);
+// Error: While condition is not a boolean
+mk_fail_test!(
+    while_non_bool,
+    "while_non_bool.garter",
+    "while expected a boolean"
+);
+// For loops over arrays and ranges, each iteration binds a fresh variable
+mk_test!(for_loop, "for_loop.garter", "12\n[1, 0]\n[2, 0]\n[2, 1]\n4");
+// Error: Range bounds must be numbers
+mk_fail_test!(
+    for_range_non_num,
+    "for_range_non_num.garter",
+    "comparison expected a number"
+);
+// Pattern matching on literals, array shapes and classes
+mk_test!(
+    match_patterns,
+    "match.garter",
+    "100\n200\n300\n7\n6\n999\n40\n20\n999\n6"
+);
+// Error: No arm matches the value
+mk_fail_test!(
+    match_fail,
+    "match_fail.garter",
+    "no match arm matched the value"
+);
+// Destructuring let bindings for arrays and objects
+mk_test!(destructure, "destructure.garter", "15\n[2, 3]");
+// Error: Array pattern with the wrong length
+mk_fail_test!(
+    destructure_fail,
+    "destructure_fail.garter",
+    "let pattern does not match the value"
+);
+// Error: The same name bound twice across the patterns of a let
+mk_fail_test!(
+    destructure_dup,
+    "destructure_dup.garter",
+    "Variable a defined twice"
+);
+// raise and try/catch, built-in failures are caught as Error objects
+mk_test!(
+    exceptions,
+    "exceptions.garter",
+    "2\n-1\n[42]\n20\n5\n10\n[2]"
+);
+// Error: A raised value that nothing catches
+mk_fail_test!(uncaught, "uncaught.garter", "uncaught exception: -2");
+// Constant arithmetic, comparisons and known conditions fold at compile time
+mk_test!(fold, "fold.garter", "19\ntrue\ntrue\n5");
+// Error: Folding keeps the overflow check on constants
+mk_fail_test!(fold_overflow, "fold_overflow.garter", "overflow");
+// Folded products are the ones the runtime computes, the others still fail
+mk_test!(
+    fold_mul,
+    "fold_mul.garter",
+    "2305843009213693952\n-2305843009213693952"
+);
+mk_fail_test!(fold_mul_overflow, "fold_mul_overflow.garter", "overflow");
+mk_fail_test!(
+    vm_fold_mul_overflow,
+    "fold_mul_overflow.garter",
+    "overflow",
+    Target::Vm
+);
+// Unused bindings are dropped, but their effects are kept
+mk_test!(dead_bindings, "dead_bindings.garter", "10\n[5, 2]");
+// Error: An unused binding still reports its runtime error
+mk_fail_test!(
+    dead_binding_error,
+    "dead_binding_error.garter",
+    "arithmetic expected a number"
+);
+// Checks on operands of statically known types are skipped
+mk_test!(known_types, "known_types.garter", "[5, 2, 3]\n4\n5\n8\n36");
+// Error: Parameters are not known, so their checks stay
+mk_fail_test!(
+    known_types_fail,
+    "known_types_fail.garter",
+    "arithmetic expected a number"
+);
+// Annotated functions, lambdas, let bindings and fields are checked statically
+mk_test!(typed, "typed.garter", "25\n240\ntrue");
+// Error: An annotated parameter rejects an argument of another type
+mk_fail_test!(
+    type_mismatch,
+    "type_mismatch.garter",
+    "expected Num but found Bool"
+);
+// Error: A lambda passed for a function type needs as many parameters
+mk_fail_test!(
+    type_arity,
+    "type_arity.garter",
+    "Expected 2 arguments but found 1"
+);
+// Error: Without annotations the same program fails at runtime
+mk_fail_test!(
+    untyped_dynamic,
+    "untyped_dynamic.garter",
+    "arithmetic expected a number"
+);
+// Known functions are called directly, closures still work
+mk_test!(
+    direct_calls,
+    "direct_calls.garter",
+    "3628800\nfalse\n7\n720"
+);
+// Small non-recursive functions and getters are inlined
+mk_test!(inline, "inline.garter", "3\n7\n9\n55");
+// Inlining a chain of doubling calls keeps the code from growing exponentially
+mk_test!(inline_chain, "inline_chain.garter", "8192");
+// Tail calls between methods of different arities run in constant stack
+mk_test!(tail_methods, "tail_methods.garter", "false\ntrue");
+mk_test!(tail_calls, "tail_calls.garter", "true\n3000000");
+// Numbers only used by arithmetic are kept untagged inside functions
+mk_test!(unboxed, "unboxed.garter", "332833500\n[20, true]\n20");
+// Error: An untagged number overflows as soon as it leaves the range
+mk_fail_test!(unboxed_overflow, "unboxed_overflow.garter", "overflow");
+mk_fail_test!(
+    unboxed_intermediate,
+    "unboxed_intermediate.garter",
+    "overflow"
+);
+// Tagged and untagged products accept the same range
+mk_test!(
+    mul_range,
+    "mul_range.garter",
+    "2305843009213693952\n2305843009213693952\n-2305843009213693952\n-2305843009213693952"
+);
+// Both raise the overflow error, code 4, for the same product
+mk_test!(mul_range_overflow, "mul_range_overflow.garter", "4\n4");
+// Imported defs and classes are in scope, each file keeps its own helpers
+mk_test!(import, "import.garter", "9\n200\n9\n16");
+mk_test!(vm_import, "import.garter", "9\n200\n9\n16", Target::Vm);
+// Error: Files that import each other are rejected
+mk_fail_test!(import_cycle, "import_cycle.garter", "forms a cycle");
+// Error: An import of a file that does not exist
+mk_fail_test!(
+    import_missing,
+    "import_missing.garter",
+    "Cannot read \"lib/missing.garter\""
+);
+// Error: An error in an imported file names that file
+mk_fail_test!(
+    import_error,
+    "import_error.garter",
+    "Unbound variable y at examples/lib/unbound.garter, line 2"
+);
+// Error: Deep non-tail recursion runs out of the default stack
+mk_fail_test!(stack_overflow, "stack_overflow.garter", "stack overflow");
+// Error: Allocating past the end of the default heap
+mk_fail_test!(out_of_memory, "out_of_memory.garter", "out of memory");
+mk_test!(deep_try, "deep_try.garter", "100000");
+// The JIT prints the same as the executables linked with the stub
+mk_test!(jit_inline, "inline.garter", "3\n7\n9\n55", Target::Jit);
+mk_test!(
+    jit_exceptions,
+    "exceptions.garter",
+    "2\n-1\n[42]\n20\n5\n10\n[2]",
+    Target::Jit
+);
+mk_test!(
+    jit_alloc_loop,
+    "alloc_loop.garter",
+    "[99999, 99999, 99999, 99999]",
+    Target::Jit
+);
+mk_fail_test!(
+    jit_unboxed_intermediate,
+    "unboxed_intermediate.garter",
+    "overflow",
+    Target::Jit
+);
+mk_test!(
+    jit_mul_range,
+    "mul_range.garter",
+    "2305843009213693952\n2305843009213693952\n-2305843009213693952\n-2305843009213693952",
+    Target::Jit
+);
+// Error: Deep recursion fails inside the JIT without taking the tests down
+mk_fail_test!(
+    jit_deep_recursion,
+    "deep_recursion.garter",
+    "stack overflow",
+    Target::Jit
+);
+// The standalone executables pass their flags to the runtime
+mk_test!(
+    built_deep_recursion,
+    "deep_recursion.garter",
+    "500000500000",
+    Target::Built(&["--stack-size", "268435456"])
+);
+mk_test!(
+    built_heap_size,
+    "alloc_loop.garter",
+    "[99999, 99999, 99999, 99999]",
+    Target::Built(&["--heap-size", "8388608"])
+);
+// Error: Recursing past the end of the default stack
+mk_fail_test!(
+    built_default_stack,
+    "deep_recursion.garter",
+    "stack overflow",
+    Target::Built(&[])
+);
+// Error: Allocating past the end of a small heap
+mk_fail_test!(
+    built_small_heap,
+    "alloc_loop.garter",
+    "out of memory",
+    Target::Built(&["--heap-size", "65536"])
+);
+// Error: The runtime rejects a flag without its size
+mk_fail_test!(
+    built_missing_size,
+    "inline.garter",
+    "usage: PROGRAM",
+    Target::Built(&["--heap-size"])
+);
+// The library API runs the source with the caller's sinks
+mk_test!(
+    api_vm_car,
+    "car.garter",
+    "8000\ntrue",
+    Target::Api(Backend::Vm)
+);
+mk_test!(
+    api_wasm_exceptions,
+    "exceptions.garter",
+    "2\n-1\n[42]\n20\n5\n10\n[2]",
+    Target::Api(Backend::Wasm)
+);
+mk_fail_test!(
+    api_vm_match_fail,
+    "match_fail.garter",
+    "no match arm matched the value",
+    Target::Api(Backend::Vm)
+);
+// A runaway program fails on the backend without taking the caller down
+mk_fail_test!(
+    api_vm_stack_overflow,
+    "stack_overflow.garter",
+    "stack overflow",
+    Target::Api(Backend::Vm)
+);
+mk_fail_test!(
+    api_wasm_stack_overflow,
+    "stack_overflow.garter",
+    "stack overflow",
+    Target::Api(Backend::Wasm)
+);
+mk_fail_test!(
+    api_vm_out_of_memory,
+    "out_of_memory.garter",
+    "out of memory",
+    Target::Api(Backend::Vm)
+);
+// The library API reports the errors and warnings with their locations
+mk_fail_test!(
+    api_check_field,
+    "set_nonfield.garter",
+    "Error at line 3: Undefined field \"nondummy\"",
+    Target::Check
+);
+mk_test!(
+    api_check_match,
+    "match_fail.garter",
+    "Warning at line 1: Match at line 1, column 0 to line 4, column 3 may not cover \
+     every value, add a wildcard arm",
+    Target::Check
+);
+mk_fail_test!(
+    api_check_parse,
+    "parse_error.garter",
+    "Error at line 2: Unrecognized EOF",
+    Target::Check
+);
+// The counts --stats reports
+mk_test!(
+    stats_car,
+    "car.garter",
+    "lifted functions: 0\nlifted methods: 2\nclasses: 1",
+    Target::Stats
+);
+mk_test!(
+    stats_multi_class,
+    "multi_class.garter",
+    "lifted functions: 0\nlifted methods: 4\nclasses: 2",
+    Target::Stats
+);
+// A known function calling a method that two classes define keeps the
+// method closures in scope. Only the first class is dispatched on, so the
+// program is compiled but not run.
+mk_test!(
+    stats_shared_method,
+    "shared_method.garter",
+    "lifted functions: 1\nlifted methods: 2\nclasses: 2",
+    Target::Stats
+);
+mk_test!(
+    stats_inline,
+    "inline.garter",
+    "lifted functions: 2\nlifted methods: 2\nclasses: 1",
+    Target::Stats
+);
+// --time-passes records every pass a compile goes through
+mk_test!(
+    time_passes_vm,
+    "car.garter",
+    "parse\ncheck_prog\ntypecheck\ndesugar\nuniquify\nclass_lift\nlower_matches\n\
+     lambda_lift\nsequentialize\noptimize\ninfer_types\ncodegen\nrun",
+    Target::TimePasses
+);
+// The object files written without nasm have the same code, relocations and
+// symbols as nasm's, when nasm is there to compare with
+mk_nasm_test!(nasm_car, "car.garter");
+mk_nasm_test!(nasm_exceptions, "exceptions.garter");
+mk_nasm_test!(nasm_match, "match.garter");
+mk_nasm_test!(nasm_tail_methods, "tail_methods.garter");
+mk_nasm_test!(nasm_unboxed, "unboxed.garter");
+// The bytecode VM prints the same as the native code
+mk_test!(vm_car, "car.garter", "8000\ntrue", Target::Vm);
+mk_test!(
+    vm_triangle,
+    "triangle.garter",
+    "true\n30\n60\n30",
+    Target::Vm
+);
+mk_test!(
+    vm_mutable_capture,
+    "mutable_capture.garter",
+    "10\n100",
+    Target::Vm
+);
+mk_test!(
+    vm_match_patterns,
+    "match.garter",
+    "100\n200\n300\n7\n6\n999\n40\n20\n999\n6",
+    Target::Vm
+);
+mk_test!(
+    vm_exceptions,
+    "exceptions.garter",
+    "2\n-1\n[42]\n20\n5\n10\n[2]",
+    Target::Vm
+);
+mk_test!(
+    vm_direct_calls,
+    "direct_calls.garter",
+    "3628800\nfalse\n7\n720",
+    Target::Vm
+);
+mk_test!(
+    vm_tail_methods,
+    "tail_methods.garter",
+    "false\ntrue",
+    Target::Vm
+);
+mk_test!(
+    vm_unboxed,
+    "unboxed.garter",
+    "332833500\n[20, true]\n20",
+    Target::Vm
+);
+// Error: The VM reports the same runtime errors
+mk_fail_test!(
+    vm_wrong_method,
+    "wrong_method.garter",
+    "calling method from another class",
+    Target::Vm
+);
+mk_fail_test!(
+    vm_uncaught,
+    "uncaught.garter",
+    "uncaught exception: -2",
+    Target::Vm
+);
+mk_fail_test!(
+    vm_known_types_fail,
+    "known_types_fail.garter",
+    "arithmetic expected a number",
+    Target::Vm
+);
+mk_fail_test!(
+    vm_unboxed_overflow,
+    "unboxed_overflow.garter",
+    "overflow",
+    Target::Vm
+);
+mk_fail_test!(
+    vm_unboxed_intermediate,
+    "unboxed_intermediate.garter",
+    "overflow",
+    Target::Vm
+);
+// Error: The VM has the stack and the heap of the native code
+mk_fail_test!(
+    vm_stack_overflow,
+    "stack_overflow.garter",
+    "stack overflow",
+    Target::Vm
+);
+mk_fail_test!(
+    vm_out_of_memory,
+    "out_of_memory.garter",
+    "out of memory",
+    Target::Vm
+);
+mk_test!(vm_deep_try, "deep_try.garter", "100000", Target::Vm);
+mk_test!(
+    vm_mul_range,
+    "mul_range.garter",
+    "2305843009213693952\n2305843009213693952\n-2305843009213693952\n-2305843009213693952",
+    Target::Vm
+);
+// The C backend prints the same once built with a C compiler
+mk_test!(c_car, "car.garter", "8000\ntrue", Target::C("-O2"));
+mk_test!(
+    c_match_patterns,
+    "match.garter",
+    "100\n200\n300\n7\n6\n999\n40\n20\n999\n6",
+    Target::C("-O2")
+);
+mk_test!(
+    c_exceptions,
+    "exceptions.garter",
+    "2\n-1\n[42]\n20\n5\n10\n[2]",
+    Target::C("-O2")
+);
+mk_test!(
+    c_direct_calls,
+    "direct_calls.garter",
+    "3628800\nfalse\n7\n720",
+    Target::C("-O2")
+);
+mk_test!(
+    c_tail_methods,
+    "tail_methods.garter",
+    "false\ntrue",
+    Target::C("-O2")
+);
+// Tail calls take no stack without the C compiler's optimisations too
+mk_test!(
+    c_tail_calls_o0,
+    "tail_calls.garter",
+    "true\n3000000",
+    Target::C("-O0")
+);
+mk_test!(
+    c_tail_methods_o0,
+    "tail_methods.garter",
+    "false\ntrue",
+    Target::C("-O0")
+);
+mk_test!(
+    c_unboxed,
+    "unboxed.garter",
+    "332833500\n[20, true]\n20",
+    Target::C("-O2")
+);
+// Error: The C runtime reports the same runtime errors
+mk_fail_test!(
+    c_wrong_method,
+    "wrong_method.garter",
+    "calling method from another class",
+    Target::C("-O2")
+);
+mk_fail_test!(
+    c_uncaught,
+    "uncaught.garter",
+    "uncaught exception: -2",
+    Target::C("-O2")
+);
+mk_fail_test!(
+    c_unboxed_overflow,
+    "unboxed_overflow.garter",
+    "overflow",
+    Target::C("-O2")
+);
+mk_fail_test!(
+    c_unboxed_intermediate,
+    "unboxed_intermediate.garter",
+    "overflow",
+    Target::C("-O2")
+);
+mk_test!(
+    c_mul_range,
+    "mul_range.garter",
+    "2305843009213693952\n2305843009213693952\n-2305843009213693952\n-2305843009213693952",
+    Target::C("-O2")
+);
+// Error: The C functions check their frames against the stack limit too
+mk_fail_test!(
+    c_stack_overflow,
+    "stack_overflow.garter",
+    "stack overflow",
+    Target::C("-O2")
+);
+mk_fail_test!(
+    c_stack_overflow_o0,
+    "stack_overflow.garter",
+    "stack overflow",
+    Target::C("-O0")
+);
+mk_test!(c_deep_try, "deep_try.garter", "100000", Target::C("-O2"));
+// The WebAssembly modules print the same on the embedded interpreter
+mk_test!(wasm_car, "car.garter", "8000\ntrue", Target::Wasm);
+mk_test!(
+    wasm_mutable_capture,
+    "mutable_capture.garter",
+    "10\n100",
+    Target::Wasm
+);
+mk_test!(
+    wasm_match_patterns,
+    "match.garter",
+    "100\n200\n300\n7\n6\n999\n40\n20\n999\n6",
+    Target::Wasm
+);
+mk_test!(
+    wasm_exceptions,
+    "exceptions.garter",
+    "2\n-1\n[42]\n20\n5\n10\n[2]",
+    Target::Wasm
+);
+mk_test!(
+    wasm_direct_calls,
+    "direct_calls.garter",
+    "3628800\nfalse\n7\n720",
+    Target::Wasm
+);
+mk_test!(
+    wasm_unboxed,
+    "unboxed.garter",
+    "332833500\n[20, true]\n20",
+    Target::Wasm
+);
+mk_test!(wasm_deep_try, "deep_try.garter", "100000", Target::Wasm);
+// Error: The host reports the runtime errors of the module
+mk_fail_test!(
+    wasm_wrong_method,
+    "wrong_method.garter",
+    "calling method from another class",
+    Target::Wasm
+);
+mk_fail_test!(
+    wasm_stack_overflow,
+    "stack_overflow.garter",
+    "stack overflow",
+    Target::Wasm
+);
+mk_fail_test!(
+    wasm_out_of_memory,
+    "out_of_memory.garter",
+    "out of memory",
+    Target::Wasm
+);
+mk_fail_test!(
+    wasm_uncaught,
+    "uncaught.garter",
+    "uncaught exception: -2",
+    Target::Wasm
+);
+mk_fail_test!(
+    wasm_unboxed_overflow,
+    "unboxed_overflow.garter",
+    "overflow",
+    Target::Wasm
+);
+mk_fail_test!(
+    wasm_unboxed_intermediate,
+    "unboxed_intermediate.garter",
+    "overflow",
+    Target::Wasm
+);
+mk_test!(
+    wasm_mul_range,
+    "mul_range.garter",
+    "2305843009213693952\n2305843009213693952\n-2305843009213693952\n-2305843009213693952",
+    Target::Wasm
+);
 
 // IMPLEMENTATION
-fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
+
+// What a test runs its example through and what it compares the output of
+enum Target<'a> {
+    // the executable linked with the stub
+    Native,
+    Jit,
+    Vm,
+    Wasm,
+    // the C backend at an optimisation level like -O2
+    C(&'a str),
+    // the standalone executable, run with the runtime's flags
+    Built(&'a [&'a str]),
+    // the library API, running on one of its backends
+    Api(Backend),
+    // the library API's diagnostics, one line each, failing on an error
+    Check,
+    // the counts --stats reports about the lifted program
+    Stats,
+    // the passes --time-passes records for a run on the VM
+    TimePasses,
+}
+
+// Runs the example, expecting its output or a part of its error message
+fn test_example_on(f: &str, target: Target, expected: Result<&str, &str>) -> std::io::Result<()> {
+    let (stdout, result) = run_example(f, target)?;
+    match (result, expected) {
+        (Ok(()), Ok(expected_str)) => assert_eq!(stdout.trim(), expected_str),
+        (Ok(()), Err(_)) => assert!(false, "Expected a failure but got: {}", stdout.trim()),
+        (Err(e), Ok(expected_str)) => {
+            assert!(false, "Expected {}, got an error: {}", expected_str, e)
+        }
+        (Err(msg), Err(includes)) => {
+            assert!(
+                msg.contains(includes),
+                "Expected error message to include the string \"{}\" but got the error: {}",
+                includes,
+                msg
+            )
+        }
+    }
+    Ok(())
+}
+
+// The output of the example on the target and whether it failed
+fn run_example(f: &str, target: Target) -> std::io::Result<(String, Result<(), String>)> {
     use std::path::Path;
+    use std::process::Command;
     let p_name = format!("examples/{}", f);
     let path = Path::new(&p_name);
-
     let tmp_dir = tempfile::TempDir::new()?;
     let mut w = Vec::new();
-    match runner::compile_and_run_file(&path, tmp_dir.path(), &mut w) {
-        Ok(()) => {
-            let stdout = std::str::from_utf8(&w).unwrap();
-            assert_eq!(stdout.trim(), expected_str)
+
+    let result = match target {
+        Target::Native => runner::compile_and_run_file(&path, tmp_dir.path(), &mut w),
+        Target::Jit => runner::jit_run_file(&path, &mut w),
+        Target::Vm => runner::vm_run_file(&path, &mut w),
+        Target::Wasm => runner::wasm_run_file(&path, &mut w),
+        Target::C(opt) => runner::c_run_file(&path, tmp_dir.path(), opt, &mut w),
+        Target::Built(args) => {
+            let exe_fname = tmp_dir.path().join("prog");
+            if let Err(e) = runner::build_file(&path, tmp_dir.path(), &exe_fname) {
+                return Ok((String::new(), Err(format!("Failed to build: {}", e))));
+            }
+            let output = Command::new(&exe_fname).args(args).output()?;
+            let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+            let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+            return Ok((
+                stdout,
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(stderr)
+                },
+            ));
         }
-        Err(e) => {
-            assert!(false, "Expected {}, got an error: {}", expected_str, e)
+        Target::Api(backend) => {
+            let source = std::fs::read_to_string(path)?;
+            let mut stderr = Vec::new();
+            let result = match api::run(&source, backend, &mut w, &mut stderr) {
+                Ok(compiled) if compiled.output && stderr.is_empty() => Ok(()),
+                Ok(_) => Err(String::from_utf8_lossy(&stderr).into_owned()),
+                Err(diagnostics) => Err(format!("{:?}", diagnostics)),
+            };
+            return Ok((String::from_utf8_lossy(&w).into_owned(), result));
         }
-    }
-    Ok(())
+        Target::Check => {
+            let source = std::fs::read_to_string(path)?;
+            let diagnostics = api::check(&source);
+            let lines: Vec<String> = diagnostics
+                .iter()
+                .map(|d| {
+                    let line = d.span.map_or(0, |s| s.start_line);
+                    format!("{:?} at line {}: {}", d.severity, line, d.message)
+                })
+                .collect();
+            let failed = diagnostics.iter().any(|d| d.severity == Severity::Error);
+            let output = lines.join("\n");
+            return Ok(if failed {
+                (String::new(), Err(output))
+            } else {
+                (output, Ok(()))
+            });
+        }
+        Target::Stats => {
+            return Ok(match runner::stats_file(&path) {
+                Ok(stats) => {
+                    assert!(stats.instructions > 0 && stats.stack_slots > 0);
+                    assert!(stats.runtime_checks > 0);
+                    let output = format!(
+                        "lifted functions: {}\nlifted methods: {}\nclasses: {}",
+                        stats.lifted_functions, stats.lifted_methods, stats.classes
+                    );
+                    (output, Ok(()))
+                }
+                Err(e) => (String::new(), Err(e.to_string())),
+            });
+        }
+        Target::TimePasses => {
+            timing::start_recording();
+            let result = runner::vm_run_file(&path, &mut Vec::new());
+            let names: Vec<String> = timing::take().into_iter().map(|(name, _)| name).collect();
+            return Ok((names.join("\n"), result.map_err(|e| e.to_string())));
+        }
+    };
+    let stdout = String::from_utf8_lossy(&w).into_owned();
+    Ok((stdout, result.map_err(|e| e.to_string())))
 }
 
-fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
+fn test_example_nasm(f: &str) -> std::io::Result<()> {
     use std::path::Path;
+    use std::process::Command;
+    // the comparison is skipped on machines without nasm
+    if Command::new("nasm").arg("-v").output().is_err() {
+        eprintln!("skipping {}: nasm is not installed", f);
+        return Ok(());
+    }
     let p_name = format!("examples/{}", f);
     let path = Path::new(&p_name);
 
     let tmp_dir = tempfile::TempDir::new()?;
-    let mut w_run = Vec::new();
-    match runner::compile_and_run_file(
-        &Path::new(&format!("examples/{}", f)),
-        tmp_dir.path(),
-        &mut w_run,
-    ) {
-        Ok(()) => {
-            let stdout = std::str::from_utf8(&w_run).unwrap();
-            assert!(false, "Expected a failure but got: {}", stdout.trim())
+    let ours = match runner::object_file(&path) {
+        Ok(obj) => obj,
+        Err(e) => panic!("Failed to write the object file: {}", e),
+    };
+    let nasm = match runner::nasm_object_file(&path, tmp_dir.path()) {
+        Ok(obj) => obj,
+        Err(e) => panic!("Failed to assemble with nasm: {}", e),
+    };
+    let (ours, nasm) = (Elf::new(&ours), Elf::new(&nasm));
+    assert_eq!(ours.section(".text"), nasm.section(".text"));
+    assert_eq!(ours.relocations(), nasm.relocations());
+    assert_eq!(ours.symbols(), nasm.symbols());
+    Ok(())
+}
+
+// Just enough of an ELF64 object to compare two of them by section names
+// and symbol names, whatever order their sections and symbols are in
+struct Elf<'a> {
+    obj: &'a [u8],
+    // the name, offset, size and link of each section
+    sections: Vec<(String, usize, usize, usize)>,
+}
+
+impl<'a> Elf<'a> {
+    fn new(obj: &'a [u8]) -> Elf<'a> {
+        let mut elf = Elf {
+            obj,
+            sections: Vec::new(),
+        };
+        let shoff = elf.u64_at(0x28);
+        let header = |idx: usize| shoff + idx * elf.u16_at(0x3a);
+        let names = elf.u64_at(header(elf.u16_at(0x3e)) + 0x18);
+        let sections = (0..elf.u16_at(0x3c))
+            .map(|idx| {
+                let h = header(idx);
+                (
+                    elf.str_at(names + elf.u32_at(h)),
+                    elf.u64_at(h + 0x18),
+                    elf.u64_at(h + 0x20),
+                    elf.u32_at(h + 0x28),
+                )
+            })
+            .collect();
+        elf.sections = sections;
+        elf
+    }
+
+    fn u16_at(&self, i: usize) -> usize {
+        u16::from_le_bytes(self.obj[i..i + 2].try_into().unwrap()) as usize
+    }
+
+    fn u32_at(&self, i: usize) -> usize {
+        u32::from_le_bytes(self.obj[i..i + 4].try_into().unwrap()) as usize
+    }
+
+    fn u64_at(&self, i: usize) -> usize {
+        u64::from_le_bytes(self.obj[i..i + 8].try_into().unwrap()) as usize
+    }
+
+    fn str_at(&self, i: usize) -> String {
+        let len = self.obj[i..].iter().position(|b| *b == 0).unwrap();
+        String::from_utf8_lossy(&self.obj[i..i + len]).into_owned()
+    }
+
+    fn find(&self, name: &str) -> &(String, usize, usize, usize) {
+        match self.sections.iter().find(|(n, ..)| n == name) {
+            Some(section) => section,
+            None => panic!("The object file has no {} section", name),
         }
-        Err(e) => {
-            let msg = format!("{}", e);
-            assert!(
-                msg.contains(includes),
-                "Expected error message to include the string \"{}\" but got the error: {}",
-                includes,
-                msg
-            )
+    }
+
+    fn section(&self, name: &str) -> &[u8] {
+        let (_, offset, size, _) = self.find(name);
+        &self.obj[*offset..offset + size]
+    }
+
+    // The name of a symbol, a section symbol goes by its section's name
+    fn symbol_name(&self, idx: usize) -> String {
+        let (_, symtab, _, strtab) = self.find(".symtab");
+        let s = symtab + idx * 24;
+        if self.obj[s + 4] & 0xf == 3 {
+            self.sections[self.u16_at(s + 6)].0.clone()
+        } else {
+            self.str_at(self.sections[*strtab].1 + self.u32_at(s))
         }
     }
-    Ok(())
+
+    // The offset, type, symbol and addend of each relocation of .text
+    fn relocations(&self) -> Vec<(usize, usize, String, i64)> {
+        let rela = self.section(".rela.text");
+        (0..rela.len() / 24)
+            .map(|idx| {
+                let r = self.find(".rela.text").1 + idx * 24;
+                let info = self.u64_at(r + 8);
+                let addend = self.u64_at(r + 16) as i64;
+                (
+                    self.u64_at(r),
+                    info & 0xffff_ffff,
+                    self.symbol_name(info >> 32),
+                    addend,
+                )
+            })
+            .collect()
+    }
+
+    // The name, binding, section and value of each symbol, leaving out the
+    // null symbol and the one naming the source file
+    fn symbols(&self) -> Vec<(String, u8, String, usize)> {
+        let (_, symtab, size, _) = self.find(".symtab");
+        let mut symbols: Vec<_> = (1..size / 24)
+            .filter(|idx| self.obj[symtab + idx * 24 + 4] & 0xf != 4)
+            .map(|idx| {
+                let s = symtab + idx * 24;
+                let section = match self.u16_at(s + 6) {
+                    0 => String::new(),
+                    shndx => self.sections[shndx].0.clone(),
+                };
+                (
+                    self.symbol_name(idx),
+                    self.obj[s + 4] >> 4,
+                    section,
+                    self.u64_at(s + 8),
+                )
+            })
+            .collect();
+        symbols.sort();
+        symbols
+    }
 }